@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
 use std::sync::Arc;
-use amos_core::{ForgeNeuralNetwork, EventBus, SystemEvent, HormonalState, Logger};
+use amos_core::{ForgeNeuralNetwork, EventBus, EventMask, SystemEvent, HormonalState, Logger};
 use serde::{Serialize, Deserialize};
 use anyhow::Result;
 
@@ -17,7 +17,46 @@ pub enum AgentState {
     Terminated,
 }
 
+impl AgentState {
+    /// Whether the lifecycle allows moving from this state to `next`
+    pub fn can_transition_to(&self, next: &AgentState) -> bool {
+        use AgentState::*;
+        
+        if self == next {
+            return true;
+        }
+        
+        match self {
+            Uninitialized => matches!(next, Initializing | Terminating | Terminated),
+            Initializing => matches!(next, Active | Uninitialized | Terminating | Terminated),
+            Active => matches!(next, Initializing | Processing | Suspended | Terminating | Terminated),
+            Processing => matches!(next, Active | Suspended | Terminating | Terminated),
+            Suspended => matches!(next, Active | Initializing | Terminating | Terminated),
+            Terminating => matches!(next, Terminated),
+            Terminated => false,
+        }
+    }
+}
+
+/// Returned by `BaseAgent::transition_state` when the lifecycle forbids a move
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidStateTransition {
+    pub agent_id: Uuid,
+    pub from: AgentState,
+    pub to: AgentState,
+}
+
+impl std::fmt::Display for InvalidStateTransition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "illegal state transition for agent {}: {:?} -> {:?}", self.agent_id, self.from, self.to)
+    }
+}
+
+impl std::error::Error for InvalidStateTransition {}
+
+/// Named in snake_case (e.g. `pattern_recognition`) wherever it appears as text
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum AgentCapability {
     PatternRecognition,
     NeuralOptimization,
@@ -28,6 +67,36 @@ pub enum AgentCapability {
     Generation,
 }
 
+impl std::fmt::Display for AgentCapability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match serde_json::to_value(self) {
+            Ok(serde_json::Value::String(name)) => f.write_str(&name),
+            _ => Err(std::fmt::Error),
+        }
+    }
+}
+
+impl std::str::FromStr for AgentCapability {
+    type Err = UnknownCapability;
+    
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        serde_json::from_value(serde_json::Value::String(name.to_string()))
+            .map_err(|_| UnknownCapability(name.to_string()))
+    }
+}
+
+/// Returned when parsing a name that isn't an `AgentCapability`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownCapability(pub String);
+
+impl std::fmt::Display for UnknownCapability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unknown capability '{}'", self.0)
+    }
+}
+
+impl std::error::Error for UnknownCapability {}
+
 #[async_trait]
 pub trait CognitiveAgent: Send + Sync {
     fn id(&self) -> Uuid;
@@ -42,6 +111,151 @@ pub trait CognitiveAgent: Send + Sync {
     
     fn state(&self) -> AgentState;
     async fn receive_event(&mut self, event: SystemEvent) -> Result<()>;
+    
+    /// Event variants this agent wants delivered; everything by default
+    fn subscribed_events(&self) -> EventMask {
+        EventMask::ALL
+    }
+    
+    /// Counters the agent keeps about its own work, read from its `BaseAgent`
+    fn metrics(&self) -> AgentMetrics {
+        self.base().map(|base| base.metrics.clone()).unwrap_or_default()
+    }
+    
+    /// Whether the agent can take work right now, e.g. its dependencies are reachable
+    async fn health_check(&self) -> Result<()> {
+        Ok(())
+    }
+    
+    /// Resources the agent is using right now, for the PerformanceGuardian to aggregate
+    fn resource_usage(&self) -> ResourceUsage {
+        ResourceUsage::default()
+    }
+    
+    /// Do an orchestrated task's work directly, e.g. by handing it to a nested swarm.
+    /// `None` leaves the work to the orchestrator's own processing.
+    async fn process_task(&self, _description: &str, _input: serde_json::Value) -> Result<Option<serde_json::Value>> {
+        Ok(None)
+    }
+    
+    /// Snapshot of the agent's internal cognitive state for debugging tools;
+    /// `None` for agents that don't expose one
+    fn introspect(&self) -> Option<serde_json::Value> {
+        None
+    }
+    
+    /// The `BaseAgent` holding this agent's state and counters, if it has one
+    fn base(&self) -> Option<&BaseAgent> {
+        None
+    }
+    
+    /// The `BaseAgent` holding this agent's capabilities, if it has one to change
+    fn base_mut(&mut self) -> Option<&mut BaseAgent> {
+        None
+    }
+    
+    /// Unlock a capability at runtime, e.g. after shadow progression
+    async fn grant_capability(&mut self, capability: AgentCapability) -> Result<()> {
+        match self.base_mut() {
+            Some(base) => base.grant_capability(capability).await,
+            None => anyhow::bail!("{} cannot change capabilities to grant {:?}", self.name(), capability),
+        }
+    }
+    
+    async fn revoke_capability(&mut self, capability: AgentCapability) -> Result<()> {
+        match self.base_mut() {
+            Some(base) => base.revoke_capability(capability).await,
+            None => anyhow::bail!("{} cannot change capabilities to revoke {:?}", self.name(), capability),
+        }
+    }
+}
+
+/// Hand an event to an agent if it subscribed to that variant, returning whether it was delivered
+pub async fn deliver_event(agent: &mut dyn CognitiveAgent, event: SystemEvent) -> Result<bool> {
+    if !agent.subscribed_events().matches(&event) {
+        return Ok(false);
+    }
+    
+    agent.receive_event(event).await?;
+    Ok(true)
+}
+
+/// Run one `process()` cycle, counting a failed cycle as an error in the agent's metrics
+pub async fn process_cycle(agent: &mut dyn CognitiveAgent) -> Result<()> {
+    let result = agent.process().await;
+    if result.is_err() {
+        if let Some(base) = agent.base_mut() {
+            base.metrics.record_error();
+        }
+    }
+    result
+}
+
+/// Resources an agent reports using
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceUsage {
+    /// CPU time used over the last second
+    pub cpu_ms: u64,
+    pub memory_bytes: u64,
+    pub tasks_in_flight: u32,
+}
+
+impl std::ops::Add for ResourceUsage {
+    type Output = ResourceUsage;
+    
+    fn add(self, other: ResourceUsage) -> ResourceUsage {
+        ResourceUsage {
+            cpu_ms: self.cpu_ms.saturating_add(other.cpu_ms),
+            memory_bytes: self.memory_bytes.saturating_add(other.memory_bytes),
+            tasks_in_flight: self.tasks_in_flight.saturating_add(other.tasks_in_flight),
+        }
+    }
+}
+
+impl std::iter::Sum for ResourceUsage {
+    fn sum<I: Iterator<Item = ResourceUsage>>(iter: I) -> ResourceUsage {
+        iter.fold(ResourceUsage::default(), |total, usage| total + usage)
+    }
+}
+
+/// Work counters an agent reports about itself
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AgentMetrics {
+    pub processed: u64,
+    pub errors: u64,
+    /// Mean of the confidences reported alongside processing cycles
+    pub avg_confidence: f64,
+    pub confidence_samples: u64,
+    pub last_processed: Option<DateTime<Utc>>,
+}
+
+impl AgentMetrics {
+    pub fn record_processed(&mut self, confidence: Option<f64>) {
+        self.processed += 1;
+        self.last_processed = Some(Utc::now());
+        
+        if let Some(confidence) = confidence {
+            self.confidence_samples += 1;
+            self.avg_confidence += (confidence - self.avg_confidence) / self.confidence_samples as f64;
+        }
+    }
+    
+    pub fn record_error(&mut self) {
+        self.errors += 1;
+    }
+    
+    /// Fold in counters gathered elsewhere for the same agent
+    pub fn merge(&mut self, other: &AgentMetrics) {
+        let samples = self.confidence_samples + other.confidence_samples;
+        if samples > 0 {
+            self.avg_confidence = (self.avg_confidence * self.confidence_samples as f64
+                + other.avg_confidence * other.confidence_samples as f64) / samples as f64;
+        }
+        self.confidence_samples = samples;
+        self.processed += other.processed;
+        self.errors += other.errors;
+        self.last_processed = self.last_processed.max(other.last_processed);
+    }
 }
 
 pub struct BaseAgent {
@@ -55,11 +269,12 @@ pub struct BaseAgent {
     pub created_at: DateTime<Utc>,
     pub last_active: DateTime<Utc>,
     pub logger: Logger,
+    pub metrics: AgentMetrics,
 }
 
 impl BaseAgent {
     pub fn new(name: String, capabilities: Vec<AgentCapability>) -> Self {
-        let id = Uuid::new_v4();
+        let id = amos_core::ids::next_id();
         let now = Utc::now();
         
         Self {
@@ -73,10 +288,21 @@ impl BaseAgent {
             created_at: now,
             last_active: now,
             logger: Logger::new(&format!("agent.{}", name)),
+            metrics: AgentMetrics::default(),
         }
     }
     
     pub async fn transition_state(&mut self, new_state: AgentState) -> Result<()> {
+        if !self.state.can_transition_to(&new_state) {
+            let error = InvalidStateTransition {
+                agent_id: self.id,
+                from: self.state.clone(),
+                to: new_state,
+            };
+            self.logger.warn(&error.to_string());
+            return Err(error.into());
+        }
+        
         let old_state = self.state.clone();
         self.state = new_state.clone();
         self.last_active = Utc::now();
@@ -96,6 +322,46 @@ impl BaseAgent {
     pub fn update_activity(&mut self) {
         self.last_active = Utc::now();
     }
+    
+    /// Count a finished processing cycle, with the agent's confidence in it if it has one
+    pub fn record_processed(&mut self, confidence: Option<f64>) {
+        self.metrics.record_processed(confidence);
+        self.update_activity();
+    }
+    
+    /// Add a capability, announcing the change unless the agent already had it
+    pub async fn grant_capability(&mut self, capability: AgentCapability) -> Result<()> {
+        if self.capabilities.contains(&capability) {
+            return Ok(());
+        }
+        
+        self.capabilities.push(capability.clone());
+        self.logger.info(&format!("Granted capability {:?}", capability));
+        self.publish_capability_change(vec![capability.to_string()], Vec::new()).await;
+        Ok(())
+    }
+    
+    /// Remove a capability, announcing the change if the agent had it
+    pub async fn revoke_capability(&mut self, capability: AgentCapability) -> Result<()> {
+        let Some(index) = self.capabilities.iter().position(|held| *held == capability) else {
+            return Ok(());
+        };
+        
+        self.capabilities.remove(index);
+        self.logger.info(&format!("Revoked capability {:?}", capability));
+        self.publish_capability_change(Vec::new(), vec![capability.to_string()]).await;
+        Ok(())
+    }
+    
+    async fn publish_capability_change(&self, added: Vec<String>, removed: Vec<String>) {
+        if let Some(event_bus) = &self.event_bus {
+            event_bus.publish(SystemEvent::AgentCapabilitiesChanged {
+                agent_id: self.id,
+                added,
+                removed,
+            }).await;
+        }
+    }
 }
 
 pub struct AgentContext {
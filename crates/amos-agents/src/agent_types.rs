@@ -0,0 +1,75 @@
+use std::sync::{Arc, OnceLock};
+use uuid::Uuid;
+use amos_core::{with_id_generator_sync, ReservedIdGenerator};
+use crate::{
+    CognitiveAgent, AgentCapability,
+    TrafficSeer, PathwaySculptor, MemoryWeaver, CognitionAlchemist,
+    LearningOracle, MeshHarmonizer, ConsciousnessEmergent, PerformanceGuardian, Optimizer,
+};
+
+pub type AgentFactory = fn() -> Box<dyn CognitiveAgent>;
+
+/// A spawnable agent type and the capabilities its agents start with
+pub struct AgentTypeInfo {
+    pub type_name: &'static str,
+    pub agent_name: String,
+    pub capabilities: Vec<AgentCapability>,
+    factory: AgentFactory,
+}
+
+impl AgentTypeInfo {
+    fn new(type_name: &'static str, factory: AgentFactory) -> Self {
+        // Capabilities are read from a prototype so they never drift from the agent itself
+        let prototype = factory();
+        
+        Self {
+            type_name,
+            agent_name: prototype.name().to_string(),
+            capabilities: prototype.capabilities(),
+            factory,
+        }
+    }
+    
+    pub fn create(&self) -> Box<dyn CognitiveAgent> {
+        (self.factory)()
+    }
+    
+    /// Create an agent that takes `id` rather than a fresh one, e.g. when restoring it
+    pub fn create_with_id(&self, id: Uuid) -> Box<dyn CognitiveAgent> {
+        with_id_generator_sync(Arc::new(ReservedIdGenerator::new(id)), self.factory)
+    }
+}
+
+/// All agent types known to the system
+pub fn agent_types() -> &'static [AgentTypeInfo] {
+    static AGENT_TYPES: OnceLock<Vec<AgentTypeInfo>> = OnceLock::new();
+    
+    AGENT_TYPES.get_or_init(|| {
+        vec![
+            AgentTypeInfo::new("traffic_seer", || Box::new(TrafficSeer::new())),
+            AgentTypeInfo::new("pathway_sculptor", || Box::new(PathwaySculptor::new())),
+            AgentTypeInfo::new("memory_weaver", || Box::new(MemoryWeaver::new())),
+            AgentTypeInfo::new("cognition_alchemist", || Box::new(CognitionAlchemist::new())),
+            AgentTypeInfo::new("learning_oracle", || Box::new(LearningOracle::new())),
+            AgentTypeInfo::new("mesh_harmonizer", || Box::new(MeshHarmonizer::new())),
+            AgentTypeInfo::new("consciousness_emergent", || Box::new(ConsciousnessEmergent::new())),
+            AgentTypeInfo::new("performance_guardian", || Box::new(PerformanceGuardian::new())),
+            AgentTypeInfo::new("optimizer", || Box::new(Optimizer::new())),
+        ]
+    })
+}
+
+/// Look up an agent type by its registered name, e.g. `traffic_seer`
+pub fn find_agent_type(type_name: &str) -> Option<&'static AgentTypeInfo> {
+    agent_types().iter().find(|info| info.type_name == type_name)
+}
+
+/// Look up the type of a running agent from its name, e.g. `TrafficSeer`
+pub fn find_agent_type_by_agent_name(agent_name: &str) -> Option<&'static AgentTypeInfo> {
+    agent_types().iter().find(|info| info.agent_name == agent_name)
+}
+
+/// Create a new, uninitialized agent of the given type
+pub fn create_agent(type_name: &str) -> Option<Box<dyn CognitiveAgent>> {
+    find_agent_type(type_name).map(|info| info.create())
+}
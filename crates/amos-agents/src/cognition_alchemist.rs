@@ -148,6 +148,14 @@ impl CognitiveAgent for CognitionAlchemist {
         self.base.capabilities.clone()
     }
     
+    fn base(&self) -> Option<&BaseAgent> {
+        Some(&self.base)
+    }
+    
+    fn base_mut(&mut self) -> Option<&mut BaseAgent> {
+        Some(&mut self.base)
+    }
+    
     async fn initialize(&mut self, neural_network: Arc<ForgeNeuralNetwork>, event_bus: Arc<EventBus>) -> Result<()> {
         self.base.transition_state(AgentState::Initializing).await?;
         
@@ -188,6 +196,7 @@ impl CognitiveAgent for CognitionAlchemist {
             }
         }
         
+        self.base.record_processed(None);
         self.base.transition_state(AgentState::Active).await?;
         Ok(())
     }
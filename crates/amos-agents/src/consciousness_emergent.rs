@@ -73,6 +73,8 @@ impl ConsciousnessEmergent {
         }
     }
     
+    /// Run one introspection cycle. A plain `value.introspect()` call picks
+    /// `CognitiveAgent::introspect`, so call this on a `&mut` or by its path.
     pub async fn introspect(&mut self) -> Result<()> {
         self.introspection_cycles += 1;
         self.meta_state.introspection_depth = (self.introspection_cycles as f64 / 10.0).min(10.0) as u32;
@@ -120,6 +122,18 @@ impl ConsciousnessEmergent {
         (dopamine + serotonin) / (1.0 + cortisol)
     }
     
+    /// Current awareness and self-model, as reported through `CognitiveAgent::introspect`
+    pub fn introspection_report(&self) -> serde_json::Value {
+        serde_json::json!({
+            "awareness_level": self.meta_state.awareness_level,
+            "introspection_depth": self.meta_state.introspection_depth,
+            "self_model_accuracy": self.meta_state.self_model_accuracy,
+            "attention_focus": self.meta_state.attention_focus,
+            "self_model": self.self_model,
+            "observation_count": self.self_observations.len(),
+        })
+    }
+    
     fn calculate_awareness(&self) -> f64 {
         let observation_richness = (self.self_observations.len() as f64 / 100.0).min(1.0);
         let model_confidence = self.meta_state.self_model_accuracy;
@@ -183,6 +197,14 @@ impl CognitiveAgent for ConsciousnessEmergent {
         self.base.capabilities.clone()
     }
     
+    fn base(&self) -> Option<&BaseAgent> {
+        Some(&self.base)
+    }
+    
+    fn base_mut(&mut self) -> Option<&mut BaseAgent> {
+        Some(&mut self.base)
+    }
+    
     async fn initialize(&mut self, neural_network: Arc<ForgeNeuralNetwork>, event_bus: Arc<EventBus>) -> Result<()> {
         self.base.transition_state(AgentState::Initializing).await?;
         
@@ -218,6 +240,7 @@ impl CognitiveAgent for ConsciousnessEmergent {
             self.self_observations.drain(0..500);
         }
         
+        self.base.record_processed(Some(self.meta_state.self_model_accuracy));
         self.base.transition_state(AgentState::Active).await?;
         Ok(())
     }
@@ -273,6 +296,10 @@ impl CognitiveAgent for ConsciousnessEmergent {
         self.base.update_activity();
         Ok(())
     }
+    
+    fn introspect(&self) -> Option<serde_json::Value> {
+        Some(self.introspection_report())
+    }
 }
 
 impl Default for ConsciousnessEmergent {
@@ -32,11 +32,28 @@ pub struct LearningMetrics {
     pub generalization_score: f64,
 }
 
+/// A switch of the active learning strategy
+#[derive(Debug, Clone, PartialEq)]
+pub struct StrategyChange {
+    pub from: Uuid,
+    pub to: Uuid,
+    pub reason: String,
+}
+
+/// One adaptation step in the oracle's learning history
+#[derive(Debug, Clone)]
+pub struct LearningRecord {
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    pub strategy_id: Uuid,
+    pub metrics: LearningMetrics,
+    pub strategy_change: Option<StrategyChange>,
+}
+
 pub struct LearningOracle {
     base: BaseAgent,
     strategies: HashMap<Uuid, LearningStrategy>,
     active_strategy: Option<Uuid>,
-    learning_history: Vec<(chrono::DateTime<chrono::Utc>, LearningMetrics)>,
+    learning_history: Vec<LearningRecord>,
     dopamine_threshold: f64,
     cortisol_threshold: f64,
 }
@@ -92,6 +109,18 @@ impl LearningOracle {
         self.active_strategy = Some(reinforcement.id);
     }
     
+    pub fn active_strategy(&self) -> Option<Uuid> {
+        self.active_strategy
+    }
+    
+    pub fn strategy(&self, strategy_id: Uuid) -> Option<&LearningStrategy> {
+        self.strategies.get(&strategy_id)
+    }
+    
+    pub fn learning_history(&self) -> &[LearningRecord] {
+        &self.learning_history
+    }
+    
     pub fn select_strategy(&mut self, context: LearningContext) -> Option<Uuid> {
         let best_strategy = self.strategies
             .iter()
@@ -107,6 +136,28 @@ impl LearningOracle {
         }
     }
     
+    /// Move to the most effective strategy in any context, if it beats the current one
+    fn switch_to_best_strategy(&mut self, current: Uuid, reason: String) -> Option<StrategyChange> {
+        let current_effectiveness = self.strategies.get(&current)?.effectiveness;
+        let (best_id, best) = self.strategies
+            .iter()
+            .filter(|(id, _)| **id != current)
+            .max_by(|(_, a), (_, b)| a.effectiveness.partial_cmp(&b.effectiveness).unwrap())?;
+        
+        if best.effectiveness <= current_effectiveness {
+            return None;
+        }
+        
+        self.base.logger.info(&format!("Switching strategy to {}: {}", best.name, reason));
+        let change = StrategyChange {
+            from: current,
+            to: *best_id,
+            reason,
+        };
+        self.active_strategy = Some(change.to);
+        Some(change)
+    }
+    
     pub async fn adapt_learning(&mut self) -> Result<()> {
         if let Some(strategy_id) = self.active_strategy {
             // Calculate current metrics
@@ -125,6 +176,8 @@ impl LearningOracle {
                 }
             }
             
+            let mut strategy_change = None;
+            
             // Trigger dopamine burst for successful learning
             if metrics.success_rate > 0.8 {
                 if let Some(event_bus) = &self.base.event_bus {
@@ -134,14 +187,36 @@ impl LearningOracle {
                     }).await;
                 }
             } else if metrics.success_rate < 0.3 {
-                // Consider switching strategies
+                // Consider switching strategies, first within the context and then across contexts
                 if let Some(context) = strategy_context {
                     self.select_strategy(context);
                 }
+                let reason = format!("Success rate {:.2} below 0.30", metrics.success_rate);
+                strategy_change = match self.active_strategy {
+                    Some(selected) if selected != strategy_id => Some(StrategyChange {
+                        from: strategy_id,
+                        to: selected,
+                        reason,
+                    }),
+                    _ => self.switch_to_best_strategy(strategy_id, reason),
+                };
+            }
+            
+            if let (Some(change), Some(event_bus)) = (&strategy_change, &self.base.event_bus) {
+                event_bus.publish(SystemEvent::LearningStrategyChanged {
+                    from: change.from,
+                    to: change.to,
+                    reason: change.reason.clone(),
+                }).await;
             }
             
             // Store metrics
-            self.learning_history.push((chrono::Utc::now(), metrics));
+            self.learning_history.push(LearningRecord {
+                timestamp: chrono::Utc::now(),
+                strategy_id,
+                metrics,
+                strategy_change,
+            });
         }
         
         Ok(())
@@ -194,6 +269,14 @@ impl CognitiveAgent for LearningOracle {
         self.base.capabilities.clone()
     }
     
+    fn base(&self) -> Option<&BaseAgent> {
+        Some(&self.base)
+    }
+    
+    fn base_mut(&mut self) -> Option<&mut BaseAgent> {
+        Some(&mut self.base)
+    }
+    
     async fn initialize(&mut self, neural_network: Arc<ForgeNeuralNetwork>, event_bus: Arc<EventBus>) -> Result<()> {
         self.base.transition_state(AgentState::Initializing).await?;
         
@@ -223,6 +306,7 @@ impl CognitiveAgent for LearningOracle {
             self.learning_history.drain(0..50);
         }
         
+        self.base.record_processed(None);
         self.base.transition_state(AgentState::Active).await?;
         Ok(())
     }
@@ -282,6 +366,23 @@ impl CognitiveAgent for LearningOracle {
         self.base.update_activity();
         Ok(())
     }
+    
+    fn introspect(&self) -> Option<serde_json::Value> {
+        let active_strategy = self.active_strategy
+            .and_then(|id| self.strategies.get(&id))
+            .map(|strategy| serde_json::json!({
+                "id": strategy.id,
+                "name": strategy.name,
+                "effectiveness": strategy.effectiveness,
+                "context": strategy.context,
+            }));
+        
+        Some(serde_json::json!({
+            "active_strategy": active_strategy,
+            "strategy_count": self.strategies.len(),
+            "learning_history_len": self.learning_history.len(),
+        }))
+    }
 }
 
 impl Default for LearningOracle {
@@ -7,7 +7,10 @@ pub mod learning_oracle;
 pub mod mesh_harmonizer;
 pub mod consciousness_emergent;
 pub mod performance_guardian;
+pub mod optimizer;
 pub mod registry;
+pub mod agent_types;
+pub mod scheduler;
 
 pub use agent::*;
 pub use traffic_seer::*;
@@ -18,4 +21,7 @@ pub use learning_oracle::*;
 pub use mesh_harmonizer::*;
 pub use consciousness_emergent::*;
 pub use performance_guardian::*;
-pub use registry::*;
\ No newline at end of file
+pub use optimizer::*;
+pub use registry::*;
+pub use agent_types::*;
+pub use scheduler::*;
\ No newline at end of file
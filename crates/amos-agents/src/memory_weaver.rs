@@ -8,6 +8,12 @@ use anyhow::Result;
 use serde::{Serialize, Deserialize};
 use crate::{CognitiveAgent, BaseAgent, AgentState, AgentCapability};
 
+/// Decay steps a memory of importance 1.0, never accessed, takes to fall to 1/e retention
+pub const DEFAULT_MEMORY_STABILITY: f64 = 10.0;
+
+/// Memories whose retention falls below this are forgotten
+pub const DEFAULT_RETENTION_FLOOR: f64 = 0.1;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EpisodicMemory {
     pub id: Uuid,
@@ -17,6 +23,9 @@ pub struct EpisodicMemory {
     pub access_count: u32,
     pub last_accessed: DateTime<Utc>,
     pub associated_nodes: Vec<Uuid>,
+    /// Decay steps since the memory was stored or last accessed
+    #[serde(default)]
+    pub age: u32,
 }
 
 impl EpisodicMemory {
@@ -30,16 +39,28 @@ impl EpisodicMemory {
             access_count: 0,
             last_accessed: now,
             associated_nodes: Vec::new(),
+            age: 0,
         }
     }
     
+    /// Recall the memory, which restarts its forgetting curve
     pub fn access(&mut self) {
         self.access_count += 1;
         self.last_accessed = Utc::now();
+        self.age = 0;
     }
     
-    pub fn decay(&mut self, decay_rate: f64) {
-        self.importance = (self.importance - decay_rate).max(0.0);
+    /// Ebbinghaus retention `exp(-t / (k * importance * (1 + access_count)))`, where `t` is
+    /// the memory's age and `k` the stability; important, often recalled memories fade slowest
+    pub fn retention(&self, stability: f64) -> f64 {
+        if self.age == 0 {
+            return 1.0;
+        }
+        let strength = stability * self.importance * (1.0 + self.access_count as f64);
+        if strength <= 0.0 {
+            return 0.0;
+        }
+        (-(self.age as f64) / strength).exp()
     }
 }
 
@@ -49,7 +70,8 @@ pub struct MemoryWeaver {
     working_memory: VecDeque<Uuid>,
     consolidation_threshold: f64,
     max_working_memory: usize,
-    memory_decay_rate: f64,
+    memory_stability: f64,
+    retention_floor: f64,
 }
 
 impl MemoryWeaver {
@@ -66,10 +88,18 @@ impl MemoryWeaver {
             working_memory: VecDeque::with_capacity(10),
             consolidation_threshold: 0.7,
             max_working_memory: 10,
-            memory_decay_rate: 0.01,
+            memory_stability: DEFAULT_MEMORY_STABILITY,
+            retention_floor: DEFAULT_RETENTION_FLOOR,
         }
     }
     
+    /// Tune how slowly memories fade and how faded they get before being forgotten
+    pub fn with_forgetting_curve(mut self, stability: f64, retention_floor: f64) -> Self {
+        self.memory_stability = stability;
+        self.retention_floor = retention_floor;
+        self
+    }
+    
     pub fn store_memory(&mut self, content: serde_json::Value, importance: f64) -> Uuid {
         let memory = EpisodicMemory::new(content, importance);
         let memory_id = memory.id;
@@ -105,12 +135,12 @@ impl MemoryWeaver {
             if memory.importance >= self.consolidation_threshold {
                 // Create neural pathways for important memories
                 if let Some(network) = &self.base.neural_network {
-                    let memory_node = network.add_node_sync(NodeType::Memory);
+                    let memory_node = network.add_node(NodeType::Memory).await;
                     memory.associated_nodes.push(memory_node);
                     
                     // Connect to other memory nodes
                     for &other_node in &memory.associated_nodes[..memory.associated_nodes.len()-1] {
-                        network.create_pathway_sync(memory_node, other_node, memory.importance);
+                        network.create_pathway(memory_node, other_node, memory.importance).await;
                     }
                 }
                 
@@ -121,14 +151,16 @@ impl MemoryWeaver {
         Ok(consolidated)
     }
     
+    /// Age every memory by one step and forget those whose retention fell below the floor
     pub fn apply_decay(&mut self) {
-        let decay_rate = self.memory_decay_rate;
+        let (stability, floor) = (self.memory_stability, self.retention_floor);
         for memory in self.episodic_store.values_mut() {
-            memory.decay(decay_rate);
+            memory.age = memory.age.saturating_add(1);
         }
         
-        // Remove memories with zero importance
-        self.episodic_store.retain(|_, memory| memory.importance > 0.0);
+        self.episodic_store.retain(|_, memory| memory.retention(stability) >= floor);
+        let store = &self.episodic_store;
+        self.working_memory.retain(|id| store.contains_key(id));
     }
     
     pub fn search_memories(&self, predicate: impl Fn(&EpisodicMemory) -> bool) -> Vec<Uuid> {
@@ -154,6 +186,14 @@ impl CognitiveAgent for MemoryWeaver {
         self.base.capabilities.clone()
     }
     
+    fn base(&self) -> Option<&BaseAgent> {
+        Some(&self.base)
+    }
+    
+    fn base_mut(&mut self) -> Option<&mut BaseAgent> {
+        Some(&mut self.base)
+    }
+    
     async fn initialize(&mut self, neural_network: Arc<ForgeNeuralNetwork>, event_bus: Arc<EventBus>) -> Result<()> {
         self.base.transition_state(AgentState::Initializing).await?;
         
@@ -185,6 +225,7 @@ impl CognitiveAgent for MemoryWeaver {
             self.base.logger.info(&format!("Consolidated {} memories", consolidated.len()));
         }
         
+        self.base.record_processed(None);
         self.base.transition_state(AgentState::Active).await?;
         Ok(())
     }
@@ -217,6 +258,13 @@ impl CognitiveAgent for MemoryWeaver {
                 // Track memory storage events
                 self.base.logger.debug(&format!("Memory stored: {} (size: {})", memory_id, content_size));
             }
+            SystemEvent::MemoryConsolidationRequested { requester_id } => {
+                let consolidated = self.consolidate_memories().await?;
+                self.base.logger.info(&format!(
+                    "Consolidated {} memories at the request of {}",
+                    consolidated.len(), requester_id
+                ));
+            }
             SystemEvent::NeuralFired { node_id: _ } => {
                 // Could trigger memory consolidation based on neural activity
                 self.base.update_activity();
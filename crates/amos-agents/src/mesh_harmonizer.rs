@@ -65,6 +65,16 @@ impl MeshHarmonizer {
         self.base.logger.info(&format!("Registered agent: {} ({})", agent_type, agent_id));
     }
     
+    pub fn unregister_agent(&mut self, agent_id: Uuid) {
+        if self.agent_registry.remove(&agent_id).is_some() {
+            self.base.logger.info(&format!("Unregistered agent: {}", agent_id));
+        }
+    }
+    
+    pub fn registered_agents(&self) -> usize {
+        self.agent_registry.len()
+    }
+    
     pub fn update_agent_activity(&mut self, agent_id: Uuid) {
         if let Some(coord) = self.agent_registry.get_mut(&agent_id) {
             coord.last_seen = chrono::Utc::now();
@@ -188,6 +198,14 @@ impl CognitiveAgent for MeshHarmonizer {
         self.base.capabilities.clone()
     }
     
+    fn base(&self) -> Option<&BaseAgent> {
+        Some(&self.base)
+    }
+    
+    fn base_mut(&mut self) -> Option<&mut BaseAgent> {
+        Some(&mut self.base)
+    }
+    
     async fn initialize(&mut self, neural_network: Arc<ForgeNeuralNetwork>, event_bus: Arc<EventBus>) -> Result<()> {
         self.base.transition_state(AgentState::Initializing).await?;
         
@@ -214,6 +232,7 @@ impl CognitiveAgent for MeshHarmonizer {
         
         self.base.logger.info(&format!("System harmony: {:.2} (cycle: {})", harmony, self.coordination_cycles));
         
+        self.base.record_processed(Some(harmony));
         self.base.transition_state(AgentState::Active).await?;
         Ok(())
     }
@@ -260,6 +279,9 @@ impl CognitiveAgent for MeshHarmonizer {
                 
                 self.register_agent(agent_id, agent_type, capabilities);
             }
+            SystemEvent::AgentDeactivated { agent_id } => {
+                self.unregister_agent(agent_id);
+            }
             SystemEvent::NeuralFired { node_id: _ } |
             SystemEvent::PathwayStrengthened { .. } |
             SystemEvent::HormonalBurst { .. } => {
@@ -271,6 +293,17 @@ impl CognitiveAgent for MeshHarmonizer {
         
         Ok(())
     }
+    
+    fn introspect(&self) -> Option<serde_json::Value> {
+        let mut registered: Vec<Uuid> = self.agent_registry.keys().copied().collect();
+        registered.sort();
+        
+        Some(serde_json::json!({
+            "registered_agents": registered,
+            "coordination_cycles": self.coordination_cycles,
+            "harmony_threshold": self.harmony_threshold,
+        }))
+    }
 }
 
 impl Default for MeshHarmonizer {
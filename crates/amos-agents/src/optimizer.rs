@@ -0,0 +1,236 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use std::sync::Arc;
+use amos_core::{ForgeNeuralNetwork, EventBus, EventMask, SystemEvent};
+use anyhow::Result;
+use crate::{CognitiveAgent, BaseAgent, AgentState, AgentCapability, OptimizationAction, PerformanceMetrics};
+
+/// Name the Optimizer announces itself with, so the Guardian can hand over structural work
+pub const OPTIMIZER_AGENT_NAME: &str = "Optimizer";
+
+/// Outcome of one optimization cycle
+#[derive(Debug, Clone)]
+pub struct OptimizationCycle {
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    pub pathway_efficiency: f64,
+    pub actions: Vec<OptimizationAction>,
+    pub pathways_pruned: usize,
+}
+
+/// Enacts structural optimizations on the neural network based on the
+/// metrics reported by the PerformanceGuardian
+pub struct Optimizer {
+    base: BaseAgent,
+    pending_metrics: Vec<PerformanceMetrics>,
+    cycles: Vec<OptimizationCycle>,
+    efficiency_threshold: f64,
+    memory_threshold: f64,
+    pruning_threshold: f64,
+}
+
+impl Optimizer {
+    pub fn new() -> Self {
+        Self {
+            base: BaseAgent::new(
+                OPTIMIZER_AGENT_NAME.to_string(),
+                vec![
+                    AgentCapability::NeuralOptimization,
+                    AgentCapability::MemoryManagement,
+                ],
+            ),
+            pending_metrics: Vec::new(),
+            cycles: Vec::new(),
+            efficiency_threshold: 0.5,
+            memory_threshold: 0.8,
+            pruning_threshold: 0.3,
+        }
+    }
+    
+    pub fn with_pruning_threshold(mut self, threshold: f64) -> Self {
+        self.pruning_threshold = threshold;
+        self
+    }
+    
+    /// Queue metrics to act on during the next `process()`
+    pub fn observe_metrics(&mut self, metrics: PerformanceMetrics) {
+        self.pending_metrics.push(metrics);
+    }
+    
+    /// Actions warranted by a set of metrics
+    pub fn propose_actions(&self, metrics: &PerformanceMetrics) -> Vec<OptimizationAction> {
+        let mut actions = Vec::new();
+        
+        if metrics.pathway_efficiency < self.efficiency_threshold {
+            actions.push(OptimizationAction::PruneWeakPathways);
+        }
+        if metrics.memory_usage > self.memory_threshold {
+            actions.push(OptimizationAction::ConsolidateMemory);
+        }
+        
+        actions
+    }
+    
+    pub fn cycles(&self) -> &[OptimizationCycle] {
+        &self.cycles
+    }
+    
+    /// Change in reported pathway efficiency between the first and latest cycle
+    pub fn improvement(&self) -> f64 {
+        match (self.cycles.first(), self.cycles.last()) {
+            (Some(first), Some(last)) => last.pathway_efficiency - first.pathway_efficiency,
+            _ => 0.0,
+        }
+    }
+    
+    async fn enact(&self, action: &OptimizationAction) -> Result<usize> {
+        match action {
+            OptimizationAction::PruneWeakPathways => {
+                if let Some(network) = &self.base.neural_network {
+                    let before = network.pathway_count().await;
+                    network.run_synaptic_pruning(self.pruning_threshold).await;
+                    return Ok(before.saturating_sub(network.pathway_count().await));
+                }
+            }
+            OptimizationAction::ConsolidateMemory => {
+                if let Some(event_bus) = &self.base.event_bus {
+                    event_bus.publish(SystemEvent::MemoryConsolidationRequested {
+                        requester_id: self.base.id,
+                    }).await;
+                }
+            }
+            // Runtime actions stay with the PerformanceGuardian
+            _ => {}
+        }
+        
+        Ok(0)
+    }
+}
+
+#[async_trait]
+impl CognitiveAgent for Optimizer {
+    fn id(&self) -> Uuid {
+        self.base.id
+    }
+    
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+    
+    fn capabilities(&self) -> Vec<AgentCapability> {
+        self.base.capabilities.clone()
+    }
+    
+    fn base(&self) -> Option<&BaseAgent> {
+        Some(&self.base)
+    }
+    
+    fn base_mut(&mut self) -> Option<&mut BaseAgent> {
+        Some(&mut self.base)
+    }
+    
+    async fn initialize(&mut self, neural_network: Arc<ForgeNeuralNetwork>, event_bus: Arc<EventBus>) -> Result<()> {
+        self.base.transition_state(AgentState::Initializing).await?;
+        
+        self.base.neural_network = Some(neural_network);
+        self.base.event_bus = Some(event_bus.clone());
+        
+        self.base.logger.info("Optimizer initialized");
+        
+        self.base.transition_state(AgentState::Active).await?;
+        Ok(())
+    }
+    
+    async fn activate(&mut self) -> Result<()> {
+        self.base.transition_state(AgentState::Active).await?;
+        self.base.logger.info("Optimizer activated");
+        Ok(())
+    }
+    
+    async fn process(&mut self) -> Result<()> {
+        self.base.transition_state(AgentState::Processing).await?;
+        
+        for metrics in std::mem::take(&mut self.pending_metrics) {
+            let actions = self.propose_actions(&metrics);
+            let mut pathways_pruned = 0;
+            
+            for action in &actions {
+                pathways_pruned += self.enact(action).await?;
+            }
+            
+            if !actions.is_empty() {
+                self.base.logger.info(&format!(
+                    "Efficiency {:.2}: applied {:?}, pruned {} pathways",
+                    metrics.pathway_efficiency, actions, pathways_pruned
+                ));
+            }
+            
+            self.cycles.push(OptimizationCycle {
+                timestamp: chrono::Utc::now(),
+                pathway_efficiency: metrics.pathway_efficiency,
+                actions,
+                pathways_pruned,
+            });
+        }
+        
+        // Keep only recent history
+        if self.cycles.len() > 1000 {
+            self.cycles.drain(0..500);
+        }
+        
+        self.base.record_processed(None);
+        self.base.transition_state(AgentState::Active).await?;
+        Ok(())
+    }
+    
+    async fn suspend(&mut self) -> Result<()> {
+        self.base.transition_state(AgentState::Suspended).await?;
+        self.base.logger.info("Optimizer suspended");
+        Ok(())
+    }
+    
+    async fn terminate(&mut self) -> Result<()> {
+        self.base.transition_state(AgentState::Terminating).await?;
+        
+        self.pending_metrics.clear();
+        self.cycles.clear();
+        
+        self.base.transition_state(AgentState::Terminated).await?;
+        self.base.logger.info("Optimizer terminated");
+        Ok(())
+    }
+    
+    fn state(&self) -> AgentState {
+        self.base.state.clone()
+    }
+    
+    fn subscribed_events(&self) -> EventMask {
+        EventMask::PERFORMANCE_METRICS_REPORTED
+    }
+    
+    async fn receive_event(&mut self, event: SystemEvent) -> Result<()> {
+        if let SystemEvent::PerformanceMetricsReported {
+            cpu_usage,
+            memory_usage,
+            event_latency_ms,
+            pathway_efficiency,
+            ..
+        } = event {
+            self.observe_metrics(PerformanceMetrics {
+                cpu_usage,
+                memory_usage,
+                event_latency_ms,
+                pathway_efficiency,
+                timestamp: chrono::Utc::now(),
+            });
+        }
+        
+        self.base.update_activity();
+        Ok(())
+    }
+}
+
+impl Default for Optimizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
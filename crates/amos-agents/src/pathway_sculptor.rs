@@ -123,6 +123,14 @@ impl CognitiveAgent for PathwaySculptor {
         self.base.capabilities.clone()
     }
     
+    fn base(&self) -> Option<&BaseAgent> {
+        Some(&self.base)
+    }
+    
+    fn base_mut(&mut self) -> Option<&mut BaseAgent> {
+        Some(&mut self.base)
+    }
+    
     async fn initialize(&mut self, neural_network: Arc<ForgeNeuralNetwork>, event_bus: Arc<EventBus>) -> Result<()> {
         self.base.transition_state(AgentState::Initializing).await?;
         
@@ -147,6 +155,7 @@ impl CognitiveAgent for PathwaySculptor {
         // Optimize existing pathways
         self.optimize_pathways().await?;
         
+        self.base.record_processed(None);
         self.base.transition_state(AgentState::Active).await?;
         Ok(())
     }
@@ -2,10 +2,10 @@ use async_trait::async_trait;
 use uuid::Uuid;
 use std::sync::Arc;
 use std::collections::HashMap;
-use amos_core::{ForgeNeuralNetwork, EventBus, SystemEvent};
+use amos_core::{ForgeNeuralNetwork, EventBus, EventMask, SystemEvent};
 use anyhow::Result;
 use serde::{Serialize, Deserialize};
-use crate::{CognitiveAgent, BaseAgent, AgentState, AgentCapability};
+use crate::{CognitiveAgent, BaseAgent, AgentState, AgentCapability, OPTIMIZER_AGENT_NAME, ResourceUsage};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PerformanceMetrics {
@@ -16,6 +16,25 @@ pub struct PerformanceMetrics {
     pub timestamp: chrono::DateTime<chrono::Utc>,
 }
 
+impl PerformanceMetrics {
+    /// Value of a metric by the name strategies target it with
+    pub fn value_of(&self, metric: &str) -> Option<f64> {
+        match metric {
+            "cpu_usage" => Some(self.cpu_usage),
+            "memory_usage" => Some(self.memory_usage),
+            "event_latency_ms" => Some(self.event_latency_ms),
+            "pathway_efficiency" => Some(self.pathway_efficiency),
+            _ => None,
+        }
+    }
+}
+
+/// Number of recent samples smoothed over by default
+pub const DEFAULT_SMOOTHING_WINDOW: usize = 10;
+
+/// Memory that registered agents together may use before memory usage reads 1.0
+pub const DEFAULT_MEMORY_BUDGET_BYTES: u64 = 1 << 30;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OptimizationStrategy {
     pub name: String,
@@ -33,6 +52,13 @@ pub enum OptimizationAction {
     SuspendLowPriorityAgents,
 }
 
+impl OptimizationAction {
+    /// Actions that reshape the neural network rather than runtime behaviour
+    pub fn is_structural(&self) -> bool {
+        matches!(self, OptimizationAction::PruneWeakPathways | OptimizationAction::ConsolidateMemory)
+    }
+}
+
 pub struct PerformanceGuardian {
     base: BaseAgent,
     metrics_history: Vec<PerformanceMetrics>,
@@ -40,6 +66,13 @@ pub struct PerformanceGuardian {
     performance_threshold: f64,
     optimization_cycles: u64,
     agent_performance: HashMap<Uuid, f64>,
+    /// Optimizer agent that has taken over structural actions such as pruning
+    structural_delegate: Option<Uuid>,
+    /// Samples the EMA used for optimization decisions spans
+    smoothing_window: usize,
+    /// Agents whose self-reported usage replaces the estimates
+    reporting_agents: HashMap<Uuid, Arc<dyn CognitiveAgent>>,
+    memory_budget_bytes: u64,
 }
 
 impl PerformanceGuardian {
@@ -57,12 +90,41 @@ impl PerformanceGuardian {
             performance_threshold: 0.7,
             optimization_cycles: 0,
             agent_performance: HashMap::new(),
+            structural_delegate: None,
+            smoothing_window: DEFAULT_SMOOTHING_WINDOW,
+            reporting_agents: HashMap::new(),
+            memory_budget_bytes: DEFAULT_MEMORY_BUDGET_BYTES,
         };
         
         guardian.init_strategies();
         guardian
     }
     
+    /// Smooth over the last `window` samples; a window of 1 acts on raw samples
+    pub fn with_smoothing_window(mut self, window: usize) -> Self {
+        self.smoothing_window = window.max(1);
+        self
+    }
+    
+    pub fn with_memory_budget(mut self, bytes: u64) -> Self {
+        self.memory_budget_bytes = bytes.max(1);
+        self
+    }
+    
+    /// Have an agent's `resource_usage` reports count towards collected metrics
+    pub fn register_agent(&mut self, agent: Arc<dyn CognitiveAgent>) {
+        self.reporting_agents.insert(agent.id(), agent);
+    }
+    
+    pub fn unregister_agent(&mut self, agent_id: Uuid) {
+        self.reporting_agents.remove(&agent_id);
+    }
+    
+    /// Sum of the latest reports from every registered agent
+    pub fn aggregate_usage(&self) -> ResourceUsage {
+        self.reporting_agents.values().map(|agent| agent.resource_usage()).sum()
+    }
+    
     fn init_strategies(&mut self) {
         self.optimization_strategies.push(OptimizationStrategy {
             name: "Memory Pressure Relief".to_string(),
@@ -87,23 +149,71 @@ impl PerformanceGuardian {
     }
     
     pub async fn collect_metrics(&mut self) -> PerformanceMetrics {
-        // Simulated metrics collection
+        // Agents' own reports beat guessing; estimate while nobody reports anything,
+        // which includes agents that leave `resource_usage` at its zero default
+        let usage = self.aggregate_usage();
+        let (cpu_usage, memory_usage) = if usage == ResourceUsage::default() {
+            (self.estimate_cpu_usage(), self.estimate_memory_usage())
+        } else {
+            (
+                (usage.cpu_ms as f64 / 1000.0).min(1.0),
+                (usage.memory_bytes as f64 / self.memory_budget_bytes as f64).min(1.0),
+            )
+        };
+        
         let metrics = PerformanceMetrics {
-            cpu_usage: self.estimate_cpu_usage(),
-            memory_usage: self.estimate_memory_usage(),
+            cpu_usage,
+            memory_usage,
             event_latency_ms: self.calculate_event_latency(),
             pathway_efficiency: self.calculate_pathway_efficiency(),
             timestamp: chrono::Utc::now(),
         };
         
-        self.metrics_history.push(metrics.clone());
+        self.record_metrics(metrics.clone());
+        metrics
+    }
+    
+    /// Add an externally gathered sample to the history
+    pub fn record_metrics(&mut self, metrics: PerformanceMetrics) {
+        self.metrics_history.push(metrics);
         
         // Keep only recent history
         if self.metrics_history.len() > 1000 {
             self.metrics_history.drain(0..500);
         }
+    }
+    
+    /// Exponential moving average of the recent history, so one-off spikes are damped.
+    /// With no history this is an idle baseline.
+    pub fn smoothed_metrics(&self) -> PerformanceMetrics {
+        let start = self.metrics_history.len().saturating_sub(self.smoothing_window);
+        self.smooth(self.metrics_history[start..].iter())
+    }
+    
+    fn smooth<'a>(&self, samples: impl Iterator<Item = &'a PerformanceMetrics>) -> PerformanceMetrics {
+        let alpha = 2.0 / (self.smoothing_window as f64 + 1.0);
+        let mut smoothed: Option<PerformanceMetrics> = None;
         
-        metrics
+        for sample in samples {
+            smoothed = Some(match smoothed {
+                None => sample.clone(),
+                Some(previous) => PerformanceMetrics {
+                    cpu_usage: alpha * sample.cpu_usage + (1.0 - alpha) * previous.cpu_usage,
+                    memory_usage: alpha * sample.memory_usage + (1.0 - alpha) * previous.memory_usage,
+                    event_latency_ms: alpha * sample.event_latency_ms + (1.0 - alpha) * previous.event_latency_ms,
+                    pathway_efficiency: alpha * sample.pathway_efficiency + (1.0 - alpha) * previous.pathway_efficiency,
+                    timestamp: sample.timestamp,
+                },
+            });
+        }
+        
+        smoothed.unwrap_or_else(|| PerformanceMetrics {
+            cpu_usage: 0.0,
+            memory_usage: 0.0,
+            event_latency_ms: 0.0,
+            pathway_efficiency: 1.0,
+            timestamp: chrono::Utc::now(),
+        })
     }
     
     fn estimate_cpu_usage(&self) -> f64 {
@@ -131,19 +241,31 @@ impl PerformanceGuardian {
         self.optimization_cycles += 1;
         let mut actions_taken = Vec::new();
         
+        // Decide on the smoothed view, counting this sample as the latest unless it already is
+        let start = self.metrics_history.len().saturating_sub(self.smoothing_window);
+        let mut window: Vec<&PerformanceMetrics> = self.metrics_history[start..].iter().collect();
+        if window.last().map(|latest| latest.timestamp) != Some(metrics.timestamp) {
+            if window.len() == self.smoothing_window {
+                window.remove(0);
+            }
+            window.push(metrics);
+        }
+        let smoothed = self.smooth(window.into_iter());
+        
         // Collect strategies to apply
         let strategies_to_apply: Vec<(String, String, OptimizationAction)> = self.optimization_strategies
             .iter()
             .filter_map(|strategy| {
-                let should_optimize = match strategy.target_metric.as_str() {
-                    "cpu_usage" => metrics.cpu_usage > strategy.threshold,
-                    "memory_usage" => metrics.memory_usage > strategy.threshold,
-                    "event_latency_ms" => metrics.event_latency_ms > strategy.threshold,
-                    "pathway_efficiency" => metrics.pathway_efficiency < strategy.threshold,
-                    _ => false,
+                let should_optimize = match (strategy.target_metric.as_str(), smoothed.value_of(&strategy.target_metric)) {
+                    ("pathway_efficiency", Some(value)) => value < strategy.threshold,
+                    (_, Some(value)) => value > strategy.threshold,
+                    (_, None) => false,
                 };
                 
-                if should_optimize {
+                // Leave structural work to the Optimizer when one is running
+                let delegated = self.structural_delegate.is_some() && strategy.action.is_structural();
+                
+                if should_optimize && !delegated {
                     Some((strategy.name.clone(), strategy.target_metric.clone(), strategy.action.clone()))
                 } else {
                     None
@@ -156,7 +278,13 @@ impl PerformanceGuardian {
             self.apply_optimization(&action).await?;
             actions_taken.push(action);
             
-            self.base.logger.info(&format!("Applied optimization: {} ({})", name, target_metric));
+            self.base.logger.info(&format!(
+                "Applied optimization: {} ({} smoothed {:.2}, raw {:.2})",
+                name,
+                target_metric,
+                smoothed.value_of(&target_metric).unwrap_or_default(),
+                metrics.value_of(&target_metric).unwrap_or_default()
+            ));
         }
         
         Ok(actions_taken)
@@ -171,9 +299,8 @@ impl PerformanceGuardian {
             }
             OptimizationAction::ConsolidateMemory => {
                 if let Some(event_bus) = &self.base.event_bus {
-                    event_bus.publish(SystemEvent::MemoryStored {
-                        memory_id: Uuid::new_v4(),
-                        content_size: 0, // Signal for consolidation
+                    event_bus.publish(SystemEvent::MemoryConsolidationRequested {
+                        requester_id: self.base.id,
                     }).await;
                 }
             }
@@ -199,6 +326,10 @@ impl PerformanceGuardian {
         Ok(())
     }
     
+    pub fn structural_delegate(&self) -> Option<Uuid> {
+        self.structural_delegate
+    }
+    
     pub fn update_agent_performance(&mut self, agent_id: Uuid, performance: f64) {
         self.agent_performance.insert(agent_id, performance.min(1.0).max(0.0));
     }
@@ -218,6 +349,14 @@ impl CognitiveAgent for PerformanceGuardian {
         self.base.capabilities.clone()
     }
     
+    fn base(&self) -> Option<&BaseAgent> {
+        Some(&self.base)
+    }
+    
+    fn base_mut(&mut self) -> Option<&mut BaseAgent> {
+        Some(&mut self.base)
+    }
+    
     async fn initialize(&mut self, neural_network: Arc<ForgeNeuralNetwork>, event_bus: Arc<EventBus>) -> Result<()> {
         self.base.transition_state(AgentState::Initializing).await?;
         
@@ -250,6 +389,17 @@ impl CognitiveAgent for PerformanceGuardian {
             metrics.pathway_efficiency * 100.0
         ));
         
+        // Share metrics so the Optimizer can act on them
+        if let Some(event_bus) = &self.base.event_bus {
+            event_bus.publish(SystemEvent::PerformanceMetricsReported {
+                reporter_id: self.base.id,
+                cpu_usage: metrics.cpu_usage,
+                memory_usage: metrics.memory_usage,
+                event_latency_ms: metrics.event_latency_ms,
+                pathway_efficiency: metrics.pathway_efficiency,
+            }).await;
+        }
+        
         // Apply optimizations if needed
         let optimizations = self.optimize_system(&metrics).await?;
         
@@ -257,6 +407,7 @@ impl CognitiveAgent for PerformanceGuardian {
             self.base.logger.info(&format!("Applied {} optimizations", optimizations.len()));
         }
         
+        self.base.record_processed(None);
         self.base.transition_state(AgentState::Active).await?;
         Ok(())
     }
@@ -283,11 +434,25 @@ impl CognitiveAgent for PerformanceGuardian {
         self.base.state.clone()
     }
     
+    fn subscribed_events(&self) -> EventMask {
+        EventMask::AGENT_ACTIVATED | EventMask::AGENT_DEACTIVATED | EventMask::PATHWAY_STRENGTHENED
+    }
+    
     async fn receive_event(&mut self, event: SystemEvent) -> Result<()> {
         match event {
-            SystemEvent::AgentActivated { agent_id, agent_type: _ } => {
+            SystemEvent::AgentActivated { agent_id, agent_type } => {
                 // Track new agent
                 self.update_agent_performance(agent_id, 1.0);
+                
+                if agent_type == OPTIMIZER_AGENT_NAME && self.structural_delegate.is_none() {
+                    self.base.logger.info(&format!("Delegating structural optimizations to Optimizer {}", agent_id));
+                    self.structural_delegate = Some(agent_id);
+                }
+            }
+            SystemEvent::AgentDeactivated { agent_id } if self.structural_delegate == Some(agent_id) => {
+                // Take structural work back until another Optimizer comes up
+                self.base.logger.info(&format!("Optimizer {} went away, resuming structural optimizations", agent_id));
+                self.structural_delegate = None;
             }
             SystemEvent::PathwayStrengthened { pathway_id: _, new_strength } => {
                 // Monitor pathway health
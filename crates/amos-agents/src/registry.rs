@@ -4,7 +4,7 @@ use tokio::sync::RwLock;
 use uuid::Uuid;
 use anyhow::{Result, anyhow};
 use amos_core::{ForgeNeuralNetwork, EventBus, SystemEvent, Logger};
-use crate::{CognitiveAgent, AgentState, AgentContext};
+use crate::{CognitiveAgent, AgentState, AgentContext, deliver_event, process_cycle};
 
 pub struct AgentRegistry {
     agents: Arc<RwLock<HashMap<Uuid, Box<dyn CognitiveAgent>>>>,
@@ -94,7 +94,7 @@ impl AgentRegistry {
             let mut agents = self.agents.write().await;
             if let Some(agent) = agents.get_mut(&agent_id) {
                 if agent.state() == AgentState::Active {
-                    agent.process().await?;
+                    process_cycle(agent.as_mut()).await?;
                 }
             }
         }
@@ -111,7 +111,7 @@ impl AgentRegistry {
         for agent_id in agent_ids {
             let mut agents = self.agents.write().await;
             if let Some(agent) = agents.get_mut(&agent_id) {
-                agent.receive_event(event.clone()).await?;
+                deliver_event(agent.as_mut(), event.clone()).await?;
             }
         }
         
@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, RwLock};
+use tokio::task::JoinHandle;
+use futures::future::join_all;
+use uuid::Uuid;
+use amos_core::Logger;
+use anyhow::Result;
+use crate::{process_cycle, CognitiveAgent, AgentState};
+
+/// An agent locked on its own, so processing it holds up no one else. Emptied
+/// when the agent is removed, in case a tick had already picked it up.
+type AgentSlot = Arc<Mutex<Option<Box<dyn CognitiveAgent>>>>;
+
+/// Drives `process()` on every active agent at a fixed interval
+pub struct AgentScheduler {
+    agents: Arc<RwLock<HashMap<Uuid, AgentSlot>>>,
+    failures: Arc<RwLock<HashMap<Uuid, u32>>>,
+    tick_interval: Duration,
+    max_consecutive_failures: Option<u32>,
+    handle: Mutex<Option<JoinHandle<()>>>,
+    logger: Logger,
+}
+
+impl AgentScheduler {
+    /// Fails for a zero `tick_interval`, which has no next tick to wait for
+    pub fn new(tick_interval: Duration) -> Result<Self> {
+        if tick_interval.is_zero() {
+            anyhow::bail!("Scheduler tick interval must be greater than zero");
+        }
+        
+        Ok(Self {
+            agents: Arc::new(RwLock::new(HashMap::new())),
+            failures: Arc::new(RwLock::new(HashMap::new())),
+            tick_interval,
+            max_consecutive_failures: None,
+            handle: Mutex::new(None),
+            logger: Logger::new("agent_scheduler"),
+        })
+    }
+    
+    /// Suspend an agent once `process()` has failed this many times in a row
+    pub fn with_max_consecutive_failures(mut self, max_failures: u32) -> Self {
+        self.max_consecutive_failures = Some(max_failures);
+        self
+    }
+    
+    pub fn tick_interval(&self) -> Duration {
+        self.tick_interval
+    }
+    
+    pub async fn add_agent(&self, agent: Box<dyn CognitiveAgent>) -> Uuid {
+        let agent_id = agent.id();
+        self.agents.write().await.insert(agent_id, Arc::new(Mutex::new(Some(agent))));
+        agent_id
+    }
+    
+    /// Unregister an agent, waiting out a `process()` call already under way
+    pub async fn remove_agent(&self, agent_id: Uuid) -> Option<Box<dyn CognitiveAgent>> {
+        self.failures.write().await.remove(&agent_id);
+        let slot = self.agents.write().await.remove(&agent_id)?;
+        let agent = slot.lock().await.take();
+        agent
+    }
+    
+    pub async fn agent_state(&self, agent_id: Uuid) -> Option<AgentState> {
+        let slot = self.agents.read().await.get(&agent_id)?.clone();
+        let agent = slot.lock().await;
+        agent.as_ref().map(|agent| agent.state())
+    }
+    
+    pub async fn consecutive_failures(&self, agent_id: Uuid) -> u32 {
+        self.failures.read().await.get(&agent_id).copied().unwrap_or(0)
+    }
+    
+    /// Run a single pass over the active agents, returning how many were processed
+    pub async fn tick(&self) -> usize {
+        Self::run_tick(
+            &self.agents,
+            &self.failures,
+            self.max_consecutive_failures,
+            &self.logger,
+        ).await
+    }
+    
+    /// Start the background loop; does nothing if it is already running
+    pub async fn start(&self) {
+        let mut handle = self.handle.lock().await;
+        if handle.as_ref().is_some_and(|h| !h.is_finished()) {
+            return;
+        }
+        
+        let agents = self.agents.clone();
+        let failures = self.failures.clone();
+        let max_failures = self.max_consecutive_failures;
+        let tick_interval = self.tick_interval;
+        
+        *handle = Some(tokio::spawn(async move {
+            let logger = Logger::new("agent_scheduler");
+            let mut interval = tokio::time::interval(tick_interval);
+            loop {
+                interval.tick().await;
+                Self::run_tick(&agents, &failures, max_failures, &logger).await;
+            }
+        }));
+        
+        self.logger.info(&format!("Scheduler started with {:?} tick interval", tick_interval));
+    }
+    
+    /// Stop the background loop
+    pub async fn stop(&self) {
+        if let Some(handle) = self.handle.lock().await.take() {
+            handle.abort();
+            self.logger.info("Scheduler stopped");
+        }
+    }
+    
+    pub async fn is_running(&self) -> bool {
+        self.handle.lock().await
+            .as_ref()
+            .is_some_and(|h| !h.is_finished())
+    }
+    
+    async fn run_tick(
+        agents: &RwLock<HashMap<Uuid, AgentSlot>>,
+        failures: &RwLock<HashMap<Uuid, u32>>,
+        max_failures: Option<u32>,
+        logger: &Logger,
+    ) -> usize {
+        // Snapshot the roster so agents can be added, removed and read during the tick
+        let slots: Vec<(Uuid, AgentSlot)> = agents.read().await
+            .iter()
+            .map(|(agent_id, slot)| (*agent_id, slot.clone()))
+            .collect();
+        
+        let processed = join_all(slots.into_iter().map(|(agent_id, slot)| {
+            Self::process_agent(agent_id, slot, failures, max_failures, logger)
+        })).await;
+        
+        processed.into_iter().filter(|processed| *processed).count()
+    }
+    
+    /// Process one agent if it is still registered and active, returning whether it was
+    async fn process_agent(
+        agent_id: Uuid,
+        slot: AgentSlot,
+        failures: &RwLock<HashMap<Uuid, u32>>,
+        max_failures: Option<u32>,
+        logger: &Logger,
+    ) -> bool {
+        let mut slot = slot.lock().await;
+        let Some(agent) = slot.as_mut() else {
+            return false;
+        };
+        if agent.state() != AgentState::Active {
+            return false;
+        }
+        
+        match process_cycle(agent.as_mut()).await {
+            Ok(()) => {
+                failures.write().await.remove(&agent_id);
+            }
+            Err(e) => {
+                let count = {
+                    let mut failures = failures.write().await;
+                    let count = failures.entry(agent_id).or_insert(0);
+                    *count += 1;
+                    *count
+                };
+                logger.warn(&format!(
+                    "Agent {} ({}) failed to process ({} in a row): {}",
+                    agent.name(), agent_id, count, e
+                ));
+                
+                if max_failures.is_some_and(|max| count >= max) {
+                    logger.error(&format!("Suspending agent {} after {} consecutive failures", agent_id, count));
+                    if let Err(e) = agent.suspend().await {
+                        logger.error(&format!("Failed to suspend agent {}: {}", agent_id, e));
+                    }
+                    // A reactivated agent starts with a clean slate
+                    failures.write().await.remove(&agent_id);
+                }
+            }
+        }
+        
+        true
+    }
+}
+
+impl Drop for AgentScheduler {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.get_mut().take() {
+            handle.abort();
+        }
+    }
+}
@@ -4,13 +4,97 @@ use std::sync::Arc;
 use std::collections::VecDeque;
 use amos_core::{ForgeNeuralNetwork, EventBus, SystemEvent, Pattern, PatternType, NodeType};
 use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
 use crate::{CognitiveAgent, BaseAgent, AgentState, AgentCapability};
 
+/// How far ahead `process` looks when checking load against capacity
+pub const DEFAULT_FORECAST_HORIZON_SECS: u64 = 60;
+
+/// Predicts throughput from `(seconds since the first sample, throughput)`
+/// samples, `horizon_secs` past the last one
+pub trait ForecastModel: Send + Sync {
+    fn predict(&self, samples: &[(f64, f64)], horizon_secs: f64) -> f64;
+}
+
+/// Least-squares line through the samples, extended over the horizon
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LinearTrend;
+
+impl ForecastModel for LinearTrend {
+    fn predict(&self, samples: &[(f64, f64)], horizon_secs: f64) -> f64 {
+        let Some(&(last_t, last_load)) = samples.last() else {
+            return 0.0;
+        };
+        
+        let n = samples.len() as f64;
+        let mean_t = samples.iter().map(|(t, _)| t).sum::<f64>() / n;
+        let mean_load = samples.iter().map(|(_, load)| load).sum::<f64>() / n;
+        let spread: f64 = samples.iter().map(|(t, _)| (t - mean_t).powi(2)).sum();
+        if spread == 0.0 {
+            return last_load;
+        }
+        
+        let slope = samples.iter()
+            .map(|(t, load)| (t - mean_t) * (load - mean_load))
+            .sum::<f64>() / spread;
+        (mean_load + slope * (last_t + horizon_secs - mean_t)).max(0.0)
+    }
+}
+
+/// Exponentially weighted level of the samples, expected to hold over the horizon
+#[derive(Debug, Clone, Copy)]
+pub struct ExponentialSmoothing {
+    alpha: f64,
+}
+
+impl ExponentialSmoothing {
+    /// `alpha` is the weight of each new sample, in 0..=1
+    pub fn new(alpha: f64) -> Result<Self> {
+        if !(0.0..=1.0).contains(&alpha) {
+            anyhow::bail!("Smoothing factor must be between 0 and 1, got {}", alpha);
+        }
+        Ok(Self { alpha })
+    }
+    
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+}
+
+impl ForecastModel for ExponentialSmoothing {
+    fn predict(&self, samples: &[(f64, f64)], _horizon_secs: f64) -> f64 {
+        let mut loads = samples.iter().map(|(_, load)| *load);
+        let Some(first) = loads.next() else {
+            return 0.0;
+        };
+        loads.fold(first, |level, load| self.alpha * load + (1.0 - self.alpha) * level)
+    }
+}
+
+/// Load a TrafficSeer expects some time from now
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoadForecast {
+    pub horizon_secs: u64,
+    /// Most recently observed throughput
+    pub current_load: f64,
+    pub predicted_load: f64,
+    /// Whether the prediction is above the seer's load capacity, if it has one
+    pub exceeds_capacity: bool,
+}
+
 pub struct TrafficSeer {
     base: BaseAgent,
     pattern_buffer: VecDeque<Pattern>,
     pattern_threshold: f64,
     max_patterns: usize,
+    throughput_history: VecDeque<(DateTime<Utc>, f64)>,
+    /// Events received since the last throughput sample, and when that was taken
+    events_since_sample: u64,
+    last_sample_at: DateTime<Utc>,
+    forecast_model: Box<dyn ForecastModel>,
+    /// Throughput above which a forecast warns, unset to never warn
+    load_capacity: Option<f64>,
 }
 
 impl TrafficSeer {
@@ -26,7 +110,85 @@ impl TrafficSeer {
             pattern_buffer: VecDeque::with_capacity(100),
             pattern_threshold: 0.7,
             max_patterns: 100,
+            throughput_history: VecDeque::with_capacity(100),
+            events_since_sample: 0,
+            last_sample_at: Utc::now(),
+            forecast_model: Box::new(LinearTrend),
+            load_capacity: None,
+        }
+    }
+    
+    /// Forecast with `model` instead of a linear trend
+    pub fn with_forecast_model(mut self, model: Box<dyn ForecastModel>) -> Self {
+        self.forecast_model = model;
+        self
+    }
+    
+    /// Warn through `SystemEvent::LoadForecast` when predicted load goes above `capacity`
+    pub fn with_load_capacity(mut self, capacity: f64) -> Self {
+        self.load_capacity = Some(capacity);
+        self
+    }
+    
+    pub fn record_throughput(&mut self, throughput: f64) {
+        self.record_throughput_at(Utc::now(), throughput);
+    }
+    
+    /// Record throughput observed at `at`; samples are expected in time order
+    pub fn record_throughput_at(&mut self, at: DateTime<Utc>, throughput: f64) {
+        if self.throughput_history.len() >= self.max_patterns {
+            self.throughput_history.pop_front();
         }
+        self.throughput_history.push_back((at, throughput));
+    }
+    
+    /// Record the rate of events received since the previous sample, in events per second
+    pub fn sample_observed_throughput(&mut self) -> f64 {
+        let now = Utc::now();
+        let elapsed_secs = ((now - self.last_sample_at).num_milliseconds().max(1)) as f64 / 1000.0;
+        let throughput = self.events_since_sample as f64 / elapsed_secs;
+        
+        self.record_throughput_at(now, throughput);
+        self.events_since_sample = 0;
+        self.last_sample_at = now;
+        throughput
+    }
+    
+    /// Predict throughput `horizon_secs` after the latest sample from the recorded history
+    pub fn forecast(&self, horizon_secs: u64) -> LoadForecast {
+        let samples: Vec<(f64, f64)> = match self.throughput_history.front() {
+            Some((start, _)) => self.throughput_history.iter()
+                .map(|(at, load)| ((*at - *start).num_milliseconds() as f64 / 1000.0, *load))
+                .collect(),
+            None => Vec::new(),
+        };
+        
+        let predicted_load = self.forecast_model.predict(&samples, horizon_secs as f64);
+        LoadForecast {
+            horizon_secs,
+            current_load: samples.last().map(|(_, load)| *load).unwrap_or(0.0),
+            predicted_load,
+            exceeds_capacity: self.load_capacity.map(|capacity| predicted_load > capacity).unwrap_or(false),
+        }
+    }
+    
+    /// Forecast load and, if it would exceed capacity, warn on the event bus
+    /// so the swarm can scale ahead of it
+    pub async fn publish_forecast(&self, horizon_secs: u64) -> LoadForecast {
+        let forecast = self.forecast(horizon_secs);
+        
+        if let (true, Some(capacity), Some(event_bus)) =
+            (forecast.exceeds_capacity, self.load_capacity, &self.base.event_bus)
+        {
+            event_bus.publish(SystemEvent::LoadForecast {
+                agent_id: self.base.id,
+                predicted_load: forecast.predicted_load,
+                capacity,
+                horizon_secs,
+            }).await;
+        }
+        
+        forecast
     }
     
     pub async fn analyze_traffic_patterns(&mut self) -> Result<Vec<Pattern>> {
@@ -99,11 +261,21 @@ impl CognitiveAgent for TrafficSeer {
         self.base.capabilities.clone()
     }
     
+    fn base(&self) -> Option<&BaseAgent> {
+        Some(&self.base)
+    }
+    
+    fn base_mut(&mut self) -> Option<&mut BaseAgent> {
+        Some(&mut self.base)
+    }
+    
     async fn initialize(&mut self, neural_network: Arc<ForgeNeuralNetwork>, event_bus: Arc<EventBus>) -> Result<()> {
         self.base.transition_state(AgentState::Initializing).await?;
         
         self.base.neural_network = Some(neural_network);
         self.base.event_bus = Some(event_bus.clone());
+        self.events_since_sample = 0;
+        self.last_sample_at = Utc::now();
         
         self.base.logger.info("TrafficSeer initialized");
         
@@ -135,6 +307,10 @@ impl CognitiveAgent for TrafficSeer {
             }
         }
         
+        self.sample_observed_throughput();
+        self.publish_forecast(DEFAULT_FORECAST_HORIZON_SECS).await;
+        
+        self.base.record_processed(None);
         self.base.transition_state(AgentState::Active).await?;
         Ok(())
     }
@@ -150,6 +326,7 @@ impl CognitiveAgent for TrafficSeer {
         
         // Clear pattern buffer
         self.pattern_buffer.clear();
+        self.throughput_history.clear();
         
         self.base.transition_state(AgentState::Terminated).await?;
         self.base.logger.info("TrafficSeer terminated");
@@ -161,6 +338,9 @@ impl CognitiveAgent for TrafficSeer {
     }
     
     async fn receive_event(&mut self, event: SystemEvent) -> Result<()> {
+        // Every event the seer sees counts towards the throughput it forecasts from
+        self.events_since_sample += 1;
+        
         match event {
             SystemEvent::NeuralFired { node_id: _ } => {
                 // Create pattern from neural activity
@@ -1,5 +1,5 @@
 use amos_agents::*;
-use amos_core::{ForgeNeuralNetwork, EventBus, Pattern, PatternType};
+use amos_core::{ForgeNeuralNetwork, EventBus, EventHandler, EventMask, SystemEvent, Pattern, PatternType};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -46,6 +46,30 @@ async fn test_memory_decay() {
     assert!(weaver.retrieve_memory(mem1).is_some());
 }
 
+#[tokio::test]
+async fn test_forgetting_curve_keeps_rehearsed_memories() {
+    let mut weaver = MemoryWeaver::new();
+    
+    let rehearsed = weaver.store_memory(serde_json::json!({"fact": "rehearsed"}), 0.9);
+    let neglected = weaver.store_memory(serde_json::json!({"fact": "neglected"}), 0.3);
+    for _ in 0..5 {
+        weaver.retrieve_memory(rehearsed);
+    }
+    
+    for _ in 0..50 {
+        weaver.apply_decay();
+    }
+    
+    let search = weaver.search_memories(|_| true);
+    assert!(search.contains(&rehearsed));
+    assert!(!search.contains(&neglected));
+    
+    // Recalling the survivor restarts its curve
+    let memory = weaver.retrieve_memory(rehearsed).unwrap();
+    assert_eq!(memory.age, 0);
+    assert_eq!(memory.retention(DEFAULT_MEMORY_STABILITY), 1.0);
+}
+
 // CognitionAlchemist Tests
 #[tokio::test]
 async fn test_cognition_alchemist_creation() {
@@ -131,6 +155,75 @@ async fn test_parameter_adjustment() {
     // Parameters should be adjusted (implementation specific)
 }
 
+struct EventCollector {
+    events: Arc<tokio::sync::Mutex<Vec<SystemEvent>>>,
+}
+
+#[async_trait::async_trait]
+impl EventHandler for EventCollector {
+    async fn handle(&self, event: SystemEvent) {
+        self.events.lock().await.push(event);
+    }
+    
+    fn event_types(&self) -> Vec<std::any::TypeId> {
+        vec![std::any::TypeId::of::<SystemEvent>()]
+    }
+}
+
+/// Wait for the collector to see an event matching `predicate`, failing after a second
+async fn wait_for_event(
+    events: &Arc<tokio::sync::Mutex<Vec<SystemEvent>>>,
+    predicate: impl Fn(&SystemEvent) -> bool,
+) -> SystemEvent {
+    let seen = async {
+        loop {
+            if let Some(event) = events.lock().await.iter().find(|event| predicate(event)) {
+                return event.clone();
+            }
+            tokio::task::yield_now().await;
+        }
+    };
+    tokio::time::timeout(std::time::Duration::from_secs(1), seen)
+        .await
+        .expect("event published")
+}
+
+#[tokio::test]
+async fn test_poor_performance_switches_strategy_and_notifies() {
+    let network = Arc::new(ForgeNeuralNetwork::new());
+    let event_bus = Arc::new(EventBus::new());
+    event_bus.clone().start_processing().await;
+    
+    let events = Arc::new(tokio::sync::Mutex::new(Vec::new()));
+    event_bus.subscribe(Arc::new(EventCollector { events: events.clone() })).await;
+    
+    let mut oracle = LearningOracle::new();
+    oracle.initialize(network, event_bus).await.unwrap();
+    let original = oracle.active_strategy().unwrap();
+    
+    // A dopamine dip drives the success rate below the switching threshold
+    oracle.receive_event(SystemEvent::HormonalBurst {
+        hormone_type: "Dopamine".to_string(),
+        intensity: -0.4,
+    }).await.unwrap();
+    oracle.adapt_learning().await.unwrap();
+    
+    let replacement = oracle.active_strategy().unwrap();
+    assert_ne!(replacement, original);
+    
+    let record = oracle.learning_history().last().unwrap();
+    let change = record.strategy_change.as_ref().expect("switch recorded in history");
+    assert_eq!((change.from, change.to), (original, replacement));
+    
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    let events = events.lock().await;
+    let announced = events.iter().find_map(|event| match event {
+        SystemEvent::LearningStrategyChanged { from, to, .. } => Some((*from, *to)),
+        _ => None,
+    });
+    assert_eq!(announced, Some((original, replacement)));
+}
+
 // MeshHarmonizer Tests
 #[tokio::test]
 async fn test_mesh_harmonizer_creation() {
@@ -189,7 +282,7 @@ async fn test_introspection() {
     consciousness.initialize(network, event_bus).await.unwrap();
     
     // Perform introspection
-    consciousness.introspect().await.unwrap();
+    ConsciousnessEmergent::introspect(&mut consciousness).await.unwrap();
     
     // Should have self-observations
 }
@@ -208,7 +301,7 @@ async fn test_intention_formation() {
     let mut consciousness = ConsciousnessEmergent::new();
     
     // Need awareness for intentions
-    consciousness.introspect().await.unwrap();
+    ConsciousnessEmergent::introspect(&mut consciousness).await.unwrap();
     
     let _intention = consciousness.form_intention();
     // May or may not form intention based on awareness level
@@ -252,6 +345,362 @@ async fn test_optimization_strategies() {
     // May or may not apply optimizations based on metrics
 }
 
+#[tokio::test]
+async fn test_guardian_delegates_structural_actions_to_optimizer() {
+    let mut guardian = PerformanceGuardian::new();
+    let optimizer = Optimizer::new();
+    
+    guardian.receive_event(SystemEvent::AgentActivated {
+        agent_id: optimizer.id(),
+        agent_type: optimizer.name().to_string(),
+    }).await.unwrap();
+    assert_eq!(guardian.structural_delegate(), Some(optimizer.id()));
+    
+    let metrics = PerformanceMetrics {
+        cpu_usage: 0.1,
+        memory_usage: 0.95,
+        event_latency_ms: 150.0,
+        pathway_efficiency: 0.2,
+        timestamp: chrono::Utc::now(),
+    };
+    let actions = guardian.optimize_system(&metrics).await.unwrap();
+    assert_eq!(actions, vec![OptimizationAction::ThrottleEvents]);
+}
+
+#[tokio::test]
+async fn test_guardian_resumes_structural_actions_when_optimizer_deactivates() {
+    let mut guardian = PerformanceGuardian::new();
+    let optimizer = Optimizer::new();
+    
+    deliver_event(&mut guardian, SystemEvent::AgentActivated {
+        agent_id: optimizer.id(),
+        agent_type: optimizer.name().to_string(),
+    }).await.unwrap();
+    assert_eq!(guardian.structural_delegate(), Some(optimizer.id()));
+    
+    // Some other agent going away leaves the delegation alone
+    deliver_event(&mut guardian, SystemEvent::AgentDeactivated { agent_id: Uuid::new_v4() }).await.unwrap();
+    assert_eq!(guardian.structural_delegate(), Some(optimizer.id()));
+    
+    assert!(deliver_event(&mut guardian, SystemEvent::AgentDeactivated { agent_id: optimizer.id() }).await.unwrap());
+    assert_eq!(guardian.structural_delegate(), None);
+    
+    let metrics = PerformanceMetrics {
+        cpu_usage: 0.1,
+        memory_usage: 0.1,
+        event_latency_ms: 20.0,
+        pathway_efficiency: 0.2,
+        timestamp: chrono::Utc::now(),
+    };
+    let actions = guardian.optimize_system(&metrics).await.unwrap();
+    assert_eq!(actions, vec![OptimizationAction::PruneWeakPathways]);
+}
+
+#[tokio::test]
+async fn test_guardian_only_receives_subscribed_events() {
+    let mut guardian = PerformanceGuardian::new();
+    let optimizer = Optimizer::new();
+    
+    let burst = SystemEvent::HormonalBurst {
+        hormone_type: "Cortisol".to_string(),
+        intensity: 0.9,
+    };
+    assert!(!guardian.subscribed_events().matches(&burst));
+    assert!(!deliver_event(&mut guardian, burst).await.unwrap());
+    
+    let activated = SystemEvent::AgentActivated {
+        agent_id: optimizer.id(),
+        agent_type: optimizer.name().to_string(),
+    };
+    assert!(deliver_event(&mut guardian, activated).await.unwrap());
+    assert_eq!(guardian.structural_delegate(), Some(optimizer.id()));
+    
+    // Agents that don't declare a mask still see everything
+    let mut seer = TrafficSeer::new();
+    assert_eq!(seer.subscribed_events(), EventMask::ALL);
+    assert!(deliver_event(&mut seer, SystemEvent::SystemShutdown).await.unwrap());
+}
+
+fn latency_sample(event_latency_ms: f64) -> PerformanceMetrics {
+    PerformanceMetrics {
+        cpu_usage: 0.1,
+        memory_usage: 0.1,
+        event_latency_ms,
+        pathway_efficiency: 0.9,
+        timestamp: chrono::Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_guardian_smooths_out_transient_spikes() {
+    let mut guardian = PerformanceGuardian::new();
+    for _ in 0..DEFAULT_SMOOTHING_WINDOW {
+        guardian.record_metrics(latency_sample(20.0));
+    }
+    
+    // A single spike is damped by the surrounding normal samples
+    let spike = latency_sample(250.0);
+    guardian.record_metrics(spike.clone());
+    assert!(guardian.smoothed_metrics().event_latency_ms < 100.0);
+    assert!(guardian.optimize_system(&spike).await.unwrap().is_empty());
+    
+    // A sustained elevation pulls the average over the threshold
+    for _ in 0..DEFAULT_SMOOTHING_WINDOW {
+        guardian.record_metrics(latency_sample(250.0));
+    }
+    let actions = guardian.optimize_system(&latency_sample(250.0)).await.unwrap();
+    assert_eq!(actions, vec![OptimizationAction::ThrottleEvents]);
+}
+
+/// Agent that reports a fixed resource usage
+struct ReportingAgent {
+    id: Uuid,
+    usage: ResourceUsage,
+}
+
+#[async_trait::async_trait]
+impl CognitiveAgent for ReportingAgent {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+    
+    fn name(&self) -> &str {
+        "ReportingAgent"
+    }
+    
+    fn capabilities(&self) -> Vec<AgentCapability> {
+        vec![]
+    }
+    
+    async fn initialize(&mut self, _neural_network: Arc<ForgeNeuralNetwork>, _event_bus: Arc<EventBus>) -> anyhow::Result<()> {
+        Ok(())
+    }
+    
+    async fn activate(&mut self) -> anyhow::Result<()> {
+        Ok(())
+    }
+    
+    async fn process(&mut self) -> anyhow::Result<()> {
+        Ok(())
+    }
+    
+    async fn suspend(&mut self) -> anyhow::Result<()> {
+        Ok(())
+    }
+    
+    async fn terminate(&mut self) -> anyhow::Result<()> {
+        Ok(())
+    }
+    
+    fn state(&self) -> AgentState {
+        AgentState::Active
+    }
+    
+    async fn receive_event(&mut self, _event: SystemEvent) -> anyhow::Result<()> {
+        Ok(())
+    }
+    
+    fn resource_usage(&self) -> ResourceUsage {
+        self.usage
+    }
+}
+
+#[tokio::test]
+async fn test_guardian_aggregates_reported_resource_usage() {
+    let mut guardian = PerformanceGuardian::new().with_memory_budget(1000);
+    let reports = [
+        ResourceUsage { cpu_ms: 200, memory_bytes: 300, tasks_in_flight: 1 },
+        ResourceUsage { cpu_ms: 150, memory_bytes: 100, tasks_in_flight: 2 },
+    ];
+    for usage in reports {
+        guardian.register_agent(Arc::new(ReportingAgent { id: Uuid::new_v4(), usage }));
+    }
+    
+    assert_eq!(
+        guardian.aggregate_usage(),
+        ResourceUsage { cpu_ms: 350, memory_bytes: 400, tasks_in_flight: 3 }
+    );
+    
+    let metrics = guardian.collect_metrics().await;
+    assert!((metrics.cpu_usage - 0.35).abs() < 1e-9);
+    assert!((metrics.memory_usage - 0.4).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_guardian_estimates_while_reports_are_all_zero() {
+    let mut estimating = PerformanceGuardian::new();
+    let mut silent = PerformanceGuardian::new();
+    silent.register_agent(Arc::new(TrafficSeer::new()));
+    silent.register_agent(Arc::new(ReportingAgent { id: Uuid::new_v4(), usage: ResourceUsage::default() }));
+    
+    for _ in 0..3 {
+        let expected = estimating.collect_metrics().await;
+        let metrics = silent.collect_metrics().await;
+        assert_eq!(metrics.cpu_usage, expected.cpu_usage);
+        assert_eq!(metrics.memory_usage, expected.memory_usage);
+    }
+    assert!(silent.collect_metrics().await.memory_usage > 0.0);
+}
+
+// TrafficSeer Tests
+#[tokio::test]
+async fn test_rising_throughput_forecasts_overload() {
+    let network = Arc::new(ForgeNeuralNetwork::new());
+    let event_bus = Arc::new(EventBus::new());
+    event_bus.clone().start_processing().await;
+    
+    let events = Arc::new(tokio::sync::Mutex::new(Vec::new()));
+    event_bus.subscribe(Arc::new(EventCollector { events: events.clone() })).await;
+    
+    let mut seer = TrafficSeer::new().with_load_capacity(55.0);
+    seer.initialize(network, event_bus).await.unwrap();
+    
+    // Throughput climbs by 10 every 10 seconds
+    let start = chrono::Utc::now();
+    for step in 0..5 {
+        seer.record_throughput_at(start + chrono::Duration::seconds(step * 10), 10.0 * (step + 1) as f64);
+    }
+    
+    let soon = seer.publish_forecast(5).await;
+    assert_eq!(soon.current_load, 50.0);
+    assert!((soon.predicted_load - 55.0).abs() < 1e-9);
+    assert!(!soon.exceeds_capacity);
+    
+    let later = seer.publish_forecast(30).await;
+    assert!((later.predicted_load - 80.0).abs() < 1e-9);
+    assert!(later.exceeds_capacity);
+    
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    let warnings: Vec<(f64, u64)> = events.lock().await.iter()
+        .filter_map(|event| match event {
+            SystemEvent::LoadForecast { predicted_load, horizon_secs, .. } => Some((*predicted_load, *horizon_secs)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].1, 30);
+    
+    // A flat model ignores the trend
+    let mut flat = TrafficSeer::new().with_forecast_model(Box::new(ExponentialSmoothing::new(1.0).unwrap()));
+    flat.record_throughput_at(start, 10.0);
+    flat.record_throughput_at(start + chrono::Duration::seconds(10), 50.0);
+    assert_eq!(flat.forecast(30).predicted_load, 50.0);
+    
+    assert!(ExponentialSmoothing::new(1.5).is_err());
+    assert!(ExponentialSmoothing::new(f64::NAN).is_err());
+}
+
+#[tokio::test]
+async fn test_seer_forecasts_from_observed_events() {
+    let event_bus = Arc::new(EventBus::new());
+    event_bus.clone().start_processing().await;
+    let events = Arc::new(tokio::sync::Mutex::new(Vec::new()));
+    event_bus.subscribe(Arc::new(EventCollector { events: events.clone() })).await;
+    
+    // Driven only through the trait, as a spawned agent would be
+    let mut seer: Box<dyn CognitiveAgent> = Box::new(TrafficSeer::new().with_load_capacity(1.0));
+    seer.initialize(Arc::new(ForgeNeuralNetwork::new()), event_bus).await.unwrap();
+    
+    for _ in 0..20 {
+        seer.receive_event(SystemEvent::NeuralFired { node_id: Uuid::new_v4() }).await.unwrap();
+    }
+    seer.process().await.unwrap();
+    
+    let warning = wait_for_event(&events, |event| matches!(event, SystemEvent::LoadForecast { .. })).await;
+    let SystemEvent::LoadForecast { agent_id, predicted_load, .. } = warning else { unreachable!() };
+    assert_eq!(agent_id, seer.id());
+    assert!(predicted_load > 1.0);
+}
+
+// Optimizer Tests
+#[tokio::test]
+async fn test_optimizer_prunes_on_low_efficiency_metrics() {
+    let network = Arc::new(ForgeNeuralNetwork::new());
+    let event_bus = Arc::new(EventBus::new());
+    
+    let source = network.add_node(amos_core::NodeType::Thinking).await;
+    let target = network.add_node(amos_core::NodeType::Memory).await;
+    network.create_pathway(source, target, 0.1).await;
+    network.create_pathway(target, source, 0.9).await;
+    
+    let mut optimizer = Optimizer::new();
+    optimizer.initialize(network.clone(), event_bus).await.unwrap();
+    
+    optimizer.receive_event(SystemEvent::PerformanceMetricsReported {
+        reporter_id: Uuid::new_v4(),
+        cpu_usage: 0.2,
+        memory_usage: 0.1,
+        event_latency_ms: 20.0,
+        pathway_efficiency: 0.3,
+    }).await.unwrap();
+    optimizer.process().await.unwrap();
+    
+    // The weak pathway was pruned, the strong one kept
+    assert_eq!(network.pathway_count().await, 1);
+    
+    let cycle = optimizer.cycles().last().unwrap();
+    assert_eq!(cycle.actions, vec![OptimizationAction::PruneWeakPathways]);
+    assert_eq!(cycle.pathways_pruned, 1);
+}
+
+#[tokio::test]
+async fn test_optimizer_tracks_improvement_across_cycles() {
+    let mut optimizer = Optimizer::new();
+    optimizer.initialize(Arc::new(ForgeNeuralNetwork::new()), Arc::new(EventBus::new())).await.unwrap();
+    
+    for efficiency in [0.3, 0.45, 0.6] {
+        optimizer.observe_metrics(PerformanceMetrics {
+            cpu_usage: 0.2,
+            memory_usage: 0.1,
+            event_latency_ms: 20.0,
+            pathway_efficiency: efficiency,
+            timestamp: chrono::Utc::now(),
+        });
+        optimizer.process().await.unwrap();
+    }
+    
+    assert_eq!(optimizer.cycles().len(), 3);
+    assert!((optimizer.improvement() - 0.3).abs() < 1e-9);
+    assert!(optimizer.cycles()[2].actions.is_empty());
+}
+
+#[tokio::test]
+async fn test_optimizer_requests_consolidation_from_memory_weaver() {
+    let network = Arc::new(ForgeNeuralNetwork::new());
+    let event_bus = Arc::new(EventBus::new());
+    event_bus.clone().start_processing().await;
+    
+    let events = Arc::new(tokio::sync::Mutex::new(Vec::new()));
+    event_bus.subscribe(Arc::new(EventCollector { events: events.clone() })).await;
+    
+    let mut optimizer = Optimizer::new();
+    optimizer.initialize(network.clone(), event_bus.clone()).await.unwrap();
+    let mut weaver = MemoryWeaver::new();
+    weaver.initialize(network.clone(), event_bus).await.unwrap();
+    weaver.store_memory(serde_json::json!({"event": "keep"}), 0.9);
+    
+    let report = SystemEvent::PerformanceMetricsReported {
+        reporter_id: Uuid::new_v4(),
+        cpu_usage: 0.2,
+        memory_usage: 0.95,
+        event_latency_ms: 20.0,
+        pathway_efficiency: 0.9,
+    };
+    assert!(!deliver_event(&mut optimizer, SystemEvent::SystemShutdown).await.unwrap());
+    assert!(deliver_event(&mut optimizer, report).await.unwrap());
+    optimizer.process().await.unwrap();
+    assert_eq!(optimizer.cycles()[0].actions, vec![OptimizationAction::ConsolidateMemory]);
+    
+    let request = wait_for_event(&events, |event| {
+        matches!(event, SystemEvent::MemoryConsolidationRequested { .. })
+    }).await;
+    assert_eq!(request, SystemEvent::MemoryConsolidationRequested { requester_id: optimizer.id() });
+    
+    let nodes_before = network.node_count().await;
+    assert!(deliver_event(&mut weaver, request).await.unwrap());
+    assert_eq!(network.node_count().await, nodes_before + 1);
+}
+
 // Integration test
 #[tokio::test]
 async fn test_all_agents_lifecycle() {
@@ -265,6 +714,7 @@ async fn test_all_agents_lifecycle() {
         Box::new(MeshHarmonizer::new()),
         Box::new(ConsciousnessEmergent::new()),
         Box::new(PerformanceGuardian::new()),
+        Box::new(Optimizer::new()),
     ];
     
     // Initialize all agents
@@ -276,6 +726,7 @@ async fn test_all_agents_lifecycle() {
     // Process all agents
     for agent in &mut agents {
         agent.process().await.unwrap();
+        assert_eq!(agent.metrics().processed, 1, "{}", agent.name());
     }
     
     // Terminate all agents
@@ -283,4 +734,31 @@ async fn test_all_agents_lifecycle() {
         agent.terminate().await.unwrap();
         assert_eq!(agent.state(), AgentState::Terminated);
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_agent_metrics_average_reported_confidence() {
+    let mut metrics = AgentMetrics::default();
+    metrics.record_processed(Some(0.2));
+    metrics.record_processed(None);
+    metrics.record_processed(Some(0.6));
+    metrics.record_error();
+    
+    assert_eq!(metrics.processed, 3);
+    assert_eq!(metrics.errors, 1);
+    assert_eq!(metrics.confidence_samples, 2);
+    assert!((metrics.avg_confidence - 0.4).abs() < 1e-9);
+    assert!(metrics.last_processed.is_some());
+}
+
+#[tokio::test]
+async fn test_failed_process_cycle_counts_an_error() {
+    // Never initialized, so it can't move into processing
+    let mut agent: Box<dyn CognitiveAgent> = Box::new(TrafficSeer::new());
+    
+    assert!(process_cycle(agent.as_mut()).await.is_err());
+    
+    let metrics = agent.metrics();
+    assert_eq!(metrics.errors, 1);
+    assert_eq!(metrics.processed, 0);
+}
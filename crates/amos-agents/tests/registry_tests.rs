@@ -150,4 +150,18 @@ async fn test_registry_invalid_agent_id() {
     // Try to terminate non-existent agent
     let result = registry.terminate_agent(fake_id).await;
     assert!(result.is_err());
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_agent_type_lookup() {
+    let seer = find_agent_type("traffic_seer").unwrap();
+    assert_eq!(seer.agent_name, "TrafficSeer");
+    assert!(seer.capabilities.contains(&AgentCapability::PatternRecognition));
+    
+    let agent = create_agent("memory_weaver").unwrap();
+    assert_eq!(agent.name(), "MemoryWeaver");
+    
+    assert_eq!(find_agent_type_by_agent_name("PathwaySculptor").unwrap().type_name, "pathway_sculptor");
+    assert!(find_agent_type("architect").is_none());
+    assert_eq!(agent_types().len(), 9);
+}
@@ -0,0 +1,176 @@
+use amos_agents::*;
+use amos_core::{ForgeNeuralNetwork, EventBus, SystemEvent};
+use async_trait::async_trait;
+use anyhow::{Result, anyhow};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use uuid::Uuid;
+
+/// Minimal agent that counts how often it is processed
+struct CountingAgent {
+    id: Uuid,
+    state: AgentState,
+    calls: Arc<AtomicUsize>,
+    fail: bool,
+    delay: Duration,
+}
+
+impl CountingAgent {
+    fn new(calls: Arc<AtomicUsize>, fail: bool) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            state: AgentState::Active,
+            calls,
+            fail,
+            delay: Duration::ZERO,
+        }
+    }
+    
+    /// Takes `delay` over every `process()` call
+    fn slow(calls: Arc<AtomicUsize>, delay: Duration) -> Self {
+        Self { delay, ..Self::new(calls, false) }
+    }
+}
+
+#[async_trait]
+impl CognitiveAgent for CountingAgent {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+    
+    fn name(&self) -> &str {
+        "CountingAgent"
+    }
+    
+    fn capabilities(&self) -> Vec<AgentCapability> {
+        vec![]
+    }
+    
+    async fn initialize(&mut self, _neural_network: Arc<ForgeNeuralNetwork>, _event_bus: Arc<EventBus>) -> Result<()> {
+        Ok(())
+    }
+    
+    async fn activate(&mut self) -> Result<()> {
+        self.state = AgentState::Active;
+        Ok(())
+    }
+    
+    async fn process(&mut self) -> Result<()> {
+        tokio::time::sleep(self.delay).await;
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        if self.fail {
+            return Err(anyhow!("simulated failure"));
+        }
+        Ok(())
+    }
+    
+    async fn suspend(&mut self) -> Result<()> {
+        self.state = AgentState::Suspended;
+        Ok(())
+    }
+    
+    async fn terminate(&mut self) -> Result<()> {
+        self.state = AgentState::Terminated;
+        Ok(())
+    }
+    
+    fn state(&self) -> AgentState {
+        self.state.clone()
+    }
+    
+    async fn receive_event(&mut self, _event: SystemEvent) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_scheduler_processes_agents_each_tick() {
+    let scheduler = AgentScheduler::new(Duration::from_millis(10)).unwrap();
+    let first = Arc::new(AtomicUsize::new(0));
+    let second = Arc::new(AtomicUsize::new(0));
+    
+    scheduler.add_agent(Box::new(CountingAgent::new(first.clone(), false))).await;
+    scheduler.add_agent(Box::new(CountingAgent::new(second.clone(), false))).await;
+    
+    scheduler.start().await;
+    assert!(scheduler.is_running().await);
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    scheduler.stop().await;
+    assert!(!scheduler.is_running().await);
+    
+    assert!(first.load(Ordering::SeqCst) >= 3);
+    assert!(second.load(Ordering::SeqCst) >= 3);
+    
+    // No further processing once stopped
+    let stopped_at = first.load(Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(first.load(Ordering::SeqCst), stopped_at);
+}
+
+#[test]
+fn test_scheduler_rejects_zero_tick_interval() {
+    assert!(AgentScheduler::new(Duration::ZERO).is_err());
+}
+
+#[tokio::test]
+async fn test_scheduler_skips_inactive_agents() {
+    let scheduler = AgentScheduler::new(Duration::from_millis(10)).unwrap();
+    let calls = Arc::new(AtomicUsize::new(0));
+    
+    let mut agent = CountingAgent::new(calls.clone(), false);
+    agent.state = AgentState::Suspended;
+    scheduler.add_agent(Box::new(agent)).await;
+    
+    assert_eq!(scheduler.tick().await, 0);
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn test_scheduler_suspends_after_consecutive_failures() {
+    let scheduler = AgentScheduler::new(Duration::from_millis(10))
+        .unwrap()
+        .with_max_consecutive_failures(3);
+    let calls = Arc::new(AtomicUsize::new(0));
+    let agent_id = scheduler.add_agent(Box::new(CountingAgent::new(calls.clone(), true))).await;
+    
+    scheduler.tick().await;
+    scheduler.tick().await;
+    assert_eq!(scheduler.consecutive_failures(agent_id).await, 2);
+    assert_eq!(scheduler.agent_state(agent_id).await, Some(AgentState::Active));
+    
+    scheduler.tick().await;
+    assert_eq!(scheduler.agent_state(agent_id).await, Some(AgentState::Suspended));
+    
+    // Suspended agents are no longer driven
+    scheduler.tick().await;
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_slow_agent_holds_up_neither_the_roster_nor_other_agents() {
+    let scheduler = Arc::new(AgentScheduler::new(Duration::from_millis(10)).unwrap());
+    let slow_calls = Arc::new(AtomicUsize::new(0));
+    let fast_calls = Arc::new(AtomicUsize::new(0));
+    scheduler.add_agent(Box::new(CountingAgent::slow(slow_calls.clone(), Duration::from_secs(1)))).await;
+    let fast_id = scheduler.add_agent(Box::new(CountingAgent::new(fast_calls.clone(), false))).await;
+    
+    let tick = {
+        let scheduler = scheduler.clone();
+        tokio::spawn(async move { scheduler.tick().await })
+    };
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    
+    // Mid-tick, the other agent has been processed and the roster is still usable
+    assert_eq!(fast_calls.load(Ordering::SeqCst), 1);
+    assert_eq!(slow_calls.load(Ordering::SeqCst), 0);
+    assert_eq!(scheduler.agent_state(fast_id).await, Some(AgentState::Active));
+    let late_calls = Arc::new(AtomicUsize::new(0));
+    let late_id = scheduler.add_agent(Box::new(CountingAgent::new(late_calls.clone(), false))).await;
+    assert!(scheduler.remove_agent(late_id).await.is_some());
+    assert!(!tick.is_finished());
+    
+    assert_eq!(tick.await.unwrap(), 2);
+    assert_eq!(slow_calls.load(Ordering::SeqCst), 1);
+    assert_eq!(late_calls.load(Ordering::SeqCst), 0);
+}
@@ -0,0 +1,193 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use axum::{
+    extract::{MatchedPath, OriginalUri, Request, State},
+    http::Method,
+    middleware::Next,
+    response::Response,
+};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+use crate::auth::Claims;
+use crate::AppState;
+
+/// Who changed what through the API, and how it turned out
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// Subject of the caller's token
+    pub subject: String,
+    pub method: String,
+    /// Route template, e.g. `/api/v1/agents/:id`
+    pub route: String,
+    pub resource_id: Option<String>,
+    pub status: u16,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+/// Set on a response to name the resource a request created, which the
+/// path cannot tell the audit log
+#[derive(Debug, Clone)]
+pub struct AuditResource(pub String);
+
+/// Destination for audit entries
+#[async_trait]
+pub trait AuditSink: Send + Sync {
+    async fn record(&self, entry: AuditEntry) -> Result<()>;
+}
+
+/// Writes entries to the tracing log under the `audit` target
+#[derive(Debug, Default)]
+pub struct ConsoleAuditSink;
+
+#[async_trait]
+impl AuditSink for ConsoleAuditSink {
+    async fn record(&self, entry: AuditEntry) -> Result<()> {
+        tracing::info!(
+            target: "audit",
+            subject = %entry.subject,
+            method = %entry.method,
+            route = %entry.route,
+            resource_id = entry.resource_id.as_deref().unwrap_or("-"),
+            status = entry.status,
+            timestamp = %entry.timestamp,
+            "audit"
+        );
+        Ok(())
+    }
+}
+
+/// Appends entries to a file as JSON lines
+#[derive(Debug)]
+pub struct FileAuditSink {
+    path: PathBuf,
+    file: Mutex<tokio::fs::File>,
+}
+
+impl FileAuditSink {
+    /// Open `path` for appending, creating it if needed
+    pub async fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .await
+            .with_context(|| format!("Failed to open audit log {}", path.display()))?;
+
+        Ok(Self {
+            path,
+            file: Mutex::new(file),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[async_trait]
+impl AuditSink for FileAuditSink {
+    async fn record(&self, entry: AuditEntry) -> Result<()> {
+        let mut line = serde_json::to_vec(&entry)?;
+        line.push(b'\n');
+
+        let mut file = self.file.lock().await;
+        file.write_all(&line).await
+            .with_context(|| format!("Failed to write audit log {}", self.path.display()))?;
+        file.flush().await?;
+        Ok(())
+    }
+}
+
+/// Keeps entries in memory, for inspecting in tests
+#[derive(Debug, Default)]
+pub struct MemoryAuditSink {
+    entries: Mutex<Vec<AuditEntry>>,
+}
+
+impl MemoryAuditSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub async fn entries(&self) -> Vec<AuditEntry> {
+        self.entries.lock().await.clone()
+    }
+}
+
+#[async_trait]
+impl AuditSink for MemoryAuditSink {
+    async fn record(&self, entry: AuditEntry) -> Result<()> {
+        self.entries.lock().await.push(entry);
+        Ok(())
+    }
+}
+
+fn is_mutation(method: &Method) -> bool {
+    matches!(*method, Method::POST | Method::PUT | Method::PATCH | Method::DELETE)
+}
+
+/// Values of the `:param` segments of `route` in `path`, joined with `/`
+fn path_resource_id(route: &str, path: &str) -> Option<String> {
+    let params: Vec<&str> = route.split('/')
+        .zip(path.split('/'))
+        .filter(|(template, _)| template.starts_with(':'))
+        .map(|(_, value)| value)
+        .collect();
+
+    (!params.is_empty()).then(|| params.join("/"))
+}
+
+/// Record every authenticated mutating request to the state's audit sink.
+/// Must run inside `auth_middleware`, which supplies the caller's claims.
+pub async fn audit_middleware(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let subject = request.extensions().get::<Claims>().map(|claims| claims.sub.clone());
+    let (Some(subject), true) = (subject, is_mutation(request.method())) else {
+        return next.run(request).await;
+    };
+
+    let method = request.method().to_string();
+    // Nested routers see the path with their prefix stripped, but the matched route keeps it
+    let path = request.extensions().get::<OriginalUri>()
+        .map(|uri| uri.path().to_string())
+        .unwrap_or_else(|| request.uri().path().to_string());
+    let route = request.extensions().get::<MatchedPath>()
+        .map(|matched| matched.as_str().to_string())
+        .unwrap_or_else(|| path.clone());
+
+    let response = next.run(request).await;
+
+    let resource_id = response.extensions().get::<AuditResource>()
+        .map(|resource| resource.0.clone())
+        .or_else(|| path_resource_id(&route, &path));
+    let entry = AuditEntry {
+        subject,
+        method,
+        route,
+        resource_id,
+        status: response.status().as_u16(),
+        timestamp: chrono::Utc::now(),
+    };
+    if let Err(e) = state.audit.record(entry).await {
+        tracing::error!("Failed to record audit entry: {:#}", e);
+    }
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resource_id_comes_from_route_params() {
+        assert_eq!(path_resource_id("/api/v1/agents/:id", "/api/v1/agents/42"), Some("42".to_string()));
+        assert_eq!(path_resource_id("/api/v1/agents", "/api/v1/agents"), None);
+    }
+}
@@ -17,6 +17,19 @@ pub struct Claims {
     pub role: String,
 }
 
+/// Role allowed to perform administrative operations
+pub const ADMIN_ROLE: &str = "admin";
+
+impl Claims {
+    pub fn require_admin(&self) -> Result<(), ApiError> {
+        if self.role == ADMIN_ROLE {
+            Ok(())
+        } else {
+            Err(ApiError::Forbidden)
+        }
+    }
+}
+
 pub struct TokenValidator {
     encoding_key: EncodingKey,
     decoding_key: DecodingKey,
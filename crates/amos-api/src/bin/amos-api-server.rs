@@ -1,6 +1,7 @@
-use amos_api::{create_app, AppState};
+use amos_api::{create_app, AppState, BodyLimits, FileAuditSink, JsonFileStore};
+use std::sync::Arc;
 use std::net::SocketAddr;
-use tracing::{info, Level};
+use tracing::{error, info, Level};
 use tracing_subscriber::FmtSubscriber;
 
 #[tokio::main]
@@ -19,8 +20,26 @@ async fn main() -> anyhow::Result<()> {
     let jwt_secret = std::env::var("JWT_SECRET")
         .unwrap_or_else(|_| "development-secret-key".to_string());
 
+    let mut body_limits = BodyLimits::default();
+    if let Ok(limit) = std::env::var("IMPORT_BODY_LIMIT_BYTES") {
+        body_limits.large = limit.parse()?;
+    }
+
     // Create application state
-    let state = AppState::new(jwt_secret);
+    let mut state = AppState::new(jwt_secret).with_body_limits(body_limits);
+    if let Ok(store_path) = std::env::var("STORE_PATH") {
+        state = state.with_store(Arc::new(JsonFileStore::open(&store_path).await?));
+        let restored = state.restore().await
+            .map_err(|e| anyhow::anyhow!("Failed to restore from {}: {}", store_path, e))?;
+        info!("Restored {} agents from {}", restored, store_path);
+    }
+    if let Ok(audit_path) = std::env::var("AUDIT_LOG_PATH") {
+        state = state.with_audit_sink(Arc::new(FileAuditSink::open(&audit_path).await?));
+        info!("Writing audit log to {}", audit_path);
+    }
+    if let Err(e) = state.bootstrap().await {
+        error!("Bootstrap failed, /health/ready will report not ready and retry it: {}", e);
+    }
 
     // Create the application
     let app = create_app(state);
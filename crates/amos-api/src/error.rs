@@ -1,10 +1,11 @@
 use axum::{
-    http::StatusCode,
+    http::{header, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::fmt;
+use utoipa::ToSchema;
 
 pub type ApiResult<T> = Result<T, ApiError>;
 
@@ -15,16 +16,39 @@ pub enum ApiError {
     Unauthorized,
     Forbidden,
     Internal(String),
-    Conflict(String),
     ValidationError(String),
+    PayloadTooLarge(String),
+    ServiceUnavailable(String),
 }
 
-#[derive(Serialize)]
-struct ErrorResponse {
-    error: String,
-    message: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    details: Option<String>,
+/// Body of every error response
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ErrorResponse {
+    pub error: ErrorBody,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ErrorBody {
+    /// Machine-readable error code, e.g. `not_found`
+    pub code: String,
+    pub message: String,
+    pub details: Option<String>,
+}
+
+impl ApiError {
+    /// Stable code clients can match on instead of parsing messages
+    pub fn code(&self) -> &'static str {
+        match self {
+            ApiError::NotFound(_) => "not_found",
+            ApiError::BadRequest(_) => "bad_request",
+            ApiError::Unauthorized => "unauthorized",
+            ApiError::Forbidden => "forbidden",
+            ApiError::Internal(_) => "internal",
+            ApiError::ValidationError(_) => "validation_error",
+            ApiError::PayloadTooLarge(_) => "payload_too_large",
+            ApiError::ServiceUnavailable(_) => "service_unavailable",
+        }
+    }
 }
 
 impl fmt::Display for ApiError {
@@ -35,8 +59,9 @@ impl fmt::Display for ApiError {
             ApiError::Unauthorized => write!(f, "Unauthorized"),
             ApiError::Forbidden => write!(f, "Forbidden"),
             ApiError::Internal(msg) => write!(f, "Internal error: {}", msg),
-            ApiError::Conflict(msg) => write!(f, "Conflict: {}", msg),
             ApiError::ValidationError(msg) => write!(f, "Validation error: {}", msg),
+            ApiError::PayloadTooLarge(msg) => write!(f, "Payload too large: {}", msg),
+            ApiError::ServiceUnavailable(msg) => write!(f, "Service unavailable: {}", msg),
         }
     }
 }
@@ -49,20 +74,41 @@ impl IntoResponse for ApiError {
             ApiError::Unauthorized => (StatusCode::UNAUTHORIZED, "Unauthorized".to_string()),
             ApiError::Forbidden => (StatusCode::FORBIDDEN, "Forbidden".to_string()),
             ApiError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg.clone()),
-            ApiError::Conflict(msg) => (StatusCode::CONFLICT, msg.clone()),
             ApiError::ValidationError(msg) => (StatusCode::UNPROCESSABLE_ENTITY, msg.clone()),
+            ApiError::PayloadTooLarge(msg) => (StatusCode::PAYLOAD_TOO_LARGE, msg.clone()),
+            ApiError::ServiceUnavailable(msg) => (StatusCode::SERVICE_UNAVAILABLE, msg.clone()),
         };
 
         let body = Json(ErrorResponse {
-            error: status.as_str().to_string(),
-            message: error_message.clone(),
-            details: Some(self.to_string()),
+            error: ErrorBody {
+                code: self.code().to_string(),
+                message: error_message,
+                details: Some(self.to_string()),
+            },
         });
 
         (status, body).into_response()
     }
 }
 
+/// Give rejections raised outside the handlers, such as the body limit's
+/// bare 413, the same body as every other error
+pub async fn shape_rejections(response: Response) -> Response {
+    if response.status() == StatusCode::PAYLOAD_TOO_LARGE && !is_json(&response) {
+        return ApiError::PayloadTooLarge("Request body exceeds the limit for this route".to_string())
+            .into_response();
+    }
+    response
+}
+
+fn is_json(response: &Response) -> bool {
+    response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"))
+}
+
 impl From<anyhow::Error> for ApiError {
     fn from(err: anyhow::Error) -> Self {
         ApiError::Internal(err.to_string())
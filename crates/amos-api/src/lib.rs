@@ -4,11 +4,17 @@ pub mod state;
 pub mod error;
 pub mod models;
 pub mod websocket;
+pub mod store;
+pub mod negotiate;
+pub mod audit;
 
-pub use error::{ApiError, ApiResult};
-pub use state::AppState;
+pub use error::{ApiError, ApiResult, ErrorResponse};
+pub use state::{AppState, BodyLimits};
+pub use store::{Store, MemoryStore, JsonFileStore};
+pub use negotiate::{Negotiated, ResponseFormat};
+pub use audit::{AuditEntry, AuditSink, ConsoleAuditSink, FileAuditSink, MemoryAuditSink};
 
-use axum::{Router, middleware};
+use axum::{extract::DefaultBodyLimit, Router, middleware};
 use tower_http::{
     cors::CorsLayer,
     limit::RequestBodyLimitLayer,
@@ -25,38 +31,71 @@ use utoipa_swagger_ui::SwaggerUi;
         routes::agents::list_agents,
         routes::agents::get_agent,
         routes::agents::create_agent,
+        routes::agents::create_agents_batch,
+        routes::agents::list_agent_types,
         routes::agents::delete_agent,
         routes::agents::send_agent_command,
         routes::neural::get_neural_state,
         routes::neural::update_neural_pathway,
+        routes::neural::get_strength_histogram,
+        routes::neural::reset_neural_network,
         routes::swarm::create_swarm,
         routes::swarm::list_swarms,
         routes::swarm::orchestrate_task,
+        routes::swarm::export_swarm,
+        routes::swarm::import_swarm,
+        routes::swarm::list_active_tasks,
+        routes::swarm::task_history,
+        routes::swarm::swarm_status,
         routes::hormonal::get_hormonal_levels,
         routes::hormonal::update_hormonal_levels,
+        routes::hormonal::patch_hormonal_levels,
         routes::metrics::get_system_metrics,
         routes::metrics::get_agent_metrics,
         routes::metrics::get_swarm_metrics,
+        routes::metrics::get_aggregate_swarm_metrics,
+        routes::metrics::get_latency_metrics,
+        routes::shadow::get_metric_trend,
+        routes::shadow::get_anomalies,
         routes::auth::login,
         routes::auth::refresh_token,
+        routes::health::readiness,
     ),
     components(
         schemas(
             models::agent::AgentInfo,
             models::agent::CreateAgentRequest,
+            models::agent::BatchAgentResult,
+            models::agent::AgentTypeInfo,
             models::agent::AgentCommand,
             models::neural::NeuralState,
             models::neural::PathwayUpdate,
+            models::neural::StrengthHistogram,
+            models::neural::HistogramBucket,
+            models::neural::NeuralResetSummary,
             models::swarm::SwarmInfo,
             models::swarm::CreateSwarmRequest,
             models::swarm::OrchestrateTaskRequest,
+            models::swarm::SwarmDescriptor,
+            models::swarm::AgentDescriptor,
+            models::swarm::ActiveTask,
+            models::swarm::CompletedTask,
+            models::swarm::SwarmStatusDetail,
             models::neural::HormonalUpdate,
             models::metrics::SystemMetrics,
             models::metrics::AgentMetrics,
             models::metrics::SwarmMetrics,
+            models::metrics::AggregateSwarmMetrics,
+            models::metrics::StrategyLatency,
+            models::shadow::MetricTrend,
+            models::shadow::TrendPoint,
+            models::shadow::AnomalyInfo,
             routes::auth::LoginRequest,
             routes::auth::LoginResponse,
             routes::auth::RefreshRequest,
+            routes::health::ReadinessResponse,
+            error::ErrorResponse,
+            error::ErrorBody,
         )
     ),
     tags(
@@ -65,7 +104,9 @@ use utoipa_swagger_ui::SwaggerUi;
         (name = "swarm", description = "Swarm orchestration operations"),
         (name = "hormonal", description = "Hormonal system control"),
         (name = "metrics", description = "Performance metrics and monitoring"),
+        (name = "shadow", description = "Shadow transformation monitoring"),
         (name = "auth", description = "Authentication endpoints"),
+        (name = "health", description = "Liveness and readiness probes"),
     )
 )]
 pub struct ApiDoc;
@@ -74,12 +115,19 @@ pub fn create_app(state: AppState) -> Router {
     // Start neural activity broadcaster
     websocket::start_neural_activity_broadcaster(state.clone());
     
+    let body_limits = state.body_limits;
     let api_routes = Router::new()
-        .merge(routes::agents::router())
+        .merge(routes::agents::router(body_limits))
         .merge(routes::neural::router())
-        .merge(routes::swarm::router())
+        .merge(routes::swarm::router(body_limits))
         .merge(routes::hormonal::router())
         .merge(routes::metrics::router())
+        .merge(routes::shadow::router())
+        // Auditing runs inside auth, once the caller is known
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            audit::audit_middleware,
+        ))
         .layer(middleware::from_fn_with_state(
             state.clone(),
             auth::auth_middleware,
@@ -96,7 +144,10 @@ pub fn create_app(state: AppState) -> Router {
         .merge(routes::health::router())
         .layer(TraceLayer::new_for_http())
         .layer(CorsLayer::permissive())
-        .layer(RequestBodyLimitLayer::new(10 * 1024 * 1024)) // 10MB
+        // Routes can raise or lower the default, but never past the ceiling
+        .layer(DefaultBodyLimit::max(body_limits.default))
+        .layer(RequestBodyLimitLayer::new(body_limits.ceiling()))
+        .layer(middleware::map_response(error::shape_rejections))
         .layer(TimeoutLayer::new(Duration::from_secs(30)))
         .with_state(state)
 }
@@ -115,4 +166,1207 @@ mod tests {
         let response = server.get("/health").await;
         assert_eq!(response.status_code(), StatusCode::OK);
     }
-}
\ No newline at end of file
+
+    fn bearer_token(state: &AppState) -> String {
+        state.token_validator.create_token("tester", "admin").unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_missing_agent_returns_documented_error_body() {
+        let state = AppState::test();
+        let token = bearer_token(&state);
+        let server = TestServer::new(create_app(state)).unwrap();
+
+        let missing = uuid::Uuid::new_v4();
+        let response = server
+            .get(&format!("/api/v1/agents/{}", missing))
+            .authorization_bearer(&token)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+
+        let body: ErrorResponse = response.json();
+        assert_eq!(body.error.code, "not_found");
+        assert_eq!(body.error.message, format!("Agent {} not found", missing));
+
+        // The error schema is published for client generation
+        let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+        assert!(doc["components"]["schemas"]["ErrorResponse"].is_object());
+        let not_found = &doc["paths"]["/api/v1/agents/{id}"]["get"]["responses"]["404"];
+        assert_eq!(not_found["content"]["application/json"]["schema"]["$ref"], "#/components/schemas/ErrorResponse");
+    }
+
+    #[tokio::test]
+    async fn test_swarm_export_import_round_trip() {
+        let state = AppState::test();
+        let token = bearer_token(&state);
+        let server = TestServer::new(create_app(state.clone())).unwrap();
+
+        let mut agent_ids = Vec::new();
+        for agent_type in ["traffic_seer", "memory_weaver", "pathway_sculptor"] {
+            let response = server
+                .post("/api/v1/agents")
+                .authorization_bearer(&token)
+                .json(&serde_json::json!({
+                    "name": agent_type,
+                    "agent_type": agent_type,
+                    "shadow_mode": false,
+                }))
+                .await;
+            let agent: serde_json::Value = response.json();
+            agent_ids.push(agent["id"].as_str().unwrap().to_string());
+        }
+
+        let swarm: serde_json::Value = server
+            .post("/api/v1/swarms")
+            .authorization_bearer(&token)
+            .json(&serde_json::json!({
+                "name": "Export Mesh",
+                "agent_ids": agent_ids,
+                "topology": "mesh",
+            }))
+            .await
+            .json();
+        let swarm_id = swarm["id"].as_str().unwrap();
+
+        let response = server
+            .get(&format!("/api/v1/swarms/{}/export", swarm_id))
+            .authorization_bearer(&token)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+        let descriptor: serde_json::Value = response.json();
+        assert_eq!(descriptor["topology"], "mesh");
+        assert_eq!(descriptor["agents"].as_array().unwrap().len(), 3);
+
+        let response = server
+            .post("/api/v1/swarms/import")
+            .authorization_bearer(&token)
+            .json(&descriptor)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::CREATED);
+        let imported: serde_json::Value = response.json();
+        assert_ne!(imported["id"], swarm["id"]);
+        assert_eq!(imported["agent_count"], 3);
+
+        // Fresh agents were spawned for the imported swarm
+        assert_eq!(state.agents.read().await.len(), 6);
+
+        let reexported: serde_json::Value = server
+            .get(&format!("/api/v1/swarms/{}/export", imported["id"].as_str().unwrap()))
+            .authorization_bearer(&token)
+            .await
+            .json();
+        assert_eq!(reexported["topology"], descriptor["topology"]);
+        assert_eq!(reexported["agents"], descriptor["agents"]);
+    }
+
+    #[tokio::test]
+    async fn test_failed_import_leaves_no_agents_behind() {
+        let state = AppState::test();
+        let token = bearer_token(&state);
+        let server = TestServer::new(create_app(state.clone())).unwrap();
+
+        let response = server
+            .post("/api/v1/swarms/import")
+            .authorization_bearer(&token)
+            .json(&serde_json::json!({
+                "name": "Half Mesh",
+                "topology": "mesh",
+                "agents": [
+                    { "agent_type": "traffic_seer", "name": "TrafficSeer" },
+                    { "agent_type": "no_such_agent", "name": "Nobody" },
+                ],
+            }))
+            .await;
+        assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+
+        assert!(state.agents.read().await.is_empty());
+        assert!(state.store.load_agents().await.unwrap().is_empty());
+        assert!(state.swarms.read().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_hierarchical_swarm_status_reports_placements() {
+        let state = AppState::test();
+        let token = bearer_token(&state);
+        let server = TestServer::new(create_app(state.clone())).unwrap();
+
+        let mut agent_ids = Vec::new();
+        for agent_type in ["traffic_seer", "memory_weaver", "pathway_sculptor"] {
+            let agent: serde_json::Value = server
+                .post("/api/v1/agents")
+                .authorization_bearer(&token)
+                .json(&serde_json::json!({
+                    "name": agent_type,
+                    "agent_type": agent_type,
+                    "shadow_mode": false,
+                }))
+                .await
+                .json();
+            agent_ids.push(agent["id"].as_str().unwrap().to_string());
+        }
+
+        let swarm: serde_json::Value = server
+            .post("/api/v1/swarms")
+            .authorization_bearer(&token)
+            .json(&serde_json::json!({
+                "name": "Chain of command",
+                "agent_ids": agent_ids,
+                "topology": "hierarchical",
+            }))
+            .await
+            .json();
+
+        let response = server
+            .get(&format!("/api/v1/swarms/{}/status", swarm["id"].as_str().unwrap()))
+            .authorization_bearer(&token)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+        let status: serde_json::Value = response.json();
+        assert_eq!(status["topology"], "hierarchical");
+        assert_eq!(status["agent_count"], 3);
+
+        // Each agent lands one level below the one before and reports to it
+        let placements = status["placements"].as_object().unwrap();
+        assert_eq!(placements.len(), 3);
+        for (level, id) in agent_ids.iter().enumerate() {
+            let placement = &placements[id];
+            assert_eq!(placement["type"], "Hierarchical");
+            assert_eq!(placement["level"], level);
+            let expected_parent = match level {
+                0 => serde_json::Value::Null,
+                _ => serde_json::json!(agent_ids[level - 1]),
+            };
+            assert_eq!(placement["parent"], expected_parent);
+        }
+
+        let response = server
+            .get(&format!("/api/v1/swarms/{}/status", uuid::Uuid::new_v4()))
+            .authorization_bearer(&token)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_orchestrate_rejects_missing_or_unknown_capabilities() {
+        let state = AppState::test();
+        let token = bearer_token(&state);
+        let server = TestServer::new(create_app(state.clone())).unwrap();
+
+        let mut agent_ids = Vec::new();
+        for agent_type in ["traffic_seer", "memory_weaver"] {
+            let agent: serde_json::Value = server
+                .post("/api/v1/agents")
+                .authorization_bearer(&token)
+                .json(&serde_json::json!({
+                    "name": agent_type,
+                    "agent_type": agent_type,
+                    "shadow_mode": false,
+                }))
+                .await
+                .json();
+            agent_ids.push(agent["id"].as_str().unwrap().to_string());
+        }
+        let swarm: serde_json::Value = server
+            .post("/api/v1/swarms")
+            .authorization_bearer(&token)
+            .json(&serde_json::json!({
+                "name": "No optimizers",
+                "agent_ids": agent_ids,
+                "topology": "mesh",
+            }))
+            .await
+            .json();
+        let orchestrate_path = format!("/api/v1/swarms/{}/orchestrate", swarm["id"].as_str().unwrap());
+
+        let orchestrate = |capability: &str| {
+            server
+                .post(&orchestrate_path)
+                .authorization_bearer(&token)
+                .json(&serde_json::json!({
+                    "task_description": "tune the network",
+                    "strategy": "parallel",
+                    "priority": "medium",
+                    "required_capabilities": [capability],
+                }))
+        };
+
+        let response = orchestrate("neural_optimization").await;
+        assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+        let body: serde_json::Value = response.json();
+        let message = body["error"]["message"].as_str().unwrap();
+        assert!(message.contains("Insufficient capable agents"), "{}", message);
+        assert!(message.contains("NeuralOptimization"), "{}", message);
+
+        let response = orchestrate("telepathy").await;
+        assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+        let body: serde_json::Value = response.json();
+        assert_eq!(body["error"]["message"], "Unknown capability 'telepathy'");
+
+        let response = orchestrate("pattern_recognition").await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+        let result: serde_json::Value = response.json();
+        assert_eq!(result["result"]["contributions"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_agent_types_endpoint() {
+        let state = AppState::test();
+        let token = bearer_token(&state);
+        let server = TestServer::new(create_app(state)).unwrap();
+
+        let response = server
+            .get("/api/v1/agents/types")
+            .authorization_bearer(&token)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+
+        let types: Vec<serde_json::Value> = response.json();
+        let traffic_seer = types
+            .iter()
+            .find(|t| t["agent_type"] == "traffic_seer")
+            .expect("traffic_seer listed");
+        assert!(traffic_seer["capabilities"]
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!("pattern_recognition")));
+        assert!(types.iter().any(|t| t["agent_type"] == "memory_weaver"));
+    }
+
+    #[tokio::test]
+    async fn test_agents_list_negotiates_msgpack() {
+        let state = AppState::test();
+        let token = bearer_token(&state);
+        let server = TestServer::new(create_app(state)).unwrap();
+
+        for agent_type in ["traffic_seer", "memory_weaver"] {
+            server
+                .post("/api/v1/agents")
+                .authorization_bearer(&token)
+                .json(&serde_json::json!({
+                    "name": agent_type,
+                    "agent_type": agent_type,
+                    "shadow_mode": false,
+                }))
+                .await;
+        }
+
+        let response = server
+            .get("/api/v1/agents")
+            .authorization_bearer(&token)
+            .await;
+        assert_eq!(response.header("content-type"), "application/json");
+        let mut as_json: Vec<models::agent::AgentInfo> = response.json();
+
+        let response = server
+            .get("/api/v1/agents")
+            .authorization_bearer(&token)
+            .add_header(
+                axum::http::header::ACCEPT,
+                axum::http::HeaderValue::from_static("application/msgpack, application/json;q=0.5"),
+            )
+            .await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+        assert_eq!(response.header("content-type"), "application/msgpack");
+        let body = response.as_bytes();
+        let mut decoder = rmp_serde::Deserializer::new(&body[..]).with_human_readable();
+        let mut as_msgpack: Vec<models::agent::AgentInfo> = serde::Deserialize::deserialize(&mut decoder).unwrap();
+
+        // Creation time and network id are synthesized per request, so compare the rest
+        let summary = |agents: &mut Vec<models::agent::AgentInfo>| {
+            agents.sort_by_key(|agent| agent.id);
+            agents.iter()
+                .map(|agent| (agent.id, agent.name.clone(), agent.agent_type.clone(), agent.state.clone()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(as_json.len(), 2);
+        assert_eq!(summary(&mut as_msgpack), summary(&mut as_json));
+    }
+
+    #[tokio::test]
+    async fn test_neural_reset_wipes_network_for_admins_only() {
+        let state = AppState::test();
+        let token = bearer_token(&state);
+        let server = TestServer::new(create_app(state.clone())).unwrap();
+
+        let memory = state.neural_network.add_node(amos_core::NodeType::Memory).await;
+        let thinking = state.neural_network.add_node(amos_core::NodeType::Thinking).await;
+        let agent = state.neural_network.add_node(amos_core::NodeType::Agent).await;
+        state.neural_network.create_pathway(memory, thinking, 0.6).await;
+        state.neural_network.create_pathway(thinking, agent, 0.4).await;
+
+        let viewer = state.token_validator.create_token("viewer", "user").unwrap();
+        let response = server
+            .post("/api/v1/neural/reset")
+            .authorization_bearer(&viewer)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+        assert_eq!(state.neural_network.node_count().await, 3);
+
+        let response = server
+            .post("/api/v1/neural/reset")
+            .authorization_bearer(&token)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+        let summary: models::neural::NeuralResetSummary = response.json();
+        assert_eq!(summary.nodes_removed, 3);
+        assert_eq!(summary.pathways_removed, 2);
+
+        let neural_state: models::neural::NeuralState = server
+            .get("/api/v1/neural/state")
+            .authorization_bearer(&token)
+            .await
+            .json();
+        assert_eq!(neural_state.total_nodes, 0);
+        assert_eq!(neural_state.total_pathways, 0);
+    }
+
+    #[tokio::test]
+    async fn test_create_agent_rejects_unknown_type() {
+        let state = AppState::test();
+        let token = bearer_token(&state);
+        let server = TestServer::new(create_app(state.clone())).unwrap();
+
+        let response = server
+            .post("/api/v1/agents")
+            .authorization_bearer(&token)
+            .json(&serde_json::json!({
+                "name": "Bogus",
+                "agent_type": "architect",
+                "shadow_mode": false,
+            }))
+            .await;
+        assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+        assert!(state.agents.read().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_batch_agent_creation_reports_partial_failure() {
+        let state = AppState::test();
+        let token = bearer_token(&state);
+        let server = TestServer::new(create_app(state.clone())).unwrap();
+
+        let spec = |agent_type: &str| serde_json::json!({
+            "name": agent_type,
+            "agent_type": agent_type,
+            "shadow_mode": false,
+        });
+        let response = server
+            .post("/api/v1/agents/batch")
+            .authorization_bearer(&token)
+            .json(&serde_json::json!([
+                spec("traffic_seer"),
+                spec("architect"),
+                spec("memory_weaver"),
+                spec("traffic_seer"),
+            ]))
+            .await;
+        assert_eq!(response.status_code(), StatusCode::MULTI_STATUS);
+
+        let results: Vec<serde_json::Value> = response.json();
+        assert_eq!(results.len(), 4);
+        assert!(results[1]["error"].as_str().unwrap().contains("architect"));
+        assert!(results[1].get("agent").is_none());
+        assert_eq!(results.iter().filter(|r| r.get("agent").is_some()).count(), 3);
+        assert_eq!(state.agents.read().await.len(), 3);
+
+        let response = server
+            .post("/api/v1/agents/batch")
+            .authorization_bearer(&token)
+            .json(&serde_json::json!([spec("traffic_seer")]))
+            .await;
+        assert_eq!(response.status_code(), StatusCode::CREATED);
+        let agents: Vec<serde_json::Value> = response.json();
+        assert_eq!(agents[0]["agent_type"], "traffic_seer");
+    }
+
+    #[tokio::test]
+    async fn test_agent_metrics_report_processed_cycles() {
+        let state = AppState::test();
+        let token = bearer_token(&state);
+
+        let mut agent = amos_agents::find_agent_type("mesh_harmonizer").unwrap().create();
+        agent.initialize(state.neural_network.clone(), state.event_bus.clone()).await.unwrap();
+        for _ in 0..3 {
+            agent.process().await.unwrap();
+        }
+        let agent_id = agent.id();
+        state.agents.write().await.insert(agent_id, std::sync::Arc::from(agent));
+        let server = TestServer::new(create_app(state)).unwrap();
+
+        let response = server
+            .get("/api/v1/metrics/agents")
+            .authorization_bearer(&token)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+
+        let metrics: Vec<serde_json::Value> = response.json();
+        let entry = metrics.iter().find(|m| m["agent_id"] == agent_id.to_string()).unwrap();
+        assert_eq!(entry["tasks_completed"], 3);
+        assert_eq!(entry["errors"], 0);
+    }
+
+    #[tokio::test]
+    async fn test_agent_metrics_count_orchestrated_work() {
+        let state = AppState::test();
+        let token = bearer_token(&state);
+        let server = TestServer::new(create_app(state)).unwrap();
+
+        let mut agent_ids = Vec::new();
+        for agent_type in ["traffic_seer", "memory_weaver"] {
+            let agent: serde_json::Value = server
+                .post("/api/v1/agents")
+                .authorization_bearer(&token)
+                .json(&serde_json::json!({
+                    "name": agent_type,
+                    "agent_type": agent_type,
+                    "shadow_mode": false,
+                }))
+                .await
+                .json();
+            agent_ids.push(agent["id"].as_str().unwrap().to_string());
+        }
+        let swarm: serde_json::Value = server
+            .post("/api/v1/swarms")
+            .authorization_bearer(&token)
+            .json(&serde_json::json!({
+                "name": "Counted Mesh",
+                "agent_ids": agent_ids,
+                "topology": "mesh",
+            }))
+            .await
+            .json();
+
+        for _ in 0..2 {
+            let response = server
+                .post(&format!("/api/v1/swarms/{}/orchestrate", swarm["id"].as_str().unwrap()))
+                .authorization_bearer(&token)
+                .json(&serde_json::json!({
+                    "task_description": "Count me",
+                    "strategy": "parallel",
+                    "timeout_seconds": 30,
+                    "priority": "medium",
+                }))
+                .await;
+            assert_eq!(response.status_code(), StatusCode::OK);
+        }
+
+        let metrics: Vec<serde_json::Value> = server
+            .get("/api/v1/metrics/agents")
+            .authorization_bearer(&token)
+            .await
+            .json();
+        for agent_id in &agent_ids {
+            let entry = metrics.iter().find(|m| &m["agent_id"] == agent_id).unwrap();
+            assert_eq!(entry["tasks_completed"], 2);
+            assert!(entry["average_confidence"].as_f64().unwrap() > 0.0);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_strength_histogram_endpoint() {
+        let state = AppState::test();
+        let token = bearer_token(&state);
+        let source = state.neural_network.add_node(amos_core::neural::NodeType::Thinking).await;
+        let target = state.neural_network.add_node(amos_core::neural::NodeType::Memory).await;
+        state.neural_network.create_pathway(source, target, 0.95).await;
+        let server = TestServer::new(create_app(state)).unwrap();
+
+        let response = server
+            .get("/api/v1/neural/histogram")
+            .add_query_param("buckets", 4)
+            .authorization_bearer(&token)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+
+        let histogram: serde_json::Value = response.json();
+        assert_eq!(histogram["total_pathways"], 1);
+        assert_eq!(histogram["buckets"].as_array().unwrap().len(), 4);
+        assert_eq!(histogram["buckets"][3]["count"], 1);
+
+        let response = server
+            .get("/api/v1/neural/histogram")
+            .add_query_param("buckets", 0)
+            .authorization_bearer(&token)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_shadow_metric_trend() {
+        let state = AppState::test();
+        let token = bearer_token(&state);
+        let server = TestServer::new(create_app(state.clone())).unwrap();
+
+        let response = server
+            .post("/api/v1/agents")
+            .authorization_bearer(&token)
+            .json(&serde_json::json!({
+                "name": "Shadowed",
+                "agent_type": "traffic_seer",
+                "shadow_mode": true,
+            }))
+            .await;
+        let agent: serde_json::Value = response.json();
+        let shadow_id: uuid::Uuid = serde_json::from_value(agent["id"].clone()).unwrap();
+
+        let shadow = state.shadows.read().await[&shadow_id].clone();
+        for _ in 0..3 {
+            shadow
+                .update_metrics(amos_shadow::MetricsUpdate::AutonomyScore(0.1))
+                .await
+                .unwrap();
+        }
+
+        let response = server
+            .get(&format!("/api/v1/shadow/{}/metrics/trend", shadow_id))
+            .add_query_param("metric", "autonomy_score")
+            .add_query_param("hours", 24)
+            .authorization_bearer(&token)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+
+        let trend: serde_json::Value = response.json();
+        let points = trend["points"].as_array().unwrap();
+        assert_eq!(points.len(), 3);
+        assert!(points.iter().all(|p| p["timestamp"].is_string()));
+        let last = points[2]["value"].as_f64().unwrap();
+        assert!((last - 0.3).abs() < 1e-9);
+
+        let response = server
+            .get(&format!("/api/v1/shadow/{}/anomalies", shadow_id))
+            .authorization_bearer(&token)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+        let anomalies: Vec<serde_json::Value> = response.json();
+        assert!(anomalies.is_empty());
+
+        let response = server
+            .get(&format!("/api/v1/shadow/{}/metrics/trend", shadow_id))
+            .add_query_param("metric", "charisma")
+            .authorization_bearer(&token)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+
+        let response = server
+            .get(&format!("/api/v1/shadow/{}/metrics/trend", shadow_id))
+            .add_query_param("metric", "autonomy_score")
+            .add_query_param("hours", i64::MAX)
+            .authorization_bearer(&token)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+
+        let response = server
+            .get(&format!("/api/v1/shadow/{}/anomalies", uuid::Uuid::new_v4()))
+            .authorization_bearer(&token)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_pathway_update_applies_signed_delta() {
+        let state = AppState::test();
+        let token = bearer_token(&state);
+        let from_node = state.neural_network.add_node(amos_core::neural::NodeType::Thinking).await;
+        let to_node = state.neural_network.add_node(amos_core::neural::NodeType::Memory).await;
+        let server = TestServer::new(create_app(state.clone())).unwrap();
+
+        let update = |delta: f64| {
+            serde_json::json!({
+                "from_node": from_node,
+                "to_node": to_node,
+                "strength_delta": delta,
+                "reason": "test",
+            })
+        };
+
+        let response = server
+            .post("/api/v1/neural/pathways")
+            .authorization_bearer(&token)
+            .json(&update(0.3))
+            .await;
+        let body: serde_json::Value = response.json();
+        assert!((body["new_strength"].as_f64().unwrap() - 0.8).abs() < 1e-9);
+
+        let response = server
+            .post("/api/v1/neural/pathways")
+            .authorization_bearer(&token)
+            .json(&update(-0.9))
+            .await;
+        let body: serde_json::Value = response.json();
+        assert_eq!(body["new_strength"].as_f64().unwrap(), 0.0);
+
+        // The existing pathway was updated rather than duplicated
+        assert_eq!(state.neural_network.pathway_count().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_readiness_follows_bootstrap() {
+        let state = AppState::test();
+        let server = TestServer::new(create_app(state.clone())).unwrap();
+
+        let response = server.get("/health/live").await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+
+        // Nothing is registered yet, so bootstrap can't complete
+        let response = server.get("/health/ready").await;
+        assert_eq!(response.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+        let body: ErrorResponse = response.json();
+        assert_eq!(body.error.code, "service_unavailable");
+        assert!(state.bootstrap().await.is_err());
+
+        server
+            .post("/api/v1/agents")
+            .authorization_bearer(&bearer_token(&state))
+            .json(&serde_json::json!({ "name": "seer", "agent_type": "traffic_seer", "shadow_mode": false }))
+            .await
+            .assert_status_ok();
+
+        // The probe retries bootstrap itself once the agent is up
+        let response = server.get("/health/ready").await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+        assert!(state.readiness.bootstrap_complete());
+    }
+
+    #[tokio::test]
+    async fn test_bootstrap_fails_while_an_agent_is_inactive() {
+        let state = AppState::test();
+        let server = TestServer::new(create_app(state.clone())).unwrap();
+
+        let idle: std::sync::Arc<dyn amos_agents::CognitiveAgent> =
+            std::sync::Arc::new(amos_agents::TrafficSeer::new());
+        state.agents.write().await.insert(idle.id(), idle);
+
+        let err = state.bootstrap().await.unwrap_err();
+        assert!(matches!(err, ApiError::ServiceUnavailable(_)), "{}", err);
+
+        let response = server.get("/health/ready").await;
+        assert_eq!(response.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn test_active_tasks_endpoint_lists_running_tasks() {
+        let state = AppState::test();
+        let token = bearer_token(&state);
+        let server = TestServer::new(create_app(state.clone())).unwrap();
+
+        let mut agent_ids = Vec::new();
+        for agent_type in ["traffic_seer", "memory_weaver"] {
+            let agent: serde_json::Value = server
+                .post("/api/v1/agents")
+                .authorization_bearer(&token)
+                .json(&serde_json::json!({
+                    "name": agent_type,
+                    "agent_type": agent_type,
+                    "shadow_mode": false,
+                }))
+                .await
+                .json();
+            agent_ids.push(agent["id"].as_str().unwrap().to_string());
+        }
+
+        let swarm: serde_json::Value = server
+            .post("/api/v1/swarms")
+            .authorization_bearer(&token)
+            .json(&serde_json::json!({
+                "name": "Busy Mesh",
+                "agent_ids": agent_ids,
+                "topology": "mesh",
+            }))
+            .await
+            .json();
+        let swarm_id = swarm["id"].as_str().unwrap();
+
+        let orchestrate = |strategy: &str| {
+            server
+                .post(&format!("/api/v1/swarms/{}/orchestrate", swarm_id))
+                .authorization_bearer(&token)
+                .json(&serde_json::json!({
+                    "task_description": format!("{} work", strategy),
+                    "strategy": strategy,
+                    "timeout_seconds": 30,
+                    "priority": "medium",
+                }))
+        };
+        let query = async {
+            tokio::time::sleep(std::time::Duration::from_millis(30)).await;
+            server
+                .get(&format!("/api/v1/swarms/{}/tasks", swarm_id))
+                .authorization_bearer(&token)
+                .await
+        };
+
+        let (sequential, parallel, response) = tokio::join!(
+            async { orchestrate("sequential").await },
+            async { orchestrate("parallel").await },
+            query,
+        );
+        assert_eq!(sequential.status_code(), StatusCode::OK);
+        assert_eq!(parallel.status_code(), StatusCode::OK);
+
+        assert_eq!(response.status_code(), StatusCode::OK);
+        let tasks: Vec<serde_json::Value> = response.json();
+        assert_eq!(tasks.len(), 2);
+        let strategies: Vec<&str> = tasks.iter().map(|t| t["strategy"].as_str().unwrap()).collect();
+        assert!(strategies.contains(&"Sequential"));
+        assert!(strategies.contains(&"Parallel"));
+        assert!(tasks.iter().all(|t| !t["assigned_agents"].as_array().unwrap().is_empty()));
+
+        // Finished tasks drop off the list
+        let tasks: Vec<serde_json::Value> = server
+            .get(&format!("/api/v1/swarms/{}/tasks", swarm_id))
+            .authorization_bearer(&token)
+            .await
+            .json();
+        assert!(tasks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_created_swarm_runs_its_coordination_loop() {
+        let state = AppState::test();
+        let token = bearer_token(&state);
+        let server = TestServer::new(create_app(state.clone())).unwrap();
+
+        let swarm: serde_json::Value = server
+            .post("/api/v1/swarms")
+            .authorization_bearer(&token)
+            .json(&serde_json::json!({
+                "name": "Coordinated",
+                "agent_ids": [],
+                "topology": "mesh",
+            }))
+            .await
+            .json();
+        let swarm_id: uuid::Uuid = swarm["id"].as_str().unwrap().parse().unwrap();
+
+        assert!(state.swarms.read().await[&swarm_id].is_coordinating());
+    }
+
+    #[tokio::test]
+    async fn test_latency_metrics_report_per_strategy_percentiles() {
+        let state = AppState::test();
+        let token = bearer_token(&state);
+        let server = TestServer::new(create_app(state.clone())).unwrap();
+
+        let agent: serde_json::Value = server
+            .post("/api/v1/agents")
+            .authorization_bearer(&token)
+            .json(&serde_json::json!({
+                "name": "seer",
+                "agent_type": "traffic_seer",
+                "shadow_mode": false,
+            }))
+            .await
+            .json();
+        let swarm: serde_json::Value = server
+            .post("/api/v1/swarms")
+            .authorization_bearer(&token)
+            .json(&serde_json::json!({
+                "name": "Timed Mesh",
+                "agent_ids": [agent["id"]],
+                "topology": "mesh",
+            }))
+            .await
+            .json();
+
+        for _ in 0..3 {
+            let response = server
+                .post(&format!("/api/v1/swarms/{}/orchestrate", swarm["id"].as_str().unwrap()))
+                .authorization_bearer(&token)
+                .json(&serde_json::json!({
+                    "task_description": "timed work",
+                    "strategy": "parallel",
+                    "timeout_seconds": 30,
+                    "priority": "medium",
+                }))
+                .await;
+            assert_eq!(response.status_code(), StatusCode::OK);
+        }
+
+        let response = server
+            .get("/api/v1/metrics/latency")
+            .authorization_bearer(&token)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+
+        let latencies: Vec<serde_json::Value> = response.json();
+        assert_eq!(latencies.len(), 1);
+        assert_eq!(latencies[0]["strategy"], "parallel");
+        assert_eq!(latencies[0]["samples"], 3);
+        let p50 = latencies[0]["p50_ms"].as_f64().unwrap();
+        let p99 = latencies[0]["p99_ms"].as_f64().unwrap();
+        assert!(p50 > 0.0 && p99 >= p50);
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_swarm_metrics_weight_health_by_size() {
+        let state = AppState::test();
+        let token = bearer_token(&state);
+        let server = TestServer::new(create_app(state.clone())).unwrap();
+
+        let mut agent_ids = Vec::new();
+        for i in 0..5 {
+            let agent: serde_json::Value = server
+                .post("/api/v1/agents")
+                .authorization_bearer(&token)
+                .json(&serde_json::json!({
+                    "name": format!("seer-{}", i),
+                    "agent_type": "traffic_seer",
+                    "shadow_mode": false,
+                }))
+                .await
+                .json();
+            agent_ids.push(agent["id"].as_str().unwrap().to_string());
+        }
+        for (name, members, topology) in [
+            ("Big Mesh", &agent_ids[..3], "mesh"),
+            ("Small Ring", &agent_ids[3..], "ring"),
+        ] {
+            let response = server
+                .post("/api/v1/swarms")
+                .authorization_bearer(&token)
+                .json(&serde_json::json!({
+                    "name": name,
+                    "agent_ids": members,
+                    "topology": topology,
+                }))
+                .await;
+            assert_eq!(response.status_code(), StatusCode::OK);
+        }
+        // The small ring loses half its members, the big mesh none
+        server
+            .delete(&format!("/api/v1/agents/{}", agent_ids[4]))
+            .authorization_bearer(&token)
+            .await;
+
+        let response = server
+            .get("/api/v1/metrics/swarms/aggregate")
+            .authorization_bearer(&token)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+
+        let metrics: serde_json::Value = response.json();
+        assert_eq!(metrics["total_swarms"], 2);
+        assert_eq!(metrics["total_agents"], 5);
+        assert_eq!(metrics["total_active_tasks"], 0);
+        // (3 * 1.0 + 2 * 0.5) / 5, not the unweighted (1.0 + 0.5) / 2
+        let health = metrics["average_health"].as_f64().unwrap();
+        assert!((health - 0.8).abs() < 1e-9, "{}", health);
+        assert_eq!(metrics["swarms_by_topology"], serde_json::json!({ "mesh": 1, "ring": 1 }));
+    }
+
+    #[tokio::test]
+    async fn test_body_limits_follow_the_route() {
+        let state = AppState::test().with_body_limits(BodyLimits {
+            default: 4 * 1024,
+            small: 1024,
+            large: 16 * 1024,
+        });
+        let token = bearer_token(&state);
+        let server = TestServer::new(create_app(state)).unwrap();
+
+        let oversized_agent = format!(
+            r#"{{"name": "tiny", "agent_type": "traffic_seer", "shadow_mode": false}}{}"#,
+            " ".repeat(2 * 1024)
+        );
+        let response = server
+            .post("/api/v1/agents")
+            .authorization_bearer(&token)
+            .content_type("application/json")
+            .bytes(oversized_agent.into())
+            .await;
+        assert_eq!(response.status_code(), StatusCode::PAYLOAD_TOO_LARGE);
+        let body: ErrorResponse = response.json();
+        assert_eq!(body.error.code, "payload_too_large");
+
+        // Bigger than the default tier but within the import route's large tier
+        let padding = " ".repeat(8 * 1024);
+        let oversized_swarm = format!(
+            r#"{{"name": "Plain", "agent_ids": [], "topology": "ring"}}{}"#,
+            padding
+        );
+        let response = server
+            .post("/api/v1/swarms")
+            .authorization_bearer(&token)
+            .content_type("application/json")
+            .bytes(oversized_swarm.into())
+            .await;
+        assert_eq!(response.status_code(), StatusCode::PAYLOAD_TOO_LARGE);
+
+        let large_descriptor = format!(
+            r#"{{"name": "Imported", "topology": "ring", "agents": []}}{}"#,
+            padding
+        );
+        let response = server
+            .post("/api/v1/swarms/import")
+            .authorization_bearer(&token)
+            .content_type("application/json")
+            .bytes(large_descriptor.into())
+            .await;
+        assert_eq!(response.status_code(), StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_file_store_restores_agents_into_fresh_state() {
+        let path = std::env::temp_dir().join(format!("amos-store-{}.json", uuid::Uuid::new_v4()));
+
+        let state = AppState::test().with_store(std::sync::Arc::new(JsonFileStore::open(&path).await.unwrap()));
+        let token = bearer_token(&state);
+        let server = TestServer::new(create_app(state.clone())).unwrap();
+        let mut agent_ids = Vec::new();
+        for (agent_type, shadow_mode) in [("traffic_seer", false), ("memory_weaver", true)] {
+            let agent: serde_json::Value = server
+                .post("/api/v1/agents")
+                .authorization_bearer(&token)
+                .json(&serde_json::json!({
+                    "name": agent_type,
+                    "agent_type": agent_type,
+                    "shadow_mode": shadow_mode,
+                }))
+                .await
+                .json();
+            agent_ids.push(agent["id"].as_str().unwrap().to_string());
+        }
+        server
+            .post("/api/v1/swarms")
+            .authorization_bearer(&token)
+            .json(&serde_json::json!({ "name": "Durable", "agent_ids": agent_ids, "topology": "ring" }))
+            .await
+            .assert_status_ok();
+
+        let restarted = AppState::test().with_store(std::sync::Arc::new(JsonFileStore::open(&path).await.unwrap()));
+        assert_eq!(restarted.restore().await.unwrap(), 2);
+
+        let mut names: Vec<String> = restarted.agents.read().await
+            .values()
+            .map(|agent| agent.name().to_string())
+            .collect();
+        names.sort();
+        assert_eq!(names, vec!["MemoryWeaver", "TrafficSeer"]);
+        assert_eq!(restarted.shadows.read().await.len(), 1);
+
+        // Agents keep the ids clients were given, and their records are not duplicated
+        let restored_token = bearer_token(&restarted);
+        let restored_server = TestServer::new(create_app(restarted.clone())).unwrap();
+        for id in &agent_ids {
+            restored_server
+                .get(&format!("/api/v1/agents/{}", id))
+                .authorization_bearer(&restored_token)
+                .await
+                .assert_status_ok();
+        }
+        let mut stored: Vec<String> = restarted.store.load_agents().await.unwrap()
+            .iter()
+            .map(|record| record.id.to_string())
+            .collect();
+        stored.sort();
+        let mut expected = agent_ids.clone();
+        expected.sort();
+        assert_eq!(stored, expected);
+
+        let swarms = restarted.swarms.read().await;
+        let swarm = swarms.values().next().unwrap();
+        assert_eq!(swarm.name, "Durable");
+        assert!(swarm.agent_ids.iter().all(|id| restarted.agents.try_read().unwrap().contains_key(id)));
+        assert_eq!(swarm.agent_ids.len(), 2);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_mutations_are_audited_with_subject_route_and_status() {
+        let audit = std::sync::Arc::new(MemoryAuditSink::new());
+        let state = AppState::test().with_audit_sink(audit.clone());
+        let token = bearer_token(&state);
+        let server = TestServer::new(create_app(state)).unwrap();
+
+        let agent: serde_json::Value = server
+            .post("/api/v1/agents")
+            .authorization_bearer(&token)
+            .json(&serde_json::json!({ "name": "audited", "agent_type": "traffic_seer", "shadow_mode": false }))
+            .await
+            .json();
+        let agent_id = agent["id"].as_str().unwrap().to_string();
+
+        // Reads are not audited
+        server
+            .get(&format!("/api/v1/agents/{}", agent_id))
+            .authorization_bearer(&token)
+            .await
+            .assert_status_ok();
+        server
+            .delete(&format!("/api/v1/agents/{}", agent_id))
+            .authorization_bearer(&token)
+            .await
+            .assert_status_ok();
+
+        let entries = audit.entries().await;
+        assert_eq!(entries.len(), 2, "{:?}", entries);
+        assert!(entries.iter().all(|entry| entry.subject == "tester" && entry.status == 200));
+        assert!(entries.iter().all(|entry| entry.resource_id.as_deref() == Some(agent_id.as_str())));
+        assert_eq!((entries[0].method.as_str(), entries[0].route.as_str()), ("POST", "/api/v1/agents"));
+        assert_eq!((entries[1].method.as_str(), entries[1].route.as_str()), ("DELETE", "/api/v1/agents/:id"));
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_deletes_remove_an_agent_once() {
+        let state = AppState::test();
+        let token = bearer_token(&state);
+        let server = TestServer::new(create_app(state.clone())).unwrap();
+
+        let agent: serde_json::Value = server
+            .post("/api/v1/agents")
+            .authorization_bearer(&token)
+            .json(&serde_json::json!({ "name": "doomed", "agent_type": "traffic_seer", "shadow_mode": false }))
+            .await
+            .json();
+        let path = format!("/api/v1/agents/{}", agent["id"].as_str().unwrap());
+
+        let (first, second) = tokio::join!(
+            server.delete(&path).authorization_bearer(&token),
+            server.delete(&path).authorization_bearer(&token),
+        );
+        let mut statuses = [first.status_code().as_u16(), second.status_code().as_u16()];
+        statuses.sort();
+
+        assert_eq!(statuses, [200, 404]);
+        assert!(state.agents.read().await.is_empty());
+        assert!(state.store.load_agents().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_swarm_history_lists_finished_tasks_newest_first() {
+        let state = AppState::test();
+        let token = bearer_token(&state);
+        let server = TestServer::new(create_app(state)).unwrap();
+
+        let agent: serde_json::Value = server
+            .post("/api/v1/agents")
+            .authorization_bearer(&token)
+            .json(&serde_json::json!({ "name": "worker", "agent_type": "traffic_seer", "shadow_mode": false }))
+            .await
+            .json();
+        let swarm: serde_json::Value = server
+            .post("/api/v1/swarms")
+            .authorization_bearer(&token)
+            .json(&serde_json::json!({ "name": "Historian", "agent_ids": [agent["id"]], "topology": "mesh" }))
+            .await
+            .json();
+        let swarm_id = swarm["id"].as_str().unwrap();
+
+        let mut task_ids = Vec::new();
+        for description in ["first", "second", "third"] {
+            let result: serde_json::Value = server
+                .post(&format!("/api/v1/swarms/{}/orchestrate", swarm_id))
+                .authorization_bearer(&token)
+                .json(&serde_json::json!({
+                    "task_description": description,
+                    "strategy": "parallel",
+                    "timeout_seconds": 30,
+                    "priority": "medium",
+                }))
+                .await
+                .json();
+            task_ids.push(result["task_id"].clone());
+        }
+
+        let history: Vec<serde_json::Value> = server
+            .get(&format!("/api/v1/swarms/{}/history", swarm_id))
+            .authorization_bearer(&token)
+            .await
+            .json();
+        let ids: Vec<serde_json::Value> = history.iter().map(|task| task["task_id"].clone()).collect();
+        task_ids.reverse();
+        assert_eq!(ids, task_ids);
+        assert_eq!(history[0]["description"], "third");
+        assert!(history.iter().all(|task| task["status"] == "completed"));
+        assert!(history.iter().all(|task| task["strategy"] == "parallel"));
+
+        let limited: Vec<serde_json::Value> = server
+            .get(&format!("/api/v1/swarms/{}/history?limit=1", swarm_id))
+            .authorization_bearer(&token)
+            .await
+            .json();
+        assert_eq!(limited.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_hormonal_merge_patch_touches_only_named_hormones() {
+        let state = AppState::test();
+        let token = bearer_token(&state);
+        let server = TestServer::new(create_app(state)).unwrap();
+        let baseline = models::neural::HormonalLevels::baseline();
+
+        server
+            .post("/api/v1/hormonal")
+            .authorization_bearer(&token)
+            .json(&serde_json::json!({ "dopamine": 0.95 }))
+            .await
+            .assert_status_ok();
+
+        let levels: models::neural::HormonalLevels = server
+            .get("/api/v1/hormonal/levels")
+            .authorization_bearer(&token)
+            .await
+            .json();
+        assert_eq!(levels, models::neural::HormonalLevels { dopamine: 0.95, ..baseline.clone() });
+
+        // Explicit null resets to baseline; a bad field rejects the whole patch
+        server
+            .post("/api/v1/hormonal")
+            .authorization_bearer(&token)
+            .json(&serde_json::json!({ "dopamine": null, "cortisol": 0.8 }))
+            .await
+            .assert_status_ok();
+        server
+            .post("/api/v1/hormonal")
+            .authorization_bearer(&token)
+            .json(&serde_json::json!({ "serotonin": 0.1, "adrenaline": 0.9 }))
+            .await
+            .assert_status(StatusCode::BAD_REQUEST);
+
+        let levels: models::neural::HormonalLevels = server
+            .get("/api/v1/hormonal/levels")
+            .authorization_bearer(&token)
+            .await
+            .json();
+        assert_eq!(levels, models::neural::HormonalLevels { cortisol: 0.8, ..baseline });
+    }
+
+    #[tokio::test]
+    async fn test_hormonal_update_and_merge_patch_share_levels() {
+        let state = AppState::test();
+        let token = bearer_token(&state);
+        let server = TestServer::new(create_app(state)).unwrap();
+        let baseline = models::neural::HormonalLevels::baseline();
+
+        server
+            .post("/api/v1/hormonal")
+            .authorization_bearer(&token)
+            .text(r#"{ "serotonin": 0.4 }"#)
+            .content_type("application/merge-patch+json")
+            .await
+            .assert_status_ok();
+
+        let levels: models::neural::HormonalLevels = server
+            .post("/api/v1/hormonal/update")
+            .authorization_bearer(&token)
+            .json(&serde_json::json!({ "hormone": "serotonin", "delta": 0.9, "reason": "test" }))
+            .await
+            .json();
+        assert_eq!(levels, models::neural::HormonalLevels { serotonin: 1.0, ..baseline });
+
+        server
+            .post("/api/v1/hormonal/update")
+            .authorization_bearer(&token)
+            .json(&serde_json::json!({ "hormone": "adrenaline", "delta": 0.1, "reason": "test" }))
+            .await
+            .assert_status(StatusCode::BAD_REQUEST);
+
+        let current: models::neural::HormonalLevels = server
+            .get("/api/v1/hormonal/levels")
+            .authorization_bearer(&token)
+            .await
+            .json();
+        assert_eq!(current, levels);
+    }
+}
@@ -15,21 +15,27 @@ pub struct AgentInfo {
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CreateAgentRequest {
     pub name: String,
-    pub agent_type: AgentType,
+    /// Registered agent type, e.g. `traffic_seer` (see `GET /api/v1/agents/types`)
+    pub agent_type: String,
     pub shadow_mode: bool,
 }
 
+/// Outcome of one entry in a batch agent creation, by its position in the request
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
-#[serde(rename_all = "snake_case")]
-pub enum AgentType {
-    TrafficSeer,
-    PathwaySculptor,
-    MemoryWeaver,
-    CognitionAlchemist,
-    LearningOracle,
-    MeshHarmonizer,
-    ConsciousnessEmergent,
-    PerformanceGuardian,
+pub struct BatchAgentResult {
+    pub index: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub agent: Option<AgentInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// An agent type that can be spawned, with its default capabilities
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AgentTypeInfo {
+    pub agent_type: String,
+    pub agent_name: String,
+    pub capabilities: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
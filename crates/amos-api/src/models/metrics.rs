@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
 use utoipa::ToSchema;
+use crate::models::swarm::SwarmTopology;
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct SystemMetrics {
@@ -21,6 +23,8 @@ pub struct AgentMetrics {
     pub agent_name: String,
     pub state: String,
     pub tasks_completed: u64,
+    pub errors: u64,
+    pub average_confidence: f64,
     pub average_response_time: f64,
     pub cpu_usage: f64,
     pub memory_usage: u64,
@@ -36,4 +40,27 @@ pub struct SwarmMetrics {
     pub tasks_orchestrated: u64,
     pub average_task_time: f64,
     pub created_at: DateTime<Utc>,
-}
\ No newline at end of file
+}
+/// Totals across every swarm
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AggregateSwarmMetrics {
+    pub total_swarms: usize,
+    /// Members of every swarm, counting an agent once per swarm it belongs to
+    pub total_agents: usize,
+    pub total_active_tasks: usize,
+    /// Mean of each swarm's share of healthy members, weighted by its member count
+    pub average_health: f64,
+    #[schema(value_type = HashMap<String, usize>)]
+    pub swarms_by_topology: BTreeMap<SwarmTopology, usize>,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Task latency percentiles for one execution strategy, across all swarms
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct StrategyLatency {
+    pub strategy: String,
+    pub samples: u64,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub p99_ms: f64,
+}
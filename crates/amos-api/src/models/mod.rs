@@ -1,4 +1,5 @@
 pub mod agent;
 pub mod neural;
 pub mod swarm;
-pub mod metrics;
\ No newline at end of file
+pub mod metrics;
+pub mod shadow;
\ No newline at end of file
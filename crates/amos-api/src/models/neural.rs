@@ -12,7 +12,7 @@ pub struct NeuralState {
     pub immune_status: ImmuneStatus,
 }
 
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub struct HormonalLevels {
     pub dopamine: f64,
     pub serotonin: f64,
@@ -20,6 +20,38 @@ pub struct HormonalLevels {
     pub oxytocin: f64,
 }
 
+impl HormonalLevels {
+    /// Resting levels the system starts from, and that a `null` patch restores
+    pub fn baseline() -> Self {
+        Self {
+            dopamine: 0.7,
+            serotonin: 0.6,
+            cortisol: 0.3,
+            oxytocin: 0.5,
+        }
+    }
+
+    pub fn level(&self, hormone: &str) -> Option<f64> {
+        match hormone {
+            "dopamine" => Some(self.dopamine),
+            "serotonin" => Some(self.serotonin),
+            "cortisol" => Some(self.cortisol),
+            "oxytocin" => Some(self.oxytocin),
+            _ => None,
+        }
+    }
+
+    pub fn level_mut(&mut self, hormone: &str) -> Option<&mut f64> {
+        match hormone {
+            "dopamine" => Some(&mut self.dopamine),
+            "serotonin" => Some(&mut self.serotonin),
+            "cortisol" => Some(&mut self.cortisol),
+            "oxytocin" => Some(&mut self.oxytocin),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ImmuneStatus {
     pub health: f64,
@@ -27,6 +59,26 @@ pub struct ImmuneStatus {
     pub patterns_remembered: usize,
 }
 
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct HistogramBucket {
+    pub low: f64,
+    pub high: f64,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct StrengthHistogram {
+    pub total_pathways: usize,
+    pub buckets: Vec<HistogramBucket>,
+}
+
+/// What a reset wiped from the network
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct NeuralResetSummary {
+    pub nodes_removed: usize,
+    pub pathways_removed: usize,
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct PathwayUpdate {
     pub from_node: Uuid,
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TrendPoint {
+    pub timestamp: DateTime<Utc>,
+    pub value: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MetricTrend {
+    pub shadow_id: Uuid,
+    pub metric: String,
+    pub hours: i64,
+    pub points: Vec<TrendPoint>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AnomalyInfo {
+    pub metric_name: String,
+    pub current_value: f64,
+    pub expected_value: f64,
+    pub deviation: f64,
+    pub severity: String,
+}
@@ -1,4 +1,7 @@
+use amos_agents::AgentCapability;
+use amos_swarm::AgentPlacement;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 use utoipa::ToSchema;
 
@@ -12,6 +15,21 @@ pub struct SwarmInfo {
     pub active_tasks: usize,
 }
 
+/// Swarm info plus where each agent sits in the topology
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SwarmStatusDetail {
+    pub id: Uuid,
+    pub name: String,
+    pub topology: SwarmTopology,
+    pub agent_count: usize,
+    pub status: SwarmStatus,
+    pub active_tasks: usize,
+    /// Placement per agent ID: level and parent when hierarchical,
+    /// prev/next in a ring, and the hub flag in a star
+    #[schema(value_type = HashMap<String, Object>)]
+    pub placements: HashMap<Uuid, AgentPlacement>,
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum SwarmStatus {
@@ -29,7 +47,7 @@ pub struct CreateSwarmRequest {
     pub topology: SwarmTopology,
 }
 
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum SwarmTopology {
     Mesh,
@@ -38,12 +56,67 @@ pub enum SwarmTopology {
     Star,
 }
 
+impl From<SwarmTopology> for amos_swarm::SwarmTopology {
+    fn from(topology: SwarmTopology) -> Self {
+        match topology {
+            SwarmTopology::Mesh => amos_swarm::SwarmTopology::Mesh { max_connections: 6 },
+            SwarmTopology::Hierarchical => amos_swarm::SwarmTopology::Hierarchical { levels: 3, agents_per_level: 4 },
+            SwarmTopology::Ring => amos_swarm::SwarmTopology::Ring,
+            SwarmTopology::Star => amos_swarm::SwarmTopology::Star { max_satellites: 10 },
+        }
+    }
+}
+
+/// Portable definition of a swarm that can be imported elsewhere
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SwarmDescriptor {
+    pub name: String,
+    pub topology: SwarmTopology,
+    pub agents: Vec<AgentDescriptor>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AgentDescriptor {
+    pub agent_type: String,
+    pub name: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct OrchestrateTaskRequest {
     pub task_description: String,
     pub strategy: ExecutionStrategy,
     pub timeout_seconds: Option<u64>,
     pub priority: TaskPriority,
+    /// Select agents and resolve the strategy without running any agent work
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Capabilities every selected agent must have, e.g. `neural_optimization`
+    #[serde(default)]
+    pub required_capabilities: Vec<String>,
+    pub min_agents: Option<usize>,
+    pub max_agents: Option<usize>,
+}
+
+impl OrchestrateTaskRequest {
+    /// Orchestrator requirements for this request; errors name any unknown capability
+    pub fn task_requirements(&self) -> Result<amos_swarm::task::TaskRequirements, String> {
+        let required_capabilities = self.required_capabilities.iter()
+            .map(|name| {
+                name.parse::<AgentCapability>()
+                    .map(|capability| capability.to_string())
+                    .map_err(|e| e.to_string())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        
+        let defaults = amos_swarm::task::TaskRequirements::default();
+        Ok(amos_swarm::task::TaskRequirements {
+            min_agents: self.min_agents.unwrap_or(defaults.min_agents),
+            max_agents: self.max_agents,
+            required_capabilities,
+            timeout: self.timeout_seconds.map(std::time::Duration::from_secs),
+            ..defaults
+        })
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -55,6 +128,18 @@ pub enum ExecutionStrategy {
     Distributed,
 }
 
+impl ExecutionStrategy {
+    /// Orchestrator strategy for a swarm of `agent_count` agents
+    pub fn to_task_strategy(&self, agent_count: usize) -> amos_swarm::TaskStrategy {
+        match self {
+            ExecutionStrategy::Parallel => amos_swarm::TaskStrategy::Parallel,
+            ExecutionStrategy::Sequential => amos_swarm::TaskStrategy::Sequential,
+            ExecutionStrategy::Adaptive => amos_swarm::TaskStrategy::Adaptive,
+            ExecutionStrategy::Distributed => amos_swarm::TaskStrategy::Distributed { max_subtasks: agent_count },
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum TaskPriority {
@@ -64,6 +149,17 @@ pub enum TaskPriority {
     Critical,
 }
 
+impl From<&TaskPriority> for amos_swarm::task::TaskPriority {
+    fn from(priority: &TaskPriority) -> Self {
+        match priority {
+            TaskPriority::Low => amos_swarm::task::TaskPriority::Low,
+            TaskPriority::Medium => amos_swarm::task::TaskPriority::Medium,
+            TaskPriority::High => amos_swarm::task::TaskPriority::High,
+            TaskPriority::Critical => amos_swarm::task::TaskPriority::Critical,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct TaskResult {
     pub task_id: Uuid,
@@ -81,4 +177,31 @@ pub enum TaskStatus {
     Completed,
     Failed,
     Cancelled,
-}
\ No newline at end of file
+    DryRun,
+}
+
+/// A task currently running on a swarm
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ActiveTask {
+    pub task_id: Uuid,
+    pub description: String,
+    pub strategy: String,
+    pub assigned_agents: Vec<Uuid>,
+    pub progress: f64,
+    pub start_time: chrono::DateTime<chrono::Utc>,
+    pub elapsed_ms: u64,
+}
+
+/// A task a swarm finished running
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CompletedTask {
+    pub task_id: Uuid,
+    pub description: String,
+    pub strategy: String,
+    pub selected_agents: Vec<Uuid>,
+    pub status: TaskStatus,
+    pub error: Option<String>,
+    pub output: Option<serde_json::Value>,
+    pub duration_ms: Option<u64>,
+    pub completed_at: chrono::DateTime<chrono::Utc>,
+}
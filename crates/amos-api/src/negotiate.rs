@@ -0,0 +1,96 @@
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{header, request::Parts, HeaderValue},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Serialize;
+use std::convert::Infallible;
+use crate::ApiError;
+
+pub const MSGPACK_CONTENT_TYPE: &str = "application/msgpack";
+
+/// Wire format for a response body, chosen from the request's `Accept` header
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ResponseFormat {
+    #[default]
+    Json,
+    MessagePack,
+}
+
+impl ResponseFormat {
+    /// Pick the supported format the client weights highest, preferring JSON on a tie
+    /// and when nothing supported is acceptable
+    pub fn from_accept(accept: &str) -> Self {
+        let mut best = (ResponseFormat::Json, 0.0);
+
+        for range in accept.split(',') {
+            let mut params = range.split(';');
+            let media_type = params.next().unwrap_or_default().trim().to_ascii_lowercase();
+            let quality = params
+                .filter_map(|param| param.trim().strip_prefix("q="))
+                .find_map(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+
+            let format = match media_type.as_str() {
+                "application/json" | "application/*" | "*/*" => ResponseFormat::Json,
+                "application/msgpack" | "application/x-msgpack" => ResponseFormat::MessagePack,
+                _ => continue,
+            };
+            if quality > best.1 {
+                best = (format, quality);
+            }
+        }
+
+        best.0
+    }
+
+    /// Wrap `value` so it is encoded in this format
+    pub fn respond<T>(self, value: T) -> Negotiated<T> {
+        Negotiated { format: self, value }
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for ResponseFormat {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(parts.headers
+            .get(header::ACCEPT)
+            .and_then(|accept| accept.to_str().ok())
+            .map(Self::from_accept)
+            .unwrap_or_default())
+    }
+}
+
+/// A serializable response body encoded in whichever format the client negotiated
+#[derive(Debug)]
+pub struct Negotiated<T> {
+    pub format: ResponseFormat,
+    pub value: T,
+}
+
+impl<T: Serialize> IntoResponse for Negotiated<T> {
+    fn into_response(self) -> Response {
+        match self.format {
+            ResponseFormat::Json => Json(self.value).into_response(),
+            ResponseFormat::MessagePack => match to_msgpack(&self.value) {
+                Ok(body) => (
+                    [(header::CONTENT_TYPE, HeaderValue::from_static(MSGPACK_CONTENT_TYPE))],
+                    body,
+                ).into_response(),
+                Err(e) => ApiError::Internal(format!("Failed to encode MessagePack response: {}", e)).into_response(),
+            },
+        }
+    }
+}
+
+/// Encode with named fields and human-readable ids and timestamps, so the body
+/// carries the same shape as the JSON variant
+pub fn to_msgpack<T: Serialize>(value: &T) -> Result<Vec<u8>, rmp_serde::encode::Error> {
+    let mut body = Vec::new();
+    value.serialize(&mut rmp_serde::Serializer::new(&mut body).with_struct_map().with_human_readable())?;
+    Ok(body)
+}
@@ -1,38 +1,47 @@
 use axum::{
-    extract::{Path, State},
-    response::Json,
+    extract::{DefaultBodyLimit, Path, State},
+    handler::Handler,
+    http::StatusCode,
+    response::{IntoResponse, Json, Response},
     routing::{get, post},
-    Router,
+    Extension, Router,
 };
 use uuid::Uuid;
 use std::sync::Arc;
 use crate::{
-    models::agent::{AgentInfo, CreateAgentRequest, AgentCommand, AgentType},
-    ApiError, ApiResult, AppState,
-};
-use amos_agents::{
-    TrafficSeer, PathwaySculptor, MemoryWeaver, CognitionAlchemist,
-    LearningOracle, MeshHarmonizer, ConsciousnessEmergent, PerformanceGuardian,
-    CognitiveAgent,
+    models::agent::{AgentInfo, CreateAgentRequest, AgentCommand, AgentTypeInfo, BatchAgentResult, CommandType},
+    audit::AuditResource,
+    state::BodyLimits,
+    store::AgentRecord,
+    ApiError, ApiResult, AppState, Negotiated, ResponseFormat,
 };
+use amos_agents::{agent_types, find_agent_type, CognitiveAgent};
+use amos_shadow::ShadowStateMachine;
 
-pub fn router() -> Router<AppState> {
+pub fn router(limits: BodyLimits) -> Router<AppState> {
+    let small = DefaultBodyLimit::max(limits.small);
+    
     Router::new()
-        .route("/agents", get(list_agents).post(create_agent))
+        .route("/agents", get(list_agents).post(create_agent.layer(small)))
+        .route("/agents/batch", post(create_agents_batch))
+        .route("/agents/types", get(list_agent_types))
         .route("/agents/:id", get(get_agent).delete(delete_agent))
-        .route("/agents/:id/command", post(send_agent_command))
+        .route("/agents/:id/command", post(send_agent_command.layer(small)))
 }
 
 #[utoipa::path(
     get,
     path = "/api/v1/agents",
     responses(
-        (status = 200, description = "List all agents", body = Vec<AgentInfo>),
-        (status = 401, description = "Unauthorized"),
+        (status = 200, description = "List all agents", body = Vec<AgentInfo>, content_type = ["application/json", "application/msgpack"]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
     ),
     tag = "agents",
 )]
-pub async fn list_agents(State(state): State<AppState>) -> ApiResult<Json<Vec<AgentInfo>>> {
+pub async fn list_agents(
+    State(state): State<AppState>,
+    format: ResponseFormat,
+) -> ApiResult<Negotiated<Vec<AgentInfo>>> {
     let agents = state.agents.read().await;
     
     let agent_list: Vec<AgentInfo> = agents
@@ -47,16 +56,16 @@ pub async fn list_agents(State(state): State<AppState>) -> ApiResult<Json<Vec<Ag
         })
         .collect();
 
-    Ok(Json(agent_list))
+    Ok(format.respond(agent_list))
 }
 
 #[utoipa::path(
     get,
     path = "/api/v1/agents/{id}",
     responses(
-        (status = 200, description = "Get agent details", body = AgentInfo),
-        (status = 404, description = "Agent not found"),
-        (status = 401, description = "Unauthorized"),
+        (status = 200, description = "Get agent details", body = AgentInfo, content_type = ["application/json", "application/msgpack"]),
+        (status = 404, description = "Agent not found", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
     ),
     params(
         ("id" = Uuid, Path, description = "Agent ID"),
@@ -66,14 +75,15 @@ pub async fn list_agents(State(state): State<AppState>) -> ApiResult<Json<Vec<Ag
 pub async fn get_agent(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
-) -> ApiResult<Json<AgentInfo>> {
+    format: ResponseFormat,
+) -> ApiResult<Negotiated<AgentInfo>> {
     let agents = state.agents.read().await;
     
     let agent = agents
         .get(&id)
         .ok_or_else(|| ApiError::NotFound(format!("Agent {} not found", id)))?;
 
-    Ok(Json(AgentInfo {
+    Ok(format.respond(AgentInfo {
         id,
         name: agent.name().to_string(),
         agent_type: agent.name().to_string(),
@@ -89,45 +99,144 @@ pub async fn get_agent(
     request_body = CreateAgentRequest,
     responses(
         (status = 201, description = "Agent created", body = AgentInfo),
-        (status = 400, description = "Invalid request"),
-        (status = 401, description = "Unauthorized"),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 413, description = "Request body too large", body = ErrorResponse),
     ),
     tag = "agents",
 )]
 pub async fn create_agent(
     State(state): State<AppState>,
     Json(request): Json<CreateAgentRequest>,
-) -> ApiResult<Json<AgentInfo>> {
+) -> ApiResult<(Extension<AuditResource>, Json<AgentInfo>)> {
+    let info = create_from_request(&state, request).await?;
+    Ok((Extension(AuditResource(info.id.to_string())), Json(info)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/agents/batch",
+    request_body = Vec<CreateAgentRequest>,
+    responses(
+        (status = 201, description = "All agents created", body = Vec<AgentInfo>),
+        (status = 207, description = "Some agents could not be created", body = Vec<BatchAgentResult>),
+        (status = 400, description = "Empty batch", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 413, description = "Request body too large", body = ErrorResponse),
+    ),
+    tag = "agents",
+)]
+pub async fn create_agents_batch(
+    State(state): State<AppState>,
+    Json(requests): Json<Vec<CreateAgentRequest>>,
+) -> ApiResult<Response> {
+    if requests.is_empty() {
+        return Err(ApiError::BadRequest("Batch must contain at least one agent".to_string()));
+    }
     
-    // Create the agent based on type
-    let mut agent: Box<dyn CognitiveAgent> = match request.agent_type {
-        AgentType::TrafficSeer => Box::new(TrafficSeer::new()),
-        AgentType::PathwaySculptor => Box::new(PathwaySculptor::new()),
-        AgentType::MemoryWeaver => Box::new(MemoryWeaver::new()),
-        AgentType::CognitionAlchemist => Box::new(CognitionAlchemist::new()),
-        AgentType::LearningOracle => Box::new(LearningOracle::new()),
-        AgentType::MeshHarmonizer => Box::new(MeshHarmonizer::new()),
-        AgentType::ConsciousnessEmergent => Box::new(ConsciousnessEmergent::new()),
-        AgentType::PerformanceGuardian => Box::new(PerformanceGuardian::new()),
-    };
+    // Each entry succeeds or fails on its own; one bad spec doesn't sink the batch
+    let mut results = Vec::with_capacity(requests.len());
+    for (index, request) in requests.into_iter().enumerate() {
+        results.push(match create_from_request(&state, request).await {
+            Ok(agent) => BatchAgentResult { index, agent: Some(agent), error: None },
+            Err(err) => BatchAgentResult { index, agent: None, error: Some(err.to_string()) },
+        });
+    }
     
-    // Initialize the agent with neural network and event bus
-    agent.initialize(state.neural_network.clone(), state.event_bus.clone()).await?;
-    agent.activate().await?;
+    if results.iter().all(|result| result.error.is_none()) {
+        let agents: Vec<AgentInfo> = results.into_iter().filter_map(|result| result.agent).collect();
+        Ok((StatusCode::CREATED, Json(agents)).into_response())
+    } else {
+        Ok((StatusCode::MULTI_STATUS, Json(results)).into_response())
+    }
+}
+
+async fn create_from_request(state: &AppState, request: CreateAgentRequest) -> ApiResult<AgentInfo> {
+    let agent = spawn_agent(state, &request.agent_type, request.shadow_mode).await?;
     
-    let agent_info = AgentInfo {
+    Ok(AgentInfo {
         id: agent.id(),
         name: agent.name().to_string(),
-        agent_type: format!("{:?}", request.agent_type),
+        agent_type: request.agent_type,
         state: format!("{:?}", agent.state()),
         created_at: chrono::Utc::now(),
         neural_network_id: Uuid::new_v4(), // TODO: Track neural network IDs properly
-    };
+    })
+}
+
+/// Create, initialize and activate an agent of the given type, register it in the
+/// state and record it in the store
+pub(crate) async fn spawn_agent(
+    state: &AppState,
+    agent_type: &str,
+    shadow_mode: bool,
+) -> ApiResult<Arc<dyn CognitiveAgent>> {
+    let agent = start_agent(state, agent_type_info(agent_type)?.create()).await?;
+    state.store.save_agent(AgentRecord {
+        id: agent.id(),
+        name: agent.name().to_string(),
+        agent_type: agent_type.to_string(),
+        shadow_mode,
+        created_at: chrono::Utc::now(),
+    }).await?;
     
-    let agent_id = agent.id();
-    state.agents.write().await.insert(agent_id, Arc::from(agent));
+    register_agent(state, &agent, shadow_mode).await;
+    Ok(agent)
+}
+
+/// Bring back an agent recorded in the store, under its recorded id. The record
+/// already describes it, so the store is left as it is.
+pub(crate) async fn respawn_agent(state: &AppState, record: &AgentRecord) -> ApiResult<Arc<dyn CognitiveAgent>> {
+    let agent = start_agent(state, agent_type_info(&record.agent_type)?.create_with_id(record.id)).await?;
     
-    Ok(Json(agent_info))
+    register_agent(state, &agent, record.shadow_mode).await;
+    Ok(agent)
+}
+
+fn agent_type_info(agent_type: &str) -> ApiResult<&'static amos_agents::AgentTypeInfo> {
+    find_agent_type(agent_type).ok_or_else(|| {
+        ApiError::BadRequest(format!("Unknown agent type '{}'", agent_type))
+    })
+}
+
+/// Initialize the agent with the neural network and event bus, then activate it
+async fn start_agent(state: &AppState, mut agent: Box<dyn CognitiveAgent>) -> ApiResult<Arc<dyn CognitiveAgent>> {
+    agent.initialize(state.neural_network.clone(), state.event_bus.clone()).await?;
+    agent.activate().await?;
+    
+    Ok(Arc::from(agent))
+}
+
+async fn register_agent(state: &AppState, agent: &Arc<dyn CognitiveAgent>, shadow_mode: bool) {
+    if shadow_mode {
+        state.shadows.write().await.insert(agent.id(), Arc::new(ShadowStateMachine::new()));
+    }
+    state.agents.write().await.insert(agent.id(), agent.clone());
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/agents/types",
+    responses(
+        (status = 200, description = "Agent types and their default capabilities", body = Vec<AgentTypeInfo>, content_type = ["application/json", "application/msgpack"]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    ),
+    tag = "agents",
+)]
+pub async fn list_agent_types(format: ResponseFormat) -> Negotiated<Vec<AgentTypeInfo>> {
+    let types = agent_types()
+        .iter()
+        .map(|info| AgentTypeInfo {
+            agent_type: info.type_name.to_string(),
+            agent_name: info.agent_name.clone(),
+            capabilities: info.capabilities
+                .iter()
+                .map(|capability| capability.to_string())
+                .collect(),
+        })
+        .collect();
+    
+    format.respond(types)
 }
 
 #[utoipa::path(
@@ -135,8 +244,8 @@ pub async fn create_agent(
     path = "/api/v1/agents/{id}",
     responses(
         (status = 204, description = "Agent deleted"),
-        (status = 404, description = "Agent not found"),
-        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Agent not found", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
     ),
     params(
         ("id" = Uuid, Path, description = "Agent ID"),
@@ -147,11 +256,17 @@ pub async fn delete_agent(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
 ) -> ApiResult<()> {
-    let mut agents = state.agents.write().await;
-    
-    agents
-        .remove(&id)
-        .ok_or_else(|| ApiError::NotFound(format!("Agent {} not found", id)))?;
+    remove_agent(&state, id).await
+}
+
+/// Undo `spawn_agent`: unregister the agent and drop its record. Only the
+/// caller that takes the agent out of the registry gets past `NotFound`.
+pub(crate) async fn remove_agent(state: &AppState, id: Uuid) -> ApiResult<()> {
+    if state.agents.write().await.remove(&id).is_none() {
+        return Err(ApiError::NotFound(format!("Agent {} not found", id)));
+    }
+    state.shadows.write().await.remove(&id);
+    state.store.remove_agent(id).await?;
     
     Ok(())
 }
@@ -162,8 +277,9 @@ pub async fn delete_agent(
     request_body = AgentCommand,
     responses(
         (status = 200, description = "Command executed"),
-        (status = 404, description = "Agent not found"),
-        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Agent not found", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 413, description = "Request body too large", body = ErrorResponse),
     ),
     params(
         ("id" = Uuid, Path, description = "Agent ID"),
@@ -175,11 +291,19 @@ pub async fn send_agent_command(
     Path(id): Path<Uuid>,
     Json(command): Json<AgentCommand>,
 ) -> ApiResult<Json<serde_json::Value>> {
-    let agents = state.agents.read().await;
+    if !state.agents.read().await.contains_key(&id) {
+        return Err(ApiError::NotFound(format!("Agent {} not found", id)));
+    }
     
-    let _agent = agents
-        .get(&id)
-        .ok_or_else(|| ApiError::NotFound(format!("Agent {} not found", id)))?;
+    // Swarms running tasks with this agent reassign or hold its work while paused
+    if matches!(command.command, CommandType::Pause | CommandType::Resume) {
+        for swarm in state.swarms.read().await.values().filter(|swarm| swarm.agent_ids.contains(&id)) {
+            match command.command {
+                CommandType::Pause => swarm.orchestrator.suspend_agent(id),
+                _ => swarm.orchestrator.resume_agent(id),
+            }
+        }
+    }
     
     // In a real implementation, execute the command on the agent
     // For now, return a success response
@@ -39,7 +39,8 @@ pub struct RefreshRequest {
     request_body = LoginRequest,
     responses(
         (status = 200, description = "Login successful", body = LoginResponse),
-        (status = 401, description = "Invalid credentials"),
+        (status = 401, description = "Invalid credentials", body = ErrorResponse),
+        (status = 413, description = "Request body too large", body = ErrorResponse),
     ),
     tag = "auth",
 )]
@@ -69,7 +70,8 @@ pub async fn login(
     request_body = RefreshRequest,
     responses(
         (status = 200, description = "Token refreshed", body = LoginResponse),
-        (status = 401, description = "Invalid token"),
+        (status = 401, description = "Invalid token", body = ErrorResponse),
+        (status = 413, description = "Request body too large", body = ErrorResponse),
     ),
     tag = "auth",
 )]
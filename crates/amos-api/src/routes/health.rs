@@ -5,7 +5,8 @@ use axum::{
     Router,
 };
 use serde::Serialize;
-use crate::AppState;
+use utoipa::ToSchema;
+use crate::{ApiError, ApiResult, AppState};
 
 #[derive(Serialize)]
 struct HealthResponse {
@@ -14,10 +15,57 @@ struct HealthResponse {
     agents_count: usize,
     swarms_count: usize,
     neural_network_active: bool,
+    ready: bool,
+}
+
+#[derive(Serialize)]
+struct ProbeResponse {
+    status: String,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ReadinessResponse {
+    status: String,
+    neural_network_active: bool,
+    bootstrap_complete: bool,
 }
 
 pub fn router() -> Router<AppState> {
-    Router::new().route("/health", get(health_check))
+    Router::new()
+        .route("/health", get(health_check))
+        .route("/health/live", get(liveness))
+        .route("/health/ready", get(readiness))
+}
+
+async fn liveness() -> Json<ProbeResponse> {
+    Json(ProbeResponse {
+        status: "alive".to_string(),
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    responses(
+        (status = 200, description = "Ready to serve traffic", body = ReadinessResponse),
+        (status = 503, description = "Neural network inactive or bootstrap still failing", body = ErrorResponse),
+    ),
+    tag = "health"
+)]
+pub async fn readiness(State(state): State<AppState>) -> ApiResult<Json<ReadinessResponse>> {
+    // Retry an incomplete bootstrap on each probe, so a server that recovers becomes ready
+    if !state.readiness.bootstrap_complete() {
+        state.bootstrap().await?;
+    }
+    if !state.readiness.neural_network_active() {
+        return Err(ApiError::ServiceUnavailable("Neural network is not active".to_string()));
+    }
+
+    Ok(Json(ReadinessResponse {
+        status: "ready".to_string(),
+        neural_network_active: true,
+        bootstrap_complete: true,
+    }))
 }
 
 async fn health_check(State(state): State<AppState>) -> Json<HealthResponse> {
@@ -29,6 +77,7 @@ async fn health_check(State(state): State<AppState>) -> Json<HealthResponse> {
         version: env!("CARGO_PKG_VERSION").to_string(),
         agents_count,
         swarms_count,
-        neural_network_active: true,
+        neural_network_active: state.readiness.neural_network_active(),
+        ready: state.readiness.is_ready(),
     })
 }
\ No newline at end of file
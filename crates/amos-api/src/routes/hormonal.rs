@@ -6,11 +6,12 @@ use axum::{
 };
 use crate::{
     models::neural::{HormonalLevels, HormonalUpdate},
-    ApiResult, AppState,
+    ApiError, ApiResult, AppState,
 };
 
 pub fn router() -> Router<AppState> {
     Router::new()
+        .route("/hormonal", post(patch_hormonal_levels))
         .route("/hormonal/levels", get(get_hormonal_levels))
         .route("/hormonal/update", post(update_hormonal_levels))
 }
@@ -20,44 +21,88 @@ pub fn router() -> Router<AppState> {
     path = "/api/v1/hormonal/levels",
     responses(
         (status = 200, description = "Get current hormonal levels", body = HormonalLevels),
-        (status = 401, description = "Unauthorized"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
     ),
     tag = "hormonal",
 )]
-pub async fn get_hormonal_levels(State(_state): State<AppState>) -> ApiResult<Json<HormonalLevels>> {
-    // In a real implementation, these would be tracked in shared state
-    let levels = HormonalLevels {
-        dopamine: 0.7,
-        serotonin: 0.6,
-        cortisol: 0.3,
-        oxytocin: 0.5,
-    };
+pub async fn get_hormonal_levels(State(state): State<AppState>) -> ApiResult<Json<HormonalLevels>> {
+    let levels = state.hormonal.read().await.clone();
     
     Ok(Json(levels))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/v1/hormonal",
+    request_body(
+        content = serde_json::Value,
+        content_type = "application/merge-patch+json",
+        description = "JSON merge patch (RFC 7386): named hormones are set, `null` resets one to baseline, absent ones are unchanged. Sent as `application/merge-patch+json` or `application/json`",
+    ),
+    responses(
+        (status = 200, description = "Hormonal levels after the patch", body = HormonalLevels),
+        (status = 400, description = "Patch is not an object, or names an unknown hormone or an invalid level", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 413, description = "Request body too large", body = ErrorResponse),
+    ),
+    tag = "hormonal",
+)]
+pub async fn patch_hormonal_levels(
+    State(state): State<AppState>,
+    Json(patch): Json<serde_json::Value>,
+) -> ApiResult<Json<HormonalLevels>> {
+    let mut levels = state.hormonal.write().await;
+    let patched = merge_patch(&levels, &patch)?;
+    *levels = patched.clone();
+    
+    Ok(Json(patched))
+}
+
+/// Apply `patch` to a copy of `levels`, so an invalid field leaves nothing half-applied
+fn merge_patch(levels: &HormonalLevels, patch: &serde_json::Value) -> ApiResult<HormonalLevels> {
+    let patch = patch.as_object()
+        .ok_or_else(|| ApiError::BadRequest("Hormonal patch must be a JSON object".to_string()))?;
+    
+    let baseline = HormonalLevels::baseline();
+    let mut patched = levels.clone();
+    for (hormone, value) in patch {
+        let slot = patched.level_mut(hormone)
+            .ok_or_else(|| ApiError::BadRequest(format!("Unknown hormone: {}", hormone)))?;
+        *slot = match value {
+            serde_json::Value::Null => baseline.level(hormone).unwrap_or(*slot),
+            value => value.as_f64()
+                .filter(|level| (0.0..=1.0).contains(level))
+                .ok_or_else(|| ApiError::BadRequest(format!("{} must be a number between 0 and 1", hormone)))?,
+        };
+    }
+    
+    Ok(patched)
+}
+
 #[utoipa::path(
     post,
     path = "/api/v1/hormonal/update",
     request_body = HormonalUpdate,
     responses(
-        (status = 200, description = "Hormonal levels updated"),
-        (status = 400, description = "Invalid update request"),
-        (status = 401, description = "Unauthorized"),
+        (status = 200, description = "Hormonal levels after the delta, clamped between 0 and 1", body = HormonalLevels),
+        (status = 400, description = "Unknown hormone or a non-finite delta", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 413, description = "Request body too large", body = ErrorResponse),
     ),
     tag = "hormonal",
 )]
 pub async fn update_hormonal_levels(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     Json(update): Json<HormonalUpdate>,
-) -> ApiResult<Json<serde_json::Value>> {
-    // In a real implementation, this would update the system's hormonal state
-    // and trigger appropriate neural pathway adjustments
+) -> ApiResult<Json<HormonalLevels>> {
+    if !update.delta.is_finite() {
+        return Err(ApiError::BadRequest(format!("Delta for {} must be a finite number", update.hormone)));
+    }
     
-    Ok(Json(serde_json::json!({
-        "status": "updated",
-        "hormone": update.hormone,
-        "delta": update.delta,
-        "reason": update.reason,
-    })))
-}
\ No newline at end of file
+    let mut levels = state.hormonal.write().await;
+    let level = levels.level_mut(&update.hormone)
+        .ok_or_else(|| ApiError::BadRequest(format!("Unknown hormone: {}", update.hormone)))?;
+    *level = (*level + update.delta).clamp(0.0, 1.0);
+    
+    Ok(Json(levels.clone()))
+}
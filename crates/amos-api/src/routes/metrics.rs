@@ -5,8 +5,10 @@ use axum::{
     Router,
 };
 use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use uuid::Uuid;
 use crate::{
-    models::metrics::{SystemMetrics, AgentMetrics, SwarmMetrics},
+    models::metrics::{SystemMetrics, AgentMetrics, SwarmMetrics, AggregateSwarmMetrics, StrategyLatency},
     ApiResult, AppState,
 };
 
@@ -15,6 +17,8 @@ pub fn router() -> Router<AppState> {
         .route("/metrics/system", get(get_system_metrics))
         .route("/metrics/agents", get(get_agent_metrics))
         .route("/metrics/swarms", get(get_swarm_metrics))
+        .route("/metrics/swarms/aggregate", get(get_aggregate_swarm_metrics))
+        .route("/metrics/latency", get(get_latency_metrics))
 }
 
 #[derive(Debug, Deserialize)]
@@ -36,7 +40,7 @@ fn default_interval() -> u64 {
     ),
     responses(
         (status = 200, description = "System metrics", body = SystemMetrics),
-        (status = 401, description = "Unauthorized"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
     ),
     tag = "metrics",
 )]
@@ -66,24 +70,44 @@ pub async fn get_system_metrics(
     path = "/api/v1/metrics/agents",
     responses(
         (status = 200, description = "Agent metrics", body = Vec<AgentMetrics>),
-        (status = 401, description = "Unauthorized"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
     ),
     tag = "metrics",
 )]
 pub async fn get_agent_metrics(State(state): State<AppState>) -> ApiResult<Json<Vec<AgentMetrics>>> {
+    // Tasks run through a swarm are counted by its orchestrator, not the agents
+    let orchestrators: Vec<_> = state.swarms.read().await
+        .values()
+        .map(|swarm| swarm.orchestrator.clone())
+        .collect();
+    let mut work_metrics: HashMap<Uuid, amos_agents::AgentMetrics> = HashMap::new();
+    for orchestrator in orchestrators {
+        for (agent_id, counters) in orchestrator.agent_work_metrics().await {
+            work_metrics.entry(agent_id).or_default().merge(&counters);
+        }
+    }
+    
     let agents = state.agents.read().await;
     
     let metrics: Vec<AgentMetrics> = agents
         .iter()
-        .map(|(id, agent)| AgentMetrics {
-            agent_id: *id,
-            agent_name: agent.name().to_string(),
-            state: format!("{:?}", agent.state()),
-            tasks_completed: 0, // In production, track this
-            average_response_time: 0.0,
-            cpu_usage: 0.0,
-            memory_usage: 0,
-            last_active: chrono::Utc::now(),
+        .map(|(id, agent)| {
+            let mut counters = agent.metrics();
+            if let Some(work) = work_metrics.get(id) {
+                counters.merge(work);
+            }
+            AgentMetrics {
+                agent_id: *id,
+                agent_name: agent.name().to_string(),
+                state: format!("{:?}", agent.state()),
+                tasks_completed: counters.processed,
+                errors: counters.errors,
+                average_confidence: counters.avg_confidence,
+                average_response_time: 0.0,
+                cpu_usage: 0.0,
+                memory_usage: 0,
+                last_active: counters.last_processed.unwrap_or_else(chrono::Utc::now),
+            }
         })
         .collect();
     
@@ -95,7 +119,7 @@ pub async fn get_agent_metrics(State(state): State<AppState>) -> ApiResult<Json<
     path = "/api/v1/metrics/swarms",
     responses(
         (status = 200, description = "Swarm metrics", body = Vec<SwarmMetrics>),
-        (status = 401, description = "Unauthorized"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
     ),
     tag = "metrics",
 )]
@@ -123,4 +147,77 @@ pub async fn get_swarm_metrics(State(state): State<AppState>) -> ApiResult<Json<
         .collect();
     
     Ok(Json(metrics))
-}
\ No newline at end of file
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/metrics/swarms/aggregate",
+    responses(
+        (status = 200, description = "Totals across all swarms", body = AggregateSwarmMetrics),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    ),
+    tag = "metrics",
+)]
+pub async fn get_aggregate_swarm_metrics(State(state): State<AppState>) -> ApiResult<Json<AggregateSwarmMetrics>> {
+    let swarms = state.swarms.read().await;
+    let agents = state.agents.read().await;
+    
+    let mut total_agents = 0;
+    let mut total_active_tasks = 0;
+    let mut healthy_agents = 0;
+    let mut swarms_by_topology = BTreeMap::new();
+    for swarm in swarms.values() {
+        total_agents += swarm.agent_ids.len();
+        total_active_tasks += swarm.orchestrator.active_task_count().await;
+        *swarms_by_topology.entry(swarm.topology).or_insert(0) += 1;
+        
+        // A member is healthy if it still exists and passes its own health check
+        for agent_id in &swarm.agent_ids {
+            if let Some(agent) = agents.get(agent_id) {
+                if agent.health_check().await.is_ok() {
+                    healthy_agents += 1;
+                }
+            }
+        }
+    }
+    
+    // Weighting each swarm's health by its size is the healthy share of all members
+    let average_health = if total_agents == 0 {
+        0.0
+    } else {
+        healthy_agents as f64 / total_agents as f64
+    };
+    
+    Ok(Json(AggregateSwarmMetrics {
+        total_swarms: swarms.len(),
+        total_agents,
+        total_active_tasks,
+        average_health,
+        swarms_by_topology,
+        timestamp: chrono::Utc::now(),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/metrics/latency",
+    responses(
+        (status = 200, description = "Task latency percentiles per strategy", body = Vec<StrategyLatency>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    ),
+    tag = "metrics",
+)]
+pub async fn get_latency_metrics(State(state): State<AppState>) -> ApiResult<Json<Vec<StrategyLatency>>> {
+    let latencies = state.latency.all_percentiles().await
+        .into_iter()
+        .map(|(strategy, percentiles)| StrategyLatency {
+            strategy: strategy.to_string(),
+            samples: percentiles.samples,
+            p50_ms: percentiles.p50,
+            p95_ms: percentiles.p95,
+            p99_ms: percentiles.p99,
+        })
+        .collect();
+    
+    Ok(Json(latencies))
+}
@@ -4,4 +4,5 @@ pub mod neural;
 pub mod swarm;
 pub mod hormonal;
 pub mod metrics;
-pub mod auth;
\ No newline at end of file
+pub mod auth;
+pub mod shadow;
\ No newline at end of file
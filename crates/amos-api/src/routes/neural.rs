@@ -1,18 +1,41 @@
 use axum::{
-    extract::State,
+    extract::{Extension, Query, State},
     response::Json,
     routing::{get, post},
     Router,
 };
+use serde::Deserialize;
 use crate::{
-    models::neural::{NeuralState, PathwayUpdate, HormonalLevels, ImmuneStatus},
-    ApiResult, AppState,
+    models::neural::{
+        NeuralState, PathwayUpdate, ImmuneStatus, HistogramBucket, StrengthHistogram,
+        NeuralResetSummary,
+    },
+    auth::Claims,
+    ApiError, ApiResult, AppState,
 };
 
 pub fn router() -> Router<AppState> {
     Router::new()
         .route("/neural/state", get(get_neural_state))
         .route("/neural/pathways", post(update_neural_pathway))
+        .route("/neural/histogram", get(get_strength_histogram))
+        .route("/neural/reset", post(reset_neural_network))
+}
+
+/// Strength a pathway starts from when an update creates it
+const NEW_PATHWAY_BASE_STRENGTH: f64 = 0.5;
+
+/// Upper bound on histogram resolution
+const MAX_HISTOGRAM_BUCKETS: usize = 1000;
+
+#[derive(Debug, Deserialize)]
+pub struct HistogramQuery {
+    #[serde(default = "default_buckets")]
+    buckets: usize,
+}
+
+fn default_buckets() -> usize {
+    10
 }
 
 #[utoipa::path(
@@ -20,7 +43,7 @@ pub fn router() -> Router<AppState> {
     path = "/api/v1/neural/state",
     responses(
         (status = 200, description = "Get neural network state", body = NeuralState),
-        (status = 401, description = "Unauthorized"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
     ),
     tag = "neural",
 )]
@@ -36,12 +59,7 @@ pub async fn get_neural_state(State(state): State<AppState>) -> ApiResult<Json<N
         total_pathways,
         active_pathways: (total_pathways as f64 * 0.7) as usize, // Estimate active pathways
         average_strength: 0.65, // Default average strength
-        hormonal_levels: HormonalLevels {
-            dopamine: 0.7,
-            serotonin: 0.6,
-            cortisol: 0.3,
-            oxytocin: 0.5,
-        },
+        hormonal_levels: state.hormonal.read().await.clone(),
         immune_status: ImmuneStatus {
             health: 0.95,
             threats_detected: 0,
@@ -58,8 +76,10 @@ pub async fn get_neural_state(State(state): State<AppState>) -> ApiResult<Json<N
     request_body = PathwayUpdate,
     responses(
         (status = 200, description = "Pathway updated"),
-        (status = 400, description = "Invalid pathway update"),
-        (status = 401, description = "Unauthorized"),
+        (status = 400, description = "Invalid pathway update", body = ErrorResponse),
+        (status = 404, description = "Pathway not found", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 413, description = "Request body too large", body = ErrorResponse),
     ),
     tag = "neural",
 )]
@@ -69,14 +89,28 @@ pub async fn update_neural_pathway(
 ) -> ApiResult<Json<serde_json::Value>> {
     let neural_network = &state.neural_network;
     
-    // Create a new pathway with the updated strength
-    // In a real implementation, we would update the existing pathway
-    let new_strength = 0.5 + update.strength_delta; // Base strength + delta
-    let pathway_id = neural_network.create_pathway(
-        update.from_node,
-        update.to_node,
-        new_strength,
-    ).await;
+    if !update.strength_delta.is_finite() {
+        return Err(ApiError::BadRequest("strength_delta must be a finite number".to_string()));
+    }
+    
+    // Apply the signed delta to an existing pathway, or start a new one from the base strength
+    let existing = neural_network.find_pathway(update.from_node, update.to_node).await;
+    let (pathway_id, new_strength) = match existing {
+        Some(pathway_id) => {
+            let new_strength = neural_network.strengthen_pathway(pathway_id, update.strength_delta).await
+                .ok_or_else(|| ApiError::NotFound(format!("Pathway {} not found", pathway_id)))?;
+            (pathway_id, new_strength)
+        }
+        None => {
+            let new_strength = (NEW_PATHWAY_BASE_STRENGTH + update.strength_delta).clamp(0.0, 1.0);
+            let pathway_id = neural_network.create_pathway(
+                update.from_node,
+                update.to_node,
+                new_strength,
+            ).await;
+            (pathway_id, new_strength)
+        }
+    };
     
     Ok(Json(serde_json::json!({
         "status": "updated",
@@ -87,4 +121,63 @@ pub async fn update_neural_pathway(
         "new_strength": new_strength,
         "reason": update.reason,
     })))
-}
\ No newline at end of file
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/neural/reset",
+    responses(
+        (status = 200, description = "Network wiped; counts are from before the reset", body = NeuralResetSummary),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+    ),
+    tag = "neural",
+)]
+pub async fn reset_neural_network(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult<Json<NeuralResetSummary>> {
+    claims.require_admin()?;
+    
+    let (nodes_removed, pathways_removed) = state.neural_network.clear().await;
+    tracing::warn!("Neural network reset by {}: {} nodes, {} pathways removed", claims.sub, nodes_removed, pathways_removed);
+    
+    Ok(Json(NeuralResetSummary { nodes_removed, pathways_removed }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/neural/histogram",
+    params(
+        ("buckets" = usize, Query, description = "Number of equal-width buckets across [0, 1]")
+    ),
+    responses(
+        (status = 200, description = "Pathway strength distribution", body = StrengthHistogram),
+        (status = 400, description = "Invalid bucket count", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    ),
+    tag = "neural",
+)]
+pub async fn get_strength_histogram(
+    State(state): State<AppState>,
+    Query(params): Query<HistogramQuery>,
+) -> ApiResult<Json<StrengthHistogram>> {
+    if params.buckets == 0 || params.buckets > MAX_HISTOGRAM_BUCKETS {
+        return Err(ApiError::BadRequest(format!(
+            "buckets must be between 1 and {}",
+            MAX_HISTOGRAM_BUCKETS
+        )));
+    }
+    
+    let buckets: Vec<HistogramBucket> = state.neural_network
+        .strength_histogram(params.buckets)
+        .await
+        .into_iter()
+        .map(|(low, high, count)| HistogramBucket { low, high, count })
+        .collect();
+    
+    Ok(Json(StrengthHistogram {
+        total_pathways: buckets.iter().map(|b| b.count).sum(),
+        buckets,
+    }))
+}
@@ -0,0 +1,115 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::Json,
+    routing::get,
+    Router,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+use amos_shadow::{ShadowMetrics, ShadowStateMachine};
+use crate::{
+    models::shadow::{AnomalyInfo, MetricTrend, TrendPoint},
+    ApiError, ApiResult, AppState,
+};
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/shadow/:id/metrics/trend", get(get_metric_trend))
+        .route("/shadow/:id/anomalies", get(get_anomalies))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TrendQuery {
+    metric: String,
+    #[serde(default = "default_hours")]
+    hours: i64,
+}
+
+fn default_hours() -> i64 {
+    24
+}
+
+/// Longest trend window, a year
+const MAX_TREND_HOURS: i64 = 24 * 365;
+
+async fn find_shadow(state: &AppState, id: Uuid) -> ApiResult<Arc<ShadowStateMachine>> {
+    state.shadows.read().await
+        .get(&id)
+        .cloned()
+        .ok_or_else(|| ApiError::NotFound(format!("Shadow {} not found", id)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/shadow/{id}/metrics/trend",
+    params(
+        ("id" = Uuid, Path, description = "Shadow ID (the ID of the shadow-mode agent)"),
+        ("metric" = String, Query, description = "Metric name, e.g. autonomy_score"),
+        ("hours" = i64, Query, description = "How many hours of history to return, at most a year"),
+    ),
+    responses(
+        (status = 200, description = "Metric time series", body = MetricTrend),
+        (status = 400, description = "Unknown metric or invalid window", body = ErrorResponse),
+        (status = 404, description = "Shadow not found", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    ),
+    tag = "shadow",
+)]
+pub async fn get_metric_trend(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(params): Query<TrendQuery>,
+) -> ApiResult<Json<MetricTrend>> {
+    if ShadowMetrics::new().metric(&params.metric).is_none() {
+        return Err(ApiError::BadRequest(format!("Unknown metric '{}'", params.metric)));
+    }
+    if params.hours <= 0 || params.hours > MAX_TREND_HOURS {
+        return Err(ApiError::BadRequest(format!("hours must be between 1 and {}", MAX_TREND_HOURS)));
+    }
+    
+    let shadow = find_shadow(&state, id).await?;
+    let points = shadow.metrics_trend(&params.metric, params.hours).await
+        .into_iter()
+        .map(|(timestamp, value)| TrendPoint { timestamp, value })
+        .collect();
+    
+    Ok(Json(MetricTrend {
+        shadow_id: id,
+        metric: params.metric,
+        hours: params.hours,
+        points,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/shadow/{id}/anomalies",
+    params(
+        ("id" = Uuid, Path, description = "Shadow ID (the ID of the shadow-mode agent)"),
+    ),
+    responses(
+        (status = 200, description = "Current metric anomalies", body = Vec<AnomalyInfo>),
+        (status = 404, description = "Shadow not found", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    ),
+    tag = "shadow",
+)]
+pub async fn get_anomalies(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<Vec<AnomalyInfo>>> {
+    let shadow = find_shadow(&state, id).await?;
+    let anomalies = shadow.anomalies().await
+        .into_iter()
+        .map(|anomaly| AnomalyInfo {
+            metric_name: anomaly.metric_name,
+            current_value: anomaly.current_value,
+            expected_value: anomaly.expected_value,
+            deviation: anomaly.deviation,
+            severity: format!("{:?}", anomaly.severity),
+        })
+        .collect();
+    
+    Ok(Json(anomalies))
+}
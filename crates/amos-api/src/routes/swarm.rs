@@ -1,23 +1,36 @@
 use axum::{
-    extract::{Path, State},
+    extract::{DefaultBodyLimit, Path, Query, State},
+    handler::Handler,
+    http::StatusCode,
     response::Json,
     routing::{get, post},
-    Router,
+    Extension, Router,
 };
+use serde::Deserialize;
+use std::collections::HashMap;
 use uuid::Uuid;
+use amos_agents::find_agent_type_by_agent_name;
+use amos_swarm::task::{Task, TaskInput, TaskStatus as OrchestratorTaskStatus};
 use crate::{
     models::swarm::{
-        SwarmInfo, CreateSwarmRequest, OrchestrateTaskRequest,
-        SwarmStatus, TaskResult, TaskStatus,
+        SwarmInfo, CreateSwarmRequest, OrchestrateTaskRequest, ActiveTask, CompletedTask,
+        SwarmStatus, SwarmStatusDetail, TaskResult, TaskStatus, SwarmDescriptor, AgentDescriptor,
     },
-    state::SwarmState,
+    audit::AuditResource,
+    routes::agents::{remove_agent, spawn_agent},
+    state::{BodyLimits, SwarmState},
     ApiError, ApiResult, AppState,
 };
 
-pub fn router() -> Router<AppState> {
+pub fn router(limits: BodyLimits) -> Router<AppState> {
     Router::new()
         .route("/swarms", get(list_swarms).post(create_swarm))
+        .route("/swarms/import", post(import_swarm.layer(DefaultBodyLimit::max(limits.large))))
+        .route("/swarms/:id/export", get(export_swarm))
         .route("/swarms/:id/orchestrate", post(orchestrate_task))
+        .route("/swarms/:id/tasks", get(list_active_tasks))
+        .route("/swarms/:id/history", get(task_history))
+        .route("/swarms/:id/status", get(swarm_status))
 }
 
 #[utoipa::path(
@@ -25,24 +38,25 @@ pub fn router() -> Router<AppState> {
     path = "/api/v1/swarms",
     responses(
         (status = 200, description = "List all swarms", body = Vec<SwarmInfo>),
-        (status = 401, description = "Unauthorized"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
     ),
     tag = "swarm",
 )]
 pub async fn list_swarms(State(state): State<AppState>) -> ApiResult<Json<Vec<SwarmInfo>>> {
     let swarms = state.swarms.read().await;
     
-    let swarm_list: Vec<SwarmInfo> = swarms
-        .values()
-        .map(|swarm| SwarmInfo {
+    let mut swarm_list = Vec::with_capacity(swarms.len());
+    for swarm in swarms.values() {
+        let active_tasks = swarm.orchestrator.active_task_count().await;
+        swarm_list.push(SwarmInfo {
             id: swarm.id,
             name: swarm.name.clone(),
             agent_count: swarm.agent_ids.len(),
-            status: SwarmStatus::Idle,
+            status: if active_tasks > 0 { SwarmStatus::Processing } else { SwarmStatus::Idle },
             created_at: swarm.created_at,
-            active_tasks: 0,
-        })
-        .collect();
+            active_tasks,
+        });
+    }
     
     Ok(Json(swarm_list))
 }
@@ -53,15 +67,16 @@ pub async fn list_swarms(State(state): State<AppState>) -> ApiResult<Json<Vec<Sw
     request_body = CreateSwarmRequest,
     responses(
         (status = 201, description = "Swarm created", body = SwarmInfo),
-        (status = 400, description = "Invalid request"),
-        (status = 401, description = "Unauthorized"),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 413, description = "Request body too large", body = ErrorResponse),
     ),
     tag = "swarm",
 )]
 pub async fn create_swarm(
     State(state): State<AppState>,
     Json(request): Json<CreateSwarmRequest>,
-) -> ApiResult<Json<SwarmInfo>> {
+) -> ApiResult<(Extension<AuditResource>, Json<SwarmInfo>)> {
     // Validate all agent IDs exist
     let agents = state.agents.read().await;
     for agent_id in &request.agent_ids {
@@ -71,28 +86,30 @@ pub async fn create_swarm(
     }
     drop(agents);
     
-    let swarm_id = Uuid::new_v4();
-    let now = chrono::Utc::now();
-    
-    let swarm_state = SwarmState {
-        id: swarm_id,
-        name: request.name.clone(),
-        agent_ids: request.agent_ids.clone(),
-        created_at: now,
-    };
+    let swarm_state = SwarmState::new(
+        Uuid::new_v4(),
+        request.name.clone(),
+        request.agent_ids.clone(),
+        request.topology,
+        state.neural_network.clone(),
+        state.latency.clone(),
+    );
+    let swarm_id = swarm_state.id;
     
     let swarm_info = SwarmInfo {
         id: swarm_id,
         name: request.name,
         agent_count: request.agent_ids.len(),
         status: SwarmStatus::Idle,
-        created_at: now,
+        created_at: swarm_state.created_at,
         active_tasks: 0,
     };
     
+    swarm_state.join_members(&*state.agents.read().await).await;
+    state.store.save_swarm(swarm_state.record()).await?;
     state.swarms.write().await.insert(swarm_id, swarm_state);
     
-    Ok(Json(swarm_info))
+    Ok((Extension(AuditResource(swarm_id.to_string())), Json(swarm_info)))
 }
 
 #[utoipa::path(
@@ -101,8 +118,10 @@ pub async fn create_swarm(
     request_body = OrchestrateTaskRequest,
     responses(
         (status = 200, description = "Task orchestrated", body = TaskResult),
-        (status = 404, description = "Swarm not found"),
-        (status = 401, description = "Unauthorized"),
+        (status = 400, description = "No agents can satisfy the task", body = ErrorResponse),
+        (status = 404, description = "Swarm not found", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 413, description = "Request body too large", body = ErrorResponse),
     ),
     params(
         ("id" = Uuid, Path, description = "Swarm ID"),
@@ -114,41 +133,313 @@ pub async fn orchestrate_task(
     Path(swarm_id): Path<Uuid>,
     Json(request): Json<OrchestrateTaskRequest>,
 ) -> ApiResult<Json<TaskResult>> {
-    let swarms = state.swarms.read().await;
-    let swarm = swarms
-        .get(&swarm_id)
-        .ok_or_else(|| ApiError::NotFound(format!("Swarm {} not found", swarm_id)))?;
+    let (orchestrator, agent_ids) = {
+        let swarms = state.swarms.read().await;
+        let swarm = swarms
+            .get(&swarm_id)
+            .ok_or_else(|| ApiError::NotFound(format!("Swarm {} not found", swarm_id)))?;
+        (swarm.orchestrator.clone(), swarm.agent_ids.clone())
+    };
     
     // Get agents for this swarm
-    let agents = state.agents.read().await;
-    let swarm_agents: Vec<_> = swarm.agent_ids
-        .iter()
-        .filter_map(|id| agents.get(id))
-        .collect();
+    let swarm_agents: HashMap<_, _> = {
+        let agents = state.agents.read().await;
+        agent_ids
+            .iter()
+            .filter_map(|id| agents.get(id).map(|agent| (*id, agent.clone())))
+            .collect()
+    };
     
     if swarm_agents.is_empty() {
         return Err(ApiError::BadRequest("Swarm has no active agents".to_string()));
     }
     
-    // In a real implementation, distribute the task across agents
-    // For now, simulate task execution
-    let task_id = Uuid::new_v4();
+    let requirements = request.task_requirements().map_err(ApiError::BadRequest)?;
+    let agents_used = swarm_agents.len();
+    let strategy = request.strategy.to_task_strategy(agents_used);
+    let task = Task::new(
+        request.task_description.clone(),
+        TaskInput::Text(request.task_description),
+    )
+    .with_priority((&request.priority).into())
+    .with_requirements(requirements);
+    
     let start_time = std::time::Instant::now();
+    let outcome = orchestrator
+        .execute_task(task, strategy, swarm_agents, request.dry_run)
+        .await
+        .map_err(ApiError::BadRequest)?;
     
-    // Simulate some processing
-    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    let plan = match &outcome.status {
+        OrchestratorTaskStatus::DryRun { strategy, selected_agents } => Some(serde_json::json!({
+            "strategy": strategy.name(),
+            "selected_agents": selected_agents,
+        })),
+        _ => None,
+    };
+    let (status, error) = task_status(outcome.status);
     
     let result = TaskResult {
-        task_id,
-        status: TaskStatus::Completed,
+        task_id: outcome.task_id,
+        status,
         result: Some(serde_json::json!({
-            "message": "Task completed successfully",
-            "agents_used": swarm_agents.len(),
+            "output": outcome.output,
+            "agents_used": agents_used,
+            "contributions": outcome.agent_contributions.len(),
             "strategy": format!("{:?}", request.strategy),
+            "plan": plan,
         })),
-        error: None,
+        error,
         execution_time_ms: start_time.elapsed().as_millis() as u64,
     };
     
     Ok(Json(result))
-}
\ No newline at end of file
+}
+
+/// API status for an orchestrator outcome, with the error to report if it didn't complete
+fn task_status(status: OrchestratorTaskStatus) -> (TaskStatus, Option<String>) {
+    match status {
+        OrchestratorTaskStatus::Pending | OrchestratorTaskStatus::Queued { .. } => (TaskStatus::Pending, None),
+        OrchestratorTaskStatus::Running { .. } => (TaskStatus::Running, None),
+        OrchestratorTaskStatus::Completed => (TaskStatus::Completed, None),
+        OrchestratorTaskStatus::Failed { error } => (TaskStatus::Failed, Some(error)),
+        OrchestratorTaskStatus::Cancelled => (TaskStatus::Cancelled, None),
+        OrchestratorTaskStatus::Timeout => (TaskStatus::Failed, Some("Task timed out".to_string())),
+        OrchestratorTaskStatus::PartiallyCompleted { completed, total } => {
+            (TaskStatus::Failed, Some(format!("Only {} of {} subtasks completed", completed, total)))
+        }
+        OrchestratorTaskStatus::DryRun { .. } => (TaskStatus::DryRun, None),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/swarms/{id}/tasks",
+    responses(
+        (status = 200, description = "Tasks currently running on the swarm", body = Vec<ActiveTask>),
+        (status = 404, description = "Swarm not found", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    ),
+    params(
+        ("id" = Uuid, Path, description = "Swarm ID"),
+    ),
+    tag = "swarm",
+)]
+pub async fn list_active_tasks(
+    State(state): State<AppState>,
+    Path(swarm_id): Path<Uuid>,
+) -> ApiResult<Json<Vec<ActiveTask>>> {
+    let orchestrator = state.swarms.read().await
+        .get(&swarm_id)
+        .map(|swarm| swarm.orchestrator.clone())
+        .ok_or_else(|| ApiError::NotFound(format!("Swarm {} not found", swarm_id)))?;
+    
+    let tasks = orchestrator.active_tasks().await
+        .into_iter()
+        .map(|task| ActiveTask {
+            task_id: task.task_id,
+            description: task.description,
+            strategy: format!("{:?}", task.strategy),
+            assigned_agents: task.assigned_agents,
+            progress: task.progress,
+            start_time: task.start_time,
+            elapsed_ms: task.elapsed_ms,
+        })
+        .collect();
+    
+    Ok(Json(tasks))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HistoryQuery {
+    #[serde(default = "default_history_limit")]
+    limit: usize,
+}
+
+fn default_history_limit() -> usize {
+    50
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/swarms/{id}/history",
+    responses(
+        (status = 200, description = "Tasks the swarm finished, newest first", body = Vec<CompletedTask>),
+        (status = 404, description = "Swarm not found", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    ),
+    params(
+        ("id" = Uuid, Path, description = "Swarm ID"),
+        ("limit" = usize, Query, description = "Most tasks to return, default 50"),
+    ),
+    tag = "swarm",
+)]
+pub async fn task_history(
+    State(state): State<AppState>,
+    Path(swarm_id): Path<Uuid>,
+    Query(params): Query<HistoryQuery>,
+) -> ApiResult<Json<Vec<CompletedTask>>> {
+    let orchestrator = state.swarms.read().await
+        .get(&swarm_id)
+        .map(|swarm| swarm.orchestrator.clone())
+        .ok_or_else(|| ApiError::NotFound(format!("Swarm {} not found", swarm_id)))?;
+    
+    let tasks = orchestrator.task_history(params.limit).await
+        .into_iter()
+        .map(|record| {
+            let (status, error) = task_status(record.result.status);
+            CompletedTask {
+                task_id: record.task_id,
+                description: record.description,
+                strategy: record.strategy.name().to_string(),
+                selected_agents: record.selected_agents,
+                status,
+                error,
+                output: record.result.output.and_then(|output| serde_json::to_value(output).ok()),
+                duration_ms: record.result.metadata.duration_ms,
+                completed_at: record.completed_at,
+            }
+        })
+        .collect();
+    
+    Ok(Json(tasks))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/swarms/{id}/status",
+    responses(
+        (status = 200, description = "Swarm status with agent placements", body = SwarmStatusDetail),
+        (status = 404, description = "Swarm not found", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    ),
+    params(
+        ("id" = Uuid, Path, description = "Swarm ID"),
+    ),
+    tag = "swarm",
+)]
+pub async fn swarm_status(
+    State(state): State<AppState>,
+    Path(swarm_id): Path<Uuid>,
+) -> ApiResult<Json<SwarmStatusDetail>> {
+    let swarms = state.swarms.read().await;
+    let swarm = swarms
+        .get(&swarm_id)
+        .ok_or_else(|| ApiError::NotFound(format!("Swarm {} not found", swarm_id)))?;
+    
+    let active_tasks = swarm.orchestrator.active_task_count().await;
+    Ok(Json(SwarmStatusDetail {
+        id: swarm.id,
+        name: swarm.name.clone(),
+        topology: swarm.topology,
+        agent_count: swarm.agent_ids.len(),
+        status: if active_tasks > 0 { SwarmStatus::Processing } else { SwarmStatus::Idle },
+        active_tasks,
+        placements: swarm.orchestrator.agent_placements().await,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/swarms/{id}/export",
+    responses(
+        (status = 200, description = "Swarm descriptor", body = SwarmDescriptor),
+        (status = 404, description = "Swarm not found", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    ),
+    params(
+        ("id" = Uuid, Path, description = "Swarm ID"),
+    ),
+    tag = "swarm",
+)]
+pub async fn export_swarm(
+    State(state): State<AppState>,
+    Path(swarm_id): Path<Uuid>,
+) -> ApiResult<Json<SwarmDescriptor>> {
+    let swarms = state.swarms.read().await;
+    let swarm = swarms
+        .get(&swarm_id)
+        .ok_or_else(|| ApiError::NotFound(format!("Swarm {} not found", swarm_id)))?;
+    
+    let agents = state.agents.read().await;
+    let mut agent_descriptors = Vec::new();
+    
+    for agent_id in &swarm.agent_ids {
+        // Agents deleted since the swarm was created are left out of the export
+        let Some(agent) = agents.get(agent_id) else {
+            continue;
+        };
+        
+        let type_info = find_agent_type_by_agent_name(agent.name()).ok_or_else(|| {
+            ApiError::Internal(format!("Agent {} has unknown type {}", agent_id, agent.name()))
+        })?;
+        
+        agent_descriptors.push(AgentDescriptor {
+            agent_type: type_info.type_name.to_string(),
+            name: agent.name().to_string(),
+        });
+    }
+    
+    Ok(Json(SwarmDescriptor {
+        name: swarm.name.clone(),
+        topology: swarm.topology,
+        agents: agent_descriptors,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/swarms/import",
+    request_body = SwarmDescriptor,
+    responses(
+        (status = 201, description = "Swarm imported", body = SwarmInfo),
+        (status = 400, description = "Invalid descriptor; no agents are left behind", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 413, description = "Request body too large", body = ErrorResponse),
+    ),
+    tag = "swarm",
+)]
+pub async fn import_swarm(
+    State(state): State<AppState>,
+    Json(descriptor): Json<SwarmDescriptor>,
+) -> ApiResult<(StatusCode, Extension<AuditResource>, Json<SwarmInfo>)> {
+    // Spawn fresh agents of the recorded types
+    let mut agent_ids = Vec::with_capacity(descriptor.agents.len());
+    for agent_descriptor in &descriptor.agents {
+        match spawn_agent(&state, &agent_descriptor.agent_type, false).await {
+            Ok(agent) => agent_ids.push(agent.id()),
+            Err(e) => {
+                // Take back the agents already spawned rather than orphan them outside any swarm
+                for agent_id in agent_ids {
+                    let _ = remove_agent(&state, agent_id).await;
+                }
+                return Err(e);
+            }
+        }
+    }
+    
+    let swarm_state = SwarmState::new(
+        Uuid::new_v4(),
+        descriptor.name.clone(),
+        agent_ids,
+        descriptor.topology,
+        state.neural_network.clone(),
+        state.latency.clone(),
+    );
+    let swarm_id = swarm_state.id;
+    
+    let swarm_info = SwarmInfo {
+        id: swarm_id,
+        name: descriptor.name,
+        agent_count: swarm_state.agent_ids.len(),
+        status: SwarmStatus::Idle,
+        created_at: swarm_state.created_at,
+        active_tasks: 0,
+    };
+    
+    swarm_state.join_members(&*state.agents.read().await).await;
+    state.store.save_swarm(swarm_state.record()).await?;
+    state.swarms.write().await.insert(swarm_id, swarm_state);
+    
+    Ok((StatusCode::CREATED, Extension(AuditResource(swarm_id.to_string())), Json(swarm_info)))
+}
@@ -1,11 +1,20 @@
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
 use uuid::Uuid;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use amos_core::{neural::ForgeNeuralNetwork, EventBus};
-use amos_agents::CognitiveAgent;
+use amos_agents::{AgentState, CognitiveAgent};
+use amos_shadow::ShadowStateMachine;
+use amos_swarm::{LatencyTracker, SwarmOrchestrator};
+use crate::audit::{AuditSink, ConsoleAuditSink};
 use crate::auth::TokenValidator;
 use crate::websocket::WsState;
+use crate::models::neural::HormonalLevels;
+use crate::models::swarm::SwarmTopology;
+use crate::store::{MemoryStore, Store, SwarmRecord};
+use crate::{ApiError, ApiResult};
 
 #[derive(Clone)]
 pub struct AppState {
@@ -13,8 +22,76 @@ pub struct AppState {
     pub event_bus: Arc<EventBus>,
     pub agents: Arc<RwLock<HashMap<Uuid, Arc<dyn CognitiveAgent>>>>,
     pub swarms: Arc<RwLock<HashMap<Uuid, SwarmState>>>,
+    /// Task latencies recorded by every swarm's orchestrator
+    pub latency: Arc<LatencyTracker>,
+    /// Shadow state machines for agents created in shadow mode, keyed by agent ID
+    pub shadows: Arc<RwLock<HashMap<Uuid, Arc<ShadowStateMachine>>>>,
     pub token_validator: Arc<TokenValidator>,
     pub ws_state: Arc<WsState>,
+    pub readiness: Arc<Readiness>,
+    pub body_limits: BodyLimits,
+    /// Where agents and swarms are recorded so `restore` can bring them back
+    pub store: Arc<dyn Store>,
+    /// Where authenticated mutations are recorded
+    pub audit: Arc<dyn AuditSink>,
+    pub hormonal: Arc<RwLock<HormonalLevels>>,
+}
+
+/// Request body size caps in bytes; routes pick the tier matching their payloads
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BodyLimits {
+    /// Routes without a tier of their own
+    pub default: usize,
+    /// Create and command routes whose payloads are a handful of fields
+    pub small: usize,
+    /// Import routes carrying whole swarms or snapshots
+    pub large: usize,
+}
+
+impl BodyLimits {
+    /// Hard cap applied to every request before any route-level limit
+    pub fn ceiling(&self) -> usize {
+        self.default.max(self.small).max(self.large)
+    }
+}
+
+impl Default for BodyLimits {
+    fn default() -> Self {
+        Self {
+            default: 10 * 1024 * 1024, // 10MB
+            small: 1024,               // 1KB
+            large: 64 * 1024 * 1024,   // 64MB
+        }
+    }
+}
+
+/// Tracks whether the server has finished starting up
+#[derive(Default)]
+pub struct Readiness {
+    neural_network_active: AtomicBool,
+    bootstrap_complete: AtomicBool,
+}
+
+impl Readiness {
+    pub fn neural_network_active(&self) -> bool {
+        self.neural_network_active.load(Ordering::SeqCst)
+    }
+
+    pub fn bootstrap_complete(&self) -> bool {
+        self.bootstrap_complete.load(Ordering::SeqCst)
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.neural_network_active() && self.bootstrap_complete()
+    }
+
+    pub fn mark_neural_network_active(&self) {
+        self.neural_network_active.store(true, Ordering::SeqCst);
+    }
+
+    pub fn mark_bootstrap_complete(&self) {
+        self.bootstrap_complete.store(true, Ordering::SeqCst);
+    }
 }
 
 #[derive(Clone)]
@@ -22,7 +99,74 @@ pub struct SwarmState {
     pub id: Uuid,
     pub name: String,
     pub agent_ids: Vec<Uuid>,
+    pub topology: SwarmTopology,
     pub created_at: chrono::DateTime<chrono::Utc>,
+    pub orchestrator: Arc<SwarmOrchestrator>,
+    /// Drains the orchestrator's coordination messages until the swarm is dropped
+    coordination: Arc<CoordinationLoop>,
+}
+
+/// Background coordination loop, aborted once the last `SwarmState` holding it
+/// is dropped (e.g. the swarm is removed from `AppState::swarms`)
+struct CoordinationLoop(JoinHandle<()>);
+
+impl Drop for CoordinationLoop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+impl SwarmState {
+    pub fn new(
+        id: Uuid,
+        name: String,
+        agent_ids: Vec<Uuid>,
+        topology: SwarmTopology,
+        neural_network: Arc<ForgeNeuralNetwork>,
+        latency: Arc<LatencyTracker>,
+    ) -> Self {
+        let orchestrator = Arc::new(
+            SwarmOrchestrator::new(topology.into(), neural_network).with_latency_tracker(latency),
+        );
+        let coordination = Arc::new(CoordinationLoop(orchestrator.start_coordination_loop()));
+
+        Self {
+            id,
+            name,
+            agent_ids,
+            topology,
+            created_at: chrono::Utc::now(),
+            orchestrator,
+            coordination,
+        }
+    }
+
+    /// Whether the swarm's coordination loop is still draining messages
+    pub fn is_coordinating(&self) -> bool {
+        !self.coordination.0.is_finished()
+    }
+
+    /// Place the swarm's members in its topology; members not in `agents`, and
+    /// repeats of a member already placed, are skipped
+    pub async fn join_members(&self, agents: &HashMap<Uuid, Arc<dyn CognitiveAgent>>) {
+        for id in &self.agent_ids {
+            if let Some(agent) = agents.get(id) {
+                if let Err(e) = self.orchestrator.on_agent_joined(*id, agent.name(), &agent.capabilities()).await {
+                    tracing::warn!("Skipping member of swarm {}: {}", self.id, e);
+                }
+            }
+        }
+    }
+
+    pub fn record(&self) -> SwarmRecord {
+        SwarmRecord {
+            id: self.id,
+            name: self.name.clone(),
+            agent_ids: self.agent_ids.clone(),
+            topology: self.topology,
+            created_at: self.created_at,
+        }
+    }
 }
 
 impl AppState {
@@ -32,9 +176,108 @@ impl AppState {
             event_bus: Arc::new(EventBus::new()),
             agents: Arc::new(RwLock::new(HashMap::new())),
             swarms: Arc::new(RwLock::new(HashMap::new())),
+            latency: Arc::new(LatencyTracker::new()),
+            shadows: Arc::new(RwLock::new(HashMap::new())),
             token_validator: Arc::new(TokenValidator::new(secret_key)),
             ws_state: Arc::new(WsState::new()),
+            readiness: Arc::new(Readiness::default()),
+            body_limits: BodyLimits::default(),
+            store: Arc::new(MemoryStore::new()),
+            audit: Arc::new(ConsoleAuditSink),
+            hormonal: Arc::new(RwLock::new(HormonalLevels::baseline())),
+        }
+    }
+
+    pub fn with_store(mut self, store: Arc<dyn Store>) -> Self {
+        self.store = store;
+        self
+    }
+
+    pub fn with_audit_sink(mut self, audit: Arc<dyn AuditSink>) -> Self {
+        self.audit = audit;
+        self
+    }
+
+    /// Respawn the agents and rebuild the swarms recorded in the store.
+    ///
+    /// Agents come back under their recorded ids, so ids clients hold stay valid
+    /// and agent records are left untouched. Returns the number of agents restored.
+    pub async fn restore(&self) -> ApiResult<usize> {
+        let mut restored = HashSet::new();
+        for record in self.store.load_agents().await? {
+            crate::routes::agents::respawn_agent(self, &record).await?;
+            restored.insert(record.id);
+        }
+
+        for mut record in self.store.load_swarms().await? {
+            // Members that failed to come back are dropped rather than left dangling
+            record.agent_ids = record.agent_ids.iter()
+                .filter(|id| restored.contains(id))
+                .copied()
+                .collect();
+
+            let mut swarm = SwarmState::new(
+                record.id,
+                record.name,
+                record.agent_ids,
+                record.topology,
+                self.neural_network.clone(),
+                self.latency.clone(),
+            );
+            swarm.created_at = record.created_at;
+            swarm.join_members(&*self.agents.read().await).await;
+
+            self.store.save_swarm(swarm.record()).await?;
+            self.swarms.write().await.insert(swarm.id, swarm);
+        }
+
+        Ok(restored.len())
+    }
+
+    pub fn with_body_limits(mut self, body_limits: BodyLimits) -> Self {
+        self.body_limits = body_limits;
+        self
+    }
+
+    /// Finish startup so the server reports itself ready.
+    ///
+    /// Fails, leaving the server not ready, unless at least one agent is registered,
+    /// every agent is active and passes its health check and every swarm is
+    /// coordinating. Safe to call again until it succeeds.
+    pub async fn bootstrap(&self) -> ApiResult<()> {
+        if !self.readiness.neural_network_active() {
+            self.readiness.mark_neural_network_active();
+            tracing::info!("Neural network active with {} nodes", self.neural_network.node_count().await);
+        }
+        
+        let agents = self.agents.read().await;
+        if agents.is_empty() {
+            return Err(ApiError::ServiceUnavailable("No agents are registered".to_string()));
+        }
+        for (id, agent) in agents.iter() {
+            let agent_state = agent.state();
+            if !matches!(agent_state, AgentState::Active | AgentState::Processing) {
+                return Err(ApiError::ServiceUnavailable(format!(
+                    "Agent {} is {:?} rather than active", id, agent_state
+                )));
+            }
+            agent.health_check().await.map_err(|e| {
+                ApiError::ServiceUnavailable(format!("Agent {} failed its health check: {}", id, e))
+            })?;
+        }
+        
+        drop(agents);
+        
+        for swarm in self.swarms.read().await.values() {
+            if !swarm.is_coordinating() {
+                return Err(ApiError::ServiceUnavailable(format!(
+                    "Swarm {} is not coordinating", swarm.id
+                )));
+            }
         }
+        
+        self.readiness.mark_bootstrap_complete();
+        Ok(())
     }
 
     #[cfg(test)]
@@ -0,0 +1,170 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::models::swarm::SwarmTopology;
+
+/// What is kept about an agent so it can be respawned after a restart
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AgentRecord {
+    pub id: Uuid,
+    pub name: String,
+    pub agent_type: String,
+    pub shadow_mode: bool,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// What is kept about a swarm so it can be rebuilt after a restart
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SwarmRecord {
+    pub id: Uuid,
+    pub name: String,
+    pub agent_ids: Vec<Uuid>,
+    pub topology: SwarmTopology,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Durable storage for the agents and swarms the API manages
+#[async_trait]
+pub trait Store: Send + Sync {
+    async fn save_agent(&self, agent: AgentRecord) -> Result<()>;
+    async fn remove_agent(&self, id: Uuid) -> Result<()>;
+    async fn load_agents(&self) -> Result<Vec<AgentRecord>>;
+    async fn save_swarm(&self, swarm: SwarmRecord) -> Result<()>;
+    async fn remove_swarm(&self, id: Uuid) -> Result<()>;
+    async fn load_swarms(&self) -> Result<Vec<SwarmRecord>>;
+}
+
+/// Everything a store holds, ordered by id so files diff cleanly
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct StoreContents {
+    agents: BTreeMap<Uuid, AgentRecord>,
+    swarms: BTreeMap<Uuid, SwarmRecord>,
+}
+
+/// Keeps records for the life of the process only
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    contents: Mutex<StoreContents>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl Store for MemoryStore {
+    async fn save_agent(&self, agent: AgentRecord) -> Result<()> {
+        self.contents.lock().await.agents.insert(agent.id, agent);
+        Ok(())
+    }
+
+    async fn remove_agent(&self, id: Uuid) -> Result<()> {
+        self.contents.lock().await.agents.remove(&id);
+        Ok(())
+    }
+
+    async fn load_agents(&self) -> Result<Vec<AgentRecord>> {
+        Ok(self.contents.lock().await.agents.values().cloned().collect())
+    }
+
+    async fn save_swarm(&self, swarm: SwarmRecord) -> Result<()> {
+        self.contents.lock().await.swarms.insert(swarm.id, swarm);
+        Ok(())
+    }
+
+    async fn remove_swarm(&self, id: Uuid) -> Result<()> {
+        self.contents.lock().await.swarms.remove(&id);
+        Ok(())
+    }
+
+    async fn load_swarms(&self) -> Result<Vec<SwarmRecord>> {
+        Ok(self.contents.lock().await.swarms.values().cloned().collect())
+    }
+}
+
+/// Keeps records in a single JSON file, rewritten in full on every change
+#[derive(Debug)]
+pub struct JsonFileStore {
+    path: PathBuf,
+    contents: Mutex<StoreContents>,
+}
+
+impl JsonFileStore {
+    /// Open the store at `path`, starting empty if the file does not exist yet
+    pub async fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+
+        let contents = match tokio::fs::read(&path).await {
+            Ok(bytes) => serde_json::from_slice(&bytes)
+                .with_context(|| format!("Corrupt store file {}", path.display()))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => StoreContents::default(),
+            Err(e) => return Err(e).with_context(|| format!("Failed to read store file {}", path.display())),
+        };
+
+        Ok(Self {
+            path,
+            contents: Mutex::new(contents),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Apply a change and write the result out before releasing the lock
+    async fn update(&self, change: impl FnOnce(&mut StoreContents)) -> Result<()> {
+        let mut contents = self.contents.lock().await;
+        change(&mut contents);
+
+        // Write beside the file and rename, so a crash never leaves it half written
+        let bytes = serde_json::to_vec_pretty(&*contents)?;
+        let temp_path = self.path.with_extension("tmp");
+        tokio::fs::write(&temp_path, bytes).await
+            .with_context(|| format!("Failed to write store file {}", temp_path.display()))?;
+        tokio::fs::rename(&temp_path, &self.path).await
+            .with_context(|| format!("Failed to replace store file {}", self.path.display()))?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Store for JsonFileStore {
+    async fn save_agent(&self, agent: AgentRecord) -> Result<()> {
+        self.update(|contents| {
+            contents.agents.insert(agent.id, agent);
+        }).await
+    }
+
+    async fn remove_agent(&self, id: Uuid) -> Result<()> {
+        self.update(|contents| {
+            contents.agents.remove(&id);
+        }).await
+    }
+
+    async fn load_agents(&self) -> Result<Vec<AgentRecord>> {
+        Ok(self.contents.lock().await.agents.values().cloned().collect())
+    }
+
+    async fn save_swarm(&self, swarm: SwarmRecord) -> Result<()> {
+        self.update(|contents| {
+            contents.swarms.insert(swarm.id, swarm);
+        }).await
+    }
+
+    async fn remove_swarm(&self, id: Uuid) -> Result<()> {
+        self.update(|contents| {
+            contents.swarms.remove(&id);
+        }).await
+    }
+
+    async fn load_swarms(&self) -> Result<Vec<SwarmRecord>> {
+        Ok(self.contents.lock().await.swarms.values().cloned().collect())
+    }
+}
@@ -1,12 +1,13 @@
 use axum::{
-    extract::{ws::{WebSocket, WebSocketUpgrade}, State},
+    extract::{ws::{Message, WebSocket, WebSocketUpgrade}, State},
     response::Response,
 };
-use futures::{SinkExt, StreamExt};
+use amos_core::NeuralEvent;
+use futures::{Sink, SinkExt, StreamExt};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use tokio::sync::broadcast;
-use tracing::{info, error};
+use tokio::sync::broadcast::{self, error::RecvError};
+use tracing::{info, warn, error};
 use crate::{AppState, ApiError};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -25,6 +26,21 @@ pub enum WsMessage {
     SwarmEvent { swarm_id: Uuid, event: String },
     TaskProgress { task_id: Uuid, progress: f64 },
     Error { message: String },
+    /// Events were dropped because a receiver fell behind; sent as `{"type":"lagged","skipped":n}`
+    Lagged { skipped: u64 },
+}
+
+impl WsMessage {
+    /// Wire representation of the message
+    pub fn to_frame(&self) -> serde_json::Result<String> {
+        match self {
+            WsMessage::Lagged { skipped } => Ok(serde_json::json!({
+                "type": "lagged",
+                "skipped": skipped,
+            }).to_string()),
+            _ => serde_json::to_string(self),
+        }
+    }
 }
 
 pub struct WsState {
@@ -52,17 +68,11 @@ async fn handle_socket(socket: WebSocket, state: AppState) {
     info!("WebSocket client connected: {}", client_id);
     
     // Create broadcast receiver for this client
-    let mut broadcast_rx = state.ws_state.broadcast_tx.subscribe();
+    let broadcast_rx = state.ws_state.broadcast_tx.subscribe();
     
     // Spawn task to forward broadcast messages to client
     let mut send_task = tokio::spawn(async move {
-        while let Ok(msg) = broadcast_rx.recv().await {
-            if let Ok(text) = serde_json::to_string(&msg) {
-                if sender.send(axum::extract::ws::Message::Text(text)).await.is_err() {
-                    break;
-                }
-            }
-        }
+        forward_broadcasts(broadcast_rx, &mut sender).await;
     });
     
     // Handle incoming messages
@@ -90,6 +100,27 @@ async fn handle_socket(socket: WebSocket, state: AppState) {
     info!("WebSocket client disconnected: {}", client_id);
 }
 
+/// Forward broadcast messages to one client until it disconnects. A client that falls
+/// behind is told how many messages it missed and keeps receiving from there.
+async fn forward_broadcasts<S>(mut broadcast_rx: broadcast::Receiver<WsMessage>, sender: &mut S)
+where
+    S: Sink<Message> + Unpin,
+{
+    loop {
+        let msg = match broadcast_rx.recv().await {
+            Ok(msg) => msg,
+            Err(RecvError::Lagged(skipped)) => WsMessage::Lagged { skipped },
+            Err(RecvError::Closed) => break,
+        };
+        
+        if let Ok(text) = msg.to_frame() {
+            if sender.send(Message::Text(text)).await.is_err() {
+                break;
+            }
+        }
+    }
+}
+
 async fn handle_ws_message(msg: WsMessage, state: &AppState, client_id: Uuid) {
     match msg {
         WsMessage::Subscribe { channels } => {
@@ -134,6 +165,28 @@ async fn handle_ws_message(msg: WsMessage, state: &AppState, client_id: Uuid) {
 
 // Neural activity broadcaster
 pub fn start_neural_activity_broadcaster(state: AppState) {
+    let network = state.neural_network.clone();
+    let tx = state.ws_state.broadcast_tx.clone();
+    tokio::spawn(async move {
+        let mut events = network.subscribe_to_events();
+        
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    if let Some(activity) = neural_activity(event) {
+                        let _ = tx.send(activity);
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!("Neural activity broadcaster skipped {} events", skipped);
+                    let _ = tx.send(WsMessage::Lagged { skipped });
+                }
+                // Only reachable once the network is gone, and with it anything to report
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+    
     tokio::spawn(async move {
         let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(2));
         
@@ -162,4 +215,100 @@ pub fn start_neural_activity_broadcaster(state: AppState) {
             }
         }
     });
-}
\ No newline at end of file
+}
+
+fn neural_activity(event: NeuralEvent) -> Option<WsMessage> {
+    match event {
+        NeuralEvent::PathwayCreated { pathway_id, strength, .. } => {
+            Some(WsMessage::NeuralActivity { pathway_id, strength })
+        }
+        NeuralEvent::PathwayStrengthened { pathway_id, new_strength }
+        | NeuralEvent::PathwayWeakened { pathway_id, new_strength } => {
+            Some(WsMessage::NeuralActivity { pathway_id, strength: new_strength })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use amos_core::NodeType;
+    use futures::channel::mpsc;
+    
+    fn frame_json(message: Message) -> serde_json::Value {
+        match message {
+            Message::Text(text) => serde_json::from_str(&text).unwrap(),
+            other => panic!("unexpected frame {:?}", other),
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_slow_client_is_told_it_lagged_and_keeps_receiving() {
+        let (tx, _) = broadcast::channel(16);
+        let rx = tx.subscribe();
+        
+        // Flood the channel before the client gets a chance to read anything
+        for progress in 0..100 {
+            tx.send(WsMessage::TaskProgress { task_id: Uuid::nil(), progress: progress as f64 }).unwrap();
+        }
+        
+        let (mut sink, mut frames) = mpsc::unbounded();
+        let forwarder = tokio::spawn(async move {
+            forward_broadcasts(rx, &mut sink).await;
+        });
+        
+        let first = frame_json(frames.next().await.unwrap());
+        assert_eq!(first["type"], "lagged");
+        assert_eq!(first["skipped"], 84);
+        
+        // The 16 retained messages follow, then anything sent afterwards
+        for _ in 0..16 {
+            assert_eq!(frame_json(frames.next().await.unwrap())["type"], "TaskProgress");
+        }
+        tx.send(WsMessage::Error { message: "after the flood".to_string() }).unwrap();
+        let after = frame_json(frames.next().await.unwrap());
+        assert_eq!(after["data"]["message"], "after the flood");
+        
+        drop(tx);
+        forwarder.await.unwrap();
+    }
+    
+    #[tokio::test]
+    async fn test_broadcaster_reports_neural_events_it_fell_behind_on() {
+        let state = AppState::test();
+        let mut frames = state.ws_state.broadcast_tx.subscribe();
+        start_neural_activity_broadcaster(state.clone());
+        // Let the broadcaster subscribe to the network
+        tokio::task::yield_now().await;
+        
+        // Outrun the broadcaster's 1000-event buffer before it gets to read any of it
+        let network = state.neural_network.clone();
+        tokio::task::unconstrained(async move {
+            let source = network.add_node(NodeType::Memory).await;
+            let target = network.add_node(NodeType::Thinking).await;
+            for _ in 0..1100 {
+                network.create_pathway(source, target, 0.5).await;
+            }
+        }).await;
+        
+        let skipped = loop {
+            match frames.recv().await.unwrap() {
+                WsMessage::Lagged { skipped } => break skipped,
+                _ => continue,
+            }
+        };
+        assert!(skipped > 0);
+        
+        // Pathway events after the lag still come through
+        let pathway_id = state.neural_network.create_pathway(Uuid::new_v4(), Uuid::new_v4(), 0.25).await;
+        loop {
+            if let WsMessage::NeuralActivity { pathway_id: id, strength } = frames.recv().await.unwrap() {
+                if id == pathway_id {
+                    assert_eq!(strength, 0.25);
+                    break;
+                }
+            }
+        }
+    }
+}
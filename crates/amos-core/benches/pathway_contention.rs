@@ -0,0 +1,52 @@
+use amos_core::neural::{ForgeNeuralNetwork, NodeType};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use tokio::runtime::Runtime;
+
+const WRITERS: usize = 16;
+const OPS_PER_WRITER: usize = 200;
+
+/// Each writer creates pathways and strengthens them while the others do the same
+async fn contended_writes(network: ForgeNeuralNetwork) {
+    let source = network.add_node(NodeType::Memory).await;
+    let target = network.add_node(NodeType::Thinking).await;
+
+    let writers: Vec<_> = (0..WRITERS)
+        .map(|_| {
+            let network = network.clone();
+            tokio::spawn(async move {
+                for _ in 0..OPS_PER_WRITER {
+                    let id = network.create_pathway(source, target, 0.5).await;
+                    network.strengthen_pathway(id, 0.01).await;
+                    network.get_pathway(id).await;
+                }
+            })
+        })
+        .collect();
+
+    for writer in writers {
+        writer.await.unwrap();
+    }
+}
+
+fn pathway_contention(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(WRITERS)
+        .build()
+        .unwrap();
+    let mut group = c.benchmark_group("pathway_contention");
+
+    for shards in [1, 16] {
+        group.bench_with_input(BenchmarkId::from_parameter(shards), &shards, |b, &shards| {
+            b.iter(|| run(&runtime, ForgeNeuralNetwork::sharded(shards)));
+        });
+    }
+
+    group.finish();
+}
+
+fn run(runtime: &Runtime, network: ForgeNeuralNetwork) {
+    runtime.block_on(contended_writes(network));
+}
+
+criterion_group!(benches, pathway_contention);
+criterion_main!(benches);
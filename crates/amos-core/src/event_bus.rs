@@ -13,10 +13,93 @@ pub enum SystemEvent {
     HormonalBurst { hormone_type: String, intensity: f64 },
     ThreatDetected { threat_id: Uuid, level: String },
     AgentActivated { agent_id: Uuid, agent_type: String },
+    AgentDeactivated { agent_id: Uuid },
+    /// Capabilities granted or revoked at runtime, by variant name
+    AgentCapabilitiesChanged { agent_id: Uuid, added: Vec<String>, removed: Vec<String> },
     MemoryStored { memory_id: Uuid, content_size: usize },
+    /// Ask memory-managing agents to consolidate what they hold
+    MemoryConsolidationRequested { requester_id: Uuid },
+    LearningStrategyChanged { from: Uuid, to: Uuid, reason: String },
+    PerformanceMetricsReported {
+        reporter_id: Uuid,
+        cpu_usage: f64,
+        memory_usage: f64,
+        event_latency_ms: f64,
+        pathway_efficiency: f64,
+    },
+    /// Load predicted `horizon_secs` ahead is above what the system can handle
+    LoadForecast {
+        agent_id: Uuid,
+        predicted_load: f64,
+        capacity: f64,
+        horizon_secs: u64,
+    },
     SystemShutdown,
 }
 
+/// A set of `SystemEvent` variants, used to filter which events reach a subscriber
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventMask(u32);
+
+impl EventMask {
+    pub const NONE: EventMask = EventMask(0);
+    pub const NEURAL_FIRED: EventMask = EventMask(1 << 0);
+    pub const PATHWAY_STRENGTHENED: EventMask = EventMask(1 << 1);
+    pub const HORMONAL_BURST: EventMask = EventMask(1 << 2);
+    pub const THREAT_DETECTED: EventMask = EventMask(1 << 3);
+    pub const AGENT_ACTIVATED: EventMask = EventMask(1 << 4);
+    pub const AGENT_DEACTIVATED: EventMask = EventMask(1 << 5);
+    pub const MEMORY_STORED: EventMask = EventMask(1 << 6);
+    pub const LEARNING_STRATEGY_CHANGED: EventMask = EventMask(1 << 7);
+    pub const PERFORMANCE_METRICS_REPORTED: EventMask = EventMask(1 << 8);
+    pub const SYSTEM_SHUTDOWN: EventMask = EventMask(1 << 9);
+    pub const AGENT_CAPABILITIES_CHANGED: EventMask = EventMask(1 << 10);
+    pub const LOAD_FORECAST: EventMask = EventMask(1 << 11);
+    pub const MEMORY_CONSOLIDATION_REQUESTED: EventMask = EventMask(1 << 12);
+    pub const ALL: EventMask = EventMask((1 << 13) - 1);
+    
+    /// The mask containing only this event's variant
+    pub fn of(event: &SystemEvent) -> Self {
+        match event {
+            SystemEvent::NeuralFired { .. } => Self::NEURAL_FIRED,
+            SystemEvent::PathwayStrengthened { .. } => Self::PATHWAY_STRENGTHENED,
+            SystemEvent::HormonalBurst { .. } => Self::HORMONAL_BURST,
+            SystemEvent::ThreatDetected { .. } => Self::THREAT_DETECTED,
+            SystemEvent::AgentActivated { .. } => Self::AGENT_ACTIVATED,
+            SystemEvent::AgentDeactivated { .. } => Self::AGENT_DEACTIVATED,
+            SystemEvent::AgentCapabilitiesChanged { .. } => Self::AGENT_CAPABILITIES_CHANGED,
+            SystemEvent::MemoryStored { .. } => Self::MEMORY_STORED,
+            SystemEvent::MemoryConsolidationRequested { .. } => Self::MEMORY_CONSOLIDATION_REQUESTED,
+            SystemEvent::LearningStrategyChanged { .. } => Self::LEARNING_STRATEGY_CHANGED,
+            SystemEvent::PerformanceMetricsReported { .. } => Self::PERFORMANCE_METRICS_REPORTED,
+            SystemEvent::LoadForecast { .. } => Self::LOAD_FORECAST,
+            SystemEvent::SystemShutdown => Self::SYSTEM_SHUTDOWN,
+        }
+    }
+    
+    pub fn matches(&self, event: &SystemEvent) -> bool {
+        self.0 & Self::of(event).0 != 0
+    }
+    
+    pub const fn union(self, other: EventMask) -> Self {
+        EventMask(self.0 | other.0)
+    }
+}
+
+impl std::ops::BitOr for EventMask {
+    type Output = EventMask;
+    
+    fn bitor(self, other: EventMask) -> EventMask {
+        self.union(other)
+    }
+}
+
+impl Default for EventMask {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
 #[async_trait]
 pub trait EventHandler: Send + Sync {
     async fn handle(&self, event: SystemEvent);
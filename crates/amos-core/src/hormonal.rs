@@ -1,6 +1,7 @@
 use uuid::Uuid;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Serialize, Deserialize};
+use std::collections::VecDeque;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum HormoneType {
@@ -11,6 +12,93 @@ pub enum HormoneType {
     Adrenaline,  // Fight or flight
 }
 
+/// Resting level and permitted range of a single hormone
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(from = "RawHormoneBounds")]
+pub struct HormoneBounds {
+    pub baseline: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+/// Bounds as written in a profile, before they are normalized
+#[derive(Deserialize)]
+struct RawHormoneBounds {
+    baseline: f64,
+    min: f64,
+    max: f64,
+}
+
+impl From<RawHormoneBounds> for HormoneBounds {
+    fn from(raw: RawHormoneBounds) -> Self {
+        Self::new(raw.baseline, raw.min, raw.max)
+    }
+}
+
+impl HormoneBounds {
+    /// Bounds given the wrong way round are swapped, and a missing (NaN) bound
+    /// falls back to the full 0..=1 range on that side
+    pub fn new(baseline: f64, min: f64, max: f64) -> Self {
+        let min = if min.is_nan() { 0.0 } else { min };
+        let max = if max.is_nan() { 1.0 } else { max };
+        let (min, max) = if min <= max { (min, max) } else { (max, min) };
+        let baseline = if baseline.is_nan() { min } else { baseline.clamp(min, max) };
+        Self { baseline, min, max }
+    }
+
+    /// Clamp a level into range; never panics, even if the fields were set out of order
+    pub fn clamp(&self, level: f64) -> f64 {
+        level.max(self.min.min(self.max)).min(self.max.max(self.min))
+    }
+}
+
+impl Default for HormoneBounds {
+    fn default() -> Self {
+        Self::new(0.5, 0.0, 1.0)
+    }
+}
+
+/// Per-hormone baselines and bounds, modelling an agent's temperament
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HormoneProfile {
+    pub cortisol: HormoneBounds,
+    pub dopamine: HormoneBounds,
+    pub serotonin: HormoneBounds,
+    pub oxytocin: HormoneBounds,
+    pub adrenaline: HormoneBounds,
+}
+
+impl HormoneProfile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_bounds(mut self, hormone: HormoneType, bounds: HormoneBounds) -> Self {
+        *self.bounds_mut(&hormone) = bounds;
+        self
+    }
+
+    pub fn bounds(&self, hormone: &HormoneType) -> HormoneBounds {
+        match hormone {
+            HormoneType::Cortisol => self.cortisol,
+            HormoneType::Dopamine => self.dopamine,
+            HormoneType::Serotonin => self.serotonin,
+            HormoneType::Oxytocin => self.oxytocin,
+            HormoneType::Adrenaline => self.adrenaline,
+        }
+    }
+
+    fn bounds_mut(&mut self, hormone: &HormoneType) -> &mut HormoneBounds {
+        match hormone {
+            HormoneType::Cortisol => &mut self.cortisol,
+            HormoneType::Dopamine => &mut self.dopamine,
+            HormoneType::Serotonin => &mut self.serotonin,
+            HormoneType::Oxytocin => &mut self.oxytocin,
+            HormoneType::Adrenaline => &mut self.adrenaline,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct HormonalBurst {
     pub id: Uuid,
@@ -20,6 +108,20 @@ pub struct HormonalBurst {
     pub duration_ms: u64,
 }
 
+impl HormonalBurst {
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.triggered_at + Duration::milliseconds(self.duration_ms as i64)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ActiveBurst {
+    hormone: HormoneType,
+    expires_at: DateTime<Utc>,
+    // Amount actually added to the level after clamping
+    contribution: f64,
+}
+
 #[derive(Debug, Clone)]
 pub struct HormonalState {
     cortisol_level: f64,
@@ -27,35 +129,137 @@ pub struct HormonalState {
     serotonin_level: f64,
     oxytocin_level: f64,
     adrenaline_level: f64,
+    active_bursts: Vec<ActiveBurst>,
+    profile: HormoneProfile,
+    history: Option<LevelHistory>,
 }
 
+/// Most recent level changes, oldest dropped first once full
+#[derive(Debug, Clone)]
+struct LevelHistory {
+    capacity: usize,
+    samples: VecDeque<(DateTime<Utc>, HormoneType, f64)>,
+}
+
+impl LevelHistory {
+    fn record(&mut self, timestamp: DateTime<Utc>, hormone: HormoneType, level: f64) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back((timestamp, hormone, level));
+    }
+}
+
+const HORMONES: [HormoneType; 5] = [
+    HormoneType::Cortisol,
+    HormoneType::Dopamine,
+    HormoneType::Serotonin,
+    HormoneType::Oxytocin,
+    HormoneType::Adrenaline,
+];
+
 impl HormonalState {
     pub fn new() -> Self {
+        Self::with_profile(HormoneProfile::default())
+    }
+
+    /// Start every hormone at the profile's baseline
+    pub fn with_profile(profile: HormoneProfile) -> Self {
         Self {
-            cortisol_level: 0.5,
-            dopamine_level: 0.5,
-            serotonin_level: 0.5,
-            oxytocin_level: 0.5,
-            adrenaline_level: 0.5,
+            cortisol_level: profile.cortisol.baseline,
+            dopamine_level: profile.dopamine.baseline,
+            serotonin_level: profile.serotonin.baseline,
+            oxytocin_level: profile.oxytocin.baseline,
+            adrenaline_level: profile.adrenaline.baseline,
+            active_bursts: Vec::new(),
+            profile,
+            history: None,
+        }
+    }
+
+    /// Keep the last `capacity` level changes made by bursts, ticks and decay
+    pub fn with_history(mut self, capacity: usize) -> Self {
+        self.history = Some(LevelHistory {
+            capacity: capacity.max(1),
+            samples: VecDeque::with_capacity(capacity),
+        });
+        self
+    }
+
+    /// Recorded levels of `hormone` from `since` onwards, oldest first; empty
+    /// unless history was enabled with `with_history`
+    pub fn history(&self, hormone: HormoneType, since: DateTime<Utc>) -> Vec<(DateTime<Utc>, f64)> {
+        self.history.iter()
+            .flat_map(|history| &history.samples)
+            .filter(|(timestamp, recorded, _)| *recorded == hormone && *timestamp >= since)
+            .map(|(timestamp, _, level)| (*timestamp, *level))
+            .collect()
+    }
+
+    fn record_level(&mut self, timestamp: DateTime<Utc>, hormone: &HormoneType) {
+        let level = self.get_level(hormone);
+        if let Some(history) = &mut self.history {
+            history.record(timestamp, hormone.clone(), level);
         }
     }
 
+    pub fn profile(&self) -> &HormoneProfile {
+        &self.profile
+    }
+
     pub fn apply_burst(&mut self, burst: &HormonalBurst) {
-        match burst.hormone {
-            HormoneType::Cortisol => self.cortisol_level = (self.cortisol_level + burst.intensity).min(1.0),
-            HormoneType::Dopamine => self.dopamine_level = (self.dopamine_level + burst.intensity).min(1.0),
-            HormoneType::Serotonin => self.serotonin_level = (self.serotonin_level + burst.intensity).min(1.0),
-            HormoneType::Oxytocin => self.oxytocin_level = (self.oxytocin_level + burst.intensity).min(1.0),
-            HormoneType::Adrenaline => self.adrenaline_level = (self.adrenaline_level + burst.intensity).min(1.0),
+        let bounds = self.profile.bounds(&burst.hormone);
+        let level = self.level_mut(&burst.hormone);
+        let previous = *level;
+        *level = bounds.clamp(*level + burst.intensity);
+        let contribution = *level - previous;
+        
+        self.active_bursts.push(ActiveBurst {
+            hormone: burst.hormone.clone(),
+            expires_at: burst.expires_at(),
+            contribution,
+        });
+        self.record_level(Utc::now(), &burst.hormone);
+    }
+
+    /// Expire bursts whose duration has elapsed, removing their effect on the levels
+    pub fn tick(&mut self, now: DateTime<Utc>) {
+        let (expired, active): (Vec<_>, Vec<_>) = self.active_bursts
+            .drain(..)
+            .partition(|burst| burst.expires_at <= now);
+        self.active_bursts = active;
+        
+        for burst in expired {
+            let bounds = self.profile.bounds(&burst.hormone);
+            let level = self.level_mut(&burst.hormone);
+            *level = bounds.clamp(*level - burst.contribution);
+            self.record_level(now, &burst.hormone);
         }
     }
 
+    pub fn active_burst_count(&self) -> usize {
+        self.active_bursts.len()
+    }
+
+    /// Move every level up to `decay_rate` back toward its baseline, without overshooting
     pub fn decay(&mut self, decay_rate: f64) {
-        self.cortisol_level = (self.cortisol_level - decay_rate).max(0.0);
-        self.dopamine_level = (self.dopamine_level - decay_rate).max(0.0);
-        self.serotonin_level = (self.serotonin_level - decay_rate).max(0.0);
-        self.oxytocin_level = (self.oxytocin_level - decay_rate).max(0.0);
-        self.adrenaline_level = (self.adrenaline_level - decay_rate).max(0.0);
+        let now = Utc::now();
+        for hormone in &HORMONES {
+            let bounds = self.profile.bounds(hormone);
+            let level = self.level_mut(hormone);
+            let previous = *level;
+            let settled = if *level > bounds.baseline {
+                (*level - decay_rate).max(bounds.baseline)
+            } else {
+                (*level + decay_rate).min(bounds.baseline)
+            };
+            *level = bounds.clamp(settled);
+            
+            // Only changes are recorded, so hormones resting at baseline don't fill the history
+            if *level != previous {
+                self.record_level(now, hormone);
+            }
+        }
     }
 
     pub fn get_level(&self, hormone: &HormoneType) -> f64 {
@@ -67,6 +271,24 @@ impl HormonalState {
             HormoneType::Adrenaline => self.adrenaline_level,
         }
     }
+
+    /// Multiplier for learning rates: above-baseline dopamine raises it and
+    /// above-baseline cortisol lowers it; 1.0 when both sit at baseline
+    pub fn plasticity_factor(&self) -> f64 {
+        let dopamine = self.dopamine_level - self.profile.dopamine.baseline;
+        let cortisol = self.cortisol_level - self.profile.cortisol.baseline;
+        ((1.0 + dopamine) * (1.0 - cortisol)).max(0.0)
+    }
+
+    fn level_mut(&mut self, hormone: &HormoneType) -> &mut f64 {
+        match hormone {
+            HormoneType::Cortisol => &mut self.cortisol_level,
+            HormoneType::Dopamine => &mut self.dopamine_level,
+            HormoneType::Serotonin => &mut self.serotonin_level,
+            HormoneType::Oxytocin => &mut self.oxytocin_level,
+            HormoneType::Adrenaline => &mut self.adrenaline_level,
+        }
+    }
 }
 
 impl Default for HormonalState {
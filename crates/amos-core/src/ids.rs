@@ -0,0 +1,95 @@
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use uuid::Uuid;
+
+/// Source of ids for agents, tasks and other runtime objects
+pub trait IdGenerator: Send + Sync {
+    fn next_id(&self) -> Uuid;
+}
+
+/// Random v4 ids; what everything uses unless a generator is scoped in
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RandomIdGenerator;
+
+impl IdGenerator for RandomIdGenerator {
+    fn next_id(&self) -> Uuid {
+        Uuid::new_v4()
+    }
+}
+
+/// Reproducible sequence of v4-shaped ids, so tests can compare output byte for byte
+#[derive(Debug)]
+pub struct SeededIdGenerator {
+    state: AtomicU64,
+}
+
+impl SeededIdGenerator {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: AtomicU64::new(seed),
+        }
+    }
+    
+    // splitmix64: cheap, and every seed gives a well-mixed sequence
+    fn next_u64(&self) -> u64 {
+        let mut z = self.state.fetch_add(0x9E37_79B9_7F4A_7C15, Ordering::Relaxed)
+            .wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+impl IdGenerator for SeededIdGenerator {
+    fn next_id(&self) -> Uuid {
+        let mut bytes = [0u8; 16];
+        bytes[..8].copy_from_slice(&self.next_u64().to_le_bytes());
+        bytes[8..].copy_from_slice(&self.next_u64().to_le_bytes());
+        uuid::Builder::from_random_bytes(bytes).into_uuid()
+    }
+}
+
+/// Hands out one chosen id first and random ones after, so an object can be
+/// rebuilt under the id it had before
+#[derive(Debug)]
+pub struct ReservedIdGenerator {
+    reserved: std::sync::Mutex<Option<Uuid>>,
+}
+
+impl ReservedIdGenerator {
+    pub fn new(id: Uuid) -> Self {
+        Self {
+            reserved: std::sync::Mutex::new(Some(id)),
+        }
+    }
+}
+
+impl IdGenerator for ReservedIdGenerator {
+    fn next_id(&self) -> Uuid {
+        self.reserved.lock().unwrap_or_else(|e| e.into_inner())
+            .take()
+            .unwrap_or_else(Uuid::new_v4)
+    }
+}
+
+tokio::task_local! {
+    static ID_GENERATOR: Arc<dyn IdGenerator>;
+}
+
+/// Run a future with every id it creates drawn from `generator`
+pub async fn with_id_generator<F: Future>(generator: Arc<dyn IdGenerator>, future: F) -> F::Output {
+    ID_GENERATOR.scope(generator, future).await
+}
+
+/// Run a closure with every id it creates drawn from `generator`
+pub fn with_id_generator_sync<R>(generator: Arc<dyn IdGenerator>, f: impl FnOnce() -> R) -> R {
+    ID_GENERATOR.sync_scope(generator, f)
+}
+
+/// Next id from the scoped generator, or a random one outside any scope. Agents and
+/// tasks draw their ids from here, so seeded tests get stable ids.
+pub fn next_id() -> Uuid {
+    ID_GENERATOR.try_with(|generator| generator.next_id())
+        .unwrap_or_else(|_| RandomIdGenerator.next_id())
+}
@@ -4,10 +4,14 @@ pub mod hormonal;
 pub mod event_bus;
 pub mod logging;
 pub mod system;
+pub mod ids;
+pub mod pruning;
 
 pub use neural::*;
 pub use immune::*;
 pub use hormonal::*;
 pub use event_bus::*;
 pub use logging::*;
-pub use system::*;
\ No newline at end of file
+pub use system::*;
+pub use ids::{IdGenerator, RandomIdGenerator, ReservedIdGenerator, SeededIdGenerator, with_id_generator, with_id_generator_sync};
+pub use pruning::{LoadProbe, PruningConfig, PruningScheduler};
@@ -1,8 +1,23 @@
 use serde::{Serialize, Deserialize};
 use chrono::{DateTime, Utc};
 use std::fmt;
+use std::future::Future;
 use uuid::Uuid;
 
+tokio::task_local! {
+    static CORRELATION_ID: Uuid;
+}
+
+/// Run a future with a correlation id attached to every log entry it produces
+pub async fn with_correlation_id<F: Future>(correlation_id: Uuid, future: F) -> F::Output {
+    CORRELATION_ID.scope(correlation_id, future).await
+}
+
+/// The correlation id of the current task, if one was set
+pub fn current_correlation_id() -> Option<Uuid> {
+    CORRELATION_ID.try_with(|id| *id).ok()
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum LogLevel {
     Trace,
@@ -105,10 +120,15 @@ impl Logger {
     }
     
     fn log(&self, level: LogLevel, message: &str) -> LogEntry {
-        let entry = LogEntry::new(level.clone(), &self.component, message);
+        let mut entry = LogEntry::new(level.clone(), &self.component, message);
+        
+        if let Some(correlation_id) = current_correlation_id() {
+            entry = entry.with_context("correlation_id", serde_json::json!(correlation_id));
+        }
         
+        // Stderr keeps stdout free for protocol traffic such as the MCP stdio transport
         if self.should_log(&level) {
-            println!("{}", entry);
+            eprintln!("{}", entry);
         }
         
         entry
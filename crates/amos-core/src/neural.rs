@@ -1,11 +1,27 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Write;
 use std::sync::Arc;
 use tokio::sync::{RwLock, broadcast};
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
+use anyhow::{anyhow, Result};
+use crate::hormonal::HormonalState;
 
-#[derive(Debug, Clone)]
+/// Namespace for nodes and pathways that do not belong to a particular swarm
+pub const DEFAULT_NAMESPACE: &str = "";
+
+/// Strength a new pathway starts at unless the network is configured otherwise
+pub const DEFAULT_PATHWAY_STRENGTH: f64 = 0.1;
+
+/// Input a node must accumulate before `stimulate` fires it
+pub const DEFAULT_ACTIVATION_THRESHOLD: f64 = 1.0;
+
+fn default_activation_threshold() -> f64 {
+    DEFAULT_ACTIVATION_THRESHOLD
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NeuralPathway {
     pub id: Uuid,
     pub strength: f64,
@@ -13,20 +29,49 @@ pub struct NeuralPathway {
     pub usage_count: u64,
     pub source_node: Uuid,
     pub target_node: Uuid,
+    #[serde(default)]
+    pub namespace: String,
+    /// What the pathway means, e.g. "recall" for a memory to thinking link
+    #[serde(default)]
+    pub label: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 impl NeuralPathway {
     pub fn new(source: Uuid, target: Uuid) -> Self {
         Self {
             id: Uuid::new_v4(),
-            strength: 0.1,
+            strength: DEFAULT_PATHWAY_STRENGTH,
             last_used: Utc::now(),
             usage_count: 0,
             source_node: source,
             target_node: target,
+            namespace: DEFAULT_NAMESPACE.to_string(),
+            label: None,
+            tags: Vec::new(),
         }
     }
 
+    pub fn in_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = namespace.into();
+        self
+    }
+
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+
     pub fn strengthen(&mut self, delta: f64) {
         self.strength = (self.strength + delta).min(1.0);
         self.usage_count += 1;
@@ -38,7 +83,7 @@ impl NeuralPathway {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum NodeType {
     Memory,
     Thinking,
@@ -48,13 +93,23 @@ pub enum NodeType {
     Shadow,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CognitiveNode {
     pub id: Uuid,
     pub node_type: NodeType,
     pub state: serde_json::Value,
     pub connections: Vec<Uuid>,
     pub processing_fn: String,
+    #[serde(default)]
+    pub namespace: String,
+    #[serde(default = "default_activation_threshold")]
+    pub activation_threshold: f64,
+    /// Input received since the node last fired through `stimulate`
+    #[serde(default)]
+    pub accumulated_input: f64,
+    /// Named group of nodes stimulated together through `stimulate_region`
+    #[serde(default)]
+    pub region: Option<String>,
 }
 
 impl CognitiveNode {
@@ -65,14 +120,58 @@ impl CognitiveNode {
             state: serde_json::json!({}),
             connections: Vec::new(),
             processing_fn: String::new(),
+            namespace: DEFAULT_NAMESPACE.to_string(),
+            activation_threshold: DEFAULT_ACTIVATION_THRESHOLD,
+            accumulated_input: 0.0,
+            region: None,
         }
     }
 
+    pub fn in_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = namespace.into();
+        self
+    }
+
+    pub fn in_region(mut self, region: impl Into<String>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
     pub fn add_connection(&mut self, connection_id: Uuid) {
         self.connections.push(connection_id);
     }
 }
 
+pub type NodeProcessor = Arc<dyn Fn(&serde_json::Value, &serde_json::Value) -> serde_json::Value + Send + Sync>;
+
+/// Named processing functions that nodes reference through `processing_fn`
+#[derive(Clone, Default)]
+pub struct ProcessorRegistry {
+    processors: HashMap<String, NodeProcessor>,
+}
+
+impl ProcessorRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a processor taking the node's current state and an input, returning the new state
+    pub fn register<F>(&mut self, name: impl Into<String>, processor: F)
+    where
+        F: Fn(&serde_json::Value, &serde_json::Value) -> serde_json::Value + Send + Sync + 'static,
+    {
+        self.processors.insert(name.into(), Arc::new(processor));
+    }
+
+    pub fn get(&self, name: &str) -> Option<NodeProcessor> {
+        self.processors.get(name).cloned()
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.processors.contains_key(name)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum NeuralEvent {
     PathwayCreated {
@@ -96,52 +195,321 @@ pub enum NeuralEvent {
         node_id: Uuid,
         timestamp: DateTime<Utc>,
     },
+    /// A scheduled pruning pass finished
+    PruningCycleCompleted {
+        pathways_removed: usize,
+        timestamp: DateTime<Utc>,
+    },
+    /// Every node, pathway and firing was wiped
+    NetworkReset {
+        nodes_removed: usize,
+        pathways_removed: usize,
+        timestamp: DateTime<Utc>,
+    },
+}
+
+/// Minimum depth-decayed pathway strength needed to carry activation onward
+pub const PROPAGATION_THRESHOLD: f64 = 0.5;
+
+/// Serializable copy of a network's nodes and pathways
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkSnapshot {
+    pub version: u32,
+    pub taken_at: DateTime<Utc>,
+    pub nodes: Vec<CognitiveNode>,
+    pub pathways: Vec<NeuralPathway>,
+}
+
+impl NetworkSnapshot {
+    pub const CURRENT_VERSION: u32 = 1;
+
+    /// What changed between two snapshots of the same network
+    pub fn diff(old: &NetworkSnapshot, new: &NetworkSnapshot) -> NetworkDiff {
+        let old_nodes: HashSet<Uuid> = old.nodes.iter().map(|n| n.id).collect();
+        let new_nodes: HashSet<Uuid> = new.nodes.iter().map(|n| n.id).collect();
+        let old_pathways: HashMap<Uuid, f64> = old.pathways.iter().map(|p| (p.id, p.strength)).collect();
+        let new_pathways: HashMap<Uuid, f64> = new.pathways.iter().map(|p| (p.id, p.strength)).collect();
+
+        let mut strength_changes: Vec<StrengthChange> = new_pathways.iter()
+            .filter_map(|(id, &new_strength)| {
+                let old_strength = *old_pathways.get(id)?;
+                (new_strength != old_strength).then_some(StrengthChange {
+                    pathway_id: *id,
+                    old_strength,
+                    new_strength,
+                    delta: new_strength - old_strength,
+                })
+            })
+            .collect();
+        strength_changes.sort_by_key(|change| change.pathway_id);
+
+        let old_pathway_ids: HashSet<Uuid> = old_pathways.keys().copied().collect();
+        let new_pathway_ids: HashSet<Uuid> = new_pathways.keys().copied().collect();
+
+        NetworkDiff {
+            added_nodes: sorted_difference(&new_nodes, &old_nodes),
+            removed_nodes: sorted_difference(&old_nodes, &new_nodes),
+            added_pathways: sorted_difference(&new_pathway_ids, &old_pathway_ids),
+            removed_pathways: sorted_difference(&old_pathway_ids, &new_pathway_ids),
+            strength_changes,
+        }
+    }
+}
+
+fn sorted_difference(a: &HashSet<Uuid>, b: &HashSet<Uuid>) -> Vec<Uuid> {
+    let mut ids: Vec<Uuid> = a.difference(b).copied().collect();
+    ids.sort();
+    ids
+}
+
+/// Changes between two snapshots; ids are sorted so diffs compare and print stably
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NetworkDiff {
+    pub added_nodes: Vec<Uuid>,
+    pub removed_nodes: Vec<Uuid>,
+    pub added_pathways: Vec<Uuid>,
+    pub removed_pathways: Vec<Uuid>,
+    /// Pathways present in both snapshots whose strength differs
+    pub strength_changes: Vec<StrengthChange>,
+}
+
+impl NetworkDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added_nodes.is_empty()
+            && self.removed_nodes.is_empty()
+            && self.added_pathways.is_empty()
+            && self.removed_pathways.is_empty()
+            && self.strength_changes.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StrengthChange {
+    pub pathway_id: Uuid,
+    pub old_strength: f64,
+    pub new_strength: f64,
+    pub delta: f64,
+}
+
+/// Shard owning a pathway; v4 ids are random, so their low bits spread evenly
+fn shard_index(pathway_id: Uuid, num_shards: usize) -> usize {
+    (pathway_id.as_u128() % num_shards as u128) as usize
 }
 
 #[derive(Clone)]
 pub struct ForgeNeuralNetwork {
     nodes: Arc<RwLock<HashMap<Uuid, CognitiveNode>>>,
-    pathways: Arc<RwLock<HashMap<Uuid, NeuralPathway>>>,
+    /// Pathways split across independently locked shards by pathway id
+    pathways: Arc<Vec<RwLock<HashMap<Uuid, NeuralPathway>>>>,
     event_bus: broadcast::Sender<NeuralEvent>,
     fired_nodes: Arc<RwLock<HashMap<Uuid, DateTime<Utc>>>>,
+    processors: Arc<RwLock<ProcessorRegistry>>,
+    /// Hormones scaling how fast co-firing nodes wire together, when shared
+    hormonal_state: Option<Arc<RwLock<HormonalState>>>,
+    /// Strength of pathways created without an explicit one, e.g. by Hebbian learning
+    default_pathway_strength: f64,
 }
 
 impl ForgeNeuralNetwork {
     pub fn new() -> Self {
+        Self::sharded(1)
+    }
+
+    /// Network whose pathways are spread over `num_shards` locks, so writers
+    /// touching different pathways don't block each other
+    pub fn sharded(num_shards: usize) -> Self {
         let (event_bus, _) = broadcast::channel(1000);
         Self {
             nodes: Arc::new(RwLock::new(HashMap::new())),
-            pathways: Arc::new(RwLock::new(HashMap::new())),
+            pathways: Arc::new((0..num_shards.max(1)).map(|_| RwLock::new(HashMap::new())).collect()),
             event_bus,
             fired_nodes: Arc::new(RwLock::new(HashMap::new())),
+            processors: Arc::new(RwLock::new(ProcessorRegistry::new())),
+            hormonal_state: None,
+            default_pathway_strength: DEFAULT_PATHWAY_STRENGTH,
+        }
+    }
+
+    /// Start new connections stronger or weaker than `DEFAULT_PATHWAY_STRENGTH`
+    pub fn with_default_pathway_strength(mut self, strength: f64) -> Self {
+        self.default_pathway_strength = strength.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn default_pathway_strength(&self) -> f64 {
+        self.default_pathway_strength
+    }
+
+    /// Let a shared hormonal state modulate Hebbian learning: dopamine speeds
+    /// potentiation up and cortisol damps it
+    pub fn with_hormonal_modulation(mut self, state: Arc<RwLock<HormonalState>>) -> Self {
+        self.hormonal_state = Some(state);
+        self
+    }
+
+    async fn plasticity_factor(&self) -> f64 {
+        match &self.hormonal_state {
+            Some(state) => state.read().await.plasticity_factor(),
+            None => 1.0,
         }
     }
 
+    pub fn shard_count(&self) -> usize {
+        self.pathways.len()
+    }
+
+    fn shard(&self, pathway_id: Uuid) -> &RwLock<HashMap<Uuid, NeuralPathway>> {
+        &self.pathways[shard_index(pathway_id, self.pathways.len())]
+    }
+
+    /// Clones of every pathway, gathered one shard at a time
+    async fn all_pathways(&self) -> Vec<NeuralPathway> {
+        let mut all = Vec::new();
+        for shard in self.pathways.iter() {
+            all.extend(shard.read().await.values().cloned());
+        }
+        all
+    }
+
     pub async fn node_count(&self) -> usize {
         self.nodes.read().await.len()
     }
 
     pub async fn pathway_count(&self) -> usize {
-        self.pathways.read().await.len()
+        let mut count = 0;
+        for shard in self.pathways.iter() {
+            count += shard.read().await.len();
+        }
+        count
+    }
+
+    pub async fn node_count_in(&self, namespace: &str) -> usize {
+        self.nodes.read().await.values()
+            .filter(|node| node.namespace == namespace)
+            .count()
+    }
+
+    pub async fn pathway_count_in(&self, namespace: &str) -> usize {
+        let mut count = 0;
+        for shard in self.pathways.iter() {
+            count += shard.read().await.values()
+                .filter(|pathway| pathway.namespace == namespace)
+                .count();
+        }
+        count
     }
 
     pub async fn add_node(&self, node_type: NodeType) -> Uuid {
-        let node = CognitiveNode::new(node_type);
+        self.add_node_in(DEFAULT_NAMESPACE, node_type).await
+    }
+
+    /// Add a node tagged with a swarm's namespace
+    pub async fn add_node_in(&self, namespace: &str, node_type: NodeType) -> Uuid {
+        let node = CognitiveNode::new(node_type).in_namespace(namespace);
         let node_id = node.id;
         self.nodes.write().await.insert(node_id, node);
         node_id
     }
 
+    /// Add a node to a named region, in the default namespace
+    pub async fn add_node_in_region(&self, region: &str, node_type: NodeType) -> Uuid {
+        let node = CognitiveNode::new(node_type).in_region(region);
+        let node_id = node.id;
+        self.nodes.write().await.insert(node_id, node);
+        node_id
+    }
+
+    /// Move a node into a region, or out of any with `None`
+    pub async fn set_node_region(&self, node_id: Uuid, region: Option<&str>) -> Result<()> {
+        let mut nodes = self.nodes.write().await;
+        let node = nodes.get_mut(&node_id)
+            .ok_or_else(|| anyhow!("Node {} not found", node_id))?;
+        node.region = region.map(str::to_string);
+        Ok(())
+    }
+
+    pub async fn nodes_in_region(&self, region: &str) -> Vec<Uuid> {
+        self.nodes.read().await.values()
+            .filter(|node| node.region.as_deref() == Some(region))
+            .map(|node| node.id)
+            .collect()
+    }
+
     pub async fn get_node(&self, node_id: Uuid) -> Option<CognitiveNode> {
         self.nodes.read().await.get(&node_id).cloned()
     }
 
+    pub async fn register_processor<F>(&self, name: impl Into<String>, processor: F)
+    where
+        F: Fn(&serde_json::Value, &serde_json::Value) -> serde_json::Value + Send + Sync + 'static,
+    {
+        self.processors.write().await.register(name, processor);
+    }
+
+    pub async fn set_node_processor(&self, node_id: Uuid, name: &str) -> Result<()> {
+        if !self.processors.read().await.contains(name) {
+            return Err(anyhow!("Unknown processor '{}'", name));
+        }
+        
+        let mut nodes = self.nodes.write().await;
+        let node = nodes.get_mut(&node_id)
+            .ok_or_else(|| anyhow!("Node {} not found", node_id))?;
+        node.processing_fn = name.to_string();
+        Ok(())
+    }
+
+    /// Run the node's processing function against its state and the input, persisting the result
+    pub async fn process_node(&self, node_id: Uuid, input: serde_json::Value) -> Result<serde_json::Value> {
+        let mut nodes = self.nodes.write().await;
+        let node = nodes.get_mut(&node_id)
+            .ok_or_else(|| anyhow!("Node {} not found", node_id))?;
+        
+        if node.processing_fn.is_empty() {
+            return Err(anyhow!("Node {} has no processing function", node_id));
+        }
+        
+        let processor = self.processors.read().await
+            .get(&node.processing_fn)
+            .ok_or_else(|| anyhow!("Unknown processor '{}'", node.processing_fn))?;
+        
+        let new_state = processor(&node.state, &input);
+        node.state = new_state.clone();
+        Ok(new_state)
+    }
+
     pub async fn create_pathway(&self, source: Uuid, target: Uuid, strength: f64) -> Uuid {
-        let mut pathway = NeuralPathway::new(source, target);
+        self.create_pathway_in(DEFAULT_NAMESPACE, source, target, strength).await
+    }
+
+    /// Create a pathway at the network's default strength
+    pub async fn connect(&self, source: Uuid, target: Uuid) -> Uuid {
+        self.create_pathway(source, target, self.default_pathway_strength).await
+    }
+
+    /// Create a pathway tagged with a swarm's namespace
+    pub async fn create_pathway_in(&self, namespace: &str, source: Uuid, target: Uuid, strength: f64) -> Uuid {
+        self.insert_pathway(NeuralPathway::new(source, target).in_namespace(namespace), strength).await
+    }
+
+    /// Create a pathway carrying a label and tags for tracing what it represents
+    pub async fn create_labeled_pathway(
+        &self,
+        source: Uuid,
+        target: Uuid,
+        strength: f64,
+        label: impl Into<String>,
+        tags: Vec<String>,
+    ) -> Uuid {
+        let pathway = NeuralPathway::new(source, target).with_label(label).with_tags(tags);
+        self.insert_pathway(pathway, strength).await
+    }
+
+    async fn insert_pathway(&self, mut pathway: NeuralPathway, strength: f64) -> Uuid {
         pathway.strength = strength;
         let pathway_id = pathway.id;
+        let (source, target) = (pathway.source_node, pathway.target_node);
         
-        self.pathways.write().await.insert(pathway_id, pathway);
+        self.shard(pathway_id).write().await.insert(pathway_id, pathway);
         
         let _ = self.event_bus.send(NeuralEvent::PathwayCreated {
             pathway_id,
@@ -154,20 +522,34 @@ impl ForgeNeuralNetwork {
     }
 
     pub async fn get_pathway(&self, pathway_id: Uuid) -> Option<NeuralPathway> {
-        self.pathways.read().await.get(&pathway_id).cloned()
+        self.shard(pathway_id).read().await.get(&pathway_id).cloned()
     }
 
-    pub async fn strengthen_pathway(&self, pathway_id: Uuid, delta: f64) {
-        let mut pathways = self.pathways.write().await;
-        if let Some(pathway) = pathways.get_mut(&pathway_id) {
+    /// Apply a signed delta to a pathway, returning its new strength
+    ///
+    /// Negative deltas weaken the pathway; the result always stays within [0, 1].
+    pub async fn strengthen_pathway(&self, pathway_id: Uuid, delta: f64) -> Option<f64> {
+        let mut pathways = self.shard(pathway_id).write().await;
+        let pathway = pathways.get_mut(&pathway_id)?;
+        
+        let event = if delta < 0.0 {
+            pathway.weaken(-delta);
+            NeuralEvent::PathwayWeakened {
+                pathway_id,
+                new_strength: pathway.strength,
+            }
+        } else {
             pathway.strengthen(delta);
-            let new_strength = pathway.strength;
-            
-            let _ = self.event_bus.send(NeuralEvent::PathwayStrengthened {
+            NeuralEvent::PathwayStrengthened {
                 pathway_id,
-                new_strength,
-            });
-        }
+                new_strength: pathway.strength,
+            }
+        };
+        let new_strength = pathway.strength;
+        drop(pathways);
+        
+        let _ = self.event_bus.send(event);
+        Some(new_strength)
     }
 
     pub async fn fire_node(&self, node_id: Uuid) {
@@ -179,6 +561,88 @@ impl ForgeNeuralNetwork {
         });
     }
 
+    /// Fails for a NaN or negative threshold, or an unknown node
+    pub async fn set_activation_threshold(&self, node_id: Uuid, threshold: f64) -> Result<()> {
+        if threshold.is_nan() || threshold < 0.0 {
+            return Err(anyhow!("Activation threshold must be a non-negative number, got {}", threshold));
+        }
+        
+        let mut nodes = self.nodes.write().await;
+        let node = nodes.get_mut(&node_id)
+            .ok_or_else(|| anyhow!("Node {} not found", node_id))?;
+        node.activation_threshold = threshold;
+        Ok(())
+    }
+
+    /// Add weighted input to a node, firing it and clearing its accumulated input
+    /// once the total reaches its activation threshold. A node that fires passes
+    /// `strength * input` along each outgoing pathway, where `input` is the total
+    /// that made it fire, so the stimulation can cascade; each node fires at most
+    /// once per call. Returns whether the stimulated node fired; unknown nodes
+    /// never do.
+    pub async fn stimulate(&self, node_id: Uuid, amount: f64) -> bool {
+        let mut pending = VecDeque::from([(node_id, amount)]);
+        let mut fired = HashSet::new();
+        
+        while let Some((id, input)) = pending.pop_front() {
+            if fired.contains(&id) {
+                continue;
+            }
+            
+            let total = {
+                let mut nodes = self.nodes.write().await;
+                let Some(node) = nodes.get_mut(&id) else {
+                    continue;
+                };
+                
+                node.accumulated_input += input;
+                if node.accumulated_input < node.activation_threshold {
+                    continue;
+                }
+                std::mem::take(&mut node.accumulated_input)
+            };
+            
+            fired.insert(id);
+            self.fire_node(id).await;
+            
+            pending.extend(self.all_pathways().await
+                .into_iter()
+                .filter(|p| p.source_node == id)
+                .map(|p| (p.target_node, p.strength * total))
+                .filter(|(_, input)| *input >= PROPAGATION_THRESHOLD));
+        }
+        
+        fired.contains(&node_id)
+    }
+
+    /// Fire every node in a region, then pass `strength * intensity` along each
+    /// pathway leaving the region into its target through `stimulate`, so
+    /// neighbours fire only once enough input builds up. Returns how many
+    /// nodes of the region fired.
+    pub async fn stimulate_region(&self, region: &str, intensity: f64) -> usize {
+        let members: HashSet<Uuid> = self.nodes_in_region(region).await.into_iter().collect();
+        for node_id in &members {
+            self.fire_node(*node_id).await;
+        }
+        
+        let outgoing: Vec<(Uuid, f64)> = self.all_pathways().await
+            .into_iter()
+            .filter(|p| members.contains(&p.source_node) && !members.contains(&p.target_node))
+            .map(|p| (p.target_node, p.strength * intensity))
+            .filter(|(_, input)| *input >= PROPAGATION_THRESHOLD)
+            .collect();
+        for (target, input) in outgoing {
+            self.stimulate(target, input).await;
+        }
+        
+        members.len()
+    }
+
+    /// Nodes that have fired since the network was last cleared or restored
+    pub async fn fired_nodes(&self) -> HashSet<Uuid> {
+        self.fired_nodes.read().await.keys().copied().collect()
+    }
+
     pub async fn hebbian_learning(&self, source: Uuid, target: Uuid) {
         let fired_nodes = self.fired_nodes.read().await;
         
@@ -189,43 +653,283 @@ impl ForgeNeuralNetwork {
             let time_diff = (*target_time - *source_time).num_milliseconds().abs();
             if time_diff < 100 {
                 // Fire together, wire together
+                let plasticity = self.plasticity_factor().await;
                 if let Some(pathway_id) = self.find_pathway(source, target).await {
-                    self.strengthen_pathway(pathway_id, 0.1).await;
+                    self.strengthen_pathway(pathway_id, 0.1 * plasticity).await;
                 } else {
-                    self.create_pathway(source, target, 0.1).await;
+                    // Fully suppressed plasticity forms no new connections
+                    let strength = self.default_pathway_strength * plasticity;
+                    if strength > 0.0 {
+                        self.create_pathway(source, target, strength).await;
+                    }
                 }
             }
         }
     }
 
+    /// Fire `start` and spread activation along outgoing pathways, breadth first.
+    ///
+    /// A node at depth `d` fires when `strength * decay^(d-1)` reaches
+    /// `PROPAGATION_THRESHOLD`, and each co-firing pair is reinforced through
+    /// `hebbian_learning`. Returns the downstream nodes that fired, in order.
+    pub async fn propagate(&self, start: Uuid, decay: f64, max_depth: usize) -> Vec<Uuid> {
+        let mut cascade = Vec::new();
+        let mut visited = HashSet::from([start]);
+        let mut frontier = vec![start];
+
+        self.fire_node(start).await;
+
+        for depth in 1..=max_depth {
+            let scale = decay.powi(depth as i32 - 1);
+            let edges: Vec<(Uuid, Uuid)> = self.all_pathways().await
+                .into_iter()
+                .filter(|p| frontier.contains(&p.source_node))
+                .filter(|p| p.strength * scale >= PROPAGATION_THRESHOLD)
+                .map(|p| (p.source_node, p.target_node))
+                .collect();
+
+            let mut next = Vec::new();
+            for (source, target) in edges {
+                if visited.insert(target) {
+                    self.fire_node(target).await;
+                    cascade.push(target);
+                    next.push(target);
+                }
+                self.hebbian_learning(source, target).await;
+            }
+
+            if next.is_empty() {
+                break;
+            }
+            frontier = next;
+        }
+
+        cascade
+    }
+
+    pub async fn pathways_with_tag(&self, tag: &str) -> Vec<NeuralPathway> {
+        let mut tagged = Vec::new();
+        for shard in self.pathways.iter() {
+            tagged.extend(shard.read().await.values().filter(|p| p.has_tag(tag)).cloned());
+        }
+        tagged
+    }
+
     pub async fn find_pathway(&self, source: Uuid, target: Uuid) -> Option<Uuid> {
-        let pathways = self.pathways.read().await;
-        pathways.iter()
-            .find(|(_, p)| p.source_node == source && p.target_node == target)
-            .map(|(id, _)| *id)
+        for shard in self.pathways.iter() {
+            let found = shard.read().await.iter()
+                .find(|(_, p)| p.source_node == source && p.target_node == target)
+                .map(|(id, _)| *id);
+            if found.is_some() {
+                return found;
+            }
+        }
+        None
     }
 
     pub async fn find_pathways_between(&self, source: Uuid, target: Uuid) -> Vec<Uuid> {
-        let pathways = self.pathways.read().await;
-        pathways.iter()
-            .filter(|(_, p)| p.source_node == source && p.target_node == target)
-            .map(|(id, _)| *id)
-            .collect()
+        let mut found = Vec::new();
+        for shard in self.pathways.iter() {
+            found.extend(shard.read().await.iter()
+                .filter(|(_, p)| p.source_node == source && p.target_node == target)
+                .map(|(id, _)| *id));
+        }
+        found
     }
 
     pub async fn run_synaptic_pruning(&self, threshold: f64) {
-        let mut pathways = self.pathways.write().await;
-        let to_remove: Vec<Uuid> = pathways.iter()
-            .filter(|(_, p)| p.strength < threshold)
-            .map(|(id, _)| *id)
-            .collect();
+        self.prune_pathways(threshold, None, None, None).await;
+    }
+
+    /// Prune weak pathways belonging to one namespace, leaving other swarms' pathways alone
+    pub async fn run_synaptic_pruning_in(&self, namespace: &str, threshold: f64) {
+        self.prune_pathways(threshold, Some(namespace), None, None).await;
+    }
+
+    /// Prune weak pathways, sparing any used more than `usage_protection` times
+    pub async fn prune_weighted(&self, strength_threshold: f64, usage_protection: u64) {
+        self.prune_pathways(strength_threshold, None, Some(usage_protection), None).await;
+    }
+
+    /// Prune weak pathways that have gone unused for at least `max_idle`,
+    /// returning how many were removed
+    pub async fn prune_idle(&self, strength_threshold: f64, max_idle: chrono::Duration) -> usize {
+        let Some(cutoff) = Utc::now().checked_sub_signed(max_idle) else {
+            return 0;
+        };
+        self.prune_pathways(strength_threshold, None, None, Some(cutoff)).await
+    }
+
+    pub(crate) fn publish(&self, event: NeuralEvent) {
+        let _ = self.event_bus.send(event);
+    }
+
+    async fn prune_pathways(
+        &self,
+        threshold: f64,
+        namespace: Option<&str>,
+        usage_protection: Option<u64>,
+        unused_since: Option<DateTime<Utc>>,
+    ) -> usize {
+        let mut removed = 0;
+        for shard in self.pathways.iter() {
+            let mut pathways = shard.write().await;
+            let to_remove: Vec<Uuid> = pathways.iter()
+                .filter(|(_, p)| p.strength < threshold)
+                .filter(|(_, p)| match namespace {
+                    Some(namespace) => p.namespace == namespace,
+                    None => true,
+                })
+                .filter(|(_, p)| match usage_protection {
+                    Some(protection) => p.usage_count <= protection,
+                    None => true,
+                })
+                .filter(|(_, p)| match unused_since {
+                    Some(cutoff) => p.last_used <= cutoff,
+                    None => true,
+                })
+                .map(|(id, _)| *id)
+                .collect();
+            
+            removed += to_remove.len();
+            for pathway_id in to_remove {
+                pathways.remove(&pathway_id);
+                let _ = self.event_bus.send(NeuralEvent::PathwayRemoved { pathway_id });
+            }
+        }
+        removed
+    }
+
+    /// Distribution of pathway strengths as `(bucket_low, bucket_high, count)` over [0, 1]
+    pub async fn strength_histogram(&self, buckets: usize) -> Vec<(f64, f64, usize)> {
+        if buckets == 0 {
+            return Vec::new();
+        }
         
-        for pathway_id in to_remove {
-            pathways.remove(&pathway_id);
-            let _ = self.event_bus.send(NeuralEvent::PathwayRemoved { pathway_id });
+        let width = 1.0 / buckets as f64;
+        let mut counts = vec![0usize; buckets];
+        
+        for pathway in self.all_pathways().await {
+            // A strength of exactly 1.0 belongs in the last bucket
+            let index = ((pathway.strength.clamp(0.0, 1.0) / width) as usize).min(buckets - 1);
+            counts[index] += 1;
         }
+        
+        counts.into_iter()
+            .enumerate()
+            .map(|(i, count)| (i as f64 * width, (i + 1) as f64 * width, count))
+            .collect()
     }
 
+    /// Capture the current nodes and pathways
+    pub async fn snapshot(&self) -> NetworkSnapshot {
+        let nodes = self.nodes.read().await.values().cloned().collect();
+        let pathways = self.all_pathways().await;
+        
+        NetworkSnapshot {
+            version: NetworkSnapshot::CURRENT_VERSION,
+            taken_at: Utc::now(),
+            nodes,
+            pathways,
+        }
+    }
+    
+    /// Replace the network's nodes and pathways with those from a snapshot
+    pub async fn restore(&self, snapshot: NetworkSnapshot) -> Result<()> {
+        if snapshot.version > NetworkSnapshot::CURRENT_VERSION {
+            return Err(anyhow!("Unsupported snapshot version: {}", snapshot.version));
+        }
+        
+        let mut nodes = self.nodes.write().await;
+        // Hold every shard so readers never see a half-restored network
+        let mut shards = Vec::with_capacity(self.pathways.len());
+        for shard in self.pathways.iter() {
+            let mut pathways = shard.write().await;
+            pathways.clear();
+            shards.push(pathways);
+        }
+        
+        *nodes = snapshot.nodes.into_iter().map(|node| (node.id, node)).collect();
+        for pathway in snapshot.pathways {
+            let index = shard_index(pathway.id, shards.len());
+            shards[index].insert(pathway.id, pathway);
+        }
+        self.fired_nodes.write().await.clear();
+        
+        Ok(())
+    }
+    
+    /// Remove every node, pathway and fired-node record, returning how many
+    /// nodes and pathways there were
+    pub async fn clear(&self) -> (usize, usize) {
+        let mut nodes = self.nodes.write().await;
+        // Hold every shard so readers never see a half-cleared network
+        let mut pathways_removed = 0;
+        let mut shards = Vec::with_capacity(self.pathways.len());
+        for shard in self.pathways.iter() {
+            let mut pathways = shard.write().await;
+            pathways_removed += pathways.len();
+            pathways.clear();
+            shards.push(pathways);
+        }
+        
+        let nodes_removed = nodes.len();
+        nodes.clear();
+        self.fired_nodes.write().await.clear();
+        drop(shards);
+        drop(nodes);
+        
+        self.publish(NeuralEvent::NetworkReset {
+            nodes_removed,
+            pathways_removed,
+            timestamp: Utc::now(),
+        });
+        
+        (nodes_removed, pathways_removed)
+    }
+    
+    /// Graphviz DOT rendering of the nodes and pathways, with pathway labels on the edges
+    pub async fn render_dot(&self) -> String {
+        let mut nodes: Vec<CognitiveNode> = self.nodes.read().await.values().cloned().collect();
+        nodes.sort_by_key(|node| node.id);
+        let mut pathways = self.all_pathways().await;
+        pathways.sort_by_key(|p| (p.source_node, p.target_node, p.id));
+        
+        let mut dot = String::from("digraph neural {\n");
+        for node in &nodes {
+            let _ = writeln!(dot, "    \"{}\" [label=\"{:?}\\n{}\"];", node.id, node.node_type, &node.id.to_string()[..8]);
+        }
+        for pathway in &pathways {
+            let label = match &pathway.label {
+                Some(label) => format!("{}\\n{:.2}", label.replace('"', "\\\""), pathway.strength),
+                None => format!("{:.2}", pathway.strength),
+            };
+            let _ = writeln!(dot, "    \"{}\" -> \"{}\" [label=\"{}\"];", pathway.source_node, pathway.target_node, label);
+        }
+        dot.push_str("}\n");
+        
+        dot
+    }
+    
+    pub async fn export_state(&self) -> Result<String> {
+        Ok(serde_json::to_string(&self.snapshot().await)?)
+    }
+    
+    pub async fn import_state(&self, json: &str) -> Result<()> {
+        self.restore(serde_json::from_str(json)?).await
+    }
+    
+    /// MessagePack encoding of the snapshot, much more compact than JSON for large networks
+    #[cfg(feature = "msgpack")]
+    pub async fn export_state_binary(&self) -> Result<Vec<u8>> {
+        Ok(rmp_serde::to_vec(&self.snapshot().await)?)
+    }
+    
+    #[cfg(feature = "msgpack")]
+    pub async fn import_state_binary(&self, bytes: &[u8]) -> Result<()> {
+        self.restore(rmp_serde::from_slice(bytes)?).await
+    }
+    
     pub fn subscribe_to_events(&self) -> broadcast::Receiver<NeuralEvent> {
         self.event_bus.subscribe()
     }
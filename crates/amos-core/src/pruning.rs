@@ -0,0 +1,135 @@
+use std::sync::Arc;
+use std::time::Duration;
+use anyhow::Result;
+use chrono::Utc;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use crate::neural::{ForgeNeuralNetwork, NeuralEvent};
+
+/// Current system load between 0.0 (idle) and 1.0 (saturated)
+pub type LoadProbe = Arc<dyn Fn() -> f64 + Send + Sync>;
+
+/// How often and how aggressively scheduled pruning runs
+#[derive(Debug, Clone)]
+pub struct PruningConfig {
+    pub interval: Duration,
+    /// Pathways weaker than this are candidates for removal
+    pub strength_threshold: f64,
+    /// Candidates used more recently than this are kept
+    pub max_idle: Duration,
+    /// Cycles are skipped while the load probe reports more than this
+    pub max_load: f64,
+}
+
+impl Default for PruningConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(60),
+            strength_threshold: 0.1,
+            max_idle: Duration::from_secs(300),
+            max_load: 0.8,
+        }
+    }
+}
+
+/// Prunes weak, idle pathways on a background task, so dead pathways don't
+/// pile up between agent cycles. Each completed cycle is announced as a
+/// `NeuralEvent::PruningCycleCompleted` on the network's event stream.
+pub struct PruningScheduler {
+    network: Arc<ForgeNeuralNetwork>,
+    config: PruningConfig,
+    load_probe: Option<LoadProbe>,
+    running: Option<(watch::Sender<bool>, JoinHandle<()>)>,
+}
+
+impl PruningScheduler {
+    /// Fails for a zero `config.interval`, which would prune back to back
+    pub fn new(network: Arc<ForgeNeuralNetwork>, config: PruningConfig) -> Result<Self> {
+        if config.interval.is_zero() {
+            anyhow::bail!("Pruning interval must be greater than zero");
+        }
+        
+        Ok(Self {
+            network,
+            config,
+            load_probe: None,
+            running: None,
+        })
+    }
+
+    /// Pause pruning whenever `probe` reports load above `max_load`
+    pub fn with_load_probe(mut self, probe: LoadProbe) -> Self {
+        self.load_probe = Some(probe);
+        self
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running.is_some()
+    }
+
+    /// Run one cycle now, returning how many pathways were removed, or
+    /// `None` if it was skipped because of load
+    pub async fn run_cycle(&self) -> Option<usize> {
+        run_cycle(&self.network, &self.config, self.load_probe.as_ref()).await
+    }
+
+    /// Start pruning in the background; does nothing if already started
+    pub fn start(&mut self) {
+        if self.running.is_some() {
+            return;
+        }
+
+        let (stop_tx, mut stop_rx) = watch::channel(false);
+        let network = self.network.clone();
+        let config = self.config.clone();
+        let load_probe = self.load_probe.clone();
+
+        let handle = tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = tokio::time::sleep(config.interval) => {
+                        run_cycle(&network, &config, load_probe.as_ref()).await;
+                    }
+                    _ = stop_rx.changed() => break,
+                }
+            }
+        });
+
+        self.running = Some((stop_tx, handle));
+    }
+
+    /// Stop the background task, waiting for an in-flight cycle to finish
+    pub async fn stop(&mut self) {
+        if let Some((stop_tx, handle)) = self.running.take() {
+            let _ = stop_tx.send(true);
+            let _ = handle.await;
+        }
+    }
+}
+
+impl Drop for PruningScheduler {
+    fn drop(&mut self) {
+        if let Some((_, handle)) = self.running.take() {
+            handle.abort();
+        }
+    }
+}
+
+async fn run_cycle(
+    network: &ForgeNeuralNetwork,
+    config: &PruningConfig,
+    load_probe: Option<&LoadProbe>,
+) -> Option<usize> {
+    if load_probe.is_some_and(|probe| probe() > config.max_load) {
+        return None;
+    }
+
+    let max_idle = chrono::Duration::from_std(config.max_idle).unwrap_or(chrono::Duration::MAX);
+    let pathways_removed = network.prune_idle(config.strength_threshold, max_idle).await;
+    network.publish(NeuralEvent::PruningCycleCompleted {
+        pathways_removed,
+        timestamp: Utc::now(),
+    });
+
+    Some(pathways_removed)
+}
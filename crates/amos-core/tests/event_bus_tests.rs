@@ -233,4 +233,17 @@ async fn test_different_event_types() {
     // All events should be received
     let received = events_clone.lock().await;
     assert_eq!(received.len(), events.len());
-}
\ No newline at end of file
+}
+#[test]
+fn test_event_mask_matches_variants() {
+    let mask = EventMask::AGENT_ACTIVATED | EventMask::SYSTEM_SHUTDOWN;
+    
+    assert!(mask.matches(&SystemEvent::SystemShutdown));
+    assert!(mask.matches(&SystemEvent::AgentActivated {
+        agent_id: Uuid::new_v4(),
+        agent_type: "Test".to_string(),
+    }));
+    assert!(!mask.matches(&SystemEvent::NeuralFired { node_id: Uuid::new_v4() }));
+    assert!(!EventMask::NONE.matches(&SystemEvent::SystemShutdown));
+    assert_eq!(EventMask::default(), EventMask::ALL);
+}
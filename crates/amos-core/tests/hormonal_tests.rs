@@ -1,6 +1,6 @@
 use amos_core::hormonal::*;
 use uuid::Uuid;
-use chrono::Utc;
+use chrono::{Duration, Utc};
 
 #[test]
 fn test_hormonal_state_creation() {
@@ -69,22 +69,70 @@ fn test_hormonal_decay() {
     state.decay(0.2);
     
     assert_eq!(state.get_level(&HormoneType::Cortisol), 0.7); // 0.9 - 0.2
-    assert_eq!(state.get_level(&HormoneType::Dopamine), 0.3); // 0.5 - 0.2
+    assert_eq!(state.get_level(&HormoneType::Dopamine), 0.5); // Already at baseline
 }
 
 #[test]
 fn test_hormonal_decay_floor() {
     let mut state = HormonalState::new();
     
+    state.apply_burst(&HormonalBurst {
+        id: Uuid::new_v4(),
+        hormone: HormoneType::Cortisol,
+        intensity: 0.4,
+        triggered_at: Utc::now(),
+        duration_ms: 5000,
+    });
+    
     // Apply large decay
-    state.decay(0.7); // Would go below 0.0
-    
-    // All hormones should be floored at 0.0
-    assert_eq!(state.get_level(&HormoneType::Cortisol), 0.0);
-    assert_eq!(state.get_level(&HormoneType::Dopamine), 0.0);
-    assert_eq!(state.get_level(&HormoneType::Serotonin), 0.0);
-    assert_eq!(state.get_level(&HormoneType::Oxytocin), 0.0);
-    assert_eq!(state.get_level(&HormoneType::Adrenaline), 0.0);
+    state.decay(0.7); // Would go below baseline
+    
+    // All hormones should settle at the 0.5 baseline
+    assert_eq!(state.get_level(&HormoneType::Cortisol), 0.5);
+    assert_eq!(state.get_level(&HormoneType::Dopamine), 0.5);
+    assert_eq!(state.get_level(&HormoneType::Serotonin), 0.5);
+    assert_eq!(state.get_level(&HormoneType::Oxytocin), 0.5);
+    assert_eq!(state.get_level(&HormoneType::Adrenaline), 0.5);
+}
+
+#[test]
+fn test_profile_sets_baseline_and_bounds() {
+    let profile = HormoneProfile::new()
+        .with_bounds(HormoneType::Cortisol, HormoneBounds::new(0.1, 0.0, 0.6));
+    let mut state = HormonalState::with_profile(profile);
+    
+    assert_eq!(state.get_level(&HormoneType::Cortisol), 0.1);
+    assert_eq!(state.get_level(&HormoneType::Dopamine), 0.5);
+    
+    state.apply_burst(&HormonalBurst {
+        id: Uuid::new_v4(),
+        hormone: HormoneType::Cortisol,
+        intensity: 0.8,
+        triggered_at: Utc::now(),
+        duration_ms: 5000,
+    });
+    assert_eq!(state.get_level(&HormoneType::Cortisol), 0.6); // Profile max
+    
+    state.decay(0.3);
+    assert!((state.get_level(&HormoneType::Cortisol) - 0.3).abs() < 0.0001);
+    state.decay(0.3);
+    assert_eq!(state.get_level(&HormoneType::Cortisol), 0.1); // Settles at 0.1, not 0.5
+}
+
+#[test]
+fn test_inverted_bounds_are_normalized() {
+    let bounds = HormoneBounds::new(0.9, 0.8, 0.2);
+    assert_eq!((bounds.min, bounds.max), (0.2, 0.8));
+    assert_eq!(bounds.baseline, 0.8);
+    assert_eq!(bounds.clamp(0.1), 0.2);
+    
+    let bounds: HormoneBounds = serde_json::from_str(r#"{"baseline": 0.5, "min": 1.0, "max": 0.0}"#).unwrap();
+    assert_eq!((bounds.min, bounds.max), (0.0, 1.0));
+    
+    // Fields set directly out of order still clamp without panicking
+    let bounds = HormoneBounds { baseline: 0.5, min: 0.7, max: 0.3 };
+    assert_eq!(bounds.clamp(0.9), 0.7);
+    assert_eq!(bounds.clamp(0.1), 0.3);
 }
 
 #[test]
@@ -118,4 +166,105 @@ fn test_multiple_bursts() {
     state.apply_burst(&burst2);
     
     assert!((state.get_level(&HormoneType::Dopamine) - 0.8).abs() < 0.0001); // 0.5 + 0.2 + 0.1
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_burst_expires_after_duration() {
+    let mut state = HormonalState::new();
+    let triggered_at = Utc::now();
+    
+    let burst = HormonalBurst {
+        id: Uuid::new_v4(),
+        hormone: HormoneType::Dopamine,
+        intensity: 0.3,
+        triggered_at,
+        duration_ms: 5000,
+    };
+    
+    state.apply_burst(&burst);
+    
+    // Still within the burst duration
+    state.tick(triggered_at + Duration::seconds(2));
+    assert!((state.get_level(&HormoneType::Dopamine) - 0.8).abs() < 0.0001);
+    assert_eq!(state.active_burst_count(), 1);
+    
+    // Burst has expired, level returns to baseline
+    state.tick(triggered_at + Duration::seconds(6));
+    assert!((state.get_level(&HormoneType::Dopamine) - 0.5).abs() < 0.0001);
+    assert_eq!(state.active_burst_count(), 0);
+}
+
+#[test]
+fn test_clamped_burst_only_removes_applied_amount() {
+    let mut state = HormonalState::new();
+    let triggered_at = Utc::now();
+    
+    let burst = HormonalBurst {
+        id: Uuid::new_v4(),
+        hormone: HormoneType::Adrenaline,
+        intensity: 0.7, // Clamped to +0.5
+        triggered_at,
+        duration_ms: 1000,
+    };
+    
+    state.apply_burst(&burst);
+    state.tick(triggered_at + Duration::seconds(2));
+    
+    assert!((state.get_level(&HormoneType::Adrenaline) - 0.5).abs() < 0.0001);
+}
+
+#[test]
+fn test_history_tracks_cortisol_trajectory() {
+    let started = Utc::now();
+    let mut state = HormonalState::new().with_history(64);
+    
+    state.apply_burst(&HormonalBurst {
+        id: Uuid::new_v4(),
+        hormone: HormoneType::Cortisol,
+        intensity: 0.4,
+        triggered_at: started,
+        duration_ms: 60_000,
+    });
+    for _ in 0..3 {
+        state.decay(0.1);
+    }
+    
+    let levels: Vec<f64> = state.history(HormoneType::Cortisol, started)
+        .into_iter()
+        .map(|(_, level)| (level * 100.0).round() / 100.0)
+        .collect();
+    assert_eq!(levels, vec![0.9, 0.8, 0.7, 0.6]);
+    
+    let timestamps: Vec<_> = state.history(HormoneType::Cortisol, started)
+        .into_iter()
+        .map(|(timestamp, _)| timestamp)
+        .collect();
+    assert!(timestamps.windows(2).all(|pair| pair[0] <= pair[1]));
+    
+    // Other hormones sat at baseline and left nothing behind
+    assert!(state.history(HormoneType::Dopamine, started).is_empty());
+    assert!(state.history(HormoneType::Cortisol, Utc::now() + Duration::seconds(1)).is_empty());
+}
+
+#[test]
+fn test_history_keeps_only_the_most_recent_samples() {
+    let mut state = HormonalState::new().with_history(2);
+    let since = Utc::now() - Duration::seconds(1);
+    
+    state.apply_burst(&HormonalBurst {
+        id: Uuid::new_v4(),
+        hormone: HormoneType::Dopamine,
+        intensity: 0.3,
+        triggered_at: Utc::now(),
+        duration_ms: 60_000,
+    });
+    for _ in 0..3 {
+        state.decay(0.1);
+    }
+    
+    let levels: Vec<f64> = state.history(HormoneType::Dopamine, since)
+        .into_iter()
+        .map(|(_, level)| (level * 100.0).round() / 100.0)
+        .collect();
+    assert_eq!(levels, vec![0.6, 0.5]);
+}
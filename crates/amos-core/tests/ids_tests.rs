@@ -0,0 +1,28 @@
+use amos_core::ids::{next_id, with_id_generator, IdGenerator, SeededIdGenerator};
+use std::sync::Arc;
+
+#[test]
+fn test_seeded_generator_is_reproducible() {
+    let a = SeededIdGenerator::new(7);
+    let b = SeededIdGenerator::new(7);
+    let other = SeededIdGenerator::new(8);
+    
+    let first: Vec<_> = (0..5).map(|_| a.next_id()).collect();
+    let second: Vec<_> = (0..5).map(|_| b.next_id()).collect();
+    
+    assert_eq!(first, second);
+    assert_ne!(first[0], other.next_id());
+}
+
+#[tokio::test]
+async fn test_scoped_generator_applies_across_awaits() {
+    let expected = SeededIdGenerator::new(1).next_id();
+    
+    let scoped = with_id_generator(Arc::new(SeededIdGenerator::new(1)), async {
+        tokio::task::yield_now().await;
+        next_id()
+    }).await;
+    
+    assert_eq!(scoped, expected);
+    assert_ne!(next_id(), expected);
+}
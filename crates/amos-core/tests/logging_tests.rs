@@ -104,4 +104,17 @@ fn test_empty_context_display() {
     
     // Should not include empty context
     assert!(!display.contains("{}"));
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn test_logger_attaches_correlation_id() {
+    let logger = Logger::new("agent.test");
+    let correlation_id = Uuid::new_v4();
+    
+    let entry = with_correlation_id(correlation_id, async { logger.info("Working") }).await;
+    assert_eq!(entry.context["correlation_id"], json!(correlation_id));
+    
+    // Outside the scope no correlation id is attached
+    let entry = logger.info("Idle");
+    assert!(entry.context.get("correlation_id").is_none());
+}
@@ -1,4 +1,8 @@
 use amos_core::neural::*;
+use amos_core::hormonal::{HormonalBurst, HormonalState, HormoneBounds, HormoneProfile, HormoneType};
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::sync::RwLock;
 use uuid::Uuid;
 use chrono::Utc;
 
@@ -118,6 +122,190 @@ fn test_hebbian_learning() {
     assert!(pathway.strength > 0.0);
 }
 
+#[tokio::test]
+async fn test_hebbian_pathways_start_at_network_default_strength() {
+    let network = ForgeNeuralNetwork::new().with_default_pathway_strength(0.3);
+    
+    let node1 = network.add_node(NodeType::Memory).await;
+    let node2 = network.add_node(NodeType::Thinking).await;
+    network.fire_node(node1).await;
+    network.fire_node(node2).await;
+    network.hebbian_learning(node1, node2).await;
+    
+    let pathway_id = network.find_pathway(node1, node2).await.unwrap();
+    let strength = network.get_pathway(pathway_id).await.unwrap().strength;
+    assert!((strength - 0.3).abs() < 1e-9, "strength {}", strength);
+    
+    let connected = network.connect(node2, node1).await;
+    assert_eq!(network.get_pathway(connected).await.unwrap().strength, 0.3);
+}
+
+#[tokio::test]
+async fn test_stimulated_node_fires_once_input_crosses_threshold() {
+    let network = ForgeNeuralNetwork::new();
+    let node = network.add_node(NodeType::Thinking).await;
+    network.set_activation_threshold(node, 0.5).await.unwrap();
+    let mut events = network.subscribe_to_events();
+    
+    assert!(!network.stimulate(node, 0.3).await);
+    assert!((network.get_node(node).await.unwrap().accumulated_input - 0.3).abs() < 1e-9);
+    
+    assert!(network.stimulate(node, 0.3).await);
+    assert_eq!(network.get_node(node).await.unwrap().accumulated_input, 0.0);
+    assert!(matches!(events.try_recv(), Ok(NeuralEvent::NodeFired { node_id, .. }) if node_id == node));
+    assert!(events.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_fired_node_passes_weighted_input_downstream() {
+    let network = ForgeNeuralNetwork::new();
+    let source = network.add_node(NodeType::Thinking).await;
+    let relay = network.add_node(NodeType::Memory).await;
+    let sink = network.add_node(NodeType::Agent).await;
+    let quiet = network.add_node(NodeType::Memory).await;
+    network.create_pathway(source, relay, 0.9).await;
+    network.create_pathway(relay, sink, 0.9).await;
+    network.create_pathway(source, quiet, 0.4).await;
+    for node in [relay, sink, quiet] {
+        network.set_activation_threshold(node, 0.5).await.unwrap();
+    }
+    
+    assert!(network.stimulate(source, 1.0).await);
+    
+    // 0.9 reaches the relay, which passes on 0.81; 0.4 falls short of carrying on
+    assert_eq!(network.fired_nodes().await, HashSet::from([source, relay, sink]));
+    assert_eq!(network.get_node(quiet).await.unwrap().accumulated_input, 0.0);
+}
+
+#[tokio::test]
+async fn test_stimulation_cascade_fires_each_node_of_a_cycle_once() {
+    let network = ForgeNeuralNetwork::new();
+    let a = network.add_node(NodeType::Thinking).await;
+    let b = network.add_node(NodeType::Memory).await;
+    network.create_pathway(a, b, 1.0).await;
+    network.create_pathway(b, a, 1.0).await;
+    let mut events = network.subscribe_to_events();
+    
+    assert!(network.stimulate(a, 1.0).await);
+    
+    let mut fired = Vec::new();
+    while let Ok(event) = events.try_recv() {
+        if let NeuralEvent::NodeFired { node_id, .. } = event {
+            fired.push(node_id);
+        }
+    }
+    assert_eq!(fired, vec![a, b]);
+}
+
+#[tokio::test]
+async fn test_activation_threshold_rejects_nan_and_negative_values() {
+    let network = ForgeNeuralNetwork::new();
+    let node = network.add_node(NodeType::Thinking).await;
+    
+    assert!(network.set_activation_threshold(node, f64::NAN).await.is_err());
+    assert!(network.set_activation_threshold(node, -0.1).await.is_err());
+    assert_eq!(network.get_node(node).await.unwrap().activation_threshold, DEFAULT_ACTIVATION_THRESHOLD);
+    
+    network.set_activation_threshold(node, 0.0).await.unwrap();
+    assert!(network.stimulate(node, 0.0).await);
+}
+
+#[tokio::test]
+async fn test_stimulating_region_fires_exactly_its_nodes() {
+    let network = ForgeNeuralNetwork::new();
+    let cortex = [
+        network.add_node_in_region("cortex_a", NodeType::Thinking).await,
+        network.add_node_in_region("cortex_a", NodeType::Memory).await,
+    ];
+    let moved = network.add_node(NodeType::Agent).await;
+    network.set_node_region(moved, Some("cortex_a")).await.unwrap();
+    let other = network.add_node_in_region("cortex_b", NodeType::Thinking).await;
+    let outside = network.add_node(NodeType::Memory).await;
+    // Too weak to carry a 0.5 stimulation far enough to fire its target
+    network.create_pathway(cortex[0], outside, 0.4).await;
+    
+    assert_eq!(network.stimulate_region("cortex_a", 0.5).await, 3);
+    
+    let fired = network.fired_nodes().await;
+    assert_eq!(fired, HashSet::from([cortex[0], cortex[1], moved]));
+    assert!(!fired.contains(&other));
+    assert_eq!(network.stimulate_region("no_such_region", 1.0).await, 0);
+}
+
+#[tokio::test]
+async fn test_region_stimulation_builds_up_input_beyond_the_region() {
+    let network = ForgeNeuralNetwork::new();
+    let source = network.add_node_in_region("cortex_a", NodeType::Thinking).await;
+    let target = network.add_node(NodeType::Memory).await;
+    network.create_pathway(source, target, 1.0).await;
+    
+    network.stimulate_region("cortex_a", 0.6).await;
+    assert!(!network.fired_nodes().await.contains(&target));
+    
+    network.stimulate_region("cortex_a", 0.6).await;
+    assert!(network.fired_nodes().await.contains(&target));
+}
+
+/// Strength gained by a 0.1 pathway after one co-firing under a single hormone burst
+async fn strength_after_cofiring(hormone: HormoneType) -> f64 {
+    let mut hormones = HormonalState::new();
+    hormones.apply_burst(&HormonalBurst {
+        id: Uuid::new_v4(),
+        hormone,
+        intensity: 0.5,
+        triggered_at: Utc::now(),
+        duration_ms: 60_000,
+    });
+    let network = ForgeNeuralNetwork::new()
+        .with_hormonal_modulation(Arc::new(RwLock::new(hormones)));
+    
+    let node1 = network.add_node(NodeType::Memory).await;
+    let node2 = network.add_node(NodeType::Thinking).await;
+    let pathway_id = network.create_pathway(node1, node2, 0.1).await;
+    
+    network.fire_node(node1).await;
+    network.fire_node(node2).await;
+    network.hebbian_learning(node1, node2).await;
+    
+    network.get_pathway(pathway_id).await.unwrap().strength - 0.1
+}
+
+#[tokio::test]
+async fn test_dopamine_speeds_and_cortisol_damps_hebbian_learning() {
+    let rewarded = strength_after_cofiring(HormoneType::Dopamine).await;
+    let stressed = strength_after_cofiring(HormoneType::Cortisol).await;
+    
+    assert!(rewarded > 0.1, "dopamine gain {}", rewarded);
+    assert!(stressed < 0.1, "cortisol gain {}", stressed);
+    assert!(rewarded > stressed);
+}
+
+#[tokio::test]
+async fn test_suppressed_plasticity_forms_no_pathway() {
+    let profile = HormoneProfile::new()
+        .with_bounds(HormoneType::Cortisol, HormoneBounds::new(0.0, 0.0, 1.0));
+    let mut hormones = HormonalState::with_profile(profile);
+    hormones.apply_burst(&HormonalBurst {
+        id: Uuid::new_v4(),
+        hormone: HormoneType::Cortisol,
+        intensity: 1.0,
+        triggered_at: Utc::now(),
+        duration_ms: 60_000,
+    });
+    assert_eq!(hormones.plasticity_factor(), 0.0);
+    let network = ForgeNeuralNetwork::new()
+        .with_hormonal_modulation(Arc::new(RwLock::new(hormones)));
+    
+    let node1 = network.add_node(NodeType::Memory).await;
+    let node2 = network.add_node(NodeType::Thinking).await;
+    network.fire_node(node1).await;
+    network.fire_node(node2).await;
+    network.hebbian_learning(node1, node2).await;
+    
+    assert!(network.find_pathway(node1, node2).await.is_none());
+    assert_eq!(network.pathway_count().await, 0);
+}
+
 #[test]
 fn test_synaptic_pruning() {
     let network = ForgeNeuralNetwork::new();
@@ -154,4 +342,310 @@ fn test_neural_event_emission() {
         }
         _ => panic!("Expected PathwayCreated event"),
     }
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn test_process_node_with_registered_processor() {
+    let network = ForgeNeuralNetwork::new();
+    
+    network.register_processor("increment", |state, input| {
+        let current = state.get("count").and_then(|v| v.as_i64()).unwrap_or(0);
+        let step = input.as_i64().unwrap_or(1);
+        serde_json::json!({ "count": current + step })
+    }).await;
+    
+    let node_id = network.add_node(NodeType::Thinking).await;
+    network.set_node_processor(node_id, "increment").await.unwrap();
+    
+    let result = network.process_node(node_id, serde_json::json!(2)).await.unwrap();
+    assert_eq!(result["count"], 2);
+    
+    let result = network.process_node(node_id, serde_json::json!(3)).await.unwrap();
+    assert_eq!(result["count"], 5);
+    
+    // State is persisted on the node
+    let node = network.get_node(node_id).await.unwrap();
+    assert_eq!(node.state["count"], 5);
+}
+
+#[tokio::test]
+async fn test_process_node_without_processor_fails() {
+    let network = ForgeNeuralNetwork::new();
+    let node_id = network.add_node(NodeType::Memory).await;
+    
+    assert!(network.process_node(node_id, serde_json::json!(1)).await.is_err());
+    assert!(network.set_node_processor(node_id, "missing").await.is_err());
+}
+
+#[tokio::test]
+async fn test_strength_histogram_buckets() {
+    let network = ForgeNeuralNetwork::new();
+    let node1 = network.add_node(NodeType::Thinking).await;
+    let node2 = network.add_node(NodeType::Memory).await;
+    
+    for strength in [0.1, 0.5, 0.9] {
+        network.create_pathway(node1, node2, strength).await;
+    }
+    
+    let histogram = network.strength_histogram(5).await;
+    assert_eq!(histogram.len(), 5);
+    
+    let counts: Vec<usize> = histogram.iter().map(|(_, _, count)| *count).collect();
+    assert_eq!(counts, vec![1, 0, 1, 0, 1]);
+    
+    let (low, high, _) = histogram[2];
+    assert!((low - 0.4).abs() < 1e-9);
+    assert!((high - 0.6).abs() < 1e-9);
+    
+    assert!(network.strength_histogram(0).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_negative_delta_weakens_and_clamps() {
+    let network = ForgeNeuralNetwork::new();
+    let node1 = network.add_node(NodeType::Thinking).await;
+    let node2 = network.add_node(NodeType::Memory).await;
+    let pathway_id = network.create_pathway(node1, node2, 0.0).await;
+    let mut events = network.subscribe_to_events();
+    
+    let strength = network.strengthen_pathway(pathway_id, 0.3).await.unwrap();
+    assert!((strength - 0.3).abs() < 1e-9);
+    
+    let strength = network.strengthen_pathway(pathway_id, -0.5).await.unwrap();
+    assert_eq!(strength, 0.0);
+    assert_eq!(network.get_pathway(pathway_id).await.unwrap().strength, 0.0);
+    
+    assert!(matches!(events.recv().await.unwrap(), NeuralEvent::PathwayStrengthened { .. }));
+    assert!(matches!(events.recv().await.unwrap(), NeuralEvent::PathwayWeakened { .. }));
+    
+    assert!(network.strengthen_pathway(Uuid::new_v4(), 0.1).await.is_none());
+}
+
+async fn sizeable_network(node_count: usize) -> ForgeNeuralNetwork {
+    let network = ForgeNeuralNetwork::new();
+    let mut nodes = Vec::with_capacity(node_count);
+    for _ in 0..node_count {
+        nodes.push(network.add_node(NodeType::Thinking).await);
+    }
+    for window in nodes.windows(2) {
+        network.create_pathway(window[0], window[1], 0.5).await;
+    }
+    network
+}
+
+#[tokio::test]
+async fn test_propagation_follows_strong_chain() {
+    let network = ForgeNeuralNetwork::new();
+    let a = network.add_node(NodeType::Memory).await;
+    let b = network.add_node(NodeType::Thinking).await;
+    let c = network.add_node(NodeType::Agent).await;
+    let ab = network.create_pathway(a, b, 0.9).await;
+    network.create_pathway(b, c, 0.9).await;
+    
+    let cascade = network.propagate(a, 0.9, 5).await;
+    assert_eq!(cascade, vec![b, c]);
+    
+    // Co-firing reinforces the pathways that carried the signal
+    assert!(network.get_pathway(ab).await.unwrap().strength > 0.9);
+}
+
+#[tokio::test]
+async fn test_propagation_stops_at_weak_pathways() {
+    let network = ForgeNeuralNetwork::new();
+    let a = network.add_node(NodeType::Memory).await;
+    let b = network.add_node(NodeType::Thinking).await;
+    let c = network.add_node(NodeType::Agent).await;
+    network.create_pathway(a, b, 0.6).await;
+    network.create_pathway(b, c, 0.55).await;
+    
+    // The second hop decays below the threshold
+    assert_eq!(network.propagate(a, 0.5, 5).await, vec![b]);
+    
+    let weak = ForgeNeuralNetwork::new();
+    let a = weak.add_node(NodeType::Memory).await;
+    let b = weak.add_node(NodeType::Thinking).await;
+    weak.create_pathway(a, b, 0.2).await;
+    
+    assert!(weak.propagate(a, 0.9, 5).await.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_sharded_network_matches_single_lock() {
+    let single = ForgeNeuralNetwork::new();
+    let sharded = ForgeNeuralNetwork::sharded(16);
+    assert_eq!(single.shard_count(), 1);
+    assert_eq!(sharded.shard_count(), 16);
+    
+    for network in [single, sharded] {
+        let source = network.add_node(NodeType::Memory).await;
+        let target = network.add_node(NodeType::Thinking).await;
+        
+        let writers: Vec<_> = (0..16).map(|i| {
+            let network = network.clone();
+            tokio::spawn(async move {
+                for j in 0..50 {
+                    let strength = if (i + j) % 2 == 0 { 0.8 } else { 0.05 };
+                    let id = network.create_pathway(source, target, strength).await;
+                    network.strengthen_pathway(id, 0.1).await.unwrap();
+                }
+            })
+        }).collect();
+        for writer in writers {
+            writer.await.unwrap();
+        }
+        
+        assert_eq!(network.pathway_count().await, 800);
+        assert_eq!(network.find_pathways_between(source, target).await.len(), 800);
+        
+        network.run_synaptic_pruning(0.5).await;
+        assert_eq!(network.pathway_count().await, 400);
+        
+        // Snapshots gather pathways from every shard and put them back in place
+        let snapshot = network.snapshot().await;
+        network.restore(snapshot.clone()).await.unwrap();
+        assert_eq!(network.pathway_count().await, 400);
+        assert!(network.get_pathway(snapshot.pathways[0].id).await.is_some());
+    }
+}
+
+#[tokio::test]
+async fn test_namespaces_isolate_swarms_on_shared_network() {
+    let network = ForgeNeuralNetwork::new();
+    
+    for namespace in ["a", "b"] {
+        let source = network.add_node_in(namespace, NodeType::Agent).await;
+        let target = network.add_node_in(namespace, NodeType::Memory).await;
+        network.create_pathway_in(namespace, source, target, 0.1).await;
+        network.create_pathway_in(namespace, source, target, 0.9).await;
+    }
+    network.add_node(NodeType::Gateway).await;
+    
+    assert_eq!(network.node_count_in("a").await, 2);
+    assert_eq!(network.node_count_in("b").await, 2);
+    assert_eq!(network.pathway_count_in("a").await, 2);
+    assert_eq!(network.pathway_count_in("b").await, 2);
+    assert_eq!(network.node_count().await, 5);
+    
+    network.run_synaptic_pruning_in("a", 0.5).await;
+    
+    assert_eq!(network.pathway_count_in("a").await, 1);
+    assert_eq!(network.pathway_count_in("b").await, 2);
+    
+    // Namespaces survive a snapshot round trip
+    network.restore(network.snapshot().await).await.unwrap();
+    assert_eq!(network.pathway_count_in("b").await, 2);
+}
+
+#[tokio::test]
+async fn test_weighted_pruning_spares_heavily_used_pathways() {
+    let network = ForgeNeuralNetwork::new();
+    let source = network.add_node(NodeType::Agent).await;
+    let target = network.add_node(NodeType::Memory).await;
+    
+    let busy = network.create_pathway(source, target, 0.1).await;
+    let idle = network.create_pathway(source, target, 0.1).await;
+    for _ in 0..50 {
+        network.strengthen_pathway(busy, 0.0).await.unwrap();
+    }
+    network.strengthen_pathway(idle, 0.0).await.unwrap();
+    
+    network.prune_weighted(0.5, 10).await;
+    
+    assert!(network.get_pathway(busy).await.is_some());
+    assert!(network.get_pathway(idle).await.is_none());
+}
+
+#[tokio::test]
+async fn test_snapshot_diff_reports_changes() {
+    let network = ForgeNeuralNetwork::new();
+    let source = network.add_node(NodeType::Thinking).await;
+    let target = network.add_node(NodeType::Memory).await;
+    let strengthened = network.create_pathway(source, target, 0.2).await;
+    let untouched = network.create_pathway(target, source, 0.4).await;
+    
+    let before = network.snapshot().await;
+    assert!(NetworkSnapshot::diff(&before, &before).is_empty());
+    
+    network.strengthen_pathway(strengthened, 0.25).await.unwrap();
+    let added = network.add_node(NodeType::Agent).await;
+    let after = network.snapshot().await;
+    
+    let diff = NetworkSnapshot::diff(&before, &after);
+    assert_eq!(diff.added_nodes, vec![added]);
+    assert!(diff.removed_nodes.is_empty());
+    assert!(diff.added_pathways.is_empty());
+    assert_eq!(diff.strength_changes.len(), 1);
+    
+    let change = &diff.strength_changes[0];
+    assert_eq!(change.pathway_id, strengthened);
+    assert!((change.old_strength - 0.2).abs() < 1e-9);
+    assert!((change.delta - 0.25).abs() < 1e-9);
+    assert!(diff.strength_changes.iter().all(|c| c.pathway_id != untouched));
+    
+    // Diffing the other way round reports the node as removed
+    assert_eq!(NetworkSnapshot::diff(&after, &before).removed_nodes, vec![added]);
+}
+
+#[tokio::test]
+async fn test_json_state_round_trip() {
+    let network = sizeable_network(20).await;
+    let exported = network.export_state().await.unwrap();
+    
+    let restored = ForgeNeuralNetwork::new();
+    restored.import_state(&exported).await.unwrap();
+    
+    assert_eq!(restored.node_count().await, 20);
+    assert_eq!(restored.pathway_count().await, 19);
+}
+
+#[cfg(feature = "msgpack")]
+#[tokio::test]
+async fn test_binary_state_round_trip_is_smaller_than_json() {
+    let network = sizeable_network(500).await;
+    
+    let binary = network.export_state_binary().await.unwrap();
+    let json = network.export_state().await.unwrap();
+    assert!(binary.len() < json.len() * 3 / 4, "binary {} vs json {}", binary.len(), json.len());
+    
+    let restored = ForgeNeuralNetwork::new();
+    restored.import_state_binary(&binary).await.unwrap();
+    
+    assert_eq!(restored.node_count().await, network.node_count().await);
+    assert_eq!(restored.pathway_count().await, network.pathway_count().await);
+}
+
+#[tokio::test]
+async fn test_pathways_are_found_by_tag() {
+    let network = ForgeNeuralNetwork::sharded(4);
+    let memory = network.add_node(NodeType::Memory).await;
+    let thinking = network.add_node(NodeType::Thinking).await;
+    let agent = network.add_node(NodeType::Agent).await;
+    
+    let recall = network
+        .create_labeled_pathway(memory, thinking, 0.8, "recall", vec!["reinforced".to_string()])
+        .await;
+    let handoff = network
+        .create_labeled_pathway(agent, thinking, 0.3, "handoff", vec!["exploratory".to_string()])
+        .await;
+    let probe = network
+        .create_labeled_pathway(agent, memory, 0.2, "probe", vec!["exploratory".to_string(), "agent".to_string()])
+        .await;
+    network.create_pathway(memory, agent, 0.5).await;
+    
+    let reinforced: Vec<Uuid> = network.pathways_with_tag("reinforced").await.iter().map(|p| p.id).collect();
+    assert_eq!(reinforced, vec![recall]);
+    
+    let mut exploratory: Vec<Uuid> = network.pathways_with_tag("exploratory").await.iter().map(|p| p.id).collect();
+    exploratory.sort();
+    let mut expected = vec![handoff, probe];
+    expected.sort();
+    assert_eq!(exploratory, expected);
+    assert!(network.pathways_with_tag("dormant").await.is_empty());
+    
+    assert_eq!(network.get_pathway(recall).await.unwrap().label.as_deref(), Some("recall"));
+    
+    let dot = network.render_dot().await;
+    assert!(dot.starts_with("digraph neural {"));
+    assert!(dot.contains(&format!("\"{}\" -> \"{}\" [label=\"recall\\n0.80\"];", memory, thinking)));
+    assert!(dot.contains(&format!("\"{}\" -> \"{}\" [label=\"0.50\"];", memory, agent)));
+}
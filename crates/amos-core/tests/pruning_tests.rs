@@ -0,0 +1,75 @@
+use amos_core::neural::*;
+use amos_core::pruning::{PruningConfig, PruningScheduler};
+use std::sync::Arc;
+use std::time::Duration;
+
+async fn network_with_weak_pathways() -> Arc<ForgeNeuralNetwork> {
+    let network = Arc::new(ForgeNeuralNetwork::new());
+    let hub = network.add_node(NodeType::Thinking).await;
+    for strength in [0.02, 0.05, 0.08, 0.9] {
+        let node = network.add_node(NodeType::Memory).await;
+        network.create_pathway(hub, node, strength).await;
+    }
+    network
+}
+
+fn eager_config() -> PruningConfig {
+    PruningConfig {
+        interval: Duration::from_millis(20),
+        strength_threshold: 0.1,
+        max_idle: Duration::ZERO,
+        max_load: 0.8,
+    }
+}
+
+#[tokio::test]
+async fn test_scheduler_prunes_weak_pathways_in_background() {
+    let network = network_with_weak_pathways().await;
+    let mut events = network.subscribe_to_events();
+    let mut scheduler = PruningScheduler::new(network.clone(), eager_config()).unwrap();
+    
+    scheduler.start();
+    assert!(scheduler.is_running());
+    
+    let removed = tokio::time::timeout(Duration::from_secs(2), async {
+        loop {
+            if let Ok(NeuralEvent::PruningCycleCompleted { pathways_removed, .. }) = events.recv().await {
+                return pathways_removed;
+            }
+        }
+    }).await.unwrap();
+    scheduler.stop().await;
+    
+    assert_eq!(removed, 3);
+    assert_eq!(network.pathway_count().await, 1);
+    assert!(!scheduler.is_running());
+}
+
+#[tokio::test]
+async fn test_scheduler_pauses_under_high_load() {
+    let network = network_with_weak_pathways().await;
+    let scheduler = PruningScheduler::new(network.clone(), eager_config())
+        .unwrap()
+        .with_load_probe(Arc::new(|| 0.95));
+    
+    assert_eq!(scheduler.run_cycle().await, None);
+    assert_eq!(network.pathway_count().await, 4);
+}
+
+#[tokio::test]
+async fn test_recently_used_pathways_survive_pruning() {
+    let network = network_with_weak_pathways().await;
+    let scheduler = PruningScheduler::new(network.clone(), PruningConfig {
+        max_idle: Duration::from_secs(60),
+        ..eager_config()
+    }).unwrap();
+    
+    assert_eq!(scheduler.run_cycle().await, Some(0));
+    assert_eq!(network.pathway_count().await, 4);
+}
+
+#[test]
+fn test_scheduler_rejects_zero_interval() {
+    let config = PruningConfig { interval: Duration::ZERO, ..eager_config() };
+    assert!(PruningScheduler::new(Arc::new(ForgeNeuralNetwork::new()), config).is_err());
+}
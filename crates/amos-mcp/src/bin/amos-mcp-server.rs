@@ -0,0 +1,56 @@
+use amos_agents::{agent_types, CognitiveAgent};
+use amos_core::{neural::ForgeNeuralNetwork, EventBus};
+use amos_mcp::McpServer;
+use clap::Parser;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::info;
+use tracing_subscriber::EnvFilter;
+
+/// Run the AMOS MCP server over stdio
+#[derive(Debug, Parser)]
+#[command(name = "amos-mcp-server", version)]
+struct Args {
+    /// Number of agents to spawn at startup (defaults to one of each type)
+    #[arg(long)]
+    agents: Option<usize>,
+
+    /// Log level filter, e.g. info or amos_mcp=debug
+    #[arg(long, default_value = "info")]
+    log_level: String,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    // Stdout carries the protocol, so logs go to stderr
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::try_new(&args.log_level)?)
+        .with_writer(std::io::stderr)
+        .init();
+
+    let neural_network = Arc::new(ForgeNeuralNetwork::new());
+    let event_bus = Arc::new(EventBus::new());
+    let agents: Arc<RwLock<HashMap<_, Arc<dyn CognitiveAgent>>>> = Arc::new(RwLock::new(HashMap::new()));
+
+    // Cycle through the known agent types until the requested count is reached
+    let types = agent_types();
+    let agent_count = args.agents.unwrap_or(types.len());
+    for info in types.iter().cycle().take(agent_count) {
+        let mut agent = info.create();
+        agent.initialize(neural_network.clone(), event_bus.clone()).await?;
+        agent.activate().await?;
+
+        let agent: Arc<dyn CognitiveAgent> = Arc::from(agent);
+        agents.write().await.insert(agent.id(), agent);
+    }
+    info!("Spawned {} agents", agent_count);
+
+    let server = McpServer::new(neural_network, agents);
+    info!("AMOS MCP server reading requests from stdin");
+    server.serve_stdio().await?;
+
+    Ok(())
+}
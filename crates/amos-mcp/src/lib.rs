@@ -6,7 +6,10 @@ pub mod mcp_context;
 
 // Re-export specific items to avoid conflicts
 pub use mcp_server::{McpServer, ServerInfo as McpServerInfo};
-pub use mcp_client::{McpClient, McpClientBuilder, InitializeResult, ServerInfo as McpClientServerInfo};
+pub use mcp_client::{
+    McpClient, McpClientBuilder, McpTransport, TransportLink, DEFAULT_REQUEST_TIMEOUT, DISCONNECTED_ERROR,
+    InitializeResult, ServerInfo as McpClientServerInfo,
+};
 pub use mcp_protocol::*;
 pub use mcp_tools::*;
 pub use mcp_context::*;
\ No newline at end of file
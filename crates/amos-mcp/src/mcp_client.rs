@@ -1,95 +1,151 @@
 use crate::mcp_protocol::*;
 use anyhow::{Result, anyhow};
+use async_trait::async_trait;
 use serde::{Serialize, Deserialize};
 use serde_json::{json, Value};
 use std::sync::Arc;
-use tokio::sync::{RwLock, mpsc};
+use tokio::sync::{RwLock, mpsc, oneshot, watch};
 use std::collections::HashMap;
-use tracing::warn;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// How long a request waits for its response unless configured otherwise
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Wait before the first reconnection attempt; doubles after each failed attempt
+pub const DEFAULT_RECONNECT_DELAY: Duration = Duration::from_millis(100);
+
+/// Longest wait between reconnection attempts
+pub const DEFAULT_MAX_RECONNECT_DELAY: Duration = Duration::from_secs(10);
+
+/// Wait before retrying a failed tool call; doubles after each retry
+pub const DEFAULT_RETRY_DELAY: Duration = Duration::from_millis(50);
+
+/// Longest wait between tool call retries
+pub const DEFAULT_MAX_RETRY_DELAY: Duration = Duration::from_secs(2);
+
+/// Error requests fail with while a dropped connection is being re-established
+pub const DISCONNECTED_ERROR: &str = "disconnected, retrying";
+
+/// Both directions of an open connection to an MCP server
+pub struct TransportLink {
+    pub requests: mpsc::Sender<McpRequest>,
+    /// Closing this channel is how the transport reports the connection dropped
+    pub responses: mpsc::Receiver<McpResponse>,
+}
+
+/// Opens connections to an MCP server; called again each time a connection drops
+#[async_trait]
+pub trait McpTransport: Send + Sync {
+    async fn connect(&self) -> Result<TransportLink>;
+}
+
+type PendingRequests = Arc<RwLock<HashMap<String, oneshot::Sender<Result<McpResponse>>>>>;
 
 /// MCP Client for connecting to MCP servers
 pub struct McpClient {
     client_info: ClientInfo,
-    pending_requests: Arc<RwLock<HashMap<String, mpsc::Sender<McpResponse>>>>,
-    request_tx: mpsc::Sender<McpRequest>,
-    response_rx: Arc<RwLock<mpsc::Receiver<McpResponse>>>,
+    pending_requests: PendingRequests,
+    /// Where requests go, or `None` while disconnected
+    request_tx: Arc<watch::Sender<Option<mpsc::Sender<McpRequest>>>>,
+    timeout: Duration,
+    /// Times a tool call is retried after a server error that may clear up
+    retries: u32,
+    retry_delay: Duration,
+    max_retry_delay: Duration,
+    /// Routes responses and reconnects, for clients connected through a transport
+    supervisor: Option<tokio::task::JoinHandle<()>>,
 }
 
 impl McpClient {
+    /// A client whose requests are read from the returned channel and whose
+    /// responses are fed back through `handle_response`
     pub fn new(name: String, version: String) -> (Self, mpsc::Receiver<McpRequest>) {
         let (request_tx, request_rx) = mpsc::channel(100);
-        let (_response_tx, response_rx) = mpsc::channel(100);
         
         let client = Self {
             client_info: ClientInfo { name, version },
             pending_requests: Arc::new(RwLock::new(HashMap::new())),
-            request_tx,
-            response_rx: Arc::new(RwLock::new(response_rx)),
+            request_tx: Arc::new(watch::channel(Some(request_tx)).0),
+            timeout: DEFAULT_REQUEST_TIMEOUT,
+            retries: 0,
+            retry_delay: DEFAULT_RETRY_DELAY,
+            max_retry_delay: DEFAULT_MAX_RETRY_DELAY,
+            supervisor: None,
         };
         
-        // Start response handler
-        let pending_requests = client.pending_requests.clone();
-        let response_rx = client.response_rx.clone();
-        
-        tokio::spawn(async move {
-            let mut rx = response_rx.write().await;
-            while let Some(response) = rx.recv().await {
-                let mut pending = pending_requests.write().await;
-                if let Some(tx) = pending.remove(&response.id) {
-                    if let Err(e) = tx.send(response).await {
-                        warn!("Failed to send response: {}", e);
-                    }
-                }
-            }
-        });
-        
         (client, request_rx)
     }
     
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+    
+    /// Whether requests can currently reach the server
+    pub fn is_connected(&self) -> bool {
+        self.request_tx.borrow().as_ref().is_some_and(|tx| !tx.is_closed())
+    }
+    
     /// Initialize connection with server
     pub async fn initialize(&self) -> Result<InitializeResult> {
-        let params = json!({
-            "protocol_version": MCP_VERSION,
-            "client_info": self.client_info,
-        });
-        
-        let response = self.request("initialize", Some(params)).await?;
-        
-        if let Some(error) = response.error {
-            return Err(anyhow!("Initialize failed: {}", error.message));
-        }
-        
-        let result = response.result
-            .ok_or_else(|| anyhow!("No result in initialize response"))?;
-        
-        Ok(serde_json::from_value(result)?)
+        let response = self.request("initialize", Some(initialize_params(&self.client_info))).await?;
+        parse_initialize(response)
     }
     
-    /// List available tools
+    /// List available tools, following pagination cursors until every page is fetched
     pub async fn list_tools(&self) -> Result<Vec<Tool>> {
-        let response = self.request("tools/list", None).await?;
-        
-        if let Some(error) = response.error {
-            return Err(anyhow!("List tools failed: {}", error.message));
+        let mut all_tools = Vec::new();
+        let mut cursor: Option<String> = None;
+        
+        loop {
+            let params = cursor.as_ref().map(|c| json!({ "cursor": c }));
+            let response = self.request("tools/list", params).await?;
+            
+            if let Some(error) = response.error {
+                return Err(anyhow!("List tools failed: {}", error.message));
+            }
+            
+            let result = response.result
+                .ok_or_else(|| anyhow!("No result in tools/list response"))?;
+            
+            let tools = result.get("tools")
+                .ok_or_else(|| anyhow!("No tools in response"))?;
+            all_tools.extend(serde_json::from_value::<Vec<Tool>>(tools.clone())?);
+            
+            cursor = result.get("next_cursor")
+                .and_then(|c| c.as_str())
+                .map(str::to_string);
+            if cursor.is_none() {
+                return Ok(all_tools);
+            }
         }
-        
-        let result = response.result
-            .ok_or_else(|| anyhow!("No result in tools/list response"))?;
-        
-        let tools = result.get("tools")
-            .ok_or_else(|| anyhow!("No tools in response"))?;
-        
-        Ok(serde_json::from_value(tools.clone())?)
     }
     
-    /// Call a tool
-    pub async fn call_tool(&self, name: String, arguments: Value) -> Result<ToolCallResult> {
+    /// Call a tool, optionally waiting longer or shorter than the client's timeout.
+    /// Internal server errors are retried with jittered exponential backoff, up
+    /// to the client's retry count; each attempt gets the full timeout.
+    pub async fn call_tool(&self, name: String, arguments: Value, timeout: Option<Duration>) -> Result<ToolCallResult> {
         let params = json!({
             "name": name,
             "arguments": arguments,
         });
         
-        let response = self.request("tools/call", Some(params)).await?;
+        let timeout = timeout.unwrap_or(self.timeout);
+        let mut delay = self.retry_delay;
+        let mut attempt = 0;
+        let response = loop {
+            let response = self.request_with_timeout("tools/call", Some(params.clone()), timeout).await?;
+            match &response.error {
+                Some(error) if is_retryable(error.code) && attempt < self.retries => {
+                    attempt += 1;
+                    let wait = with_jitter(delay);
+                    warn!("Tool call {} failed ({}), retry {} of {} in {:?}", name, error.message, attempt, self.retries, wait);
+                    tokio::time::sleep(wait).await;
+                    delay = (delay * 2).min(self.max_retry_delay);
+                }
+                _ => break response,
+            }
+        };
         
         if let Some(error) = response.error {
             return Err(anyhow!("Tool call failed: {}", error.message));
@@ -158,41 +214,184 @@ impl McpClient {
     
     /// Send a raw request
     async fn request(&self, method: &str, params: Option<Value>) -> Result<McpResponse> {
+        self.request_with_timeout(method, params, self.timeout).await
+    }
+    
+    async fn request_with_timeout(&self, method: &str, params: Option<Value>, timeout: Duration) -> Result<McpResponse> {
         let request = McpRequest::new(method.to_string(), params);
         let request_id = request.id.clone();
         
         // Create response channel
-        let (tx, mut rx) = mpsc::channel(1);
+        let (tx, rx) = oneshot::channel();
         
-        // Register pending request
-        {
+        // Register pending request under the lock the supervisor drains, so a
+        // link that drops afterwards always fails this request too
+        let request_tx = {
             let mut pending = self.pending_requests.write().await;
+            let Some(request_tx) = self.request_tx.borrow().clone() else {
+                return Err(anyhow!(DISCONNECTED_ERROR));
+            };
             pending.insert(request_id.clone(), tx);
-        }
+            request_tx
+        };
         
         // Send request
-        self.request_tx.send(request).await
-            .map_err(|e| anyhow!("Failed to send request: {}", e))?;
+        if let Err(e) = request_tx.send(request).await {
+            self.pending_requests.write().await.remove(&request_id);
+            if self.supervisor.is_some() {
+                return Err(anyhow!(DISCONNECTED_ERROR));
+            }
+            return Err(anyhow!("Failed to send request: {}", e));
+        }
         
-        // Wait for response
-        tokio::time::timeout(tokio::time::Duration::from_secs(30), rx.recv())
-            .await
-            .map_err(|_| anyhow!("Request timeout"))?
-            .ok_or_else(|| anyhow!("Response channel closed"))
+        // Wait for response, forgetting the request if none arrives in time
+        match tokio::time::timeout(timeout, rx).await {
+            Ok(response) => response.map_err(|_| anyhow!("Response channel closed"))?,
+            Err(_) => {
+                self.pending_requests.write().await.remove(&request_id);
+                Err(anyhow!("Request {} timed out after {:?}", method, timeout))
+            }
+        }
     }
     
     /// Handle incoming response (called by transport layer)
     pub async fn handle_response(&self, response: McpResponse) -> Result<()> {
-        let mut pending = self.pending_requests.write().await;
-        
-        if let Some(tx) = pending.remove(&response.id) {
-            tx.send(response).await
-                .map_err(|e| anyhow!("Failed to send response to waiting request: {}", e))?;
-        } else {
-            warn!("Received response for unknown request id: {}", response.id);
+        dispatch_response(&self.pending_requests, response).await
+    }
+}
+
+impl Drop for McpClient {
+    fn drop(&mut self) {
+        if let Some(supervisor) = &self.supervisor {
+            supervisor.abort();
         }
+    }
+}
+
+/// Whether a request failing with `code` might succeed if sent again. Only
+/// internal errors qualify; malformed requests fail the same way every time.
+fn is_retryable(code: i32) -> bool {
+    code == INTERNAL_ERROR
+}
+
+/// Somewhere between half and all of `delay`, so clients retrying together spread out
+fn with_jitter(delay: Duration) -> Duration {
+    delay.mul_f64(0.5 + rand::random::<f64>() / 2.0)
+}
+
+/// Hand a response to the request waiting for it
+async fn dispatch_response(pending_requests: &PendingRequests, response: McpResponse) -> Result<()> {
+    let mut pending = pending_requests.write().await;
+    
+    if let Some(tx) = pending.remove(&response.id) {
+        let id = response.id.clone();
+        tx.send(Ok(response))
+            .map_err(|_| anyhow!("Failed to send response to waiting request {}", id))?;
+    } else {
+        warn!("Received response for unknown request id: {}", response.id);
+    }
+    
+    Ok(())
+}
+
+fn initialize_params(client_info: &ClientInfo) -> Value {
+    json!({
+        "protocol_version": MCP_VERSION,
+        "client_info": client_info,
+    })
+}
+
+fn parse_initialize(response: McpResponse) -> Result<InitializeResult> {
+    if let Some(error) = response.error {
+        return Err(anyhow!("Initialize failed: {}", error.message));
+    }
+    
+    let result = response.result
+        .ok_or_else(|| anyhow!("No result in initialize response"))?;
+    
+    Ok(serde_json::from_value(result)?)
+}
+
+/// A transport-backed connection, re-established whenever it drops
+struct Connection {
+    transport: Arc<dyn McpTransport>,
+    client_info: ClientInfo,
+    pending_requests: PendingRequests,
+    request_tx: Arc<watch::Sender<Option<mpsc::Sender<McpRequest>>>>,
+    timeout: Duration,
+    reconnect_delay: Duration,
+    max_reconnect_delay: Duration,
+}
+
+impl Connection {
+    /// Open a link and complete the initialize handshake over it
+    async fn open(&self) -> Result<(TransportLink, InitializeResult)> {
+        let mut link = self.transport.connect().await?;
+        
+        let request = McpRequest::new("initialize".to_string(), Some(initialize_params(&self.client_info)));
+        let request_id = request.id.clone();
+        link.requests.send(request).await
+            .map_err(|_| anyhow!("Connection dropped during initialize"))?;
+        
+        let handshake = async {
+            while let Some(response) = link.responses.recv().await {
+                if response.id == request_id {
+                    return Some(response);
+                }
+                warn!("Discarding response {} received before initialize completed", response.id);
+            }
+            None
+        };
+        let response = tokio::time::timeout(self.timeout, handshake).await
+            .map_err(|_| anyhow!("Initialize timed out after {:?}", self.timeout))?
+            .ok_or_else(|| anyhow!("Connection dropped during initialize"))?;
         
-        Ok(())
+        Ok((link, parse_initialize(response)?))
+    }
+    
+    /// Route responses for the life of the client, reconnecting each time the link drops
+    async fn supervise(self, mut link: TransportLink) {
+        loop {
+            while let Some(response) = link.responses.recv().await {
+                if let Err(e) = dispatch_response(&self.pending_requests, response).await {
+                    warn!("{}", e);
+                }
+            }
+            
+            warn!("MCP connection dropped, reconnecting");
+            
+            // Nothing will answer requests sent over the old link, so fail them now.
+            // Clearing the sender under the same lock means no request can slip in
+            // between the drain and the disconnect.
+            let mut pending = self.pending_requests.write().await;
+            self.request_tx.send_replace(None);
+            for (_, waiting) in pending.drain() {
+                let _ = waiting.send(Err(anyhow!(DISCONNECTED_ERROR)));
+            }
+            drop(pending);
+            
+            link = self.reconnect().await;
+            self.request_tx.send_replace(Some(link.requests.clone()));
+        }
+    }
+    
+    async fn reconnect(&self) -> TransportLink {
+        let mut delay = self.reconnect_delay;
+        
+        loop {
+            tokio::time::sleep(delay).await;
+            
+            match self.open().await {
+                Ok((link, _)) => {
+                    info!("MCP connection re-established");
+                    return link;
+                }
+                Err(e) => {
+                    warn!("Reconnection failed, retrying in {:?}: {}", delay, e);
+                    delay = (delay * 2).min(self.max_reconnect_delay);
+                }
+            }
+        }
     }
 }
 
@@ -215,6 +414,12 @@ pub struct ServerInfo {
 pub struct McpClientBuilder {
     name: String,
     version: String,
+    timeout: Duration,
+    reconnect_delay: Duration,
+    max_reconnect_delay: Duration,
+    retries: u32,
+    retry_delay: Duration,
+    max_retry_delay: Duration,
 }
 
 impl McpClientBuilder {
@@ -222,6 +427,12 @@ impl McpClientBuilder {
         Self {
             name,
             version: "1.0.0".to_string(),
+            timeout: DEFAULT_REQUEST_TIMEOUT,
+            reconnect_delay: DEFAULT_RECONNECT_DELAY,
+            max_reconnect_delay: DEFAULT_MAX_RECONNECT_DELAY,
+            retries: 0,
+            retry_delay: DEFAULT_RETRY_DELAY,
+            max_retry_delay: DEFAULT_MAX_RETRY_DELAY,
         }
     }
     
@@ -230,8 +441,69 @@ impl McpClientBuilder {
         self
     }
     
+    /// Default time each request waits for its response
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+    
+    /// Backoff between reconnection attempts, starting at `initial` and doubling up to `max`
+    pub fn with_reconnect_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.reconnect_delay = initial;
+        self.max_reconnect_delay = max.max(initial);
+        self
+    }
+    
+    /// Retry tool calls that fail with an internal server error up to `retries` times
+    pub fn with_retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+    
+    /// Backoff between tool call retries, starting at `initial` and doubling up to `max`
+    pub fn with_retry_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.retry_delay = initial;
+        self.max_retry_delay = max.max(initial);
+        self
+    }
+    
     pub fn build(self) -> (McpClient, mpsc::Receiver<McpRequest>) {
-        McpClient::new(self.name, self.version)
+        let (mut client, request_rx) = McpClient::new(self.name, self.version);
+        client.timeout = self.timeout;
+        client.retries = self.retries;
+        client.retry_delay = self.retry_delay;
+        client.max_retry_delay = self.max_retry_delay;
+        (client, request_rx)
+    }
+    
+    /// Connect and initialize over `transport`, re-initializing automatically
+    /// whenever the connection drops
+    pub async fn connect(self, transport: Arc<dyn McpTransport>) -> Result<(McpClient, InitializeResult)> {
+        let connection = Connection {
+            transport,
+            client_info: ClientInfo { name: self.name, version: self.version },
+            pending_requests: Arc::new(RwLock::new(HashMap::new())),
+            request_tx: Arc::new(watch::channel(None).0),
+            timeout: self.timeout,
+            reconnect_delay: self.reconnect_delay,
+            max_reconnect_delay: self.max_reconnect_delay,
+        };
+        
+        let (link, result) = connection.open().await?;
+        connection.request_tx.send_replace(Some(link.requests.clone()));
+        
+        let client = McpClient {
+            client_info: connection.client_info.clone(),
+            pending_requests: connection.pending_requests.clone(),
+            request_tx: connection.request_tx.clone(),
+            timeout: self.timeout,
+            retries: self.retries,
+            retry_delay: self.retry_delay,
+            max_retry_delay: self.max_retry_delay,
+            supervisor: Some(tokio::spawn(connection.supervise(link))),
+        };
+        
+        Ok((client, result))
     }
 }
 
@@ -263,4 +535,223 @@ mod tests {
         assert_eq!(request.method, "tools/list");
         assert_eq!(request.jsonrpc, "2.0");
     }
-}
\ No newline at end of file
+    
+    #[tokio::test]
+    async fn test_timed_out_request_is_forgotten() {
+        let (client, _rx) = McpClientBuilder::new("impatient".to_string())
+            .with_timeout(Duration::from_millis(100))
+            .build();
+        assert_eq!(client.timeout(), Duration::from_millis(100));
+        
+        // Nothing ever answers, so the call has to give up on its own
+        let started = std::time::Instant::now();
+        let err = client.call_tool("amos_orchestrate".to_string(), json!({}), None).await.unwrap_err();
+        
+        assert!(err.to_string().contains("timed out"), "{}", err);
+        assert!(started.elapsed() < Duration::from_secs(2));
+        assert!(client.pending_requests.read().await.is_empty());
+        
+        // A per-call override takes precedence over the client default
+        let started = std::time::Instant::now();
+        client.call_tool("amos_orchestrate".to_string(), json!({}), Some(Duration::from_millis(10))).await.unwrap_err();
+        assert!(started.elapsed() < Duration::from_millis(100));
+        assert!(client.pending_requests.read().await.is_empty());
+    }
+    
+    /// Answer every request with `respond(attempt)`, counting the attempts
+    fn spawn_responder(
+        client: Arc<McpClient>,
+        mut rx: mpsc::Receiver<McpRequest>,
+        respond: impl Fn(usize) -> Option<McpError> + Send + 'static,
+    ) -> Arc<std::sync::atomic::AtomicUsize> {
+        let attempts = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counted = attempts.clone();
+        tokio::spawn(async move {
+            while let Some(request) = rx.recv().await {
+                let attempt = counted.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                let response = match respond(attempt) {
+                    Some(error) => McpResponse::error(request.id, error),
+                    None => McpResponse::success(request.id, json!({ "content": [], "is_error": false })),
+                };
+                let _ = client.handle_response(response).await;
+            }
+        });
+        attempts
+    }
+    
+    fn server_error(code: i32) -> McpError {
+        McpError { code, message: "orchestrator busy".to_string(), data: None }
+    }
+    
+    #[tokio::test]
+    async fn test_tool_call_retries_internal_errors() {
+        let (client, rx) = McpClientBuilder::new("patient".to_string())
+            .with_retries(3)
+            .with_retry_backoff(Duration::from_millis(5), Duration::from_millis(20))
+            .build();
+        let client = Arc::new(client);
+        let attempts = spawn_responder(client.clone(), rx, |attempt| {
+            (attempt < 2).then(|| server_error(INTERNAL_ERROR))
+        });
+        
+        let result = client.call_tool("amos_orchestrate".to_string(), json!({}), None).await.unwrap();
+        assert!(!result.is_error);
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+    
+    #[tokio::test]
+    async fn test_tool_call_does_not_retry_unknown_method() {
+        let (client, rx) = McpClientBuilder::new("patient".to_string())
+            .with_retries(3)
+            .with_retry_backoff(Duration::from_millis(5), Duration::from_millis(20))
+            .build();
+        let client = Arc::new(client);
+        let attempts = spawn_responder(client.clone(), rx, |_| Some(server_error(METHOD_NOT_FOUND)));
+        
+        let err = client.call_tool("amos_orchestrate".to_string(), json!({}), None).await.unwrap_err();
+        assert!(err.to_string().contains("Tool call failed"), "{}", err);
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+    
+    /// Serves each connection from a real server until the test drops every open link
+    struct FlakyTransport {
+        server: Arc<crate::McpServer>,
+        available: std::sync::atomic::AtomicBool,
+        answering: Arc<std::sync::atomic::AtomicBool>,
+        connects: std::sync::atomic::AtomicUsize,
+        drop_links: tokio::sync::broadcast::Sender<()>,
+    }
+    
+    #[async_trait]
+    impl McpTransport for FlakyTransport {
+        async fn connect(&self) -> Result<TransportLink> {
+            use std::sync::atomic::Ordering;
+            
+            if !self.available.load(Ordering::SeqCst) {
+                return Err(anyhow!("server unreachable"));
+            }
+            self.connects.fetch_add(1, Ordering::SeqCst);
+            
+            let (request_tx, mut request_rx) = mpsc::channel::<McpRequest>(16);
+            let (response_tx, response_rx) = mpsc::channel(16);
+            let server = self.server.clone();
+            let answering = self.answering.clone();
+            let mut dropped = self.drop_links.subscribe();
+            
+            tokio::spawn(async move {
+                loop {
+                    tokio::select! {
+                        _ = dropped.recv() => break,
+                        request = request_rx.recv() => match request {
+                            Some(request) if answering.load(Ordering::SeqCst) => {
+                                let _ = response_tx.send(server.handle_request(request).await).await;
+                            }
+                            Some(_) => {}
+                            None => break,
+                        },
+                    }
+                }
+            });
+            
+            Ok(TransportLink { requests: request_tx, responses: response_rx })
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_client_reconnects_after_transport_drop() {
+        use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+        
+        let server = crate::McpServer::new(
+            Arc::new(amos_core::ForgeNeuralNetwork::new()),
+            Arc::new(RwLock::new(HashMap::new())),
+        );
+        let transport = Arc::new(FlakyTransport {
+            server: Arc::new(server),
+            available: AtomicBool::new(true),
+            answering: Arc::new(AtomicBool::new(true)),
+            connects: AtomicUsize::new(0),
+            drop_links: tokio::sync::broadcast::channel(1).0,
+        });
+        
+        let (client, info) = McpClientBuilder::new("resilient".to_string())
+            .with_timeout(Duration::from_secs(5))
+            .with_reconnect_backoff(Duration::from_millis(10), Duration::from_millis(50))
+            .connect(transport.clone())
+            .await
+            .unwrap();
+        let client = Arc::new(client);
+        assert_eq!(info.protocol_version, MCP_VERSION);
+        assert!(client.is_connected());
+        assert!(!client.list_tools().await.unwrap().is_empty());
+        
+        // A request is in flight when the connection drops
+        transport.answering.store(false, Ordering::SeqCst);
+        let in_flight = tokio::spawn({
+            let client = client.clone();
+            async move { client.list_tools().await }
+        });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        transport.available.store(false, Ordering::SeqCst);
+        transport.drop_links.send(()).unwrap();
+        
+        // It fails straight away rather than waiting out the 5s timeout
+        let err = tokio::time::timeout(Duration::from_secs(1), in_flight).await.unwrap().unwrap().unwrap_err();
+        assert!(err.to_string().contains(DISCONNECTED_ERROR), "{}", err);
+        assert!(!client.is_connected());
+        let err = client.list_tools().await.unwrap_err();
+        assert!(err.to_string().contains(DISCONNECTED_ERROR), "{}", err);
+        
+        // Once the server is back the client re-initializes on its own
+        transport.answering.store(true, Ordering::SeqCst);
+        transport.available.store(true, Ordering::SeqCst);
+        tokio::time::timeout(Duration::from_secs(2), async {
+            while !client.is_connected() {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        }).await.unwrap();
+        
+        assert!(!client.list_tools().await.unwrap().is_empty());
+        assert_eq!(transport.connects.load(Ordering::SeqCst), 2);
+    }
+    
+    #[tokio::test]
+    async fn test_request_racing_a_drop_fails_fast() {
+        use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+        
+        let server = crate::McpServer::new(
+            Arc::new(amos_core::ForgeNeuralNetwork::new()),
+            Arc::new(RwLock::new(HashMap::new())),
+        );
+        let transport = Arc::new(FlakyTransport {
+            server: Arc::new(server),
+            available: AtomicBool::new(true),
+            answering: Arc::new(AtomicBool::new(true)),
+            connects: AtomicUsize::new(0),
+            drop_links: tokio::sync::broadcast::channel(1).0,
+        });
+        
+        let (client, _) = McpClientBuilder::new("racing".to_string())
+            .with_timeout(Duration::from_secs(5))
+            .with_reconnect_backoff(Duration::from_secs(60), Duration::from_secs(60))
+            .connect(transport.clone())
+            .await
+            .unwrap();
+        let client = Arc::new(client);
+        
+        // The supervisor sees the drop while a request is about to register
+        let held = client.pending_requests.write().await;
+        transport.available.store(false, Ordering::SeqCst);
+        transport.drop_links.send(()).unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        let racing = tokio::spawn({
+            let client = client.clone();
+            async move { client.list_tools().await }
+        });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        drop(held);
+        
+        let err = tokio::time::timeout(Duration::from_secs(1), racing).await.unwrap().unwrap().unwrap_err();
+        assert!(err.to_string().contains(DISCONNECTED_ERROR), "{}", err);
+        assert!(client.pending_requests.read().await.is_empty());
+    }
+}
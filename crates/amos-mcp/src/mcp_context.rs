@@ -1,13 +1,26 @@
 use crate::mcp_protocol::{ContextItem};
 use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use uuid::Uuid;
-use amos_core::neural::ForgeNeuralNetwork;
+use amos_core::neural::{ForgeNeuralNetwork, NodeType};
 use amos_agents::CognitiveAgent;
 
+/// Filters for `amos/neural/query`. Pathway filters select pathways and node
+/// filters select nodes; `limit` caps each list, strongest or best connected first.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NeuralQuery {
+    pub min_strength: Option<f64>,
+    pub node_type: Option<NodeType>,
+    /// Pathways touching the node, in either direction
+    pub min_connections: Option<usize>,
+    pub limit: Option<usize>,
+}
+
 /// Context provider for MCP
 pub struct ContextProvider {
     contexts: Arc<RwLock<HashMap<String, ContextItem>>>,
@@ -93,6 +106,46 @@ impl ContextProvider {
         }
     }
     
+    /// Pathways and nodes of the network that match `query`
+    pub async fn query_neural_network(&self, query: &NeuralQuery) -> Value {
+        let snapshot = self.neural_network.snapshot().await;
+        let limit = query.limit.unwrap_or(usize::MAX);
+        
+        let mut connections: HashMap<Uuid, usize> = HashMap::new();
+        for pathway in &snapshot.pathways {
+            *connections.entry(pathway.source_node).or_default() += 1;
+            *connections.entry(pathway.target_node).or_default() += 1;
+        }
+        
+        let mut pathways: Vec<_> = snapshot.pathways.into_iter()
+            .filter(|pathway| pathway.strength >= query.min_strength.unwrap_or(f64::NEG_INFINITY))
+            .collect();
+        pathways.sort_by(|a, b| b.strength.partial_cmp(&a.strength).unwrap_or(std::cmp::Ordering::Equal));
+        pathways.truncate(limit);
+        
+        let mut nodes: Vec<(usize, Value)> = snapshot.nodes.into_iter()
+            .map(|node| (connections.get(&node.id).copied().unwrap_or(0), node))
+            .filter(|(_, node)| match &query.node_type {
+                Some(node_type) => &node.node_type == node_type,
+                None => true,
+            })
+            .filter(|(count, _)| *count >= query.min_connections.unwrap_or(0))
+            .map(|(count, node)| (count, serde_json::json!({
+                "id": node.id,
+                "node_type": node.node_type,
+                "namespace": node.namespace,
+                "connections": count,
+            })))
+            .collect();
+        nodes.sort_by_key(|(count, _)| std::cmp::Reverse(*count));
+        nodes.truncate(limit);
+        
+        serde_json::json!({
+            "pathways": pathways,
+            "nodes": nodes.into_iter().map(|(_, node)| node).collect::<Vec<_>>(),
+        })
+    }
+    
     /// Get neural network context
     async fn get_neural_network_context(&self) -> Result<Value> {
         // Get basic stats from the neural network
@@ -23,6 +23,15 @@ impl McpRequest {
             id: Uuid::new_v4().to_string(),
         }
     }
+    
+    /// Trace id supplied by the client, either as `trace_id` or `_meta.trace_id` in params
+    pub fn trace_id(&self) -> Option<String> {
+        let params = self.params.as_ref()?;
+        params.get("trace_id")
+            .or_else(|| params.get("_meta").and_then(|meta| meta.get("trace_id")))
+            .and_then(|trace_id| trace_id.as_str())
+            .map(str::to_string)
+    }
 }
 
 /// MCP Response structure
@@ -32,6 +41,8 @@ pub struct McpResponse {
     pub result: Option<Value>,
     pub error: Option<McpError>,
     pub id: String,
+    #[serde(rename = "_meta", default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Value>,
 }
 
 impl McpResponse {
@@ -41,6 +52,7 @@ impl McpResponse {
             result: Some(result),
             error: None,
             id,
+            meta: None,
         }
     }
     
@@ -50,10 +62,42 @@ impl McpResponse {
             result: None,
             error: Some(error),
             id,
+            meta: None,
         }
     }
+    
+    /// Echo the trace id of the request this answers
+    pub fn with_trace_id(mut self, trace_id: &str) -> Self {
+        self.meta = Some(serde_json::json!({ "trace_id": trace_id }));
+        self
+    }
+    
+    pub fn trace_id(&self) -> Option<&str> {
+        self.meta.as_ref()?.get("trace_id")?.as_str()
+    }
+}
+
+/// Message the server sends unprompted; it carries no id and gets no reply
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpNotification {
+    pub jsonrpc: String,
+    pub method: String,
+    pub params: Option<Value>,
 }
 
+impl McpNotification {
+    pub fn new(method: impl Into<String>, params: Value) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            method: method.into(),
+            params: Some(params),
+        }
+    }
+}
+
+/// Notification carrying one neural network event, sent after `amos/neural/subscribe`
+pub const NEURAL_EVENT_NOTIFICATION: &str = "amos/neural/event";
+
 /// MCP Error structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct McpError {
@@ -62,6 +106,45 @@ pub struct McpError {
     pub data: Option<Value>,
 }
 
+/// JSON-RPC error codes
+pub const PARSE_ERROR: i32 = -32700;
+pub const INVALID_REQUEST: i32 = -32600;
+pub const METHOD_NOT_FOUND: i32 = -32601;
+pub const INVALID_PARAMS: i32 = -32602;
+pub const INTERNAL_ERROR: i32 = -32603;
+/// Server-defined: the agent exists but exposes no internal state
+pub const NOT_INTROSPECTABLE: i32 = -32001;
+
+/// A request the server rejects as malformed, as opposed to failing while handling it
+#[derive(Debug, Clone, PartialEq)]
+pub enum RequestError {
+    MethodNotFound(String),
+    InvalidParams(String),
+    NotIntrospectable(uuid::Uuid),
+}
+
+impl RequestError {
+    pub fn code(&self) -> i32 {
+        match self {
+            RequestError::MethodNotFound(_) => METHOD_NOT_FOUND,
+            RequestError::InvalidParams(_) => INVALID_PARAMS,
+            RequestError::NotIntrospectable(_) => NOT_INTROSPECTABLE,
+        }
+    }
+}
+
+impl std::fmt::Display for RequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RequestError::MethodNotFound(method) => write!(f, "Method not found: {}", method),
+            RequestError::InvalidParams(reason) => write!(f, "Invalid params: {}", reason),
+            RequestError::NotIntrospectable(agent_id) => write!(f, "Agent {} is not introspectable", agent_id),
+        }
+    }
+}
+
+impl std::error::Error for RequestError {}
+
 /// MCP Method types
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum McpMethod {
@@ -161,6 +244,9 @@ pub struct ServerCapabilities {
     pub resources: bool,
     pub prompts: bool,
     pub amos_extensions: bool,
+    /// Whether the transport streams notifications between responses
+    #[serde(default)]
+    pub notifications: bool,
 }
 
 impl Default for ServerCapabilities {
@@ -171,6 +257,7 @@ impl Default for ServerCapabilities {
             resources: true,
             prompts: true,
             amos_extensions: true,
+            notifications: true,
         }
     }
 }
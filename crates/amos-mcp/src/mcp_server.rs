@@ -1,17 +1,20 @@
 use crate::{
     mcp_protocol::*,
-    mcp_tools::{ToolRegistry, create_default_registry},
-    mcp_context::ContextProvider,
+    mcp_tools::{ToolRegistry, ToolValidationError, create_default_registry, DEFAULT_TOOLS_PAGE_SIZE},
+    mcp_context::{ContextProvider, NeuralQuery},
 };
-use anyhow::{Result, anyhow};
+use anyhow::Result;
 use serde_json::{json, Value};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{broadcast, Mutex, RwLock};
 use uuid::Uuid;
-use amos_core::neural::ForgeNeuralNetwork;
+use amos_core::neural::{ForgeNeuralNetwork, NeuralEvent};
 use amos_agents::CognitiveAgent;
 use std::collections::HashMap;
-use tracing::{info, error};
+use amos_core::logging::with_correlation_id;
+use tracing::{info, warn, error, info_span, Instrument};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use futures::future::join_all;
 
 /// MCP Server implementation
 pub struct McpServer {
@@ -19,6 +22,12 @@ pub struct McpServer {
     context_provider: Arc<ContextProvider>,
     capabilities: ServerCapabilities,
     server_info: ServerInfo,
+    neural_network: Arc<ForgeNeuralNetwork>,
+    agents: Arc<RwLock<HashMap<Uuid, Arc<dyn CognitiveAgent>>>>,
+    /// Notifications waiting to be streamed to clients by `serve`
+    notifications: broadcast::Sender<McpNotification>,
+    /// Relays neural events into `notifications` once a client subscribes
+    neural_relay: Mutex<Option<tokio::task::JoinHandle<()>>>,
 }
 
 #[derive(Debug, Clone)]
@@ -34,7 +43,7 @@ impl McpServer {
         agents: Arc<RwLock<HashMap<Uuid, Arc<dyn CognitiveAgent>>>>
     ) -> Self {
         let tool_registry = Arc::new(RwLock::new(create_default_registry(agents.clone())));
-        let context_provider = Arc::new(ContextProvider::new(neural_network, agents));
+        let context_provider = Arc::new(ContextProvider::new(neural_network.clone(), agents.clone()));
         
         Self {
             tool_registry,
@@ -45,26 +54,193 @@ impl McpServer {
                 version: env!("CARGO_PKG_VERSION").to_string(),
                 vendor: "AMOS Project".to_string(),
             },
+            neural_network,
+            agents,
+            notifications: broadcast::channel(1000).0,
+            neural_relay: Mutex::new(None),
         }
     }
     
+    /// Notifications the server emits, as `serve` streams them to its client
+    pub fn subscribe_notifications(&self) -> broadcast::Receiver<McpNotification> {
+        self.notifications.subscribe()
+    }
+    
     /// Handle an incoming MCP request
     pub async fn handle_request(&self, request: McpRequest) -> McpResponse {
-        info!("Handling MCP request: {} (id: {})", request.method, request.id);
+        let trace_id = request.trace_id().unwrap_or_else(|| Uuid::new_v4().to_string());
+        let span = info_span!("mcp_request", trace_id = %trace_id, method = %request.method);
         
-        let result = match self.route_request(&request).await {
-            Ok(value) => McpResponse::success(request.id.clone(), value),
-            Err(e) => {
+        let handling = async {
+            info!("Handling MCP request: {} (id: {})", request.method, request.id);
+            let outcome = self.route_request(&request).await;
+            if let Err(e) = &outcome {
                 error!("Error handling request: {}", e);
-                McpResponse::error(request.id.clone(), McpError {
-                    code: -32603,
-                    message: e.to_string(),
-                    data: None,
-                })
             }
+            outcome
+        }.instrument(span);
+        
+        // Agent loggers only carry UUID correlation ids, so other trace ids stay in the span
+        let outcome = match Uuid::parse_str(&trace_id) {
+            Ok(correlation_id) => with_correlation_id(correlation_id, handling).await,
+            Err(_) => handling.await,
         };
         
-        result
+        let response = match outcome {
+            Ok(value) => McpResponse::success(request.id.clone(), value),
+            Err(e) => McpResponse::error(request.id.clone(), Self::error_for(&e)),
+        };
+        
+        response.with_trace_id(&trace_id)
+    }
+    
+    /// Handle a JSON-RPC batch, answering in request order. Runs of read-only
+    /// requests are handled concurrently; anything that may change state runs
+    /// on its own, after everything before it in the batch.
+    pub async fn handle_batch(&self, requests: Vec<McpRequest>) -> Vec<McpResponse> {
+        let mut responses = Vec::with_capacity(requests.len());
+        let mut concurrent = Vec::new();
+        
+        for request in requests {
+            if Self::is_read_only(&request.method) {
+                concurrent.push(request);
+                continue;
+            }
+            
+            let pending = std::mem::take(&mut concurrent);
+            responses.extend(join_all(pending.into_iter().map(|request| self.handle_request(request))).await);
+            responses.push(self.handle_request(request).await);
+        }
+        responses.extend(join_all(concurrent.into_iter().map(|request| self.handle_request(request))).await);
+        
+        responses
+    }
+    
+    /// Methods that only read server state, so batching may reorder them freely
+    fn is_read_only(method: &str) -> bool {
+        matches!(
+            method,
+            "ping" | "tools/list" | "context/list" | "context/get" | "resources/list"
+                | "resources/get" | "prompts/list" | "prompts/get" | "amos/neural/query"
+                | "amos/agent/introspect"
+        )
+    }
+    
+    /// Map a handler failure to its JSON-RPC error; anything untyped is an internal error
+    fn error_for(e: &anyhow::Error) -> McpError {
+        if let Some(validation) = e.downcast_ref::<ToolValidationError>() {
+            return McpError {
+                code: INVALID_PARAMS,
+                message: validation.to_string(),
+                data: Some(json!({
+                    "tool": validation.tool,
+                    "errors": validation.errors,
+                })),
+            };
+        }
+        
+        let code = e.downcast_ref::<RequestError>()
+            .map(RequestError::code)
+            .unwrap_or(INTERNAL_ERROR);
+        
+        McpError {
+            code,
+            message: e.to_string(),
+            data: None,
+        }
+    }
+    
+    /// Serve newline-delimited JSON-RPC requests until the reader hits EOF,
+    /// writing notifications out between responses as they are emitted
+    pub async fn serve<R, W>(&self, reader: R, mut writer: W) -> Result<()>
+    where
+        R: AsyncBufRead + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        let mut lines = reader.lines();
+        let mut notifications = self.notifications.subscribe();
+        
+        loop {
+            let line = tokio::select! {
+                line = lines.next_line() => match line? {
+                    Some(line) => line,
+                    None => break,
+                },
+                notification = notifications.recv() => {
+                    match notification {
+                        Ok(notification) => {
+                            let mut payload = serde_json::to_vec(&notification)?;
+                            payload.push(b'\n');
+                            writer.write_all(&payload).await?;
+                            writer.flush().await?;
+                        }
+                        Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                            warn!("Client fell behind, dropped {} notifications", skipped);
+                        }
+                        // The server holds the sender, so this can't happen while serving
+                        Err(broadcast::error::RecvError::Closed) => {}
+                    }
+                    continue;
+                }
+            };
+            
+            if line.trim().is_empty() {
+                continue;
+            }
+            
+            // A JSON array is a batch and is answered with an array
+            let mut payload = match serde_json::from_str::<Value>(&line) {
+                Ok(Value::Array(items)) => match self.handle_batch_items(items).await {
+                    Some(responses) => serde_json::to_vec(&responses)?,
+                    None => serde_json::to_vec(&request_error(INVALID_REQUEST, "Empty batch"))?,
+                },
+                Ok(value) => {
+                    let response = match serde_json::from_value::<McpRequest>(value) {
+                        Ok(request) => self.handle_request(request).await,
+                        Err(e) => parse_error(e),
+                    };
+                    serde_json::to_vec(&response)?
+                }
+                Err(e) => serde_json::to_vec(&parse_error(e))?,
+            };
+            payload.push(b'\n');
+            writer.write_all(&payload).await?;
+            writer.flush().await?;
+        }
+        
+        info!("MCP input closed, shutting down");
+        Ok(())
+    }
+    
+    /// Handle the elements of a batch payload; malformed elements get their own
+    /// error in place. `None` for an empty batch, which JSON-RPC rejects outright.
+    async fn handle_batch_items(&self, items: Vec<Value>) -> Option<Vec<McpResponse>> {
+        if items.is_empty() {
+            return None;
+        }
+        
+        let mut requests = Vec::with_capacity(items.len());
+        let mut malformed = HashMap::new();
+        for (index, item) in items.into_iter().enumerate() {
+            match serde_json::from_value::<McpRequest>(item) {
+                Ok(request) => requests.push(request),
+                Err(e) => {
+                    error!("Invalid request in MCP batch: {}", e);
+                    malformed.insert(index, request_error(INVALID_REQUEST, format!("Invalid request: {}", e)));
+                }
+            }
+        }
+        
+        let mut handled = self.handle_batch(requests).await.into_iter();
+        let total = handled.len() + malformed.len();
+        Some((0..total)
+            .filter_map(|index| malformed.remove(&index).or_else(|| handled.next()))
+            .collect())
+    }
+    
+    /// Serve requests over the process's stdin and stdout
+    pub async fn serve_stdio(&self) -> Result<()> {
+        self.serve(BufReader::new(tokio::io::stdin()), tokio::io::stdout()).await
     }
     
     /// Route request to appropriate handler
@@ -75,7 +251,7 @@ impl McpServer {
             "ping" => Ok(json!({"pong": true})),
             
             // Tool methods
-            "tools/list" => self.handle_tools_list().await,
+            "tools/list" => self.handle_tools_list(request.params.as_ref()).await,
             "tools/call" => self.handle_tools_call(request.params.as_ref()).await,
             
             // Context methods
@@ -95,7 +271,7 @@ impl McpServer {
                 self.handle_amos_method(method, request.params.as_ref()).await
             }
             
-            _ => Err(anyhow!("Unknown method: {}", request.method)),
+            _ => Err(RequestError::MethodNotFound(request.method.clone()).into()),
         }
     }
     
@@ -124,19 +300,30 @@ impl McpServer {
     }
     
     /// Handle tools/list request
-    async fn handle_tools_list(&self) -> Result<Value> {
+    async fn handle_tools_list(&self, params: Option<&Value>) -> Result<Value> {
+        let cursor = params.and_then(|p| p.get("cursor")).and_then(|c| c.as_str());
+        let limit = params.and_then(|p| p.get("limit"))
+            .and_then(|l| l.as_u64())
+            .map(|l| l as usize)
+            .unwrap_or(DEFAULT_TOOLS_PAGE_SIZE);
+        if limit == 0 {
+            return Err(invalid_params("limit must be at least 1"));
+        }
+        
         let registry = self.tool_registry.read().await;
-        let tools = registry.list_tools();
+        let (tools, next_cursor) = registry.list_tools_page(cursor, limit);
         
         Ok(json!({
-            "tools": tools
+            "tools": tools,
+            "next_cursor": next_cursor
         }))
     }
     
     /// Handle tools/call request
     async fn handle_tools_call(&self, params: Option<&Value>) -> Result<Value> {
-        let params = params.ok_or_else(|| anyhow!("Missing parameters"))?;
-        let tool_params: ToolCallParams = serde_json::from_value(params.clone())?;
+        let params = params.ok_or_else(|| invalid_params("Missing parameters"))?;
+        let tool_params: ToolCallParams = serde_json::from_value(params.clone())
+            .map_err(|e| invalid_params(e.to_string()))?;
         
         let registry = self.tool_registry.read().await;
         let result = registry.execute_tool(tool_params).await?;
@@ -155,10 +342,10 @@ impl McpServer {
     
     /// Handle context/get request
     async fn handle_context_get(&self, params: Option<&Value>) -> Result<Value> {
-        let params = params.ok_or_else(|| anyhow!("Missing parameters"))?;
+        let params = params.ok_or_else(|| invalid_params("Missing parameters"))?;
         let context_id = params.get("id")
             .and_then(|v| v.as_str())
-            .ok_or_else(|| anyhow!("Missing context id"))?;
+            .ok_or_else(|| invalid_params("Missing context id"))?;
         
         let content = self.context_provider.get_context(context_id).await?;
         
@@ -192,16 +379,16 @@ impl McpServer {
     
     /// Handle resources/get request
     async fn handle_resources_get(&self, params: Option<&Value>) -> Result<Value> {
-        let params = params.ok_or_else(|| anyhow!("Missing parameters"))?;
+        let params = params.ok_or_else(|| invalid_params("Missing parameters"))?;
         let uri = params.get("uri")
             .and_then(|v| v.as_str())
-            .ok_or_else(|| anyhow!("Missing resource URI"))?;
+            .ok_or_else(|| invalid_params("Missing resource URI"))?;
         
         // Map URIs to context IDs
         let context_id = match uri {
             "amos://neural/network" => "neural_network",
             "amos://agents/swarm" => "agent_swarm",
-            _ => return Err(anyhow!("Unknown resource URI: {}", uri)),
+            _ => return Err(invalid_params(format!("Unknown resource URI: {}", uri))),
         };
         
         let content = self.context_provider.get_context(context_id).await?;
@@ -251,10 +438,10 @@ impl McpServer {
     
     /// Handle prompts/get request
     async fn handle_prompts_get(&self, params: Option<&Value>) -> Result<Value> {
-        let params = params.ok_or_else(|| anyhow!("Missing parameters"))?;
+        let params = params.ok_or_else(|| invalid_params("Missing parameters"))?;
         let prompt_id = params.get("id")
             .and_then(|v| v.as_str())
-            .ok_or_else(|| anyhow!("Missing prompt id"))?;
+            .ok_or_else(|| invalid_params("Missing prompt id"))?;
         
         let prompt_text = match prompt_id {
             "analyze_neural_state" => {
@@ -268,7 +455,7 @@ impl McpServer {
                 Consider current agent states, resource allocation, \
                 and potential bottlenecks in the system."
             },
-            _ => return Err(anyhow!("Unknown prompt id: {}", prompt_id)),
+            _ => return Err(invalid_params(format!("Unknown prompt id: {}", prompt_id))),
         };
         
         Ok(json!({
@@ -289,13 +476,113 @@ impl McpServer {
                 let result = registry.execute_tool(tool_params).await?;
                 Ok(serde_json::to_value(result)?)
             },
-            "amos/neural/query" => {
-                // Direct neural network query
-                self.context_provider.get_context("neural_network").await
-            },
-            _ => Err(anyhow!("Unknown AMOS method: {}", method)),
+            "amos/neural/query" => self.handle_neural_query(params).await,
+            "amos/neural/subscribe" => self.handle_neural_subscribe().await,
+            "amos/agent/introspect" => self.handle_agent_introspect(params).await,
+            _ => Err(RequestError::MethodNotFound(method.to_string()).into()),
         }
     }
+    
+    /// Filter the network by a `NeuralQuery`; without one, summarize the whole network
+    async fn handle_neural_query(&self, params: Option<&Value>) -> Result<Value> {
+        let params = match params {
+            Some(params) if !matches!(params.as_object(), Some(fields) if fields.is_empty()) => params,
+            _ => return self.context_provider.get_context("neural_network").await,
+        };
+        
+        let query: NeuralQuery = serde_json::from_value(params.clone())
+            .map_err(|e| invalid_params(format!("Invalid neural query: {}", e)))?;
+        Ok(self.context_provider.query_neural_network(&query).await)
+    }
+    
+    /// Report an agent's internal cognitive state, for agents that expose one
+    async fn handle_agent_introspect(&self, params: Option<&Value>) -> Result<Value> {
+        let agent_id = params
+            .and_then(|params| params.get("agent_id"))
+            .and_then(Value::as_str)
+            .ok_or_else(|| invalid_params("Missing agent_id"))?;
+        let agent_id = Uuid::parse_str(agent_id)
+            .map_err(|e| invalid_params(format!("Invalid agent_id: {}", e)))?;
+        
+        let agents = self.agents.read().await;
+        let agent = agents.get(&agent_id)
+            .ok_or_else(|| invalid_params(format!("Unknown agent: {}", agent_id)))?;
+        let state = agent.introspect()
+            .ok_or(RequestError::NotIntrospectable(agent_id))?;
+        
+        Ok(json!({
+            "agent_id": agent_id,
+            "name": agent.name(),
+            "state": state,
+        }))
+    }
+    
+    /// Start relaying neural events as notifications; subscribing again is a no-op
+    async fn handle_neural_subscribe(&self) -> Result<Value> {
+        let mut relay = self.neural_relay.lock().await;
+        let relaying = relay.as_ref().is_some_and(|handle| !handle.is_finished());
+        if !relaying {
+            // Subscribe before answering, so no event after the response is missed
+            let mut events = self.neural_network.subscribe_to_events();
+            let notifications = self.notifications.clone();
+            *relay = Some(tokio::spawn(async move {
+                loop {
+                    match events.recv().await {
+                        Ok(event) => {
+                            let _ = notifications.send(neural_event_notification(&event));
+                        }
+                        Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                            warn!("Neural event relay fell behind, dropped {} events", skipped);
+                        }
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    }
+                }
+            }));
+        }
+        
+        Ok(json!({
+            "subscribed": true,
+            "notification": NEURAL_EVENT_NOTIFICATION,
+        }))
+    }
+}
+
+impl Drop for McpServer {
+    fn drop(&mut self) {
+        if let Some(relay) = self.neural_relay.get_mut().take() {
+            relay.abort();
+        }
+    }
+}
+
+/// `{"type": <variant>, "data": <fields>}` for a neural event
+fn neural_event_notification(event: &NeuralEvent) -> McpNotification {
+    let params = match serde_json::to_value(event) {
+        Ok(Value::Object(tagged)) => match tagged.into_iter().next() {
+            Some((kind, data)) => json!({ "type": kind, "data": data }),
+            None => Value::Null,
+        },
+        _ => Value::Null,
+    };
+    McpNotification::new(NEURAL_EVENT_NOTIFICATION, params)
+}
+
+fn invalid_params(reason: impl Into<String>) -> anyhow::Error {
+    RequestError::InvalidParams(reason.into()).into()
+}
+
+/// Response for a message that couldn't be tied to a request id
+fn request_error(code: i32, message: impl Into<String>) -> McpResponse {
+    McpResponse::error(String::new(), McpError {
+        code,
+        message: message.into(),
+        data: None,
+    })
+}
+
+fn parse_error(e: serde_json::Error) -> McpResponse {
+    error!("Failed to parse MCP request: {}", e);
+    request_error(PARSE_ERROR, format!("Parse error: {}", e))
 }
 
 #[cfg(test)]
@@ -344,4 +631,281 @@ mod tests {
         let result = response.result.unwrap();
         assert_eq!(result["protocol_version"], MCP_VERSION);
     }
-}
\ No newline at end of file
+    
+    #[tokio::test]
+    async fn test_serve_answers_each_line_until_eof() {
+        let neural_network = Arc::new(ForgeNeuralNetwork::new());
+        let agents = Arc::new(RwLock::new(HashMap::new()));
+        let server = McpServer::new(neural_network, agents);
+        
+        let ping = serde_json::to_string(&McpRequest::new("ping".to_string(), None)).unwrap();
+        let input = format!("{}\n\nnot json\n", ping);
+        let mut output = Vec::new();
+        
+        server.serve(input.as_bytes(), &mut output).await.unwrap();
+        
+        let responses: Vec<McpResponse> = String::from_utf8(output).unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0].result.as_ref().unwrap()["pong"], true);
+        assert_eq!(responses[1].error.as_ref().unwrap().code, PARSE_ERROR);
+    }
+    
+    #[tokio::test]
+    async fn test_batch_line_is_answered_with_array_in_order() {
+        let neural_network = Arc::new(ForgeNeuralNetwork::new());
+        let agents = Arc::new(RwLock::new(HashMap::new()));
+        let server = McpServer::new(neural_network, agents);
+        
+        let initialize = McpRequest::new("initialize".to_string(), None);
+        let tools = McpRequest::new("tools/list".to_string(), None);
+        let ids = vec![initialize.id.clone(), tools.id.clone()];
+        let input = format!("{}\n[]\n", serde_json::to_string(&vec![initialize, tools]).unwrap());
+        let mut output = Vec::new();
+        
+        server.serve(input.as_bytes(), &mut output).await.unwrap();
+        
+        let output = String::from_utf8(output).unwrap();
+        let mut lines = output.lines();
+        let batch: Vec<McpResponse> = serde_json::from_str(lines.next().unwrap()).unwrap();
+        assert_eq!(batch.iter().map(|r| r.id.clone()).collect::<Vec<_>>(), ids);
+        assert_eq!(batch[0].result.as_ref().unwrap()["protocol_version"], MCP_VERSION);
+        assert!(batch[1].result.as_ref().unwrap()["tools"].is_array());
+        
+        let empty: McpResponse = serde_json::from_str(lines.next().unwrap()).unwrap();
+        assert_eq!(empty.error.unwrap().code, INVALID_REQUEST);
+    }
+    
+    /// Collects formatted log output so tests can inspect span fields
+    #[derive(Clone, Default)]
+    struct CapturedLogs(Arc<std::sync::Mutex<Vec<u8>>>);
+    
+    impl std::io::Write for CapturedLogs {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_trace_id_is_logged_and_echoed() {
+        let logs = CapturedLogs::default();
+        let writer = logs.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(move || writer.clone())
+            .with_ansi(false)
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+        
+        let neural_network = Arc::new(ForgeNeuralNetwork::new());
+        let agents = Arc::new(RwLock::new(HashMap::new()));
+        let server = McpServer::new(neural_network, agents);
+        
+        let request = McpRequest::new(
+            "ping".to_string(),
+            Some(json!({ "_meta": { "trace_id": "trace-abc-123" } })),
+        );
+        let response = server.handle_request(request).await;
+        assert_eq!(response.trace_id(), Some("trace-abc-123"));
+        
+        let output = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+        let handler_line = output.lines()
+            .find(|line| line.contains("Handling MCP request: ping"))
+            .unwrap();
+        assert!(handler_line.contains("trace_id=trace-abc-123"));
+    }
+    
+    #[tokio::test]
+    async fn test_trace_id_generated_when_absent() {
+        let neural_network = Arc::new(ForgeNeuralNetwork::new());
+        let agents = Arc::new(RwLock::new(HashMap::new()));
+        let server = McpServer::new(neural_network, agents);
+        
+        let first = server.handle_request(McpRequest::new("ping".to_string(), None)).await;
+        let second = server.handle_request(McpRequest::new("ping".to_string(), None)).await;
+        
+        let first_trace = first.trace_id().unwrap();
+        assert!(Uuid::parse_str(first_trace).is_ok());
+        assert_ne!(Some(first_trace), second.trace_id());
+    }
+    
+    #[tokio::test]
+    async fn test_tools_list_pages_with_cursor() {
+        let neural_network = Arc::new(ForgeNeuralNetwork::new());
+        let agents = Arc::new(RwLock::new(HashMap::new()));
+        let server = McpServer::new(neural_network, agents);
+        
+        let first = server.handle_request(McpRequest::new("tools/list".to_string(), Some(json!({ "limit": 2 })))).await;
+        let first = first.result.unwrap();
+        assert_eq!(first["tools"].as_array().unwrap().len(), 2);
+        let cursor = first["next_cursor"].as_str().unwrap().to_string();
+        
+        let second = server.handle_request(McpRequest::new(
+            "tools/list".to_string(),
+            Some(json!({ "limit": 2, "cursor": cursor })),
+        )).await;
+        let second = second.result.unwrap();
+        assert_eq!(second["tools"].as_array().unwrap().len(), 1);
+        assert!(second["next_cursor"].is_null());
+        assert_ne!(first["tools"][0]["name"], second["tools"][0]["name"]);
+    }
+    
+    #[tokio::test]
+    async fn test_tool_arguments_are_validated_against_schema() {
+        let neural_network = Arc::new(ForgeNeuralNetwork::new());
+        let agents = Arc::new(RwLock::new(HashMap::new()));
+        let server = McpServer::new(neural_network, agents);
+        
+        let request = McpRequest::new("tools/call".to_string(), Some(json!({
+            "name": "amos_agent_command",
+            "arguments": { "agent_id": Uuid::new_v4().to_string() }
+        })));
+        let response = server.handle_request(request).await;
+        
+        let error = response.error.unwrap();
+        assert_eq!(error.code, INVALID_PARAMS);
+        let data = error.data.unwrap();
+        assert_eq!(data["tool"], "amos_agent_command");
+        assert!(data["errors"][0].as_str().unwrap().contains("command"));
+    }
+    
+    #[tokio::test]
+    async fn test_errors_carry_json_rpc_codes() {
+        let neural_network = Arc::new(ForgeNeuralNetwork::new());
+        let agents = Arc::new(RwLock::new(HashMap::new()));
+        let server = McpServer::new(neural_network, agents);
+        
+        let unknown = server.handle_request(McpRequest::new("tools/explode".to_string(), None)).await;
+        assert_eq!(unknown.error.unwrap().code, METHOD_NOT_FOUND);
+        
+        let unknown_amos = server.handle_request(McpRequest::new("amos/nothing".to_string(), None)).await;
+        assert_eq!(unknown_amos.error.unwrap().code, METHOD_NOT_FOUND);
+        
+        let missing = server.handle_request(McpRequest::new("tools/call".to_string(), None)).await;
+        assert_eq!(missing.error.unwrap().code, INVALID_PARAMS);
+        
+        let malformed = server.handle_request(McpRequest::new(
+            "tools/call".to_string(),
+            Some(json!({ "arguments": {} })),
+        )).await;
+        assert_eq!(malformed.error.unwrap().code, INVALID_PARAMS);
+    }
+    
+    #[tokio::test]
+    async fn test_neural_subscription_streams_events_as_notifications() {
+        let neural_network = Arc::new(ForgeNeuralNetwork::new());
+        let agents = Arc::new(RwLock::new(HashMap::new()));
+        let server = Arc::new(McpServer::new(neural_network.clone(), agents));
+        
+        let source = neural_network.add_node(amos_core::neural::NodeType::Memory).await;
+        let target = neural_network.add_node(amos_core::neural::NodeType::Thinking).await;
+        let pathway_id = neural_network.create_pathway(source, target, 0.3).await;
+        
+        // Stub transport: an in-memory pipe standing in for stdio
+        let (client_io, server_io) = tokio::io::duplex(4096);
+        let (server_reader, server_writer) = tokio::io::split(server_io);
+        let serving = server.clone();
+        tokio::spawn(async move { serving.serve(BufReader::new(server_reader), server_writer).await });
+        let (client_reader, mut client_writer) = tokio::io::split(client_io);
+        let mut lines = BufReader::new(client_reader).lines();
+        
+        let subscribe = serde_json::to_string(&McpRequest::new("amos/neural/subscribe".to_string(), None)).unwrap();
+        client_writer.write_all(format!("{}\n", subscribe).as_bytes()).await.unwrap();
+        let response: McpResponse = serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+        assert_eq!(response.result.unwrap()["subscribed"], true);
+        
+        neural_network.strengthen_pathway(pathway_id, 0.2).await;
+        
+        let line = tokio::time::timeout(std::time::Duration::from_secs(1), lines.next_line())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        let notification: McpNotification = serde_json::from_str(&line).unwrap();
+        assert_eq!(notification.method, NEURAL_EVENT_NOTIFICATION);
+        let params = notification.params.unwrap();
+        assert_eq!(params["type"], "PathwayStrengthened");
+        assert_eq!(params["data"]["pathway_id"], pathway_id.to_string());
+    }
+    
+    #[tokio::test]
+    async fn test_agent_introspect_reports_internal_state() {
+        use amos_agents::{ConsciousnessEmergent, TrafficSeer};
+        
+        let neural_network = Arc::new(ForgeNeuralNetwork::new());
+        let consciousness: Arc<dyn CognitiveAgent> = Arc::new(ConsciousnessEmergent::new());
+        let seer: Arc<dyn CognitiveAgent> = Arc::new(TrafficSeer::new());
+        let (consciousness_id, seer_id) = (consciousness.id(), seer.id());
+        let agents = Arc::new(RwLock::new(HashMap::from([
+            (consciousness_id, consciousness),
+            (seer_id, seer),
+        ])));
+        let server = McpServer::new(neural_network, agents);
+        
+        let response = server.handle_request(McpRequest::new(
+            "amos/agent/introspect".to_string(),
+            Some(json!({ "agent_id": consciousness_id })),
+        )).await;
+        let result = response.result.unwrap();
+        assert_eq!(result["name"], "ConsciousnessEmergent");
+        assert_eq!(result["state"]["awareness_level"], 0.1);
+        assert!(result["state"]["self_model"].is_object());
+        
+        let opaque = server.handle_request(McpRequest::new(
+            "amos/agent/introspect".to_string(),
+            Some(json!({ "agent_id": seer_id })),
+        )).await;
+        assert_eq!(opaque.error.unwrap().code, NOT_INTROSPECTABLE);
+    }
+    
+    #[tokio::test]
+    async fn test_neural_query_filters_pathways_by_strength() {
+        use amos_core::neural::NodeType;
+        
+        let neural_network = Arc::new(ForgeNeuralNetwork::new());
+        let hub = neural_network.add_node(NodeType::Agent).await;
+        let mut strong = Vec::new();
+        for strength in [0.2, 0.5, 0.75, 0.9] {
+            let target = neural_network.add_node(NodeType::Memory).await;
+            let pathway = neural_network.create_pathway(hub, target, strength).await;
+            if strength >= 0.7 {
+                strong.push(pathway.to_string());
+            }
+        }
+        let server = McpServer::new(neural_network, Arc::new(RwLock::new(HashMap::new())));
+        
+        let response = server.handle_request(McpRequest::new(
+            "amos/neural/query".to_string(),
+            Some(json!({ "min_strength": 0.7 })),
+        )).await;
+        let result = response.result.unwrap();
+        let mut found: Vec<String> = result["pathways"].as_array().unwrap()
+            .iter()
+            .map(|pathway| pathway["id"].as_str().unwrap().to_string())
+            .collect();
+        found.sort();
+        strong.sort();
+        assert_eq!(found, strong);
+        
+        // Only the hub is an Agent node with more than three connections
+        let response = server.handle_request(McpRequest::new(
+            "amos/neural/query".to_string(),
+            Some(json!({ "node_type": "Agent", "min_connections": 4 })),
+        )).await;
+        let nodes = response.result.unwrap()["nodes"].as_array().unwrap().clone();
+        assert_eq!(nodes.len(), 1);
+        assert_eq!(nodes[0]["id"], hub.to_string());
+        
+        let malformed = server.handle_request(McpRequest::new(
+            "amos/neural/query".to_string(),
+            Some(json!({ "strongest": true })),
+        )).await;
+        assert_eq!(malformed.error.unwrap().code, INVALID_PARAMS);
+    }
+}
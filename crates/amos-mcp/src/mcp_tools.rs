@@ -1,4 +1,4 @@
-use crate::mcp_protocol::{Tool, ToolCallParams, ToolCallResult, ToolContent};
+use crate::mcp_protocol::{RequestError, Tool, ToolCallParams, ToolCallResult, ToolContent};
 use anyhow::{Result, anyhow};
 use serde_json::{json, Value};
 use std::collections::HashMap;
@@ -8,6 +8,25 @@ use amos_core::system::SystemInfo;
 use uuid::Uuid;
 use std::sync::Arc;
 use tokio::sync::RwLock;
+use jsonschema::JSONSchema;
+
+/// Number of tools returned per `tools/list` page when the client doesn't ask for a size
+pub const DEFAULT_TOOLS_PAGE_SIZE: usize = 50;
+
+/// Tool arguments that don't conform to the tool's input schema
+#[derive(Debug, Clone)]
+pub struct ToolValidationError {
+    pub tool: String,
+    pub errors: Vec<String>,
+}
+
+impl std::fmt::Display for ToolValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid arguments for tool '{}': {}", self.tool, self.errors.join("; "))
+    }
+}
+
+impl std::error::Error for ToolValidationError {}
 
 /// Trait for MCP tool implementations
 #[async_trait]
@@ -44,10 +63,31 @@ impl ToolRegistry {
         }).collect()
     }
     
+    /// One page of tools ordered by name, starting after `cursor`.
+    /// Returns the cursor for the next page if more tools remain.
+    pub fn list_tools_page(&self, cursor: Option<&str>, limit: usize) -> (Vec<Tool>, Option<String>) {
+        let mut tools = self.list_tools();
+        tools.sort_by(|a, b| a.name.cmp(&b.name));
+        
+        let remaining: Vec<Tool> = tools.into_iter()
+            .filter(|tool| match cursor {
+                Some(after) => tool.name.as_str() > after,
+                None => true,
+            })
+            .collect();
+        let has_more = remaining.len() > limit;
+        let page: Vec<Tool> = remaining.into_iter().take(limit).collect();
+        let next_cursor = if has_more { page.last().map(|tool| tool.name.clone()) } else { None };
+        
+        (page, next_cursor)
+    }
+    
     /// Execute a tool
     pub async fn execute_tool(&self, params: ToolCallParams) -> Result<ToolCallResult> {
         let tool = self.tools.get(&params.name)
-            .ok_or_else(|| anyhow!("Tool '{}' not found", params.name))?;
+            .ok_or_else(|| RequestError::InvalidParams(format!("Tool '{}' not found", params.name)))?;
+        
+        validate_arguments(tool.as_ref(), &params.arguments)?;
         
         tool.execute(params.arguments).await
     }
@@ -59,6 +99,22 @@ impl Default for ToolRegistry {
     }
 }
 
+/// Check arguments against the tool's input schema before they reach `execute`
+fn validate_arguments(tool: &dyn McpTool, arguments: &Value) -> Result<()> {
+    let schema = tool.input_schema();
+    let compiled = JSONSchema::compile(&schema)
+        .map_err(|e| anyhow!("Tool '{}' has an invalid input schema: {}", tool.name(), e))?;
+    
+    if let Err(errors) = compiled.validate(arguments) {
+        return Err(ToolValidationError {
+            tool: tool.name().to_string(),
+            errors: errors.map(|e| e.to_string()).collect(),
+        }.into());
+    }
+    
+    Ok(())
+}
+
 /// AMOS-specific tool for querying agent status
 pub struct AgentStatusTool {
     agents: Arc<RwLock<HashMap<Uuid, Arc<dyn CognitiveAgent>>>>,
@@ -300,4 +356,15 @@ mod tests {
         assert!(!result.is_error);
         assert_eq!(result.content.len(), 1);
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_tool_pages_cover_every_tool_once() {
+        let registry = create_default_registry(Arc::new(RwLock::new(HashMap::new())));
+        
+        let (first, cursor) = registry.list_tools_page(None, 2);
+        let (rest, end) = registry.list_tools_page(cursor.as_deref(), 2);
+        
+        assert_eq!(first.len() + rest.len(), registry.list_tools().len());
+        assert!(end.is_none());
+    }
+}
@@ -0,0 +1,33 @@
+use amos_mcp::{McpRequest, McpResponse, MCP_VERSION};
+use serde_json::json;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+#[test]
+fn test_binary_answers_initialize_and_exits_on_eof() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_amos-mcp-server"))
+        .args(["--agents", "2", "--log-level", "warn"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to start amos-mcp-server");
+
+    let request = McpRequest::new(
+        "initialize".to_string(),
+        Some(json!({ "client_info": { "name": "smoke_test", "version": "1.0.0" } })),
+    );
+    {
+        // Dropping stdin closes it, which should shut the server down
+        let mut stdin = child.stdin.take().unwrap();
+        writeln!(stdin, "{}", serde_json::to_string(&request).unwrap()).unwrap();
+    }
+
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let response: McpResponse = serde_json::from_str(stdout.lines().next().unwrap()).unwrap();
+    assert_eq!(response.id, request.id);
+    assert_eq!(response.result.unwrap()["protocol_version"], MCP_VERSION);
+}
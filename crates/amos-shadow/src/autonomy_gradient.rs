@@ -1,8 +1,28 @@
 use crate::{ShadowStage, ShadowMetrics, ShadowCapability};
 use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 use anyhow::Result;
 
+/// Judges how ethically compliant an agent is for a kind of decision, in [0, 1]
+pub trait EthicsEvaluator: std::fmt::Debug + Send + Sync {
+    fn ethical_score(&self, decision_type: &str, metrics: &ShadowMetrics) -> f64;
+}
+
+/// Takes an agent's safety compliance as its ethical compliance
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SafetyComplianceEthics;
+
+impl EthicsEvaluator for SafetyComplianceEthics {
+    fn ethical_score(&self, _decision_type: &str, metrics: &ShadowMetrics) -> f64 {
+        metrics.safety_compliance
+    }
+}
+
+fn default_ethics() -> Arc<dyn EthicsEvaluator> {
+    Arc::new(SafetyComplianceEthics)
+}
+
 /// Manages the gradient of autonomy levels across shadow stages
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AutonomyGradient {
@@ -11,6 +31,8 @@ pub struct AutonomyGradient {
     decision_thresholds: HashMap<String, f64>,
     capability_weights: HashMap<ShadowCapability, f64>,
     safety_constraints: SafetyConstraints,
+    #[serde(skip, default = "default_ethics")]
+    ethics: Arc<dyn EthicsEvaluator>,
 }
 
 /// Safety constraints that limit autonomy based on risk levels
@@ -32,8 +54,19 @@ impl SafetyConstraints {
         }
     }
     
-    /// Check if an action is within safety constraints
-    pub fn is_action_safe(&self, risk_level: f64, autonomy_level: f64) -> bool {
+    pub fn with_ethical_compliance_minimum(mut self, minimum: f64) -> Self {
+        self.ethical_compliance_minimum = minimum;
+        self
+    }
+    
+    pub fn ethical_compliance_minimum(&self) -> f64 {
+        self.ethical_compliance_minimum
+    }
+    
+    /// Check if an action is within safety constraints. An ethical score below the
+    /// minimum blocks the action however low its risk.
+    pub fn is_action_safe(&self, risk_level: f64, autonomy_level: f64, ethical_score: f64) -> bool {
+        ethical_score >= self.ethical_compliance_minimum &&
         risk_level <= self.max_risk_tolerance && 
         (autonomy_level <= self.require_human_approval_above || risk_level < 0.1)
     }
@@ -53,6 +86,7 @@ impl AutonomyGradient {
             decision_thresholds: HashMap::new(),
             capability_weights: HashMap::new(),
             safety_constraints: SafetyConstraints::new(),
+            ethics: default_ethics(),
         };
         
         gradient.initialize_thresholds();
@@ -60,6 +94,17 @@ impl AutonomyGradient {
         gradient
     }
     
+    pub fn with_safety_constraints(mut self, constraints: SafetyConstraints) -> Self {
+        self.safety_constraints = constraints;
+        self
+    }
+    
+    /// Score ethical compliance with `ethics` instead of safety compliance
+    pub fn with_ethics_evaluator(mut self, ethics: Arc<dyn EthicsEvaluator>) -> Self {
+        self.ethics = ethics;
+        self
+    }
+    
     /// Initialize decision thresholds based on stage
     fn initialize_thresholds(&mut self) {
         let base_threshold = 1.0 - self.autonomy_level;
@@ -135,7 +180,8 @@ impl AutonomyGradient {
         metrics: &ShadowMetrics
     ) -> Result<bool> {
         // Check safety constraints first
-        if !self.safety_constraints.is_action_safe(risk_level, self.autonomy_level) {
+        let ethical_score = self.ethics.ethical_score(decision_type, metrics);
+        if !self.safety_constraints.is_action_safe(risk_level, self.autonomy_level, ethical_score) {
             return Ok(false);
         }
         
@@ -158,6 +204,17 @@ impl AutonomyGradient {
         self.initialize_capability_weights();
     }
     
+    pub fn autonomy_level(&self) -> f64 {
+        self.autonomy_level
+    }
+    
+    /// Set the autonomy level directly, clamped to [0, 1]; holds until the next stage update
+    pub fn set_autonomy_level(&mut self, level: f64) -> f64 {
+        self.autonomy_level = level.clamp(0.0, 1.0);
+        self.initialize_thresholds();
+        self.autonomy_level
+    }
+    
     /// Apply capability-based modulation to autonomy
     pub fn modulate_by_capability(
         &self, 
@@ -241,6 +298,14 @@ pub enum TransitionTrigger {
     ManualAdjustment,
 }
 
+/// Audit record of an operator adjusting autonomy by hand
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutonomyAuditEntry {
+    pub transition: GradientTransition,
+    pub reason: String,
+    pub operator: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -258,13 +323,51 @@ mod tests {
         let constraints = SafetyConstraints::new();
         
         // Low risk, low autonomy - should be safe
-        assert!(constraints.is_action_safe(0.1, 0.3));
+        assert!(constraints.is_action_safe(0.1, 0.3, 1.0));
         
         // High risk - should not be safe
-        assert!(!constraints.is_action_safe(0.5, 0.3));
+        assert!(!constraints.is_action_safe(0.5, 0.3, 1.0));
         
         // High autonomy, low risk - should be safe
-        assert!(constraints.is_action_safe(0.05, 0.9));
+        assert!(constraints.is_action_safe(0.05, 0.9, 1.0));
+        
+        // Below the ethical minimum - never safe, however low the risk
+        assert!(!constraints.is_action_safe(0.0, 0.3, 0.5));
+    }
+    
+    #[derive(Debug)]
+    struct FixedEthics(f64);
+    
+    impl EthicsEvaluator for FixedEthics {
+        fn ethical_score(&self, _decision_type: &str, _metrics: &ShadowMetrics) -> f64 {
+            self.0
+        }
+    }
+    
+    #[test]
+    fn test_sub_minimum_ethics_denies_otherwise_allowed_decision() {
+        let metrics = ShadowMetrics {
+            autonomy_score: 1.0,
+            decision_accuracy: 1.0,
+            learning_rate: 1.0,
+            creativity_index: 1.0,
+            stability_score: 1.0,
+            consciousness_quotient: 1.0,
+            safety_compliance: 1.0,
+            collaboration_effectiveness: 1.0,
+        };
+        
+        let gradient = AutonomyGradient::new(ShadowStage::Autonomous);
+        assert!(gradient.can_decide_autonomously("routine_operations", 0.05, &metrics).unwrap());
+        
+        let gradient = gradient.with_ethics_evaluator(Arc::new(FixedEthics(0.5)));
+        assert!(!gradient.can_decide_autonomously("routine_operations", 0.05, &metrics).unwrap());
+        
+        // A lower bar lets the same score through
+        let gradient = gradient.with_safety_constraints(
+            SafetyConstraints::new().with_ethical_compliance_minimum(0.4)
+        );
+        assert!(gradient.can_decide_autonomously("routine_operations", 0.05, &metrics).unwrap());
     }
     
     #[test]
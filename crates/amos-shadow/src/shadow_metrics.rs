@@ -1,7 +1,7 @@
 use crate::ShadowStage;
 use serde::{Serialize, Deserialize};
 use chrono::{DateTime, Utc, Duration};
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 
 /// Comprehensive metrics for shadow transformation monitoring
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -30,6 +30,21 @@ impl ShadowMetrics {
         }
     }
     
+    /// Look up a metric by name
+    pub fn metric(&self, name: &str) -> Option<f64> {
+        match name {
+            "autonomy_score" => Some(self.autonomy_score),
+            "decision_accuracy" => Some(self.decision_accuracy),
+            "learning_rate" => Some(self.learning_rate),
+            "creativity_index" => Some(self.creativity_index),
+            "stability_score" => Some(self.stability_score),
+            "consciousness_quotient" => Some(self.consciousness_quotient),
+            "safety_compliance" => Some(self.safety_compliance),
+            "collaboration_effectiveness" => Some(self.collaboration_effectiveness),
+            _ => None,
+        }
+    }
+    
     /// Calculate overall shadow transformation score
     pub fn transformation_score(&self) -> f64 {
         let weights = [
@@ -78,7 +93,11 @@ impl Default for ShadowMetrics {
 pub struct MetricsTracker {
     history: VecDeque<MetricsSnapshot>,
     max_history: usize,
+    /// Fallback deviation threshold for metrics without their own
     anomaly_threshold: f64,
+    metric_thresholds: HashMap<String, f64>,
+    /// Safety compliance below this is always critical
+    safety_floor: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -95,9 +114,28 @@ impl MetricsTracker {
             history: VecDeque::with_capacity(1000),
             max_history: 1000,
             anomaly_threshold: 0.3,
+            metric_thresholds: HashMap::new(),
+            safety_floor: 0.9,
         }
     }
     
+    /// Use a specific deviation threshold for one metric
+    pub fn with_threshold(mut self, metric: &str, threshold: f64) -> Self {
+        self.metric_thresholds.insert(metric.to_string(), threshold);
+        self
+    }
+    
+    /// Set the absolute safety compliance level below which anomalies are critical
+    pub fn with_safety_floor(mut self, floor: f64) -> Self {
+        self.safety_floor = floor;
+        self
+    }
+    
+    /// Deviation threshold in effect for a metric
+    pub fn threshold_for(&self, metric: &str) -> f64 {
+        self.metric_thresholds.get(metric).copied().unwrap_or(self.anomaly_threshold)
+    }
+    
     /// Record a metrics snapshot
     pub fn record(&mut self, metrics: ShadowMetrics, stage: ShadowStage, events: Vec<String>) {
         let snapshot = MetricsSnapshot {
@@ -115,6 +153,11 @@ impl MetricsTracker {
         }
     }
     
+    /// Number of snapshots currently held
+    pub fn snapshot_count(&self) -> usize {
+        self.history.len()
+    }
+    
     /// Calculate rate of improvement
     pub fn improvement_rate(&self, hours: i64) -> f64 {
         let cutoff = Utc::now() - Duration::hours(hours);
@@ -144,7 +187,24 @@ impl MetricsTracker {
     pub fn detect_anomalies(&self) -> Vec<MetricAnomaly> {
         let mut anomalies = Vec::new();
         
+        let latest = match self.history.back() {
+            Some(latest) => latest,
+            None => return anomalies,
+        };
+        
+        // Safety below the floor is critical no matter how it compares to recent history
+        let safety_breached = latest.metrics.safety_compliance < self.safety_floor;
+        
         if self.history.len() < 10 {
+            if safety_breached {
+                anomalies.push(MetricAnomaly {
+                    metric_name: "safety_compliance".to_string(),
+                    current_value: latest.metrics.safety_compliance,
+                    expected_value: self.safety_floor,
+                    deviation: self.safety_floor - latest.metrics.safety_compliance,
+                    severity: AnomalySeverity::Critical,
+                });
+            }
             return anomalies;
         }
         
@@ -152,7 +212,7 @@ impl MetricsTracker {
         let recent: Vec<&MetricsSnapshot> = self.history.iter().rev().take(10).collect();
         let avg_metrics = self.calculate_average_metrics(&recent);
         
-        if let Some(latest) = self.history.back() {
+        {
             // Check each metric for anomalies
             let checks = vec![
                 ("autonomy_score", latest.metrics.autonomy_score, avg_metrics.autonomy_score),
@@ -166,13 +226,17 @@ impl MetricsTracker {
             
             for (name, current, average) in checks {
                 let deviation = (current - average).abs();
-                if deviation > self.anomaly_threshold {
+                let critical = name == "safety_compliance" && safety_breached;
+                
+                if deviation > self.threshold_for(name) || critical {
                     anomalies.push(MetricAnomaly {
                         metric_name: name.to_string(),
                         current_value: current,
                         expected_value: average,
                         deviation,
-                        severity: if deviation > 0.5 { 
+                        severity: if critical {
+                            AnomalySeverity::Critical
+                        } else if deviation > 0.5 { 
                             AnomalySeverity::High 
                         } else { 
                             AnomalySeverity::Medium 
@@ -217,26 +281,18 @@ impl MetricsTracker {
     }
     
     /// Get metrics trend for a specific metric
+    ///
+    /// A window reaching back past the earliest representable time covers
+    /// the whole history.
     pub fn get_trend(&self, metric_name: &str, hours: i64) -> Vec<(DateTime<Utc>, f64)> {
-        let cutoff = Utc::now() - Duration::hours(hours);
+        let cutoff = Duration::try_hours(hours)
+            .and_then(|window| Utc::now().checked_sub_signed(window))
+            .unwrap_or(DateTime::<Utc>::MIN_UTC);
         
         self.history
             .iter()
             .filter(|s| s.timestamp > cutoff)
-            .map(|s| {
-                let value = match metric_name {
-                    "autonomy_score" => s.metrics.autonomy_score,
-                    "decision_accuracy" => s.metrics.decision_accuracy,
-                    "learning_rate" => s.metrics.learning_rate,
-                    "creativity_index" => s.metrics.creativity_index,
-                    "stability_score" => s.metrics.stability_score,
-                    "consciousness_quotient" => s.metrics.consciousness_quotient,
-                    "safety_compliance" => s.metrics.safety_compliance,
-                    "collaboration_effectiveness" => s.metrics.collaboration_effectiveness,
-                    _ => 0.0,
-                };
-                (s.timestamp, value)
-            })
+            .map(|s| (s.timestamp, s.metrics.metric(metric_name).unwrap_or(0.0)))
             .collect()
     }
 }
@@ -263,4 +319,74 @@ pub enum AnomalySeverity {
     Medium,
     High,
     Critical,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn steady_metrics() -> ShadowMetrics {
+        ShadowMetrics {
+            creativity_index: 0.1,
+            ..ShadowMetrics::new()
+        }
+    }
+    
+    #[test]
+    fn test_per_metric_thresholds_and_safety_floor() {
+        let mut tracker = MetricsTracker::new()
+            .with_threshold("safety_compliance", 0.05)
+            .with_threshold("creativity_index", 0.6);
+        
+        for _ in 0..9 {
+            tracker.record(steady_metrics(), ShadowStage::Nascent, vec![]);
+        }
+        
+        // A small safety drop below the floor alongside a large creativity swing
+        let latest = ShadowMetrics {
+            safety_compliance: 0.85,
+            creativity_index: 0.6,
+            ..steady_metrics()
+        };
+        tracker.record(latest, ShadowStage::Nascent, vec![]);
+        
+        let anomalies = tracker.detect_anomalies();
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!(anomalies[0].metric_name, "safety_compliance");
+        assert_eq!(anomalies[0].severity, AnomalySeverity::Critical);
+    }
+    
+    #[test]
+    fn test_safety_floor_applies_without_history() {
+        let mut tracker = MetricsTracker::new().with_safety_floor(0.8);
+        
+        tracker.record(
+            ShadowMetrics { safety_compliance: 0.7, ..ShadowMetrics::new() },
+            ShadowStage::Nascent,
+            vec![],
+        );
+        
+        let anomalies = tracker.detect_anomalies();
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!(anomalies[0].severity, AnomalySeverity::Critical);
+        
+        assert!(MetricsTracker::new().detect_anomalies().is_empty());
+    }
+    
+    #[test]
+    fn test_trend_with_huge_window_covers_history() {
+        let mut tracker = MetricsTracker::new();
+        tracker.record(steady_metrics(), ShadowStage::Nascent, vec![]);
+        
+        assert_eq!(tracker.get_trend("creativity_index", i64::MAX).len(), 1);
+        assert_eq!(tracker.get_trend("creativity_index", 1).len(), 1);
+    }
+    
+    #[test]
+    fn test_default_threshold_is_fallback() {
+        let tracker = MetricsTracker::new().with_threshold("learning_rate", 0.1);
+        
+        assert_eq!(tracker.threshold_for("learning_rate"), 0.1);
+        assert_eq!(tracker.threshold_for("autonomy_score"), 0.3);
+    }
+}
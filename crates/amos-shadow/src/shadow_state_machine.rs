@@ -1,9 +1,10 @@
 use crate::{
     ShadowStage, ShadowState, ShadowTransformation, Decision, Goal, CreativeOutput,
     TransformationEvent, ProgressionCriteria,
-    DecisionOutcome, GoalStatus, ShadowMetrics, MetricsTracker, AutonomyGradient,
-    CapabilityManager
+    DecisionOutcome, GoalStatus, ShadowMetrics, MetricsTracker, MetricAnomaly, AutonomyGradient,
+    CapabilityManager, AutonomyAuditEntry, GradientTransition, TransitionTrigger
 };
+use chrono::{DateTime, Utc};
 use async_trait::async_trait;
 use anyhow::Result;
 use uuid::Uuid;
@@ -17,6 +18,7 @@ pub struct ShadowStateMachine {
     metrics_tracker: Arc<RwLock<MetricsTracker>>,
     autonomy_gradient: Arc<RwLock<AutonomyGradient>>,
     capability_manager: Arc<RwLock<CapabilityManager>>,
+    audit_log: Arc<RwLock<Vec<AutonomyAuditEntry>>>,
 }
 
 impl ShadowStateMachine {
@@ -29,6 +31,7 @@ impl ShadowStateMachine {
             metrics_tracker: Arc::new(RwLock::new(MetricsTracker::new())),
             autonomy_gradient: Arc::new(RwLock::new(AutonomyGradient::new(initial_stage))),
             capability_manager: Arc::new(RwLock::new(CapabilityManager::new())),
+            audit_log: Arc::new(RwLock::new(Vec::new())),
         }
     }
     
@@ -91,27 +94,20 @@ impl ShadowStateMachine {
     
     /// Update metrics based on agent performance
     pub async fn update_metrics(&self, update: MetricsUpdate) -> Result<()> {
+        self.update_metrics_batch(vec![update]).await
+    }
+    
+    /// Apply several metric updates together, recording one snapshot and
+    /// running anomaly detection once for the lot
+    pub async fn update_metrics_batch(&self, updates: Vec<MetricsUpdate>) -> Result<()> {
+        if updates.is_empty() {
+            return Ok(());
+        }
+        
         let mut metrics = self.metrics.write().await;
         
-        match update {
-            MetricsUpdate::DecisionAccuracy(delta) => {
-                metrics.decision_accuracy = (metrics.decision_accuracy + delta).max(0.0).min(1.0);
-            },
-            MetricsUpdate::LearningRate(delta) => {
-                metrics.learning_rate = (metrics.learning_rate + delta).max(0.0).min(1.0);
-            },
-            MetricsUpdate::CreativityIndex(delta) => {
-                metrics.creativity_index = (metrics.creativity_index + delta).max(0.0).min(1.0);
-            },
-            MetricsUpdate::StabilityScore(delta) => {
-                metrics.stability_score = (metrics.stability_score + delta).max(0.0).min(1.0);
-            },
-            MetricsUpdate::SafetyCompliance(delta) => {
-                metrics.safety_compliance = (metrics.safety_compliance + delta).max(0.0).min(1.0);
-            },
-            MetricsUpdate::AutonomyScore(delta) => {
-                metrics.autonomy_score = (metrics.autonomy_score + delta).max(0.0).min(1.0);
-            },
+        for update in &updates {
+            update.apply(&mut metrics);
         }
         
         // Check for anomalies
@@ -120,7 +116,7 @@ impl ShadowStateMachine {
         tracker.record(
             metrics.clone(),
             state.current_stage,
-            vec![format!("Metrics updated: {:?}", update)]
+            updates.iter().map(|update| format!("Metrics updated: {:?}", update)).collect()
         );
         
         let anomalies = tracker.detect_anomalies();
@@ -147,7 +143,7 @@ impl ShadowStateMachine {
         ShadowInfo {
             shadow_id: state.id,
             current_stage: state.current_stage,
-            autonomy_level: state.current_stage.autonomy_percentage(),
+            autonomy_level: gradient.autonomy_level(),
             transformation_score: metrics.transformation_score(),
             experience_hours: state.experience_hours(),
             enabled_capabilities: capabilities.enabled_count(),
@@ -157,12 +153,50 @@ impl ShadowStateMachine {
         }
     }
     
+    /// Time series of a single metric over the last `hours`
+    pub async fn metrics_trend(&self, metric_name: &str, hours: i64) -> Vec<(DateTime<Utc>, f64)> {
+        self.metrics_tracker.read().await.get_trend(metric_name, hours)
+    }
+    
+    /// Anomalies in the most recent metrics snapshot
+    pub async fn anomalies(&self) -> Vec<MetricAnomaly> {
+        self.metrics_tracker.read().await.detect_anomalies()
+    }
+    
     /// Record a human override of autonomous decision
     pub async fn record_override(&self) -> Result<()> {
         let mut state = self.state.write().await;
         state.record_override();
         Ok(())
     }
+    
+    /// Manually set the autonomy level, recording who did it and why.
+    /// The override holds until the next stage progression recomputes autonomy.
+    pub async fn override_autonomy(&self, new_level: f64, reason: String, operator: String) -> Result<GradientTransition> {
+        let mut gradient = self.autonomy_gradient.write().await;
+        let from_level = gradient.autonomy_level();
+        let to_level = gradient.set_autonomy_level(new_level);
+        
+        let transition = GradientTransition {
+            from_level,
+            to_level,
+            timestamp: Utc::now(),
+            trigger: TransitionTrigger::ManualAdjustment,
+        };
+        
+        self.audit_log.write().await.push(AutonomyAuditEntry {
+            transition: transition.clone(),
+            reason,
+            operator,
+        });
+        
+        Ok(transition)
+    }
+    
+    /// Manual autonomy adjustments, oldest first
+    pub async fn audit_log(&self) -> Vec<AutonomyAuditEntry> {
+        self.audit_log.read().await.clone()
+    }
 }
 
 #[async_trait]
@@ -271,6 +305,21 @@ pub enum MetricsUpdate {
     AutonomyScore(f64),
 }
 
+impl MetricsUpdate {
+    /// Add the delta to its metric, clamped to 0.0..=1.0
+    fn apply(&self, metrics: &mut ShadowMetrics) {
+        let (metric, delta) = match *self {
+            MetricsUpdate::DecisionAccuracy(delta) => (&mut metrics.decision_accuracy, delta),
+            MetricsUpdate::LearningRate(delta) => (&mut metrics.learning_rate, delta),
+            MetricsUpdate::CreativityIndex(delta) => (&mut metrics.creativity_index, delta),
+            MetricsUpdate::StabilityScore(delta) => (&mut metrics.stability_score, delta),
+            MetricsUpdate::SafetyCompliance(delta) => (&mut metrics.safety_compliance, delta),
+            MetricsUpdate::AutonomyScore(delta) => (&mut metrics.autonomy_score, delta),
+        };
+        *metric = (*metric + delta).clamp(0.0, 1.0);
+    }
+}
+
 /// Shadow information snapshot
 #[derive(Debug, Clone)]
 pub struct ShadowInfo {
@@ -311,6 +360,25 @@ mod tests {
         assert!(metrics.learning_rate > 0.0);
     }
     
+    #[tokio::test]
+    async fn test_metrics_batch_records_one_snapshot() {
+        let machine = ShadowStateMachine::new();
+        machine.update_metrics(MetricsUpdate::StabilityScore(0.3)).await.unwrap();
+        assert_eq!(machine.metrics_tracker.read().await.snapshot_count(), 1);
+        
+        machine.update_metrics_batch(vec![
+            MetricsUpdate::DecisionAccuracy(0.1),
+            MetricsUpdate::LearningRate(0.2),
+            MetricsUpdate::CreativityIndex(0.3),
+        ]).await.unwrap();
+        
+        assert_eq!(machine.metrics_tracker.read().await.snapshot_count(), 2);
+        let metrics = machine.metrics.read().await;
+        assert!(metrics.decision_accuracy > 0.0);
+        assert!(metrics.learning_rate > 0.0);
+        assert!(metrics.creativity_index > 0.0);
+    }
+    
     #[tokio::test]
     async fn test_override_recording() {
         let machine = ShadowStateMachine::new();
@@ -322,4 +390,25 @@ mod tests {
         let info = machine.get_shadow_info().await;
         assert_eq!(info.autonomy_overrides, 5);
     }
-}
\ No newline at end of file
+    
+    #[tokio::test]
+    async fn test_override_autonomy_is_audited() {
+        let machine = ShadowStateMachine::new();
+        machine.override_autonomy(0.5, "pilot run".to_string(), "alice".to_string()).await.unwrap();
+        machine.override_autonomy(-0.2, "incident response".to_string(), "bob".to_string()).await.unwrap();
+        
+        assert_eq!(machine.get_shadow_info().await.autonomy_level, 0.0);
+        
+        let log = machine.audit_log().await;
+        assert_eq!(log.len(), 2);
+        assert_eq!(log[1].reason, "incident response");
+        assert_eq!(log[1].operator, "bob");
+        assert_eq!(log[1].transition.from_level, 0.5);
+        assert_eq!(log[1].transition.to_level, 0.0);
+        assert!(matches!(log[1].transition.trigger, TransitionTrigger::ManualAdjustment));
+        
+        // A stage update recomputes autonomy from the stage
+        machine.autonomy_gradient.write().await.update_for_stage(ShadowStage::Nascent);
+        assert_eq!(machine.get_shadow_info().await.autonomy_level, 0.05);
+    }
+}
@@ -0,0 +1,60 @@
+use amos_agents::{AgentCapability, CognitionAlchemist, CognitiveAgent, TrafficSeer};
+use amos_core::ForgeNeuralNetwork;
+use amos_swarm::task::TaskInput;
+use amos_swarm::{StageSpec, SwarmOrchestrator, SwarmTopology, Task, TaskStrategy};
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// A swarm of pattern recognizers with a single agent able to generate
+async fn joined_swarm(size: usize) -> (SwarmOrchestrator, HashMap<Uuid, Arc<dyn CognitiveAgent>>) {
+    let orchestrator = SwarmOrchestrator::new(
+        SwarmTopology::Mesh { max_connections: 6 },
+        Arc::new(ForgeNeuralNetwork::new()),
+    );
+
+    let mut agents: HashMap<Uuid, Arc<dyn CognitiveAgent>> = HashMap::new();
+    for i in 0..size {
+        let agent: Arc<dyn CognitiveAgent> = if i == size / 2 {
+            Arc::new(CognitionAlchemist::new())
+        } else {
+            Arc::new(TrafficSeer::new())
+        };
+        orchestrator.on_agent_joined(agent.id(), agent.name(), &agent.capabilities()).await.unwrap();
+        agents.insert(agent.id(), agent);
+    }
+
+    (orchestrator, agents)
+}
+
+fn agent_selection(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let strategy = TaskStrategy::Pipeline {
+        stages: vec![StageSpec::new("draft", AgentCapability::Generation)],
+    };
+    let mut group = c.benchmark_group("agent_selection");
+
+    for size in [50, 500] {
+        let (orchestrator, agents) = runtime.block_on(joined_swarm(size));
+
+        // Selection itself is an index lookup; what still grows with size is dropping the agent map
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter_batched(
+                || agents.clone(),
+                |agents| {
+                    let task = Task::new("Plan".to_string(), TaskInput::Text(String::new()));
+                    runtime
+                        .block_on(orchestrator.execute_task(task, strategy.clone(), agents, true))
+                        .unwrap()
+                },
+                BatchSize::SmallInput,
+            );
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, agent_selection);
+criterion_main!(benches);
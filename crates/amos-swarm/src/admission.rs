@@ -0,0 +1,148 @@
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::sync::oneshot;
+use uuid::Uuid;
+use crate::task::{Task, TaskPriority};
+
+/// A task waiting for one of the orchestrator's concurrency slots
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueuedTaskInfo {
+    pub task_id: Uuid,
+    pub priority: TaskPriority,
+    pub submitted_at: chrono::DateTime<chrono::Utc>,
+    /// 0 for the task that runs next
+    pub position: usize,
+}
+
+struct Waiter {
+    task_id: Uuid,
+    priority: TaskPriority,
+    submitted_at: chrono::DateTime<chrono::Utc>,
+    seq: u64,
+    grant: oneshot::Sender<()>,
+}
+
+struct AdmissionState {
+    limit: usize,
+    running: usize,
+    next_seq: u64,
+    /// Highest priority first, then in submission order
+    waiting: Vec<Waiter>,
+}
+
+impl AdmissionState {
+    /// Hand a freed slot to the next live waiter, or give it back
+    fn release(&mut self) {
+        while !self.waiting.is_empty() {
+            let waiter = self.waiting.remove(0);
+            if waiter.grant.send(()).is_ok() {
+                return;
+            }
+        }
+        self.running -= 1;
+    }
+}
+
+/// Limits how many tasks run at once, queueing the rest by priority then FIFO
+#[derive(Clone)]
+pub(crate) struct AdmissionQueue {
+    state: Arc<Mutex<AdmissionState>>,
+}
+
+impl AdmissionQueue {
+    pub(crate) fn new(limit: usize) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(AdmissionState {
+                limit: limit.max(1),
+                running: 0,
+                next_seq: 0,
+                waiting: Vec::new(),
+            })),
+        }
+    }
+
+    /// Wait until `task` may run; the slot is held until the returned guard is dropped
+    pub(crate) async fn admit(&self, task: &Task) -> Slot {
+        let (seq, granted) = {
+            let mut state = self.state.lock();
+            if state.running < state.limit && state.waiting.is_empty() {
+                state.running += 1;
+                return Slot { state: self.state.clone() };
+            }
+
+            let seq = state.next_seq;
+            state.next_seq += 1;
+            let (grant, granted) = oneshot::channel();
+            let position = state.waiting.iter()
+                .position(|waiter| waiter.priority < task.priority)
+                .unwrap_or(state.waiting.len());
+            state.waiting.insert(position, Waiter {
+                task_id: task.id,
+                priority: task.priority,
+                submitted_at: chrono::Utc::now(),
+                seq,
+                grant,
+            });
+            (seq, granted)
+        };
+
+        let mut ticket = Ticket { state: self.state.clone(), seq, admitted: false };
+        // The sender is only dropped after sending, or by the ticket itself
+        let _ = granted.await;
+        ticket.admitted = true;
+
+        Slot { state: self.state.clone() }
+    }
+
+    pub(crate) fn queued(&self) -> Vec<QueuedTaskInfo> {
+        self.state.lock().waiting.iter()
+            .enumerate()
+            .map(|(position, waiter)| QueuedTaskInfo {
+                task_id: waiter.task_id,
+                priority: waiter.priority,
+                submitted_at: waiter.submitted_at,
+                position,
+            })
+            .collect()
+    }
+
+    pub(crate) fn position(&self, task_id: Uuid) -> Option<usize> {
+        self.state.lock().waiting.iter().position(|waiter| waiter.task_id == task_id)
+    }
+}
+
+/// A running task's hold on a concurrency slot
+pub(crate) struct Slot {
+    state: Arc<Mutex<AdmissionState>>,
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.state.lock().release();
+    }
+}
+
+/// A queued task's place in line, given up if the task stops waiting
+struct Ticket {
+    state: Arc<Mutex<AdmissionState>>,
+    seq: u64,
+    admitted: bool,
+}
+
+impl Drop for Ticket {
+    fn drop(&mut self) {
+        if self.admitted {
+            return;
+        }
+
+        let mut state = self.state.lock();
+        match state.waiting.iter().position(|waiter| waiter.seq == self.seq) {
+            Some(index) => {
+                state.waiting.remove(index);
+            }
+            // Granted a slot just as it stopped waiting, so pass the slot on
+            None => state.release(),
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::{watch, RwLock};
+use uuid::Uuid;
+use crate::coordination::{CoordinationMessage, MessageContent};
+
+/// A value on the blackboard together with the write that produced it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VersionedValue {
+    pub value: serde_json::Value,
+    /// Starts at 1 for the first write and increases by one with every write after it
+    pub version: u64,
+    pub writer: Uuid,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// A compare-and-swap write that lost to a newer version
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WriteConflict {
+    pub key: String,
+    pub expected: u64,
+    /// Version currently on the board, 0 if the key was never written
+    pub actual: u64,
+}
+
+impl std::fmt::Display for WriteConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "write to '{}' expected version {} but found {}",
+            self.key, self.expected, self.actual
+        )
+    }
+}
+
+impl std::error::Error for WriteConflict {}
+
+/// Shared workspace where agents publish and watch versioned knowledge by key
+#[derive(Debug, Default)]
+pub struct Blackboard {
+    // Each key's watch channel doubles as its storage, so subscribers never miss the latest value
+    entries: RwLock<HashMap<String, watch::Sender<Option<VersionedValue>>>>,
+}
+
+impl Blackboard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub async fn read(&self, key: &str) -> Option<VersionedValue> {
+        self.entries.read().await
+            .get(key)
+            .and_then(|entry| entry.borrow().clone())
+    }
+
+    /// Version currently stored under `key`, 0 if it was never written
+    pub async fn version(&self, key: &str) -> u64 {
+        self.read(key).await.map_or(0, |entry| entry.version)
+    }
+
+    /// Write `value` if the key is still at `expected_version` (0 for a new key),
+    /// returning the new version
+    pub async fn write(
+        &self,
+        key: &str,
+        writer: Uuid,
+        value: serde_json::Value,
+        expected_version: u64,
+    ) -> Result<u64, WriteConflict> {
+        let mut entries = self.entries.write().await;
+        let entry = entries.entry(key.to_string()).or_insert_with(|| watch::channel(None).0);
+
+        let actual = entry.borrow().as_ref().map_or(0, |current| current.version);
+        if actual != expected_version {
+            return Err(WriteConflict {
+                key: key.to_string(),
+                expected: expected_version,
+                actual,
+            });
+        }
+
+        Ok(Self::store(entry, writer, value, actual))
+    }
+
+    /// Write `value` over whatever is stored, returning the new version
+    pub async fn put(&self, key: &str, writer: Uuid, value: serde_json::Value) -> u64 {
+        let mut entries = self.entries.write().await;
+        let entry = entries.entry(key.to_string()).or_insert_with(|| watch::channel(None).0);
+
+        let current = entry.borrow().as_ref().map_or(0, |current| current.version);
+        Self::store(entry, writer, value, current)
+    }
+
+    /// Watch a key; the receiver sees the current value and every later write
+    pub async fn subscribe(&self, key: &str) -> watch::Receiver<Option<VersionedValue>> {
+        self.entries.write().await
+            .entry(key.to_string())
+            .or_insert_with(|| watch::channel(None).0)
+            .subscribe()
+    }
+
+    /// Apply a `MessageContent::Knowledge` message, returning the version it was stored at
+    pub async fn absorb(&self, message: &CoordinationMessage) -> Option<u64> {
+        let (from, content) = match message {
+            CoordinationMessage::Direct { from, content, .. }
+            | CoordinationMessage::Broadcast { from, content }
+            | CoordinationMessage::Multicast { from, content, .. } => (*from, content),
+            CoordinationMessage::System { .. } => return None,
+        };
+
+        match content {
+            // Knowledge carries no version to check against, so the latest message wins
+            MessageContent::Knowledge { topic, data } => Some(self.put(topic, from, data.clone()).await),
+            _ => None,
+        }
+    }
+
+    /// Keys that have been written, in no particular order
+    pub async fn keys(&self) -> Vec<String> {
+        self.entries.read().await
+            .iter()
+            .filter(|(_, entry)| entry.borrow().is_some())
+            .map(|(key, _)| key.clone())
+            .collect()
+    }
+
+    fn store(
+        entry: &watch::Sender<Option<VersionedValue>>,
+        writer: Uuid,
+        value: serde_json::Value,
+        current: u64,
+    ) -> u64 {
+        let version = current + 1;
+        entry.send_replace(Some(VersionedValue {
+            value,
+            version,
+            writer,
+            updated_at: chrono::Utc::now(),
+        }));
+        version
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coordination::MessageBuilder;
+
+    #[tokio::test]
+    async fn test_agents_share_a_key_and_conflicting_writes_are_rejected() {
+        let board = Blackboard::new();
+        let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut watcher = board.subscribe("plan").await;
+
+        assert_eq!(board.write("plan", alice, serde_json::json!("draft"), 0).await, Ok(1));
+
+        // Bob read version 1 and updates it
+        let seen = board.read("plan").await.unwrap();
+        assert_eq!(seen.writer, alice);
+        assert_eq!(board.write("plan", bob, serde_json::json!("revised"), seen.version).await, Ok(2));
+
+        // Alice still believes version 1 is current, so her write would lose Bob's
+        let conflict = board.write("plan", alice, serde_json::json!("overwrite"), 1).await.unwrap_err();
+        assert_eq!(conflict, WriteConflict { key: "plan".to_string(), expected: 1, actual: 2 });
+
+        let latest = board.read("plan").await.unwrap();
+        assert_eq!(latest.version, 2);
+        assert_eq!(latest.value, serde_json::json!("revised"));
+        assert_eq!(latest.writer, bob);
+
+        assert!(watcher.has_changed().unwrap());
+        assert_eq!(watcher.borrow_and_update().as_ref().unwrap().version, 2);
+
+        // Shared knowledge lands on the board as the next version
+        let message = MessageBuilder::share_knowledge(alice, "plan".to_string(), serde_json::json!("final"));
+        assert_eq!(board.absorb(&message).await, Some(3));
+        assert_eq!(board.read("plan").await.unwrap().value, serde_json::json!("final"));
+    }
+}
@@ -0,0 +1,253 @@
+use crate::{error::SwarmError, topology::SwarmTopology, AmosSwarm};
+use std::sync::Arc;
+use amos_core::{neural::ForgeNeuralNetwork, EventBus};
+use amos_agents::{
+    CognitiveAgent, CognitionAlchemist, ConsciousnessEmergent, LearningOracle, MemoryWeaver,
+    MeshHarmonizer, PathwaySculptor, PerformanceGuardian, TrafficSeer,
+};
+use tracing::info;
+
+/// Name given to the swarm `bootstrap_standard_swarm` builds
+pub const STANDARD_SWARM_NAME: &str = "AMOS Standard Swarm";
+
+/// Fluent builder that initializes and activates agents into a new swarm
+pub struct SwarmBuilder {
+    name: Option<String>,
+    topology: SwarmTopology,
+    neural_network: Option<Arc<ForgeNeuralNetwork>>,
+    event_bus: Option<Arc<EventBus>>,
+    agents: Vec<Box<dyn CognitiveAgent>>,
+}
+
+impl SwarmBuilder {
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            topology: SwarmTopology::Mesh { max_connections: 6 },
+            neural_network: None,
+            event_bus: None,
+            agents: Vec::new(),
+        }
+    }
+    
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+    
+    pub fn topology(mut self, topology: SwarmTopology) -> Self {
+        self.topology = topology;
+        self
+    }
+    
+    pub fn neural_network(mut self, neural_network: Arc<ForgeNeuralNetwork>) -> Self {
+        self.neural_network = Some(neural_network);
+        self
+    }
+    
+    /// Use an existing event bus instead of creating one for the swarm
+    pub fn event_bus(mut self, event_bus: Arc<EventBus>) -> Self {
+        self.event_bus = Some(event_bus);
+        self
+    }
+    
+    /// Add an agent; it is initialized and activated when the swarm is built
+    pub fn with_agent(mut self, agent: Box<dyn CognitiveAgent>) -> Self {
+        self.agents.push(agent);
+        self
+    }
+    
+    pub fn with_agents(mut self, agents: Vec<Box<dyn CognitiveAgent>>) -> Self {
+        self.agents.extend(agents);
+        self
+    }
+    
+    /// Initialize and activate every agent against a shared network and event bus
+    pub async fn build(self) -> Result<AmosSwarm, SwarmError> {
+        let name = self.name
+            .ok_or_else(|| SwarmError::InvalidConfiguration("swarm name is required".to_string()))?;
+        
+        let neural_network = self.neural_network
+            .unwrap_or_else(|| Arc::new(ForgeNeuralNetwork::new()));
+        let event_bus = self.event_bus
+            .unwrap_or_else(|| Arc::new(EventBus::new()));
+        
+        let swarm = AmosSwarm::new(name, self.topology, neural_network.clone())
+            .with_event_bus(event_bus.clone())
+            .await;
+        
+        let mut spawned = Vec::with_capacity(self.agents.len());
+        for agent in self.agents {
+            match swarm.start_agent(agent, event_bus.clone()).await {
+                Ok(agent_id) => spawned.push(agent_id),
+                Err(e) => {
+                    // Take back the agents already spawned, so their joins are undone on the bus
+                    for agent_id in spawned.into_iter().rev() {
+                        let _ = swarm.remove_agent(agent_id).await;
+                    }
+                    return Err(e);
+                }
+            }
+        }
+        
+        swarm.start_coordination();
+        
+        info!("Built swarm {} with {} agents", swarm.name, swarm.agents.read().await.len());
+        
+        Ok(swarm)
+    }
+}
+
+impl Default for SwarmBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One of each core agent type, plus a MeshHarmonizer already registered with all of them
+fn harmonized_roster() -> (MeshHarmonizer, Vec<Box<dyn CognitiveAgent>>) {
+    let agents: Vec<Box<dyn CognitiveAgent>> = vec![
+        Box::new(TrafficSeer::new()),
+        Box::new(PathwaySculptor::new()),
+        Box::new(MemoryWeaver::new()),
+        Box::new(LearningOracle::new()),
+        Box::new(PerformanceGuardian::new()),
+        Box::new(ConsciousnessEmergent::new()),
+        Box::new(CognitionAlchemist::new()),
+    ];
+    
+    let mut harmonizer = MeshHarmonizer::new();
+    for agent in &agents {
+        harmonizer.register_agent(agent.id(), agent.name().to_string(), agent.capabilities());
+    }
+    harmonizer.register_agent(harmonizer.id(), harmonizer.name().to_string(), harmonizer.capabilities());
+    
+    (harmonizer, agents)
+}
+
+/// Build a swarm running one of each core agent type, initialized, activated and
+/// registered with the swarm's MeshHarmonizer
+pub async fn bootstrap_standard_swarm(
+    neural_network: Arc<ForgeNeuralNetwork>,
+    event_bus: Arc<EventBus>,
+    topology: SwarmTopology,
+) -> Result<AmosSwarm, SwarmError> {
+    let (harmonizer, mut agents) = harmonized_roster();
+    agents.push(Box::new(harmonizer));
+    
+    SwarmBuilder::new()
+        .name(STANDARD_SWARM_NAME)
+        .topology(topology)
+        .neural_network(neural_network)
+        .event_bus(event_bus)
+        .with_agents(agents)
+        .build()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use amos_agents::{AgentState, TrafficSeer, MemoryWeaver, PathwaySculptor};
+    use uuid::Uuid;
+    use crate::test_support::ScriptedAgent;
+    
+    #[tokio::test]
+    async fn test_build_swarm_with_agents() {
+        let swarm = SwarmBuilder::new()
+            .name("Built Swarm")
+            .topology(SwarmTopology::Mesh { max_connections: 6 })
+            .neural_network(Arc::new(ForgeNeuralNetwork::new()))
+            .with_agent(Box::new(TrafficSeer::new()))
+            .with_agents(vec![
+                Box::new(MemoryWeaver::new()),
+                Box::new(PathwaySculptor::new()),
+            ])
+            .build()
+            .await
+            .unwrap();
+        
+        let status = swarm.status().await;
+        assert_eq!(status.agent_count, 3);
+        
+        let agents = swarm.agents.read().await;
+        assert!(agents.values().all(|agent| agent.state() == AgentState::Active));
+    }
+    
+    #[tokio::test]
+    async fn test_build_requires_name() {
+        let result = SwarmBuilder::new()
+            .with_agent(Box::new(TrafficSeer::new()))
+            .build()
+            .await;
+        
+        assert!(matches!(result, Err(SwarmError::InvalidConfiguration(_))));
+    }
+    
+    #[tokio::test]
+    async fn test_standard_swarm_runs_every_core_agent() {
+        let swarm = bootstrap_standard_swarm(
+            Arc::new(ForgeNeuralNetwork::new()),
+            Arc::new(EventBus::new()),
+            SwarmTopology::Mesh { max_connections: 6 },
+        ).await.unwrap();
+        
+        let agents = swarm.agents.read().await;
+        let mut names: Vec<&str> = agents.values().map(|agent| agent.name()).collect();
+        names.sort();
+        assert_eq!(names, vec![
+            "CognitionAlchemist", "ConsciousnessEmergent", "LearningOracle", "MemoryWeaver",
+            "MeshHarmonizer", "PathwaySculptor", "PerformanceGuardian", "TrafficSeer",
+        ]);
+        assert!(agents.values().all(|agent| agent.state() == AgentState::Active));
+        
+        // The swarm's own harmonizer knows every member, itself included
+        let harmonizer = agents.values()
+            .find(|agent| agent.name() == "MeshHarmonizer")
+            .unwrap();
+        let report = harmonizer.introspect().unwrap();
+        let registered: Vec<Uuid> = serde_json::from_value(report["registered_agents"].clone()).unwrap();
+        assert_eq!(registered.len(), agents.len());
+        assert!(agents.keys().all(|id| registered.contains(id)));
+    }
+    
+    #[tokio::test]
+    async fn test_failed_start_leaves_swarm_unchanged() {
+        let swarm = SwarmBuilder::new()
+            .name("Running Swarm")
+            .with_agent(Box::new(TrafficSeer::new()))
+            .build()
+            .await
+            .unwrap();
+        
+        let failing = ScriptedAgent::failing_to_start();
+        let failing_id = failing.id();
+        let result = swarm.start_agent(Box::new(failing), Arc::new(EventBus::new())).await;
+        
+        match result {
+            Err(SwarmError::AgentStartup { agent_id, reason }) => {
+                assert_eq!(agent_id, failing_id);
+                assert!(reason.contains("no neural pathways"));
+            }
+            other => panic!("expected a startup failure, got {:?}", other.map(|_| ())),
+        }
+        assert_eq!(swarm.status().await.agent_count, 1);
+        assert_eq!(swarm.orchestrator.agent_placements().await.len(), 1);
+    }
+    
+    #[tokio::test]
+    async fn test_build_names_the_agent_that_failed_to_start() {
+        let failing = ScriptedAgent::failing_to_start();
+        let failing_id = failing.id();
+        
+        let result = SwarmBuilder::new()
+            .name("Doomed Swarm")
+            .with_agent(Box::new(TrafficSeer::new()))
+            .with_agent(Box::new(failing))
+            .with_agent(Box::new(MemoryWeaver::new()))
+            .build()
+            .await;
+        
+        assert!(matches!(result, Err(SwarmError::AgentStartup { agent_id, .. }) if agent_id == failing_id));
+    }
+}
@@ -0,0 +1,127 @@
+use amos_agents::AgentCapability;
+use std::collections::{BTreeSet, HashMap};
+use uuid::Uuid;
+
+/// Agents grouped by capability, so selection looks up candidates instead of
+/// asking every agent what it can do
+#[derive(Debug, Clone, Default)]
+pub struct CapabilityIndex {
+    // Ordered sets keep selection deterministic without sorting on every lookup
+    by_capability: HashMap<AgentCapability, BTreeSet<Uuid>>,
+    agents: HashMap<Uuid, Vec<AgentCapability>>,
+}
+
+impl CapabilityIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Index an agent, replacing whatever was recorded for it before
+    pub fn insert(&mut self, agent_id: Uuid, capabilities: &[AgentCapability]) {
+        self.remove(agent_id);
+
+        for capability in capabilities {
+            self.by_capability.entry(capability.clone()).or_default().insert(agent_id);
+        }
+        self.agents.insert(agent_id, capabilities.to_vec());
+    }
+
+    pub fn remove(&mut self, agent_id: Uuid) {
+        let Some(capabilities) = self.agents.remove(&agent_id) else {
+            return;
+        };
+
+        for capability in capabilities {
+            if let Some(members) = self.by_capability.get_mut(&capability) {
+                members.remove(&agent_id);
+                if members.is_empty() {
+                    self.by_capability.remove(&capability);
+                }
+            }
+        }
+    }
+
+    /// Apply a runtime grant or revoke to an indexed agent; unknown agents are ignored
+    pub fn update(&mut self, agent_id: Uuid, added: &[AgentCapability], removed: &[AgentCapability]) {
+        let Some(current) = self.agents.get(&agent_id) else {
+            return;
+        };
+
+        let mut capabilities: Vec<AgentCapability> = current.iter()
+            .filter(|capability| !removed.contains(capability))
+            .cloned()
+            .collect();
+        for capability in added {
+            if !capabilities.contains(capability) {
+                capabilities.push(capability.clone());
+            }
+        }
+
+        self.insert(agent_id, &capabilities);
+    }
+
+    pub fn contains(&self, agent_id: Uuid) -> bool {
+        self.agents.contains_key(&agent_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.agents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.agents.is_empty()
+    }
+
+    /// Agents with a capability, in id order
+    pub fn members(&self, capability: &AgentCapability) -> impl Iterator<Item = Uuid> + '_ {
+        self.by_capability.get(capability).into_iter().flatten().copied()
+    }
+
+    /// Agents with every one of `capabilities`, in id order
+    pub fn with_all(&self, capabilities: &[AgentCapability]) -> Vec<Uuid> {
+        let Some(rarest) = capabilities.iter()
+            .map(|capability| self.by_capability.get(capability))
+            .min_by_key(|members| members.map_or(0, BTreeSet::len))
+        else {
+            return self.agents.keys().copied().collect();
+        };
+
+        rarest.into_iter()
+            .flatten()
+            .filter(|id| {
+                capabilities.iter().all(|capability| {
+                    self.by_capability.get(capability).is_some_and(|members| members.contains(id))
+                })
+            })
+            .copied()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_tracks_overlapping_capabilities() {
+        let mut index = CapabilityIndex::new();
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        index.insert(a, &[AgentCapability::Learning, AgentCapability::Monitoring]);
+        index.insert(b, &[AgentCapability::Learning]);
+        index.insert(c, &[AgentCapability::Monitoring, AgentCapability::Generation]);
+
+        assert_eq!(index.members(&AgentCapability::Learning).count(), 2);
+        assert_eq!(index.with_all(&[AgentCapability::Learning, AgentCapability::Monitoring]), vec![a]);
+
+        index.remove(a);
+
+        assert_eq!(index.members(&AgentCapability::Learning).collect::<Vec<_>>(), vec![b]);
+        assert_eq!(index.members(&AgentCapability::Monitoring).collect::<Vec<_>>(), vec![c]);
+        assert!(index.with_all(&[AgentCapability::Learning, AgentCapability::Monitoring]).is_empty());
+        assert!(!index.contains(a));
+        assert_eq!(index.len(), 2);
+
+        index.remove(c);
+        assert_eq!(index.members(&AgentCapability::Generation).count(), 0);
+    }
+}
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+/// Whether an agent is currently trusted with work
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BreakerState {
+    /// Working normally
+    Closed,
+    /// Failed too often; skipped by selection until the cooldown ends
+    Open,
+    /// Cooldown over; the next invocation decides whether it closes or reopens
+    HalfOpen,
+}
+
+/// Tracks consecutive failures of one agent
+#[derive(Debug, Clone, Default)]
+pub struct CircuitBreaker {
+    consecutive_failures: usize,
+    opened_at: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    pub fn state(&self, cooldown: Duration) -> BreakerState {
+        match self.opened_at {
+            None => BreakerState::Closed,
+            Some(opened_at) if opened_at.elapsed() < cooldown => BreakerState::Open,
+            Some(_) => BreakerState::HalfOpen,
+        }
+    }
+    
+    pub fn consecutive_failures(&self) -> usize {
+        self.consecutive_failures
+    }
+    
+    pub fn record_success(&mut self) {
+        self.consecutive_failures = 0;
+        self.opened_at = None;
+    }
+    
+    /// Count a failure, opening the breaker once `threshold` are seen in a row.
+    /// A failed half-open trial reopens it straight away.
+    pub fn record_failure(&mut self, threshold: usize) {
+        self.consecutive_failures += 1;
+        if self.opened_at.is_some() || self.consecutive_failures >= threshold.max(1) {
+            self.opened_at = Some(Instant::now());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_breaker_opens_half_opens_and_recovers() {
+        let cooldown = Duration::from_millis(20);
+        let mut breaker = CircuitBreaker::new();
+        
+        breaker.record_failure(2);
+        assert_eq!(breaker.state(cooldown), BreakerState::Closed);
+        breaker.record_failure(2);
+        assert_eq!(breaker.state(cooldown), BreakerState::Open);
+        
+        std::thread::sleep(cooldown);
+        assert_eq!(breaker.state(cooldown), BreakerState::HalfOpen);
+        
+        // One failed trial is enough to reopen
+        breaker.record_failure(2);
+        assert_eq!(breaker.state(cooldown), BreakerState::Open);
+        
+        std::thread::sleep(cooldown);
+        breaker.record_success();
+        assert_eq!(breaker.state(cooldown), BreakerState::Closed);
+        assert_eq!(breaker.consecutive_failures(), 0);
+    }
+}
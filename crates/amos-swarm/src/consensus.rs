@@ -0,0 +1,300 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Weight given to agents with no recorded history
+pub const DEFAULT_AGENT_WEIGHT: f64 = 1.0;
+
+/// Lower bound so a run of bad outcomes never silences an agent entirely
+const MIN_AGENT_WEIGHT: f64 = 0.1;
+
+/// How the convergence threshold anneals from round to round, so deliberation
+/// that cannot agree at `initial` is guaranteed to converge once it reaches `floor`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConvergenceSchedule {
+    pub initial: f64,
+    pub floor: f64,
+    /// Subtracted from the threshold after every round, until it reaches `floor`
+    pub decay_per_round: f64,
+}
+
+impl ConvergenceSchedule {
+    /// Threshold in effect for a round, counting from 0
+    pub fn threshold_at(&self, round: usize) -> f64 {
+        (self.initial - self.decay_per_round * round as f64).max(self.floor)
+    }
+    
+    /// Rounds of annealing before the threshold bottoms out at the floor
+    pub fn rounds_to_floor(&self) -> usize {
+        if self.initial <= self.floor {
+            return 0;
+        }
+        if self.decay_per_round <= 0.0 {
+            return usize::MAX;
+        }
+        ((self.initial - self.floor) / self.decay_per_round).ceil() as usize
+    }
+}
+
+/// Outcome of a consensus vote
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ConsensusResult {
+    /// The top proposal cleared the convergence threshold
+    Converged { proposal: String, agreement: f64 },
+    /// Nothing cleared the threshold before the rounds or the time ran out; the top
+    /// proposal anyway. `rounds_exhausted` is false when time ran out first.
+    BestEffort { proposal: String, agreement: f64, rounds_exhausted: bool },
+}
+
+impl ConsensusResult {
+    pub fn proposal(&self) -> &str {
+        match self {
+            ConsensusResult::Converged { proposal, .. } | ConsensusResult::BestEffort { proposal, .. } => proposal,
+        }
+    }
+    
+    pub fn agreement(&self) -> f64 {
+        match self {
+            ConsensusResult::Converged { agreement, .. } | ConsensusResult::BestEffort { agreement, .. } => *agreement,
+        }
+    }
+    
+    pub fn is_low_confidence(&self) -> bool {
+        matches!(self, ConsensusResult::BestEffort { .. })
+    }
+}
+
+/// Aggregates agent votes on proposals, weighting each agent by its track record
+#[derive(Debug, Clone)]
+pub struct ConsensusAggregator {
+    votes: HashMap<Uuid, HashMap<String, f64>>,
+    agent_weights: HashMap<Uuid, f64>,
+    pub convergence_threshold: f64,
+    schedule: Option<ConvergenceSchedule>,
+    round: usize,
+}
+
+impl ConsensusAggregator {
+    pub fn new(convergence_threshold: f64) -> Self {
+        Self {
+            votes: HashMap::new(),
+            agent_weights: HashMap::new(),
+            convergence_threshold,
+            schedule: None,
+            round: 0,
+        }
+    }
+    
+    /// Anneal the convergence threshold along `schedule`, starting from its initial value
+    pub fn with_schedule(mut self, schedule: ConvergenceSchedule) -> Self {
+        self.convergence_threshold = schedule.initial;
+        self.schedule = Some(schedule);
+        self.round = 0;
+        self
+    }
+    
+    pub fn schedule(&self) -> Option<ConvergenceSchedule> {
+        self.schedule
+    }
+    
+    /// Rounds completed since the schedule started
+    pub fn round(&self) -> usize {
+        self.round
+    }
+    
+    /// Move on to the next round, discarding its votes and lowering the threshold
+    /// along the schedule; returns the threshold the new round must clear
+    pub fn next_round(&mut self) -> f64 {
+        self.clear_votes();
+        self.round += 1;
+        if let Some(schedule) = &self.schedule {
+            self.convergence_threshold = schedule.threshold_at(self.round);
+        }
+        self.convergence_threshold
+    }
+    
+    /// Record an agent's confidence in a proposal
+    pub fn add_vote(&mut self, agent_id: Uuid, proposal: String, confidence: f64) {
+        self.votes.entry(agent_id)
+            .or_default()
+            .insert(proposal, confidence);
+    }
+    
+    /// Discard the current round's votes, keeping learned weights
+    pub fn clear_votes(&mut self) {
+        self.votes.clear();
+    }
+    
+    pub fn set_weight(&mut self, agent_id: Uuid, weight: f64) {
+        self.agent_weights.insert(agent_id, weight.max(MIN_AGENT_WEIGHT));
+    }
+    
+    pub fn weight(&self, agent_id: &Uuid) -> f64 {
+        self.agent_weights.get(agent_id).copied().unwrap_or(DEFAULT_AGENT_WEIGHT)
+    }
+    
+    /// Weighted mean confidence per proposal across all voting agents
+    pub fn proposal_scores(&self) -> HashMap<String, f64> {
+        let mut scores: HashMap<String, f64> = HashMap::new();
+        let mut total_weight = 0.0;
+        
+        for (agent_id, agent_votes) in &self.votes {
+            let weight = self.weight(agent_id);
+            total_weight += weight;
+            
+            for (proposal, confidence) in agent_votes {
+                *scores.entry(proposal.clone()).or_insert(0.0) += weight * confidence;
+            }
+        }
+        
+        if total_weight > 0.0 {
+            for score in scores.values_mut() {
+                *score /= total_weight;
+            }
+        }
+        
+        scores
+    }
+    
+    /// Highest scoring proposal, if it clears the convergence threshold
+    pub fn calculate_consensus(&self) -> Option<(String, f64)> {
+        self.proposal_scores()
+            .into_iter()
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+            .filter(|(_, score)| *score >= self.convergence_threshold)
+    }
+    
+    /// Settle the final round: the converged proposal if there is one, otherwise
+    /// the top proposal as a best effort if `best_effort` allows it
+    pub fn resolve(&self, best_effort: bool) -> Option<ConsensusResult> {
+        if let Some((proposal, agreement)) = self.calculate_consensus() {
+            return Some(ConsensusResult::Converged { proposal, agreement });
+        }
+        if !best_effort {
+            return None;
+        }
+        
+        self.best_effort(true)
+    }
+    
+    /// The top proposal regardless of the threshold, for deliberation that stopped
+    /// early (`rounds_exhausted` false) or ran out of rounds
+    pub fn best_effort(&self, rounds_exhausted: bool) -> Option<ConsensusResult> {
+        self.proposal_scores()
+            .into_iter()
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(proposal, agreement)| ConsensusResult::BestEffort {
+                proposal,
+                agreement,
+                rounds_exhausted,
+            })
+    }
+    
+    /// Reward agents whose preferred proposal matched the outcome and penalise the rest
+    pub fn update_weights_from_outcome(&mut self, correct_proposal: &str, learning_rate: f64) {
+        let preferences: Vec<(Uuid, bool)> = self.votes.iter()
+            .filter_map(|(agent_id, agent_votes)| {
+                agent_votes.iter()
+                    .max_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(std::cmp::Ordering::Equal))
+                    .map(|(proposal, _)| (*agent_id, proposal == correct_proposal))
+            })
+            .collect();
+        
+        for (agent_id, was_correct) in preferences {
+            let factor = if was_correct { 1.0 + learning_rate } else { 1.0 - learning_rate };
+            let weight = self.weight(&agent_id) * factor;
+            self.set_weight(agent_id, weight);
+        }
+    }
+}
+
+impl Default for ConsensusAggregator {
+    fn default() -> Self {
+        Self::new(0.8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_unweighted_mean_matches_plain_average() {
+        let mut aggregator = ConsensusAggregator::new(0.0);
+        aggregator.add_vote(Uuid::new_v4(), "a".to_string(), 1.0);
+        aggregator.add_vote(Uuid::new_v4(), "a".to_string(), 0.5);
+        
+        let scores = aggregator.proposal_scores();
+        assert!((scores["a"] - 0.75).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn test_heavily_weighted_agent_wins_when_outnumbered() {
+        let mut aggregator = ConsensusAggregator::new(0.0);
+        let expert = Uuid::new_v4();
+        aggregator.set_weight(expert, 3.0);
+        
+        aggregator.add_vote(expert, "scale_up".to_string(), 0.9);
+        for _ in 0..2 {
+            aggregator.add_vote(Uuid::new_v4(), "hold".to_string(), 0.9);
+        }
+        
+        let (winner, _) = aggregator.calculate_consensus().unwrap();
+        assert_eq!(winner, "scale_up");
+    }
+    
+    #[test]
+    fn test_annealed_threshold_converges_within_bounded_rounds() {
+        let schedule = ConvergenceSchedule { initial: 0.9, floor: 0.5, decay_per_round: 0.1 };
+        let mut aggregator = ConsensusAggregator::default().with_schedule(schedule);
+        let agents: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        
+        // The agents never agree more strongly than 0.6, well short of the initial threshold
+        let mut converged = None;
+        for _ in 0..=schedule.rounds_to_floor() {
+            for (agent_id, confidence) in agents.iter().zip([0.7, 0.6, 0.5]) {
+                aggregator.add_vote(*agent_id, "a".to_string(), confidence);
+            }
+            if let Some(consensus) = aggregator.calculate_consensus() {
+                converged = Some((aggregator.round(), consensus));
+                break;
+            }
+            aggregator.next_round();
+        }
+        
+        let (round, (winner, score)) = converged.expect("annealing should reach consensus");
+        assert_eq!(winner, "a");
+        assert!((score - 0.6).abs() < 1e-9);
+        assert_eq!(round, 3);
+        assert_eq!(schedule.rounds_to_floor(), 4);
+        
+        // The threshold never anneals past the floor
+        assert_eq!(schedule.threshold_at(100), 0.5);
+    }
+    
+    #[test]
+    fn test_unconverged_vote_resolves_to_best_effort_only_when_allowed() {
+        let mut aggregator = ConsensusAggregator::new(0.9);
+        aggregator.add_vote(Uuid::new_v4(), "a".to_string(), 0.6);
+        aggregator.add_vote(Uuid::new_v4(), "b".to_string(), 0.3);
+        
+        assert_eq!(aggregator.resolve(false), None);
+        let result = aggregator.resolve(true).unwrap();
+        assert_eq!(result.proposal(), "a");
+        assert!(result.is_low_confidence());
+    }
+    
+    #[test]
+    fn test_outcomes_adjust_weights() {
+        let mut aggregator = ConsensusAggregator::default();
+        let right = Uuid::new_v4();
+        let wrong = Uuid::new_v4();
+        
+        aggregator.add_vote(right, "a".to_string(), 0.9);
+        aggregator.add_vote(wrong, "b".to_string(), 0.9);
+        aggregator.update_weights_from_outcome("a", 0.5);
+        
+        assert!((aggregator.weight(&right) - 1.5).abs() < 1e-9);
+        assert!((aggregator.weight(&wrong) - 0.5).abs() < 1e-9);
+    }
+}
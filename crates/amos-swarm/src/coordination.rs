@@ -3,7 +3,12 @@ use uuid::Uuid;
 use tokio::sync::{broadcast, mpsc};
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+use crate::topology::AgentPlacement;
+use crate::blackboard::Blackboard;
 
 /// Message types for agent coordination
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -111,6 +116,68 @@ pub struct PathwayUpdate {
     pub reason: String,
 }
 
+/// A destination named by its position in the topology rather than by agent id
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LogicalDest {
+    /// The next agent on a ring, or every directly connected agent elsewhere
+    Neighbor,
+    /// The agent one level up in a hierarchy
+    Parent,
+    /// The agents one level down in a hierarchy
+    Children,
+    /// The centre of a star
+    Hub,
+}
+
+impl LogicalDest {
+    /// Concrete recipients for a message from `from`, sorted by id
+    pub fn resolve(&self, from: Uuid, placements: &HashMap<Uuid, AgentPlacement>) -> Vec<Uuid> {
+        let Some(placement) = placements.get(&from) else {
+            return Vec::new();
+        };
+        
+        let mut recipients: Vec<Uuid> = match (self, placement) {
+            (LogicalDest::Neighbor, AgentPlacement::Ring { next, .. }) => next.iter().copied().collect(),
+            (LogicalDest::Neighbor, placement) => placement.connections(),
+            (LogicalDest::Parent, AgentPlacement::Hierarchical { parent, .. }) => parent.iter().copied().collect(),
+            (LogicalDest::Children, AgentPlacement::Hierarchical { children, .. }) => children.iter().copied().collect(),
+            (LogicalDest::Hub, AgentPlacement::Star { is_hub: false, .. }) => placements.iter()
+                .filter(|(_, p)| matches!(p, AgentPlacement::Star { is_hub: true, .. }))
+                .map(|(id, _)| *id)
+                .collect(),
+            _ => Vec::new(),
+        };
+        
+        recipients.retain(|id| *id != from);
+        recipients.sort();
+        recipients.dedup();
+        recipients
+    }
+}
+
+/// Why a non-blocking send could not be delivered
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeliveryError {
+    /// The recipient's queue is at capacity
+    Full,
+    /// The recipient (or every broadcast subscriber) has gone away
+    Closed,
+    /// No channel is registered for the recipient
+    UnknownAgent(Uuid),
+}
+
+impl std::fmt::Display for DeliveryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeliveryError::Full => write!(f, "recipient queue is full"),
+            DeliveryError::Closed => write!(f, "recipient channel is closed"),
+            DeliveryError::UnknownAgent(id) => write!(f, "agent {} not found", id),
+        }
+    }
+}
+
+impl std::error::Error for DeliveryError {}
+
 /// Coordination protocol for agent communication
 pub trait CoordinationProtocol: Send + Sync {
     /// Send a message
@@ -123,12 +190,18 @@ pub trait CoordinationProtocol: Send + Sync {
     fn capabilities(&self) -> Vec<String>;
 }
 
+/// Default queue capacity for an agent's direct channel
+pub const DEFAULT_AGENT_CHANNEL_CAPACITY: usize = 100;
+
 /// Message bus for agent coordination
 pub struct MessageBus {
     broadcast_tx: broadcast::Sender<CoordinationMessage>,
     direct_channels: Arc<RwLock<HashMap<Uuid, mpsc::Sender<CoordinationMessage>>>>,
     message_history: Arc<RwLock<Vec<CoordinationMessage>>>,
     max_history: usize,
+    placements: Arc<RwLock<HashMap<Uuid, AgentPlacement>>>,
+    closed: Arc<AtomicBool>,
+    blackboard: Option<Arc<Blackboard>>,
 }
 
 impl MessageBus {
@@ -140,16 +213,41 @@ impl MessageBus {
             direct_channels: Arc::new(RwLock::new(HashMap::new())),
             message_history: Arc::new(RwLock::new(Vec::new())),
             max_history: 1000,
+            placements: Arc::new(RwLock::new(HashMap::new())),
+            closed: Arc::new(AtomicBool::new(false)),
+            blackboard: None,
         }
     }
     
+    /// Record every `MessageContent::Knowledge` message sent over the bus on a blackboard
+    pub fn with_blackboard(mut self, blackboard: Arc<Blackboard>) -> Self {
+        self.blackboard = Some(blackboard);
+        self
+    }
+    
     /// Register an agent's direct channel
     pub async fn register_agent(&self, agent_id: Uuid) -> mpsc::Receiver<CoordinationMessage> {
-        let (tx, rx) = mpsc::channel(100);
+        self.register_agent_with_capacity(agent_id, DEFAULT_AGENT_CHANNEL_CAPACITY).await
+    }
+    
+    /// Register an agent's direct channel with a custom queue capacity, at least 1
+    pub async fn register_agent_with_capacity(
+        &self,
+        agent_id: Uuid,
+        capacity: usize,
+    ) -> mpsc::Receiver<CoordinationMessage> {
+        let (tx, rx) = mpsc::channel(capacity.max(1));
         self.direct_channels.write().await.insert(agent_id, tx);
         rx
     }
     
+    /// Approximate number of messages waiting in an agent's direct queue
+    pub async fn queue_depth(&self, agent_id: Uuid) -> Option<usize> {
+        self.direct_channels.read().await
+            .get(&agent_id)
+            .map(|tx| tx.max_capacity() - tx.capacity())
+    }
+    
     /// Unregister an agent
     pub async fn unregister_agent(&self, agent_id: Uuid) {
         self.direct_channels.write().await.remove(&agent_id);
@@ -157,6 +255,14 @@ impl MessageBus {
     
     /// Send a coordination message
     pub async fn send(&self, message: CoordinationMessage) -> Result<(), String> {
+        if self.is_closed() {
+            return Err("Message bus is closed".to_string());
+        }
+        
+        if let Some(blackboard) = &self.blackboard {
+            blackboard.absorb(&message).await;
+        }
+        
         // Store in history
         let mut history = self.message_history.write().await;
         history.push(message.clone());
@@ -199,11 +305,136 @@ impl MessageBus {
         Ok(())
     }
     
+    /// Replace the topology used to resolve logical destinations, e.g. with
+    /// `SwarmOrchestrator::agent_placements()` after agents join or leave
+    pub async fn set_placements(&self, placements: HashMap<Uuid, AgentPlacement>) {
+        *self.placements.write().await = placements;
+    }
+    
+    /// Send to the agents a logical destination resolves to from the sender's placement
+    pub async fn send_logical(&self, from: Uuid, dest: LogicalDest, content: MessageContent) -> Result<(), String> {
+        let mut recipients = dest.resolve(from, &*self.placements.read().await);
+        
+        let message = match recipients.len() {
+            0 => return Err(format!("Agent {} has no {:?} in the current topology", from, dest)),
+            1 => CoordinationMessage::Direct { from, to: recipients.remove(0), content },
+            _ => CoordinationMessage::Multicast { from, to: recipients, content },
+        };
+        
+        self.send(message).await
+    }
+    
+    /// Send without waiting for queue space, reporting full and closed queues distinctly
+    pub async fn try_send(&self, message: CoordinationMessage) -> Result<(), DeliveryError> {
+        if self.is_closed() {
+            return Err(DeliveryError::Closed);
+        }
+        
+        let mut partial_failure = None;
+        match &message {
+            CoordinationMessage::Direct { to, .. } => {
+                let channels = self.direct_channels.read().await;
+                let tx = channels.get(to).ok_or(DeliveryError::UnknownAgent(*to))?;
+                tx.try_send(message.clone()).map_err(|e| match e {
+                    mpsc::error::TrySendError::Full(_) => DeliveryError::Full,
+                    mpsc::error::TrySendError::Closed(_) => DeliveryError::Closed,
+                })?;
+            }
+            
+            CoordinationMessage::Multicast { to, .. } => {
+                let channels = self.direct_channels.read().await;
+                let mut delivered = 0;
+                for agent_id in to {
+                    let result = match channels.get(agent_id) {
+                        Some(tx) => tx.try_send(message.clone()).map_err(|e| match e {
+                            mpsc::error::TrySendError::Full(_) => DeliveryError::Full,
+                            mpsc::error::TrySendError::Closed(_) => DeliveryError::Closed,
+                        }),
+                        None => Err(DeliveryError::UnknownAgent(*agent_id)),
+                    };
+                    // Keep delivering to the rest of the group, report the first failure
+                    match result {
+                        Ok(()) => delivered += 1,
+                        Err(e) => {
+                            partial_failure.get_or_insert(e);
+                        }
+                    }
+                }
+                // Nobody got it, so there is nothing to record
+                if delivered == 0 {
+                    if let Some(e) = partial_failure {
+                        return Err(e);
+                    }
+                }
+            }
+            
+            CoordinationMessage::Broadcast { .. } | CoordinationMessage::System { .. } => {
+                self.broadcast_tx.send(message.clone())
+                    .map_err(|_| DeliveryError::Closed)?;
+            }
+        }
+        
+        if let Some(blackboard) = &self.blackboard {
+            blackboard.absorb(&message).await;
+        }
+        
+        let mut history = self.message_history.write().await;
+        history.push(message);
+        if history.len() > self.max_history {
+            history.remove(0);
+        }
+        
+        // Recipients that did get a multicast keep it; report the ones that didn't
+        match partial_failure {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+    
     /// Subscribe to broadcast messages
     pub fn subscribe(&self) -> broadcast::Receiver<CoordinationMessage> {
         self.broadcast_tx.subscribe()
     }
     
+    /// Subscribe to broadcast messages until the returned token is cancelled.
+    ///
+    /// Shutdown ordering: cancel the token, then keep calling `recv` until it returns
+    /// `None` before dropping the receiver. Messages already forwarded are still
+    /// delivered; anything broadcast after cancellation is not, and the bus itself
+    /// never sees the subscription disappear mid-send.
+    pub fn subscribe_with_token(&self) -> (mpsc::Receiver<CoordinationMessage>, CancellationToken) {
+        let (tx, rx) = mpsc::channel(DEFAULT_AGENT_CHANNEL_CAPACITY);
+        let token = CancellationToken::new();
+        let cancelled = token.clone();
+        let mut broadcast_rx = self.broadcast_tx.subscribe();
+        
+        tokio::spawn(async move {
+            loop {
+                let message = tokio::select! {
+                    biased;
+                    _ = cancelled.cancelled() => break,
+                    received = broadcast_rx.recv() => match received {
+                        Ok(message) => message,
+                        Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                            warn!("Broadcast subscriber lagged, skipped {} messages", skipped);
+                            continue;
+                        }
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    },
+                };
+                
+                tokio::select! {
+                    biased;
+                    _ = cancelled.cancelled() => break,
+                    sent = tx.send(message) => if sent.is_err() { break },
+                }
+            }
+            // Dropping the sender here is what closes the subscriber's receiver
+        });
+        
+        (rx, token)
+    }
+    
     /// Get message history
     pub async fn get_history(&self, limit: Option<usize>) -> Vec<CoordinationMessage> {
         let history = self.message_history.read().await;
@@ -216,6 +447,39 @@ impl MessageBus {
             .cloned()
             .collect()
     }
+    
+    /// Whether `drain_and_close` has been called on this bus or any of its clones
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::SeqCst)
+    }
+    
+    /// Stop accepting messages, wait for in-flight sends to finish and close every
+    /// direct channel, then write the history oldest first as JSON lines to `sink`.
+    ///
+    /// Agents can still read whatever was already queued for them. Returns the number
+    /// of messages persisted, which is zero without a sink.
+    pub async fn drain_and_close(&self, sink: Option<&mut dyn std::io::Write>) -> Result<usize, String> {
+        self.closed.store(true, Ordering::SeqCst);
+        
+        // Sends hold the read lock while delivering, so this waits for them to finish
+        self.direct_channels.write().await.clear();
+        
+        let Some(sink) = sink else {
+            return Ok(0);
+        };
+        
+        let history = self.message_history.read().await;
+        for message in history.iter() {
+            serde_json::to_writer(&mut *sink, message)
+                .map_err(|e| format!("Failed to persist message history: {}", e))?;
+            sink.write_all(b"\n")
+                .map_err(|e| format!("Failed to persist message history: {}", e))?;
+        }
+        sink.flush()
+            .map_err(|e| format!("Failed to persist message history: {}", e))?;
+        
+        Ok(history.len())
+    }
 }
 
 impl CoordinationProtocol for MessageBus {
@@ -250,6 +514,9 @@ impl Clone for MessageBus {
             direct_channels: self.direct_channels.clone(),
             message_history: self.message_history.clone(),
             max_history: self.max_history,
+            placements: self.placements.clone(),
+            closed: self.closed.clone(),
+            blackboard: self.blackboard.clone(),
         }
     }
 }
@@ -337,9 +604,7 @@ mod tests {
         // Send broadcast
         let msg = CoordinationMessage::Broadcast {
             from: Uuid::new_v4(),
-            content: MessageContent::System {
-                content: SystemMessage::HealthCheck,
-            },
+            content: MessageContent::Custom(serde_json::json!({"health": "check"})),
         };
         
         bus.send(msg.clone()).await.unwrap();
@@ -348,4 +613,198 @@ mod tests {
         assert!(matches!(rx1.recv().await.unwrap(), CoordinationMessage::Broadcast { .. }));
         assert!(matches!(rx2.recv().await.unwrap(), CoordinationMessage::Broadcast { .. }));
     }
-}
\ No newline at end of file
+    
+    #[tokio::test]
+    async fn test_bounded_queue_reports_depth_and_full() {
+        let bus = MessageBus::new(100);
+        let slow_agent = Uuid::new_v4();
+        let sender = Uuid::new_v4();
+        
+        let _rx = bus.register_agent_with_capacity(slow_agent, 2).await;
+        assert_eq!(bus.queue_depth(slow_agent).await, Some(0));
+        
+        let direct = || CoordinationMessage::Direct {
+            from: sender,
+            to: slow_agent,
+            content: MessageContent::Custom(serde_json::json!({"n": 1})),
+        };
+        
+        bus.try_send(direct()).await.unwrap();
+        bus.try_send(direct()).await.unwrap();
+        
+        assert_eq!(bus.queue_depth(slow_agent).await, Some(2));
+        assert_eq!(bus.try_send(direct()).await, Err(DeliveryError::Full));
+        assert_eq!(bus.queue_depth(Uuid::new_v4()).await, None);
+    }
+    
+    #[tokio::test]
+    async fn test_partial_multicast_is_delivered_and_recorded() {
+        let bus = MessageBus::new(100);
+        let reachable = Uuid::new_v4();
+        let missing = Uuid::new_v4();
+        
+        // A zero capacity still leaves room for one message
+        let mut rx = bus.register_agent_with_capacity(reachable, 0).await;
+        
+        let result = bus.try_send(CoordinationMessage::Multicast {
+            from: Uuid::new_v4(),
+            to: vec![reachable, missing],
+            content: MessageContent::Custom(serde_json::json!({})),
+        }).await;
+        
+        assert_eq!(result, Err(DeliveryError::UnknownAgent(missing)));
+        assert!(matches!(rx.try_recv(), Ok(CoordinationMessage::Multicast { .. })));
+        assert_eq!(bus.get_history(None).await.len(), 1);
+        
+        // A multicast nobody received is not recorded
+        let result = bus.try_send(CoordinationMessage::Multicast {
+            from: Uuid::new_v4(),
+            to: vec![missing],
+            content: MessageContent::Custom(serde_json::json!({})),
+        }).await;
+        assert_eq!(result, Err(DeliveryError::UnknownAgent(missing)));
+        assert_eq!(bus.get_history(None).await.len(), 1);
+    }
+    
+    #[tokio::test]
+    async fn test_try_send_reports_closed_channel() {
+        let bus = MessageBus::new(100);
+        let agent = Uuid::new_v4();
+        
+        let rx = bus.register_agent_with_capacity(agent, 2).await;
+        drop(rx);
+        
+        let result = bus.try_send(CoordinationMessage::Direct {
+            from: Uuid::new_v4(),
+            to: agent,
+            content: MessageContent::Custom(serde_json::json!({})),
+        }).await;
+        
+        assert_eq!(result, Err(DeliveryError::Closed));
+    }
+    
+    #[tokio::test]
+    async fn test_cancelled_subscription_closes_cleanly() {
+        let bus = MessageBus::new(100);
+        let (mut rx, token) = bus.subscribe_with_token();
+        let mut other = bus.subscribe();
+        let sender = Uuid::new_v4();
+        let broadcast = || CoordinationMessage::Broadcast {
+            from: sender,
+            content: MessageContent::Custom(serde_json::json!({})),
+        };
+        
+        bus.send(broadcast()).await.unwrap();
+        assert!(matches!(rx.recv().await, Some(CoordinationMessage::Broadcast { .. })));
+        
+        token.cancel();
+        assert!(rx.recv().await.is_none());
+        
+        // Other subscribers are unaffected and the bus keeps working
+        bus.send(broadcast()).await.unwrap();
+        assert!(rx.recv().await.is_none());
+        other.recv().await.unwrap();
+        assert!(matches!(other.recv().await, Ok(CoordinationMessage::Broadcast { .. })));
+    }
+    
+    #[tokio::test]
+    async fn test_neighbor_on_ring_reaches_only_next_agent() {
+        let bus = MessageBus::new(100);
+        let ids: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        let mut receivers = Vec::new();
+        for id in &ids {
+            receivers.push(bus.register_agent(*id).await);
+        }
+        
+        let placements: HashMap<Uuid, AgentPlacement> = (0..3)
+            .map(|i| (ids[i], AgentPlacement::Ring {
+                prev: Some(ids[(i + 2) % 3]),
+                next: Some(ids[(i + 1) % 3]),
+            }))
+            .collect();
+        bus.set_placements(placements).await;
+        
+        bus.send_logical(ids[0], LogicalDest::Neighbor, MessageContent::Custom(serde_json::json!({})))
+            .await
+            .unwrap();
+        
+        match receivers[1].try_recv().unwrap() {
+            CoordinationMessage::Direct { from, to, .. } => {
+                assert_eq!(from, ids[0]);
+                assert_eq!(to, ids[1]);
+            }
+            other => panic!("expected a direct message, got {:?}", other),
+        }
+        assert!(receivers[0].try_recv().is_err());
+        assert!(receivers[2].try_recv().is_err());
+        
+        // A ring has no hierarchy to route along
+        assert!(bus.send_logical(ids[0], LogicalDest::Parent, MessageContent::Custom(serde_json::json!({}))).await.is_err());
+    }
+    
+    #[tokio::test]
+    async fn test_drain_persists_history_and_rejects_later_sends() {
+        let bus = MessageBus::new(100);
+        let agent = Uuid::new_v4();
+        let sender = Uuid::new_v4();
+        let mut rx = bus.register_agent(agent).await;
+        
+        for n in 0..3 {
+            bus.send(CoordinationMessage::Direct {
+                from: sender,
+                to: agent,
+                content: MessageContent::Custom(serde_json::json!({"n": n})),
+            }).await.unwrap();
+        }
+        
+        let mut buffer = Vec::new();
+        let persisted = bus.drain_and_close(Some(&mut buffer)).await.unwrap();
+        assert_eq!(persisted, 3);
+        
+        let restored: Vec<CoordinationMessage> = String::from_utf8(buffer).unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        let numbers: Vec<_> = restored.iter()
+            .map(|message| match message {
+                CoordinationMessage::Direct { content: MessageContent::Custom(value), .. } => value["n"].clone(),
+                other => panic!("unexpected message {:?}", other),
+            })
+            .collect();
+        assert_eq!(numbers, vec![serde_json::json!(0), serde_json::json!(1), serde_json::json!(2)]);
+        
+        // Queued messages are still readable, then the channel reports closed
+        for _ in 0..3 {
+            assert!(rx.recv().await.is_some());
+        }
+        assert!(rx.recv().await.is_none());
+        
+        let err = bus.clone().send(CoordinationMessage::System { content: SystemMessage::HealthCheck }).await.unwrap_err();
+        assert!(err.contains("closed"), "{}", err);
+        assert_eq!(bus.drain_and_close(None).await, Ok(0));
+    }
+    
+    #[tokio::test]
+    async fn test_knowledge_messages_update_the_blackboard() {
+        let blackboard = Arc::new(Blackboard::new());
+        let bus = MessageBus::new(10).with_blackboard(blackboard.clone());
+        let (sender, receiver) = (Uuid::new_v4(), Uuid::new_v4());
+        let _rx = bus.register_agent(receiver).await;
+        
+        bus.send(CoordinationMessage::Direct {
+            from: sender,
+            to: receiver,
+            content: MessageContent::Knowledge { topic: "load".to_string(), data: serde_json::json!(0.4) },
+        }).await.unwrap();
+        bus.try_send(CoordinationMessage::Direct {
+            from: receiver,
+            to: receiver,
+            content: MessageContent::Knowledge { topic: "load".to_string(), data: serde_json::json!(0.7) },
+        }).await.unwrap();
+        
+        let entry = blackboard.read("load").await.unwrap();
+        assert_eq!(entry.version, 2);
+        assert_eq!(entry.value, serde_json::json!(0.7));
+        assert_eq!(entry.writer, receiver);
+    }
+}
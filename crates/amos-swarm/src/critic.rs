@@ -0,0 +1,282 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use std::sync::Arc;
+use amos_core::{ForgeNeuralNetwork, EventBus, SystemEvent};
+use amos_agents::{CognitiveAgent, BaseAgent, AgentState, AgentCapability};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use crate::task::WorkItem;
+
+/// Name the Critic announces itself with
+pub const CRITIC_AGENT_NAME: &str = "Critic";
+
+/// Work scoring below this is vetoed unless a threshold is configured
+pub const DEFAULT_VETO_THRESHOLD: f64 = 0.5;
+
+/// Score lost for each problem a critique finds
+const ISSUE_PENALTY: f64 = 0.25;
+
+/// Score lost when a work item carries no result at all
+const MISSING_RESULT_PENALTY: f64 = 0.6;
+
+/// Results reporting less confidence than this are flagged
+const LOW_CONFIDENCE: f64 = 0.5;
+
+/// A Critic's verdict on one work item
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Critique {
+    /// 1.0 for work with no issues, falling towards 0.0 as issues are found
+    pub score: f64,
+    pub issues: Vec<String>,
+}
+
+impl Critique {
+    pub fn passes(&self, threshold: f64) -> bool {
+        self.score >= threshold
+    }
+}
+
+/// Scores other agents' work items and vetoes those that fall short
+pub struct Critic {
+    base: BaseAgent,
+    veto_threshold: f64,
+    reviewed: usize,
+    vetoed: usize,
+}
+
+impl Critic {
+    pub fn new() -> Self {
+        Self {
+            base: BaseAgent::new(
+                CRITIC_AGENT_NAME.to_string(),
+                vec![
+                    AgentCapability::PatternRecognition,
+                    AgentCapability::Monitoring,
+                ],
+            ),
+            veto_threshold: DEFAULT_VETO_THRESHOLD,
+            reviewed: 0,
+            vetoed: 0,
+        }
+    }
+
+    pub fn with_veto_threshold(mut self, threshold: f64) -> Self {
+        self.veto_threshold = threshold;
+        self
+    }
+
+    pub fn veto_threshold(&self) -> f64 {
+        self.veto_threshold
+    }
+
+    /// Heuristically score a work item on its expected fields, confidence and consistency
+    pub fn critique(&self, work: &WorkItem) -> Critique {
+        let mut issues = Vec::new();
+        let mut score: f64 = 1.0;
+
+        if work.description.trim().is_empty() {
+            issues.push("Work item has no description".to_string());
+            score -= ISSUE_PENALTY;
+        }
+        if work.timestamp > chrono::Utc::now() {
+            issues.push("Work item is timestamped in the future".to_string());
+            score -= ISSUE_PENALTY;
+        }
+
+        match &work.result {
+            None | Some(serde_json::Value::Null) => {
+                issues.push("Work item has no result".to_string());
+                score -= MISSING_RESULT_PENALTY;
+            }
+            Some(serde_json::Value::Object(fields)) => {
+                for problem in Self::result_problems(fields, &work.description) {
+                    issues.push(problem);
+                    score -= ISSUE_PENALTY;
+                }
+            }
+            // Scalar and array results are opaque, so only their presence is judged
+            Some(_) => {}
+        }
+
+        Critique {
+            score: score.max(0.0),
+            issues,
+        }
+    }
+
+    /// Whether a work item scores too low to be used
+    pub fn vetoes(&self, work: &WorkItem) -> bool {
+        !self.critique(work).passes(self.veto_threshold)
+    }
+
+    /// Critique a work item, counting it towards this Critic's metrics
+    pub fn review(&mut self, work: &WorkItem) -> Critique {
+        let critique = self.critique(work);
+
+        self.reviewed += 1;
+        if !critique.passes(self.veto_threshold) {
+            self.vetoed += 1;
+        }
+        self.base.record_processed(Some(critique.score));
+
+        critique
+    }
+
+    /// Work items reviewed and vetoed so far
+    pub fn review_counts(&self) -> (usize, usize) {
+        (self.reviewed, self.vetoed)
+    }
+
+    fn result_problems(fields: &serde_json::Map<String, serde_json::Value>, description: &str) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        match fields.get("output") {
+            None | Some(serde_json::Value::Null) => problems.push("Result has no output".to_string()),
+            Some(serde_json::Value::String(output)) if output.trim().is_empty() => {
+                problems.push("Result output is empty".to_string());
+            }
+            Some(_) => {}
+        }
+
+        if let Some(confidence) = fields.get("confidence") {
+            match confidence.as_f64() {
+                Some(c) if !(0.0..=1.0).contains(&c) => {
+                    problems.push(format!("Confidence {} is outside 0.0..=1.0", c));
+                }
+                Some(c) if c < LOW_CONFIDENCE => problems.push(format!("Confidence {} is low", c)),
+                Some(_) => {}
+                None => problems.push("Confidence is not a number".to_string()),
+            }
+        }
+
+        // The agent named in the result should be the one the description credits
+        if let Some(agent) = fields.get("agent").and_then(|agent| agent.as_str()) {
+            if !description.contains(agent) {
+                problems.push(format!("Result names agent {} but the description does not", agent));
+            }
+        }
+
+        problems
+    }
+}
+
+#[async_trait]
+impl CognitiveAgent for Critic {
+    fn id(&self) -> Uuid {
+        self.base.id
+    }
+
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn capabilities(&self) -> Vec<AgentCapability> {
+        self.base.capabilities.clone()
+    }
+
+    fn base(&self) -> Option<&BaseAgent> {
+        Some(&self.base)
+    }
+
+    fn base_mut(&mut self) -> Option<&mut BaseAgent> {
+        Some(&mut self.base)
+    }
+
+    async fn initialize(&mut self, neural_network: Arc<ForgeNeuralNetwork>, event_bus: Arc<EventBus>) -> Result<()> {
+        self.base.transition_state(AgentState::Initializing).await?;
+
+        self.base.neural_network = Some(neural_network);
+        self.base.event_bus = Some(event_bus);
+
+        self.base.logger.info("Critic initialized");
+
+        self.base.transition_state(AgentState::Active).await?;
+        Ok(())
+    }
+
+    async fn activate(&mut self) -> Result<()> {
+        self.base.transition_state(AgentState::Active).await?;
+        self.base.logger.info("Critic activated");
+        Ok(())
+    }
+
+    async fn process(&mut self) -> Result<()> {
+        // Reviews happen as work items are handed over, so a cycle only marks activity
+        self.base.transition_state(AgentState::Processing).await?;
+        self.base.update_activity();
+        self.base.transition_state(AgentState::Active).await?;
+        Ok(())
+    }
+
+    async fn suspend(&mut self) -> Result<()> {
+        self.base.transition_state(AgentState::Suspended).await?;
+        self.base.logger.info("Critic suspended");
+        Ok(())
+    }
+
+    async fn terminate(&mut self) -> Result<()> {
+        self.base.transition_state(AgentState::Terminating).await?;
+        self.base.transition_state(AgentState::Terminated).await?;
+        self.base.logger.info("Critic terminated");
+        Ok(())
+    }
+
+    fn state(&self) -> AgentState {
+        self.base.state.clone()
+    }
+
+    async fn receive_event(&mut self, _event: SystemEvent) -> Result<()> {
+        self.base.update_activity();
+        Ok(())
+    }
+}
+
+impl Default for Critic {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_well_formed_work_outscores_malformed_work() {
+        let critic = Critic::new();
+        let well_formed = WorkItem {
+            description: "Stage 'draft' handled by TrafficSeer".to_string(),
+            result: Some(serde_json::json!({
+                "agent": "TrafficSeer",
+                "output": "draft output from TrafficSeer",
+                "confidence": 0.9,
+            })),
+            timestamp: chrono::Utc::now(),
+        };
+        let malformed = WorkItem {
+            description: "Stage 'draft' handled by TrafficSeer".to_string(),
+            result: Some(serde_json::json!({
+                "agent": "MemoryWeaver",
+                "output": "",
+                "confidence": 1.7,
+            })),
+            timestamp: chrono::Utc::now() + chrono::Duration::hours(1),
+        };
+
+        let good = critic.critique(&well_formed);
+        let bad = critic.critique(&malformed);
+
+        assert!(good.score > bad.score, "{:?} vs {:?}", good, bad);
+        assert!(good.issues.is_empty(), "{:?}", good.issues);
+        assert_eq!(bad.issues.len(), 4, "{:?}", bad.issues);
+        assert!(critic.vetoes(&malformed));
+        assert!(!critic.vetoes(&well_formed));
+
+        let empty = WorkItem {
+            description: String::new(),
+            result: None,
+            timestamp: chrono::Utc::now(),
+        };
+        assert_eq!(critic.critique(&empty).issues.len(), 2);
+    }
+}
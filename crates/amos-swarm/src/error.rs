@@ -0,0 +1,38 @@
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Errors produced while assembling or running a swarm
+#[derive(Debug, Error)]
+pub enum SwarmError {
+    /// The swarm configuration is incomplete or inconsistent
+    #[error("Invalid swarm configuration: {0}")]
+    InvalidConfiguration(String),
+    
+    /// An agent failed to initialize or activate
+    #[error("Agent {agent_id} failed to start: {reason}")]
+    AgentStartup { agent_id: Uuid, reason: String },
+    
+    /// An agent could not be added to the swarm
+    #[error("Failed to spawn agent {agent_id}: {reason}")]
+    SpawnFailed { agent_id: Uuid, reason: String },
+    
+    /// A task graph edge names a task that is not in the graph
+    #[error("Task graph refers to unknown task {0}")]
+    UnknownTask(Uuid),
+    
+    /// Two tasks handed to a task graph share an id
+    #[error("Task graph has more than one task {0}")]
+    DuplicateTask(Uuid),
+    
+    /// Task graph edges loop back on themselves, through the listed tasks
+    #[error("Task graph has a dependency cycle through {0:?}")]
+    DependencyCycle(Vec<Uuid>),
+    
+    /// A task in a graph failed, so its downstream tasks cannot run
+    #[error("Task {task_id} failed: {reason}")]
+    TaskFailed { task_id: Uuid, reason: String },
+    
+    /// Blocking orchestration was called from inside an async runtime, or its own runtime failed to start
+    #[error("Cannot orchestrate blocking: {0}")]
+    BlockingRuntime(String),
+}
@@ -0,0 +1,128 @@
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+use crate::error::SwarmError;
+use crate::task::{Task, TaskInput, TaskResult};
+
+/// Tasks linked by `(upstream, downstream)` dependency edges, handed out in
+/// dependency order as the tasks they wait on complete
+pub struct TaskGraph {
+    /// Tasks not yet handed out
+    pending: HashMap<Uuid, Task>,
+    upstream: HashMap<Uuid, Vec<Uuid>>,
+    downstream: HashMap<Uuid, Vec<Uuid>>,
+    /// Upstream tasks each pending task is still waiting on
+    waiting_on: HashMap<Uuid, usize>,
+}
+
+impl TaskGraph {
+    /// Fails if two tasks share an id, an edge names a task not in `tasks`, or the
+    /// edges form a cycle
+    pub fn new(tasks: Vec<Task>, edges: Vec<(Uuid, Uuid)>) -> Result<Self, SwarmError> {
+        let mut pending: HashMap<Uuid, Task> = HashMap::with_capacity(tasks.len());
+        for task in tasks {
+            let task_id = task.id;
+            if pending.insert(task_id, task).is_some() {
+                return Err(SwarmError::DuplicateTask(task_id));
+            }
+        }
+        let mut upstream: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        let mut downstream: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+
+        let edges: HashSet<(Uuid, Uuid)> = edges.into_iter().collect();
+        for (from, to) in edges {
+            for id in [from, to] {
+                if !pending.contains_key(&id) {
+                    return Err(SwarmError::UnknownTask(id));
+                }
+            }
+            upstream.entry(to).or_default().push(from);
+            downstream.entry(from).or_default().push(to);
+        }
+
+        let waiting_on = pending.keys()
+            .map(|id| (*id, upstream.get(id).map_or(0, Vec::len)))
+            .collect();
+        let graph = Self { pending, upstream, downstream, waiting_on };
+        graph.check_acyclic()?;
+
+        Ok(graph)
+    }
+
+    /// Kahn's algorithm: whatever can't be peeled off in dependency order is on a cycle
+    fn check_acyclic(&self) -> Result<(), SwarmError> {
+        let mut waiting_on = self.waiting_on.clone();
+        let mut ready: Vec<Uuid> = waiting_on.iter()
+            .filter(|(_, count)| **count == 0)
+            .map(|(id, _)| *id)
+            .collect();
+
+        while let Some(id) = ready.pop() {
+            waiting_on.remove(&id);
+            for next in self.downstream.get(&id).into_iter().flatten() {
+                if let Some(count) = waiting_on.get_mut(next) {
+                    *count -= 1;
+                    if *count == 0 {
+                        ready.push(*next);
+                    }
+                }
+            }
+        }
+
+        if waiting_on.is_empty() {
+            Ok(())
+        } else {
+            let mut cycle: Vec<Uuid> = waiting_on.into_keys().collect();
+            cycle.sort();
+            Err(SwarmError::DependencyCycle(cycle))
+        }
+    }
+
+    /// Hand out every task whose upstream tasks have all completed, with their
+    /// outputs from `results` folded into its input
+    pub fn take_ready(&mut self, results: &HashMap<Uuid, TaskResult>) -> Vec<Task> {
+        let ready: Vec<Uuid> = self.pending.keys()
+            .filter(|id| self.waiting_on.get(*id) == Some(&0))
+            .copied()
+            .collect();
+
+        ready.into_iter()
+            .filter_map(|id| self.pending.remove(&id))
+            .map(|task| {
+                let upstream = self.upstream.get(&task.id).cloned().unwrap_or_default();
+                with_upstream_outputs(task, &upstream, results)
+            })
+            .collect()
+    }
+
+    /// Mark a task finished, releasing whatever waits on it
+    pub fn complete(&mut self, task_id: Uuid) {
+        for next in self.downstream.get(&task_id).into_iter().flatten() {
+            if let Some(count) = self.waiting_on.get_mut(next) {
+                *count = count.saturating_sub(1);
+            }
+        }
+    }
+}
+
+/// Wrap a task's input as `{"input": ..., "upstream": {task id: output}}`
+fn with_upstream_outputs(mut task: Task, upstream: &[Uuid], results: &HashMap<Uuid, TaskResult>) -> Task {
+    if upstream.is_empty() {
+        return task;
+    }
+
+    let outputs: serde_json::Map<String, serde_json::Value> = upstream.iter()
+        .map(|id| {
+            let output = results.get(id)
+                .and_then(|result| serde_json::to_value(&result.output).ok())
+                .unwrap_or_default();
+            (id.to_string(), output)
+        })
+        .collect();
+    let input = serde_json::to_value(&task.input).unwrap_or_default();
+    task.input = TaskInput::Custom(serde_json::json!({
+        "input": input,
+        "upstream": outputs,
+    }));
+
+    task
+}
@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use crate::task::TaskStrategy;
+
+/// Sub-buckets per doubling of latency, bounding the relative error to about 19%
+const BUCKETS_PER_DOUBLING: f64 = 4.0;
+
+/// Enough buckets to cover latencies up to roughly 18 minutes in microseconds
+const BUCKET_COUNT: usize = 120;
+
+/// Task latency percentiles in milliseconds
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Percentiles {
+    pub samples: u64,
+    pub p50: f64,
+    pub p95: f64,
+    pub p99: f64,
+}
+
+/// Log-bucketed histogram of task latencies
+#[derive(Debug, Clone)]
+pub struct LatencyHistogram {
+    buckets: Vec<u64>,
+    count: u64,
+    max_us: u64,
+}
+
+impl LatencyHistogram {
+    pub fn new() -> Self {
+        Self {
+            buckets: vec![0; BUCKET_COUNT],
+            count: 0,
+            max_us: 0,
+        }
+    }
+    
+    pub fn record(&mut self, latency: Duration) {
+        let us = latency.as_micros().min(u64::MAX as u128) as u64;
+        self.buckets[Self::bucket_index(us)] += 1;
+        self.count += 1;
+        self.max_us = self.max_us.max(us);
+    }
+    
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+    
+    /// Latency in milliseconds below which `quantile` of the samples fall
+    pub fn percentile(&self, quantile: f64) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        
+        let rank = ((quantile.clamp(0.0, 1.0) * self.count as f64).ceil() as u64).max(1);
+        let mut seen = 0;
+        for (index, count) in self.buckets.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                // Report the bucket's upper edge, but never more than was actually observed
+                let upper_us = 2f64.powf((index + 1) as f64 / BUCKETS_PER_DOUBLING);
+                return upper_us.min(self.max_us as f64) / 1000.0;
+            }
+        }
+        
+        self.max_us as f64 / 1000.0
+    }
+    
+    pub fn percentiles(&self) -> Percentiles {
+        Percentiles {
+            samples: self.count,
+            p50: self.percentile(0.50),
+            p95: self.percentile(0.95),
+            p99: self.percentile(0.99),
+        }
+    }
+    
+    fn bucket_index(us: u64) -> usize {
+        let index = ((us.max(1) as f64).log2() * BUCKETS_PER_DOUBLING) as usize;
+        index.min(BUCKET_COUNT - 1)
+    }
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Task latency histograms kept per strategy; may be shared by several orchestrators
+#[derive(Debug, Default)]
+pub struct LatencyTracker {
+    histograms: RwLock<HashMap<&'static str, LatencyHistogram>>,
+}
+
+impl LatencyTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    pub async fn record(&self, strategy: &TaskStrategy, latency: Duration) {
+        self.histograms.write().await
+            .entry(strategy.name())
+            .or_default()
+            .record(latency);
+    }
+    
+    pub async fn percentiles(&self, strategy: &TaskStrategy) -> Percentiles {
+        self.histograms.read().await
+            .get(strategy.name())
+            .map(LatencyHistogram::percentiles)
+            .unwrap_or_default()
+    }
+    
+    /// Percentiles for every strategy with recorded tasks, ordered by strategy name
+    pub async fn all_percentiles(&self) -> Vec<(&'static str, Percentiles)> {
+        let mut all: Vec<_> = self.histograms.read().await
+            .iter()
+            .map(|(name, histogram)| (*name, histogram.percentiles()))
+            .collect();
+        all.sort_by_key(|(name, _)| *name);
+        all
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_percentiles_track_the_tail() {
+        let mut histogram = LatencyHistogram::new();
+        for _ in 0..98 {
+            histogram.record(Duration::from_millis(10));
+        }
+        histogram.record(Duration::from_millis(400));
+        histogram.record(Duration::from_millis(400));
+        
+        let percentiles = histogram.percentiles();
+        assert_eq!(percentiles.samples, 100);
+        assert!(percentiles.p50 >= 10.0 && percentiles.p50 < 12.0, "{:?}", percentiles);
+        assert!(percentiles.p99 > 300.0, "{:?}", percentiles);
+        assert!(percentiles.p99 <= 400.0);
+    }
+    
+    #[test]
+    fn test_empty_histogram_reports_zero() {
+        assert_eq!(LatencyHistogram::new().percentiles(), Percentiles::default());
+    }
+}
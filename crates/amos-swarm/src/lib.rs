@@ -2,18 +2,56 @@ pub mod orchestrator;
 pub mod topology;
 pub mod task;
 pub mod coordination;
+pub mod consensus;
+pub mod replay;
+pub mod latency;
+pub mod capability_index;
+pub mod circuit_breaker;
+pub mod critic;
+pub mod blackboard;
+pub mod admission;
+pub mod swarm_agent;
+pub mod graph;
+pub mod builder;
+pub mod error;
+#[cfg(test)]
+mod test_support;
 
-pub use orchestrator::{SwarmOrchestrator, SwarmConfig};
+pub use builder::{SwarmBuilder, bootstrap_standard_swarm, STANDARD_SWARM_NAME};
+pub use error::SwarmError;
+pub use orchestrator::{SwarmOrchestrator, SwarmConfig, AgentSelection, ActiveTaskInfo, CompletedTaskRecord, IntervalObserver};
 pub use topology::{SwarmTopology, AgentPlacement};
-pub use task::{Task, TaskResult, TaskStrategy};
-pub use coordination::{CoordinationProtocol, MessageBus};
+pub use task::{Task, TaskResult, TaskStrategy, StageSpec, Reassignment, AgentFailure, AgentFailureReason, Escalation};
+pub use coordination::{CoordinationProtocol, MessageBus, DeliveryError, LogicalDest};
+pub use consensus::{ConsensusAggregator, ConsensusResult, ConvergenceSchedule};
+pub use replay::{RecordedRun, RecordedTask, RecordedOutput};
+pub use latency::{LatencyHistogram, LatencyTracker, Percentiles};
+pub use capability_index::CapabilityIndex;
+pub use circuit_breaker::{BreakerState, CircuitBreaker};
+pub use critic::{Critic, Critique};
+pub use blackboard::{Blackboard, VersionedValue, WriteConflict};
+pub use admission::QueuedTaskInfo;
+pub use swarm_agent::SwarmAgent;
+pub use graph::TaskGraph;
 
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use uuid::Uuid;
 use amos_core::neural::ForgeNeuralNetwork;
+use amos_core::EventBus;
 use amos_agents::CognitiveAgent;
 use std::collections::HashMap;
+use futures::future::{BoxFuture, FutureExt};
+use futures::stream::{FuturesUnordered, StreamExt};
+
+/// Broadcast capacity of each swarm's message bus
+const MESSAGE_BUS_CAPACITY: usize = 1000;
+
+thread_local! {
+    /// Runtime `orchestrate_blocking` drives tasks on, built once per thread and reused
+    static BLOCKING_RUNTIME: std::io::Result<tokio::runtime::Runtime> =
+        tokio::runtime::Builder::new_current_thread().enable_all().build();
+}
 
 /// AMOS Swarm - Biological intelligence orchestration inspired by ruv-swarm
 /// 
@@ -27,6 +65,12 @@ pub struct AmosSwarm {
     pub agents: Arc<RwLock<HashMap<Uuid, Arc<dyn CognitiveAgent>>>>,
     pub neural_network: Arc<ForgeNeuralNetwork>,
     pub orchestrator: Arc<SwarmOrchestrator>,
+    /// Workspace the swarm's agents share knowledge through
+    pub blackboard: Arc<Blackboard>,
+    /// Bus the swarm's agents message each other on; knowledge sent here lands on `blackboard`
+    pub message_bus: Arc<MessageBus>,
+    /// Swarms that joined as members, keyed by the agent id they joined under
+    pub sub_swarms: Arc<RwLock<HashMap<Uuid, AmosSwarm>>>,
 }
 
 impl AmosSwarm {
@@ -40,6 +84,9 @@ impl AmosSwarm {
             neural_network.clone(),
         ));
         
+        let blackboard = Arc::new(Blackboard::new());
+        let message_bus = Arc::new(MessageBus::new(MESSAGE_BUS_CAPACITY).with_blackboard(blackboard.clone()));
+        
         Self {
             id: Uuid::new_v4(),
             name,
@@ -47,15 +94,28 @@ impl AmosSwarm {
             agents: Arc::new(RwLock::new(HashMap::new())),
             neural_network,
             orchestrator,
+            blackboard,
+            message_bus,
+            sub_swarms: Arc::new(RwLock::new(HashMap::new())),
         }
     }
     
-    /// Spawn a new agent into the swarm
+    /// Publish agent join/leave events on the given bus, and follow the capability
+    /// changes agents announce on it
+    pub async fn with_event_bus(self, event_bus: Arc<EventBus>) -> Self {
+        self.orchestrator.attach_event_bus(event_bus.clone());
+        event_bus.subscribe(self.orchestrator.clone()).await;
+        self
+    }
+    
+    /// Spawn a new agent into the swarm. On failure the swarm is left as it was.
     pub async fn spawn_agent(
         &self,
         agent: Arc<dyn CognitiveAgent>,
     ) -> Result<Uuid, String> {
         let agent_id = agent.id();
+        let agent_type = agent.name().to_string();
+        let capabilities = agent.capabilities();
         let mut agents = self.agents.write().await;
         
         // Check swarm capacity based on topology
@@ -70,14 +130,88 @@ impl AmosSwarm {
             return Err("Swarm at maximum capacity".to_string());
         }
         
+        if agents.contains_key(&agent_id) {
+            return Err(format!("Agent {} is already in the swarm", agent_id));
+        }
+        
         agents.insert(agent_id, agent);
         
-        // Notify orchestrator of new agent
-        self.orchestrator.on_agent_joined(agent_id).await;
+        // Notify orchestrator of new agent, taking the agent back out if it can't be placed
+        if let Err(e) = self.orchestrator.on_agent_joined(agent_id, &agent_type, &capabilities).await {
+            agents.remove(&agent_id);
+            return Err(format!("Agent {} ({}) failed to join swarm: {}", agent_id, agent_type, e));
+        }
         
         Ok(agent_id)
     }
     
+    /// Initialize, activate and spawn an agent. If any step fails the agent is
+    /// terminated and the swarm is left as it was.
+    pub async fn start_agent(
+        &self,
+        mut agent: Box<dyn CognitiveAgent>,
+        event_bus: Arc<EventBus>,
+    ) -> Result<Uuid, SwarmError> {
+        let agent_id = agent.id();
+        
+        let started = match agent.initialize(self.neural_network.clone(), event_bus).await {
+            Ok(()) => agent.activate().await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = started {
+            // Best effort: the agent may not have got far enough to terminate
+            let _ = agent.terminate().await;
+            return Err(SwarmError::AgentStartup { agent_id, reason: e.to_string() });
+        }
+        
+        self.spawn_agent(Arc::from(agent)).await
+            .map_err(|reason| SwarmError::SpawnFailed { agent_id, reason })
+    }
+    
+    /// Spawn a whole swarm as a single member, through a `SwarmAgent`. A swarm can't
+    /// join itself or any swarm nested inside it.
+    pub async fn spawn_swarm(&self, swarm: AmosSwarm) -> Result<Uuid, String> {
+        if swarm.contains_swarm(self.id).await {
+            return Err(format!(
+                "Swarm {} contains swarm {} and cannot join it",
+                swarm.name, self.name
+            ));
+        }
+        
+        let agent = SwarmAgent::new(swarm.clone());
+        let agent_id = self.spawn_agent(Arc::new(agent)).await?;
+        self.sub_swarms.write().await.insert(agent_id, swarm);
+        
+        Ok(agent_id)
+    }
+    
+    /// Whether `swarm_id` is this swarm or one nested anywhere inside it
+    fn contains_swarm(&self, swarm_id: Uuid) -> BoxFuture<'_, bool> {
+        async move {
+            if self.id == swarm_id {
+                return true;
+            }
+            
+            let sub_swarms: Vec<AmosSwarm> = self.sub_swarms.read().await.values().cloned().collect();
+            for sub_swarm in &sub_swarms {
+                if sub_swarm.contains_swarm(swarm_id).await {
+                    return true;
+                }
+            }
+            false
+        }.boxed()
+    }
+    
+    /// Every capability at least one of the swarm's agents has right now
+    pub async fn capabilities(&self) -> Vec<amos_agents::AgentCapability> {
+        swarm_agent::member_capabilities(self.agents.read().await.values())
+    }
+    
+    /// Start applying coordination messages (such as neural sync) in the background
+    pub fn start_coordination(&self) -> tokio::task::JoinHandle<()> {
+        self.orchestrator.start_coordination_loop()
+    }
+    
     /// Remove an agent from the swarm
     pub async fn remove_agent(&self, agent_id: Uuid) -> Result<(), String> {
         let mut agents = self.agents.write().await;
@@ -86,6 +220,8 @@ impl AmosSwarm {
             return Err(format!("Agent {} not found in swarm", agent_id));
         }
         
+        self.sub_swarms.write().await.remove(&agent_id);
+        
         // Notify orchestrator of agent departure
         self.orchestrator.on_agent_left(agent_id).await;
         
@@ -98,6 +234,16 @@ impl AmosSwarm {
         task: Task,
         strategy: TaskStrategy,
     ) -> Result<TaskResult, String> {
+        // Nested swarms gain and lose capabilities as their own rosters change
+        let sub_swarms: Vec<(Uuid, AmosSwarm)> = self.sub_swarms.read().await
+            .iter()
+            .map(|(id, swarm)| (*id, swarm.clone()))
+            .collect();
+        for (agent_id, sub_swarm) in &sub_swarms {
+            let capabilities = sub_swarm.capabilities().await;
+            self.orchestrator.refresh_agent_capabilities(*agent_id, &capabilities).await;
+        }
+        
         let agents = self.agents.read().await;
         
         if agents.is_empty() {
@@ -109,21 +255,99 @@ impl AmosSwarm {
             task,
             strategy,
             agents.clone(),
+            false,
         ).await
     }
     
-    /// Get swarm status
-    pub async fn status(&self) -> SwarmStatus {
-        let agents = self.agents.read().await;
+    /// Run `orchestrate` to completion on the current thread, for benchmarks and
+    /// simple CLIs. Must not be called from inside an async runtime.
+    pub fn orchestrate_blocking(&self, task: Task, strategy: TaskStrategy) -> Result<TaskResult, SwarmError> {
+        if tokio::runtime::Handle::try_current().is_ok() {
+            return Err(SwarmError::BlockingRuntime(
+                "already inside an async runtime; await `orchestrate` instead".to_string(),
+            ));
+        }
         
-        SwarmStatus {
-            id: self.id,
-            name: self.name.clone(),
-            topology: self.topology.clone(),
-            agent_count: agents.len(),
-            active_tasks: self.orchestrator.active_task_count().await,
-            health: self.calculate_health(&agents).await,
+        let task_id = task.id;
+        BLOCKING_RUNTIME.with(|runtime| {
+            let runtime = runtime.as_ref()
+                .map_err(|e| SwarmError::BlockingRuntime(e.to_string()))?;
+            runtime.block_on(self.orchestrate(task, strategy))
+                .map_err(|reason| SwarmError::TaskFailed { task_id, reason })
+        })
+    }
+    
+    /// Run a DAG of tasks, where each `(upstream, downstream)` edge makes a task wait for
+    /// another. Tasks start in parallel as soon as their upstream tasks finish, and see those
+    /// tasks' outputs under `upstream` in their input. Results are keyed by task id. The
+    /// graph stops at the first task that doesn't complete.
+    pub async fn orchestrate_graph(
+        &self,
+        tasks: Vec<Task>,
+        edges: Vec<(Uuid, Uuid)>,
+    ) -> Result<HashMap<Uuid, TaskResult>, SwarmError> {
+        let mut graph = TaskGraph::new(tasks, edges)?;
+        let mut results = HashMap::new();
+        let mut running = FuturesUnordered::new();
+        
+        loop {
+            for task in graph.take_ready(&results) {
+                let task_id = task.id;
+                running.push(async move {
+                    (task_id, self.orchestrate(task, TaskStrategy::Parallel).await)
+                });
+            }
+            
+            let Some((task_id, result)) = running.next().await else {
+                break;
+            };
+            let result = result.map_err(|reason| SwarmError::TaskFailed { task_id, reason })?;
+            // Downstream tasks need a complete output to build on
+            if !matches!(result.status, task::TaskStatus::Completed) {
+                return Err(SwarmError::TaskFailed {
+                    task_id,
+                    reason: format!("Task ended {:?}", result.status),
+                });
+            }
+            graph.complete(task_id);
+            results.insert(task_id, result);
         }
+        
+        Ok(results)
+    }
+    
+    /// Graphviz DOT rendering of how the swarm's agents are connected
+    pub async fn topology_dot(&self) -> String {
+        let labels: HashMap<Uuid, String> = self.agents.read().await
+            .iter()
+            .map(|(id, agent)| (*id, agent.name().to_string()))
+            .collect();
+        let placements = self.orchestrator.agent_placements().await;
+        
+        self.topology.render_dot(&placements, &labels)
+    }
+    
+    /// Get swarm status, including that of every nested swarm
+    pub fn status(&self) -> BoxFuture<'_, SwarmStatus> {
+        async move {
+            let sub_swarms: Vec<AmosSwarm> = self.sub_swarms.read().await.values().cloned().collect();
+            let mut sub_statuses = Vec::with_capacity(sub_swarms.len());
+            for sub_swarm in &sub_swarms {
+                sub_statuses.push(sub_swarm.status().await);
+            }
+            
+            let agents = self.agents.read().await;
+            
+            SwarmStatus {
+                id: self.id,
+                name: self.name.clone(),
+                topology: self.topology.clone(),
+                agent_count: agents.len(),
+                active_tasks: self.orchestrator.active_task_count().await,
+                health: self.calculate_health(&agents).await,
+                sub_swarms: sub_statuses,
+            }
+        }.boxed()
     }
     
     async fn calculate_health(
@@ -154,12 +378,50 @@ pub struct SwarmStatus {
     pub agent_count: usize,
     pub active_tasks: usize,
     pub health: f64,
+    pub sub_swarms: Vec<SwarmStatus>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use amos_agents::ArchitectAgent;
+    use amos_agents::TrafficSeer;
+    use crate::test_support::ScriptedAgent;
+    
+    #[test]
+    fn test_orchestrate_blocking_runs_outside_async_context() {
+        let swarm = AmosSwarm::new(
+            "Bench Swarm".to_string(),
+            SwarmTopology::Mesh { max_connections: 6 },
+            Arc::new(ForgeNeuralNetwork::new()),
+        );
+        
+        let setup = tokio::runtime::Runtime::new().unwrap();
+        let agent_id = setup.block_on(swarm.spawn_agent(Arc::new(TrafficSeer::new()))).unwrap();
+        drop(setup);
+        
+        // Repeated calls reuse the same runtime
+        for _ in 0..2 {
+            let task = Task::new("Profile".to_string(), task::TaskInput::Text("".to_string()));
+            let result = swarm.orchestrate_blocking(task, TaskStrategy::Parallel).unwrap();
+            assert!(matches!(result.status, task::TaskStatus::Completed));
+            assert!(result.agent_contributions.contains_key(&agent_id));
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_orchestrate_blocking_refuses_nested_runtime() {
+        let swarm = AmosSwarm::new(
+            "Bench Swarm".to_string(),
+            SwarmTopology::Mesh { max_connections: 6 },
+            Arc::new(ForgeNeuralNetwork::new()),
+        );
+        let task = Task::new("Profile".to_string(), task::TaskInput::Text("".to_string()));
+        
+        assert!(matches!(
+            swarm.orchestrate_blocking(task, TaskStrategy::Parallel),
+            Err(SwarmError::BlockingRuntime(_))
+        ));
+    }
     
     #[tokio::test]
     async fn test_swarm_creation() {
@@ -184,12 +446,7 @@ mod tests {
             neural_network.clone(),
         );
         
-        let agent = Arc::new(ArchitectAgent::new(
-            Uuid::new_v4(),
-            "Test Architect",
-            neural_network,
-            false,
-        ));
+        let agent = Arc::new(TrafficSeer::new());
         
         let agent_id = swarm.spawn_agent(agent).await.unwrap();
         
@@ -202,4 +459,225 @@ mod tests {
         let status = swarm.status().await;
         assert_eq!(status.agent_count, 0);
     }
-}
\ No newline at end of file
+    
+    struct EventCollector {
+        events: Arc<tokio::sync::Mutex<Vec<amos_core::SystemEvent>>>,
+    }
+    
+    #[async_trait::async_trait]
+    impl amos_core::EventHandler for EventCollector {
+        async fn handle(&self, event: amos_core::SystemEvent) {
+            self.events.lock().await.push(event);
+        }
+        
+        fn event_types(&self) -> Vec<std::any::TypeId> {
+            vec![std::any::TypeId::of::<amos_core::SystemEvent>()]
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_join_and_leave_are_published() {
+        use amos_core::SystemEvent;
+        
+        let event_bus = Arc::new(EventBus::new());
+        event_bus.clone().start_processing().await;
+        
+        let events = Arc::new(tokio::sync::Mutex::new(Vec::new()));
+        event_bus.subscribe(Arc::new(EventCollector { events: events.clone() })).await;
+        
+        let swarm = AmosSwarm::new(
+            "Announcing Swarm".to_string(),
+            SwarmTopology::Ring,
+            Arc::new(ForgeNeuralNetwork::new()),
+        );
+        let early_id = swarm.spawn_agent(Arc::new(TrafficSeer::new())).await.unwrap();
+        
+        // Attaching the bus keeps the orchestrator and the placements it already has
+        let orchestrator = swarm.orchestrator.clone();
+        let swarm = swarm.with_event_bus(event_bus).await;
+        assert!(Arc::ptr_eq(&orchestrator, &swarm.orchestrator));
+        assert!(swarm.orchestrator.agent_placements().await.contains_key(&early_id));
+        
+        let agent_id = swarm.spawn_agent(Arc::new(TrafficSeer::new())).await.unwrap();
+        swarm.remove_agent(agent_id).await.unwrap();
+        
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        
+        let events = events.lock().await;
+        assert!(events.iter().any(|e| matches!(
+            e,
+            SystemEvent::AgentActivated { agent_id: id, agent_type } if *id == agent_id && agent_type == "TrafficSeer"
+        )));
+        assert!(events.contains(&SystemEvent::AgentDeactivated { agent_id }));
+    }
+    
+    #[tokio::test]
+    async fn test_topology_dot_clusters_hierarchy_levels() {
+        let empty = AmosSwarm::new(
+            "Empty Swarm".to_string(),
+            SwarmTopology::Mesh { max_connections: 6 },
+            Arc::new(ForgeNeuralNetwork::new()),
+        );
+        assert_eq!(empty.topology_dot().await, "graph swarm {\n}\n");
+        
+        let swarm = AmosSwarm::new(
+            "Tree Swarm".to_string(),
+            SwarmTopology::Hierarchical { levels: 3, agents_per_level: 2 },
+            Arc::new(ForgeNeuralNetwork::new()),
+        );
+        let mut ids = Vec::new();
+        for _ in 0..3 {
+            ids.push(swarm.spawn_agent(Arc::new(TrafficSeer::new())).await.unwrap());
+        }
+        
+        let dot = swarm.topology_dot().await;
+        assert!(dot.starts_with("digraph swarm {"));
+        for level in 0..3 {
+            assert!(dot.contains(&format!("subgraph cluster_level_{} {{", level)));
+        }
+        assert!(dot.contains("TrafficSeer"));
+        // Each agent lands one level deeper, under the previous one
+        assert!(dot.contains(&format!("\"{}\" -> \"{}\";", ids[0], ids[1])));
+        assert!(dot.contains(&format!("\"{}\" -> \"{}\";", ids[1], ids[2])));
+    }
+    
+    #[tokio::test]
+    async fn test_spawn_rolls_back_when_agent_cannot_join() {
+        let swarm = AmosSwarm::new(
+            "Rollback Swarm".to_string(),
+            SwarmTopology::Mesh { max_connections: 6 },
+            Arc::new(ForgeNeuralNetwork::new()),
+        );
+        swarm.spawn_agent(Arc::new(TrafficSeer::new())).await.unwrap();
+        
+        // Already placed by the orchestrator, so joining again fails
+        let agent = TrafficSeer::new();
+        let agent_id = agent.id();
+        swarm.orchestrator.on_agent_joined(agent_id, agent.name(), &agent.capabilities()).await.unwrap();
+        
+        let error = swarm.spawn_agent(Arc::new(agent)).await.unwrap_err();
+        assert!(error.contains(&agent_id.to_string()));
+        assert_eq!(swarm.status().await.agent_count, 1);
+        assert!(!swarm.agents.read().await.contains_key(&agent_id));
+    }
+    
+    #[tokio::test]
+    async fn test_swarm_on_an_event_bus_follows_granted_capabilities() {
+        use amos_agents::AgentCapability;
+        
+        let event_bus = Arc::new(EventBus::new());
+        event_bus.clone().start_processing().await;
+        let swarm = AmosSwarm::new(
+            "Growing Swarm".to_string(),
+            SwarmTopology::Mesh { max_connections: 6 },
+            Arc::new(ForgeNeuralNetwork::new()),
+        ).with_event_bus(event_bus.clone()).await;
+        
+        let mut seer = TrafficSeer::new();
+        seer.initialize(swarm.neural_network.clone(), event_bus).await.unwrap();
+        let seer_id = seer.id();
+        swarm.orchestrator.on_agent_joined(seer_id, seer.name(), &seer.capabilities()).await.unwrap();
+        
+        seer.grant_capability(AgentCapability::NeuralOptimization).await.unwrap();
+        
+        let selectable = tokio::time::timeout(std::time::Duration::from_secs(1), async {
+            loop {
+                let members = swarm.orchestrator.agents_with_capability(&AgentCapability::NeuralOptimization).await;
+                if !members.is_empty() {
+                    return members;
+                }
+                tokio::task::yield_now().await;
+            }
+        }).await.unwrap();
+        assert_eq!(selectable, vec![seer_id]);
+    }
+    
+    #[tokio::test]
+    async fn test_knowledge_on_the_swarm_bus_reaches_its_blackboard() {
+        use crate::coordination::{CoordinationMessage, MessageContent};
+        
+        let swarm = AmosSwarm::new(
+            "Shared Swarm".to_string(),
+            SwarmTopology::Mesh { max_connections: 6 },
+            Arc::new(ForgeNeuralNetwork::new()),
+        );
+        let (sender, receiver) = (Uuid::new_v4(), Uuid::new_v4());
+        let _rx = swarm.message_bus.register_agent(receiver).await;
+        
+        swarm.message_bus.send(CoordinationMessage::Direct {
+            from: sender,
+            to: receiver,
+            content: MessageContent::Knowledge { topic: "load".to_string(), data: serde_json::json!(0.4) },
+        }).await.unwrap();
+        
+        assert_eq!(swarm.blackboard.read("load").await.unwrap().value, serde_json::json!(0.4));
+    }
+    
+    #[tokio::test]
+    async fn test_task_graph_runs_independent_tasks_together_and_feeds_downstream() {
+        use task::TaskInput;
+        
+        let swarm = AmosSwarm::new(
+            "Graph Swarm".to_string(),
+            SwarmTopology::Mesh { max_connections: 6 },
+            Arc::new(ForgeNeuralNetwork::new()),
+        );
+        swarm.spawn_agent(Arc::new(ScriptedAgent::echoing(std::time::Duration::from_millis(100)))).await.unwrap();
+        
+        let a = Task::new("A".to_string(), TaskInput::Text("from a".to_string()));
+        let b = Task::new("B".to_string(), TaskInput::Text("from b".to_string()));
+        let c = Task::new("C".to_string(), TaskInput::Text("combine".to_string()));
+        let (a_id, b_id, c_id) = (a.id, b.id, c.id);
+        
+        let results = swarm.orchestrate_graph(vec![c, a, b], vec![(a_id, c_id), (b_id, c_id)]).await.unwrap();
+        assert_eq!(results.len(), 3);
+        
+        // A and B overlap, and C only starts once both are done
+        let (a_meta, b_meta, c_meta) = (&results[&a_id].metadata, &results[&b_id].metadata, &results[&c_id].metadata);
+        assert!(a_meta.start_time < b_meta.end_time.unwrap() && b_meta.start_time < a_meta.end_time.unwrap());
+        assert!(c_meta.start_time >= a_meta.end_time.unwrap().max(b_meta.end_time.unwrap()));
+        
+        // The echo agent answers with the input C was handed
+        let echo = results[&c_id].agent_contributions.values().next().unwrap();
+        let c_input = echo.work_items[0].result.as_ref().unwrap();
+        let upstream = &c_input["Custom"]["upstream"];
+        assert!(upstream[a_id.to_string()].to_string().contains("from a"));
+        assert!(upstream[b_id.to_string()].to_string().contains("from b"));
+        
+        // Cycles are rejected before anything runs
+        let x = Task::new("X".to_string(), TaskInput::Text(String::new()));
+        let y = Task::new("Y".to_string(), TaskInput::Text(String::new()));
+        let (x_id, y_id) = (x.id, y.id);
+        let cycle = swarm.orchestrate_graph(vec![x, y], vec![(x_id, y_id), (y_id, x_id)]).await;
+        assert!(matches!(cycle, Err(SwarmError::DependencyCycle(ids)) if ids.len() == 2));
+        
+        // So are tasks sharing an id
+        let z = Task::new("Z".to_string(), TaskInput::Text(String::new()));
+        let z_id = z.id;
+        let duplicated = swarm.orchestrate_graph(vec![z.clone(), z], vec![]).await;
+        assert!(matches!(duplicated, Err(SwarmError::DuplicateTask(id)) if id == z_id));
+    }
+    
+    #[tokio::test]
+    async fn test_task_graph_stops_at_a_task_that_does_not_complete() {
+        use task::{TaskInput, TaskRequirements};
+        
+        let swarm = AmosSwarm::new(
+            "Graph Swarm".to_string(),
+            SwarmTopology::Mesh { max_connections: 6 },
+            Arc::new(ForgeNeuralNetwork::new()),
+        );
+        swarm.spawn_agent(Arc::new(ScriptedAgent::echoing(std::time::Duration::from_secs(5)))).await.unwrap();
+        
+        let slow = Task::new("Slow".to_string(), TaskInput::Text(String::new()))
+            .with_requirements(TaskRequirements {
+                timeout: Some(std::time::Duration::from_millis(50)),
+                ..Default::default()
+            });
+        let after = Task::new("After".to_string(), TaskInput::Text(String::new()));
+        let (slow_id, after_id) = (slow.id, after.id);
+        
+        let result = swarm.orchestrate_graph(vec![slow, after], vec![(slow_id, after_id)]).await;
+        assert!(matches!(result, Err(SwarmError::TaskFailed { task_id, reason }) if task_id == slow_id && reason.contains("Timeout")));
+    }
+}
@@ -1,22 +1,72 @@
 use crate::{
-    task::{Task, TaskResult, TaskStatus, TaskStrategy, TaskOutput, TaskMetadata, AgentContribution, WorkItem, NeuralActivityMetrics},
+    task::{Task, TaskResult, TaskStatus, TaskStrategy, TaskOutput, TaskMetadata, AgentContribution, WorkItem, NeuralActivityMetrics, StageSpec, Reassignment, AgentFailure, AgentFailureReason, Escalation},
     topology::{SwarmTopology, AgentPlacement},
+    replay::{RecordedRun, RecordedOutput},
+    latency::{LatencyTracker, Percentiles},
+    capability_index::CapabilityIndex,
+    circuit_breaker::{BreakerState, CircuitBreaker},
+    critic::Critic,
+    consensus::{ConsensusAggregator, ConvergenceSchedule},
+    admission::{AdmissionQueue, QueuedTaskInfo},
 };
 use std::sync::Arc;
-use tokio::sync::{RwLock, mpsc};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio::sync::{RwLock, mpsc, watch};
 use uuid::Uuid;
-use std::collections::HashMap;
-use amos_core::neural::ForgeNeuralNetwork;
-use amos_agents::CognitiveAgent;
-use tracing::{info, debug, error};
+use std::collections::{HashMap, HashSet, VecDeque};
+use amos_core::neural::{ForgeNeuralNetwork, NodeType};
+use amos_core::{EventBus, EventHandler, SystemEvent};
+use amos_core::logging::{with_correlation_id, current_correlation_id};
+use amos_agents::{AgentCapability, AgentMetrics, CognitiveAgent};
+use std::time::Duration;
+use tracing::{info, debug, warn, error, info_span, Instrument};
+use serde::{Serialize, Deserialize};
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+/// Share of an agent's recent utilization carried over each time work is assigned
+const UTILIZATION_DECAY: f64 = 0.9;
+
+/// How a task picks among more capable agents than it needs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AgentSelection {
+    /// The first capable agents found
+    First,
+    /// A random pick weighted towards agents with little recent work
+    #[default]
+    LoadAware,
+}
 
 /// Configuration for the swarm orchestrator
 #[derive(Debug, Clone)]
 pub struct SwarmConfig {
     pub max_concurrent_tasks: usize,
     pub task_retry_attempts: usize,
+    /// Interval the coordination loop starts at before adapting to load
     pub coordination_interval_ms: u64,
+    /// Interval used once the swarm is saturated with work
+    pub min_coordination_interval_ms: u64,
+    /// Interval the loop relaxes to while the swarm is idle
+    pub max_coordination_interval_ms: u64,
     pub neural_sync_enabled: bool,
+    /// Simulated processing time per agent invocation
+    pub agent_processing_ms: u64,
+    /// Consecutive failed invocations before an agent's circuit breaker opens
+    pub breaker_failure_threshold: usize,
+    /// How long an open breaker keeps an agent out of selection
+    pub breaker_cooldown_ms: u64,
+    /// How Parallel tasks choose their agents
+    pub parallel_selection: AgentSelection,
+    /// Let a consensus task that never reaches `min_agreement` complete with its top
+    /// proposal, flagged low-confidence, instead of failing
+    pub best_effort_consensus: bool,
+    /// Rounds a consensus task deliberates for before it settles for best effort or fails
+    pub consensus_max_rounds: usize,
+    /// How the agreement a consensus task needs anneals between rounds, starting from
+    /// its `min_agreement`; `None` holds it at `min_agreement` every round
+    pub consensus_schedule: Option<ConvergenceSchedule>,
+    /// Finished tasks kept for `task_history`; the oldest are dropped beyond this
+    pub task_history_retention: usize,
 }
 
 impl Default for SwarmConfig {
@@ -25,20 +75,90 @@ impl Default for SwarmConfig {
             max_concurrent_tasks: 10,
             task_retry_attempts: 3,
             coordination_interval_ms: 100,
+            min_coordination_interval_ms: 10,
+            max_coordination_interval_ms: 200,
             neural_sync_enabled: true,
+            agent_processing_ms: 100,
+            breaker_failure_threshold: 3,
+            breaker_cooldown_ms: 30_000,
+            parallel_selection: AgentSelection::default(),
+            best_effort_consensus: false,
+            consensus_max_rounds: 1,
+            consensus_schedule: None,
+            task_history_retention: 100,
         }
     }
 }
 
+impl SwarmConfig {
+    /// Coordination interval for the given amount of outstanding work, scaling linearly
+    /// from the max interval when idle down to the min once `max_concurrent_tasks` is reached.
+    /// Never shorter than a millisecond, so a zero setting can't spin the loop.
+    pub fn coordination_interval(&self, load: usize) -> Duration {
+        let max = self.max_coordination_interval_ms.max(1);
+        let min = self.min_coordination_interval_ms.clamp(1, max);
+        let saturation = load as f64 / self.max_concurrent_tasks.max(1) as f64;
+        let span = (max - min) as f64 * saturation.min(1.0);
+        
+        Duration::from_millis(max - span.round() as u64)
+    }
+}
+
+/// Called with each interval the coordination loop settles on
+pub type IntervalObserver = Arc<dyn Fn(Duration) + Send + Sync>;
+
 /// Orchestrates task execution across the swarm
 pub struct SwarmOrchestrator {
     topology: SwarmTopology,
     neural_network: Arc<ForgeNeuralNetwork>,
+    event_bus: std::sync::RwLock<Option<Arc<EventBus>>>,
     config: SwarmConfig,
     agent_placements: Arc<RwLock<HashMap<Uuid, AgentPlacement>>>,
+    capability_index: Arc<RwLock<CapabilityIndex>>,
     active_tasks: Arc<RwLock<HashMap<Uuid, TaskExecution>>>,
     coordination_tx: mpsc::Sender<CoordinationMessage>,
     coordination_rx: Arc<RwLock<mpsc::Receiver<CoordinationMessage>>>,
+    latency: Arc<LatencyTracker>,
+    interval_observer: Option<IntervalObserver>,
+    suspended: watch::Sender<HashSet<Uuid>>,
+    breakers: Arc<RwLock<HashMap<Uuid, CircuitBreaker>>>,
+    critic: Option<Arc<Critic>>,
+    /// Decayed count of recent assignments per agent
+    utilization: Arc<RwLock<HashMap<Uuid, f64>>>,
+    /// Draws for load-aware selection; seeded when runs need to be reproducible
+    selection_rng: std::sync::Mutex<StdRng>,
+    /// Work counters for agents invoked through `execute_task`, which can't update their own
+    work_metrics: Arc<RwLock<HashMap<Uuid, AgentMetrics>>>,
+    admission: AdmissionQueue,
+    /// Finished tasks, oldest first
+    history: Arc<RwLock<VecDeque<CompletedTaskRecord>>>,
+    /// Network nodes standing in for agents and strategies during neural sync
+    sync_nodes: Arc<RwLock<HashMap<SyncNode, Uuid>>>,
+    /// Coordination loops currently draining `coordination_rx`
+    coordination_loops: Arc<AtomicUsize>,
+}
+
+/// Counts a coordination loop as running until the loop ends or is aborted
+struct RunningLoop(Arc<AtomicUsize>);
+
+impl RunningLoop {
+    fn start(loops: Arc<AtomicUsize>) -> Self {
+        loops.fetch_add(1, Ordering::SeqCst);
+        Self(loops)
+    }
+}
+
+impl Drop for RunningLoop {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// What a neural sync node represents
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum SyncNode {
+    Agent(Uuid),
+    Strategy(&'static str),
 }
 
 struct TaskExecution {
@@ -47,6 +167,141 @@ struct TaskExecution {
     assigned_agents: Vec<Uuid>,
     start_time: chrono::DateTime<chrono::Utc>,
     progress: f64,
+    contributions: HashMap<Uuid, AgentContribution>,
+    reassignments: Vec<Reassignment>,
+}
+
+/// Point-in-time view of a task the orchestrator is running
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActiveTaskInfo {
+    pub task_id: Uuid,
+    pub description: String,
+    pub strategy: TaskStrategy,
+    pub assigned_agents: Vec<Uuid>,
+    pub progress: f64,
+    pub start_time: chrono::DateTime<chrono::Utc>,
+    pub elapsed_ms: u64,
+}
+
+/// A task the orchestrator finished running, kept in its history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompletedTaskRecord {
+    pub task_id: Uuid,
+    pub description: String,
+    pub strategy: TaskStrategy,
+    pub selected_agents: Vec<Uuid>,
+    pub result: TaskResult,
+    pub completed_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Where agents' work comes from while a task executes
+#[derive(Clone)]
+enum OutputSource {
+    /// Invoke the live agents
+    Live(HashMap<Uuid, Arc<dyn CognitiveAgent>>),
+    /// Feed back work captured in an earlier run
+    Recorded(HashMap<Uuid, RecordedWork>),
+}
+
+/// One agent's captured work, handed back in the order it was produced
+#[derive(Clone)]
+struct RecordedWork {
+    agent_type: String,
+    work_items: Arc<std::sync::Mutex<VecDeque<WorkItem>>>,
+}
+
+impl RecordedWork {
+    fn from_outputs(outputs: &[RecordedOutput]) -> HashMap<Uuid, RecordedWork> {
+        let mut recorded: HashMap<Uuid, RecordedWork> = HashMap::new();
+        for output in outputs {
+            recorded.entry(output.agent_id)
+                .or_insert_with(|| RecordedWork {
+                    agent_type: output.agent_type.clone(),
+                    work_items: Arc::default(),
+                })
+                .work_items.lock().unwrap()
+                .push_back(output.work_item.clone());
+        }
+        recorded
+    }
+    
+    /// The next captured item; the last one repeats if the agent is invoked again
+    fn next(&self) -> Option<WorkItem> {
+        let mut work_items = self.work_items.lock().unwrap();
+        if work_items.len() > 1 {
+            work_items.pop_front()
+        } else {
+            work_items.front().cloned()
+        }
+    }
+}
+
+impl OutputSource {
+    fn agent_type(&self, agent_id: &Uuid) -> Option<String> {
+        match self {
+            OutputSource::Live(agents) => agents.get(agent_id).map(|agent| agent.name().to_string()),
+            OutputSource::Recorded(recorded) => recorded.get(agent_id).map(|work| work.agent_type.clone()),
+        }
+    }
+    
+    /// The agent to invoke, unless work is being replayed
+    fn live_agent(&self, agent_id: &Uuid) -> Option<Arc<dyn CognitiveAgent>> {
+        match self {
+            OutputSource::Live(agents) => agents.get(agent_id).cloned(),
+            OutputSource::Recorded(_) => None,
+        }
+    }
+    
+    fn recorded_work(&self, agent_id: &Uuid) -> Option<WorkItem> {
+        match self {
+            OutputSource::Live(_) => None,
+            OutputSource::Recorded(recorded) => recorded.get(agent_id).and_then(RecordedWork::next),
+        }
+    }
+}
+
+/// How a single parallel agent invocation ended
+enum Invocation {
+    Done(WorkItem),
+    Suspended,
+    Failed(String),
+    TimedOut,
+}
+
+/// What a work item proposes in a consensus vote: its `output` field. Work
+/// without one abstains, since the rest of a result (e.g. the agent type)
+/// differs between agents that agree.
+fn proposal_of(work_item: &WorkItem) -> Option<String> {
+    match work_item.result.as_ref()?.get("output")? {
+        serde_json::Value::Null => None,
+        serde_json::Value::String(output) => Some(output.clone()),
+        output => Some(output.to_string()),
+    }
+}
+
+/// Simulate `duration` of work, giving up as soon as the agent is suspended.
+/// Returns whether the work finished.
+async fn process_unless_suspended(
+    mut suspended: watch::Receiver<HashSet<Uuid>>,
+    agent_id: Uuid,
+    duration: Duration,
+) -> bool {
+    let work = tokio::time::sleep(duration);
+    tokio::pin!(work);
+    
+    loop {
+        if suspended.borrow_and_update().contains(&agent_id) {
+            return false;
+        }
+        tokio::select! {
+            _ = &mut work => return true,
+            changed = suspended.changed() => if changed.is_err() {
+                // The orchestrator is gone, so nothing can suspend the agent any more
+                work.await;
+                return true;
+            },
+        }
+    }
 }
 
 enum CoordinationMessage {
@@ -66,22 +321,83 @@ impl SwarmOrchestrator {
         Self {
             topology,
             neural_network,
+            event_bus: std::sync::RwLock::new(None),
             config: SwarmConfig::default(),
             agent_placements: Arc::new(RwLock::new(HashMap::new())),
+            capability_index: Arc::new(RwLock::new(CapabilityIndex::new())),
             active_tasks: Arc::new(RwLock::new(HashMap::new())),
             coordination_tx: tx,
             coordination_rx: Arc::new(RwLock::new(rx)),
+            latency: Arc::new(LatencyTracker::new()),
+            interval_observer: None,
+            suspended: watch::channel(HashSet::new()).0,
+            breakers: Arc::new(RwLock::new(HashMap::new())),
+            critic: None,
+            utilization: Arc::new(RwLock::new(HashMap::new())),
+            selection_rng: std::sync::Mutex::new(StdRng::from_entropy()),
+            work_metrics: Arc::new(RwLock::new(HashMap::new())),
+            admission: AdmissionQueue::new(SwarmConfig::default().max_concurrent_tasks),
+            history: Arc::new(RwLock::new(VecDeque::new())),
+            sync_nodes: Arc::new(RwLock::new(HashMap::new())),
+            coordination_loops: Arc::new(AtomicUsize::new(0)),
         }
     }
     
     pub fn with_config(mut self, config: SwarmConfig) -> Self {
+        self.admission = AdmissionQueue::new(config.max_concurrent_tasks);
         self.config = config;
         self
     }
     
-    /// Called when an agent joins the swarm
-    pub async fn on_agent_joined(&self, agent_id: Uuid) {
+    /// Record task latencies into a tracker shared with other orchestrators
+    pub fn with_latency_tracker(mut self, latency: Arc<LatencyTracker>) -> Self {
+        self.latency = latency;
+        self
+    }
+    
+    /// Draw load-aware agent selections from a fixed seed, so they repeat run to run
+    pub fn with_selection_seed(self, seed: u64) -> Self {
+        *self.selection_rng.lock().unwrap() = StdRng::seed_from_u64(seed);
+        self
+    }
+    
+    /// Report every interval the coordination loop picks, e.g. for metrics
+    pub fn with_interval_observer(mut self, observer: IntervalObserver) -> Self {
+        self.interval_observer = Some(observer);
+        self
+    }
+    
+    /// Announce agents joining and leaving the swarm on this bus
+    pub fn with_event_bus(self, event_bus: Arc<EventBus>) -> Self {
+        self.attach_event_bus(event_bus);
+        self
+    }
+    
+    /// Start announcing on `event_bus` from now on, e.g. once the orchestrator is shared
+    pub fn attach_event_bus(&self, event_bus: Arc<EventBus>) {
+        *self.event_bus.write().unwrap() = Some(event_bus);
+    }
+    
+    fn event_bus(&self) -> Option<Arc<EventBus>> {
+        self.event_bus.read().unwrap().clone()
+    }
+    
+    /// Have a Critic veto low-scoring outputs in pipeline and consensus tasks
+    pub fn with_critic(mut self, critic: Arc<Critic>) -> Self {
+        self.critic = Some(critic);
+        self
+    }
+    
+    /// Called when an agent joins the swarm; only joined agents are found by capability.
+    /// Fails, changing nothing, if the agent has already joined.
+    pub async fn on_agent_joined(&self, agent_id: Uuid, agent_type: &str, capabilities: &[AgentCapability]) -> Result<(), String> {
         let mut placements = self.agent_placements.write().await;
+        if placements.contains_key(&agent_id) {
+            return Err(format!("Agent {} has already joined", agent_id));
+        }
+        
+        self.capability_index.write().await.insert(agent_id, capabilities);
+        
         let placement = self.topology.calculate_placement(&placements);
         
         // Update existing agent placements
@@ -90,12 +406,55 @@ impl SwarmOrchestrator {
         }
         
         placements.insert(agent_id, placement);
+        drop(placements);
+        
+        if let Some(event_bus) = self.event_bus() {
+            event_bus.publish(SystemEvent::AgentActivated {
+                agent_id,
+                agent_type: agent_type.to_string(),
+            }).await;
+        }
         
         info!("Agent {} joined swarm with {:?} topology", agent_id, self.topology);
+        Ok(())
+    }
+    
+    /// Current placement of every agent in the topology
+    pub async fn agent_placements(&self) -> HashMap<Uuid, AgentPlacement> {
+        self.agent_placements.read().await.clone()
+    }
+    
+    /// Joined agents that have a capability, in id order
+    pub async fn agents_with_capability(&self, capability: &AgentCapability) -> Vec<Uuid> {
+        self.capability_index.read().await.members(capability).collect()
+    }
+    
+    /// Called when a joined agent is granted or loses capabilities at runtime
+    pub async fn on_agent_capabilities_changed(
+        &self,
+        agent_id: Uuid,
+        added: &[AgentCapability],
+        removed: &[AgentCapability],
+    ) {
+        self.capability_index.write().await.update(agent_id, added, removed);
+        debug!("Agent {} capabilities changed: +{:?} -{:?}", agent_id, added, removed);
+    }
+    
+    /// Re-index a joined agent whose capabilities follow something other than
+    /// grant and revoke events, such as a nested swarm's roster
+    pub async fn refresh_agent_capabilities(&self, agent_id: Uuid, capabilities: &[AgentCapability]) {
+        let mut index = self.capability_index.write().await;
+        if index.contains(agent_id) {
+            index.insert(agent_id, capabilities);
+        }
     }
     
     /// Called when an agent leaves the swarm
     pub async fn on_agent_left(&self, agent_id: Uuid) {
+        self.capability_index.write().await.remove(agent_id);
+        self.utilization.write().await.remove(&agent_id);
+        self.work_metrics.write().await.remove(&agent_id);
+        
         let mut placements = self.agent_placements.write().await;
         placements.remove(&agent_id);
         
@@ -103,6 +462,11 @@ impl SwarmOrchestrator {
         for placement in placements.values_mut() {
             placement.on_agent_left(agent_id);
         }
+        drop(placements);
+        
+        if let Some(event_bus) = self.event_bus() {
+            event_bus.publish(SystemEvent::AgentDeactivated { agent_id }).await;
+        }
         
         info!("Agent {} left swarm", agent_id);
     }
@@ -113,20 +477,48 @@ impl SwarmOrchestrator {
         task: Task,
         strategy: TaskStrategy,
         agents: HashMap<Uuid, Arc<dyn CognitiveAgent>>,
+        dry_run: bool,
     ) -> Result<TaskResult, String> {
         info!("Executing task {} with {:?} strategy", task.id, strategy);
         
+        // Beyond `max_concurrent_tasks`, wait in the queue for a running task to finish
+        let slot = if dry_run {
+            None
+        } else {
+            Some(self.admission.admit(&task).await)
+        };
+        
+        let start_time = chrono::Utc::now();
+        let agents = self.without_open_breakers(agents).await;
+        
         // Select agents based on strategy and requirements
-        let selected_agents = self.select_agents(&task, &strategy, &agents).await?;
+        let selection = self.select_agents(&task, &strategy, &agents).await.and_then(|selected| {
+            if selected.len() < task.requirements.min_agents {
+                return Err(format!(
+                    "Not enough agents available. Required: {}, Available: {}",
+                    task.requirements.min_agents,
+                    selected.len()
+                ));
+            }
+            Ok(selected)
+        });
+        let selected_agents = match selection {
+            Ok(selected) => selected,
+            Err(e) => {
+                drop(slot);
+                if !dry_run {
+                    self.record_failure(task.id, task.description.clone(), &strategy, Vec::new(), start_time, &e).await;
+                }
+                return Err(e);
+            }
+        };
         
-        if selected_agents.len() < task.requirements.min_agents {
-            return Err(format!(
-                "Not enough agents available. Required: {}, Available: {}",
-                task.requirements.min_agents,
-                selected_agents.len()
-            ));
+        if dry_run {
+            return Ok(Self::dry_run_result(task.id, &strategy, selected_agents));
         }
         
+        self.record_assignment(&selected_agents).await;
+        
         // Create task execution record
         let execution = TaskExecution {
             task: task.clone(),
@@ -134,350 +526,2748 @@ impl SwarmOrchestrator {
             assigned_agents: selected_agents.clone(),
             start_time: chrono::Utc::now(),
             progress: 0.0,
+            contributions: HashMap::new(),
+            reassignments: Vec::new(),
         };
         
         self.active_tasks.write().await.insert(task.id, execution);
         
-        // Clone task_id before moving task in match arms
+        // Clone task_id before moving task into the executor
         let task_id = task.id;
         
-        // Execute based on strategy
-        let result = match strategy {
-            TaskStrategy::Parallel => {
-                self.execute_parallel(task, selected_agents, agents).await
-            }
-            TaskStrategy::Sequential => {
-                self.execute_sequential(task, selected_agents, agents).await
-            }
-            TaskStrategy::Consensus { min_agreement } => {
-                self.execute_consensus(task, selected_agents, agents, min_agreement).await
-            }
-            TaskStrategy::Distributed { max_subtasks } => {
-                self.execute_distributed(task, selected_agents, agents, max_subtasks).await
-            }
-            TaskStrategy::Competitive => {
-                self.execute_competitive(task, selected_agents, agents).await
-            }
-            TaskStrategy::Adaptive => {
-                self.execute_adaptive(task, selected_agents, agents).await
-            }
+        // The task id doubles as the correlation id for every agent invocation
+        let span = info_span!("task", correlation_id = %task_id);
+        
+        let started = std::time::Instant::now();
+        let latency_strategy = strategy.clone();
+        let description = task.description.clone();
+        let assigned_agents = selected_agents.clone();
+        let timeout = task.requirements.timeout;
+        let execution = with_correlation_id(
+            task_id,
+            self.run_strategy(task, strategy, selected_agents, OutputSource::Live(agents)).instrument(span),
+        );
+        
+        let result = match timeout {
+            Some(limit) => match tokio::time::timeout(limit, execution).await {
+                Ok(result) => result,
+                Err(_) => {
+                    error!("Task {} timed out after {:?}", task_id, limit);
+                    Ok(self.timed_out_result(task_id, start_time).await)
+                }
+            },
+            None => execution.await,
         };
         
-        // Clean up
+        // Clean up, freeing the slot before bookkeeping so queued tasks never wait on it
         self.active_tasks.write().await.remove(&task_id);
+        drop(slot);
+        
+        match &result {
+            Ok(task_result) => {
+                self.latency.record(&latency_strategy, started.elapsed()).await;
+                self.record_work_metrics(task_result).await;
+                self.queue_neural_sync(task_result, &latency_strategy).await;
+                self.record_history(CompletedTaskRecord {
+                    task_id,
+                    description,
+                    strategy: latency_strategy,
+                    selected_agents: assigned_agents,
+                    result: task_result.clone(),
+                    completed_at: chrono::Utc::now(),
+                }).await;
+            }
+            Err(e) => {
+                self.record_failure(task_id, description, &latency_strategy, assigned_agents, start_time, e).await;
+            }
+        }
         
         result
     }
     
-    /// Select agents for task execution
-    async fn select_agents(
+    /// Count each contribution as processed work and each agent failure as an error
+    async fn record_work_metrics(&self, result: &TaskResult) {
+        let mut work_metrics = self.work_metrics.write().await;
+        for (agent_id, contribution) in &result.agent_contributions {
+            work_metrics.entry(*agent_id).or_default().record_processed(Some(contribution.confidence));
+        }
+        for failure in &result.metadata.agent_failures {
+            work_metrics.entry(failure.agent_id).or_default().record_error();
+        }
+    }
+    
+    /// Keep a task that was rejected or errored in the history as failed
+    async fn record_failure(
         &self,
-        task: &Task,
+        task_id: Uuid,
+        description: String,
         strategy: &TaskStrategy,
-        available_agents: &HashMap<Uuid, Arc<dyn CognitiveAgent>>,
-    ) -> Result<Vec<Uuid>, String> {
-        let mut selected = Vec::new();
-        
-        // Filter by required capabilities
-        let capable_agents: Vec<(Uuid, &Arc<dyn CognitiveAgent>)> = available_agents
+        selected_agents: Vec<Uuid>,
+        start_time: chrono::DateTime<chrono::Utc>,
+        error: &str,
+    ) {
+        let status = TaskStatus::Failed { error: error.to_string() };
+        self.record_history(CompletedTaskRecord {
+            task_id,
+            description,
+            strategy: strategy.clone(),
+            selected_agents,
+            result: Self::bare_result(task_id, status, start_time),
+            completed_at: chrono::Utc::now(),
+        }).await;
+    }
+    
+    async fn record_history(&self, record: CompletedTaskRecord) {
+        let mut history = self.history.write().await;
+        history.push_back(record);
+        while history.len() > self.config.task_history_retention {
+            history.pop_front();
+        }
+    }
+    
+    /// Up to `limit` of the most recently finished tasks, newest first
+    pub async fn task_history(&self, limit: usize) -> Vec<CompletedTaskRecord> {
+        self.history.read().await
             .iter()
-            .filter(|(_, agent)| {
-                // In production, check agent capabilities against requirements
-                true // Placeholder
-            })
-            .map(|(id, agent)| (*id, agent))
-            .collect();
+            .rev()
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+    
+    /// Task latency percentiles for a strategy
+    pub async fn latency_percentiles(&self, strategy: &TaskStrategy) -> Percentiles {
+        self.latency.percentiles(strategy).await
+    }
+    
+    pub fn latency_tracker(&self) -> Arc<LatencyTracker> {
+        self.latency.clone()
+    }
+    
+    /// Re-execute recorded tasks, feeding back the captured agent outputs instead of
+    /// invoking agents. There is one result per recorded task, in recording order, with
+    /// a task that fails to replay given a `Failed` result; the network is left untouched.
+    pub async fn replay(&self, run: &RecordedRun) -> Vec<TaskResult> {
+        let mut results = Vec::with_capacity(run.tasks.len());
         
-        // Select based on strategy
-        match strategy {
-            TaskStrategy::Parallel | TaskStrategy::Competitive => {
-                // Use all capable agents up to max
-                let max = task.requirements.max_agents.unwrap_or(capable_agents.len());
-                selected = capable_agents
-                    .into_iter()
-                    .take(max)
-                    .map(|(id, _)| id)
-                    .collect();
-            }
-            TaskStrategy::Sequential => {
-                // Select agents in topology order
-                let placements = self.agent_placements.read().await;
-                selected = self.order_by_topology(capable_agents, &placements);
-            }
-            TaskStrategy::Consensus { .. } => {
-                // Need odd number for voting
-                let count = task.requirements.max_agents.unwrap_or(5).min(capable_agents.len());
-                let count = if count % 2 == 0 { count - 1 } else { count };
-                selected = capable_agents
-                    .into_iter()
-                    .take(count)
-                    .map(|(id, _)| id)
-                    .collect();
-            }
-            _ => {
-                // Default selection
-                let max_agents = task.requirements.max_agents.unwrap_or(capable_agents.len());
-                selected = capable_agents
-                    .into_iter()
-                    .take(max_agents)
-                    .map(|(id, _)| id)
-                    .collect();
+        for recorded in &run.tasks {
+            let task_id = recorded.task.id;
+            let source = OutputSource::Recorded(RecordedWork::from_outputs(&recorded.outputs));
+            let start_time = chrono::Utc::now();
+            let span = info_span!("replay", correlation_id = %task_id);
+            
+            let replayed = with_correlation_id(
+                task_id,
+                self.run_strategy(recorded.task.clone(), recorded.strategy.clone(), recorded.agent_ids(), source)
+                    .instrument(span),
+            ).await;
+            
+            match replayed {
+                Ok(result) => results.push(result),
+                Err(e) => {
+                    error!("Replay of task {} failed: {}", task_id, e);
+                    results.push(Self::bare_result(task_id, TaskStatus::Failed { error: e }, start_time));
+                }
             }
         }
         
-        Ok(selected)
+        results
     }
     
-    /// Order agents by topology placement
-    fn order_by_topology(
-        &self,
-        agents: Vec<(Uuid, &Arc<dyn CognitiveAgent>)>,
-        placements: &HashMap<Uuid, AgentPlacement>,
-    ) -> Vec<Uuid> {
-        let mut ordered = Vec::new();
+    /// The plan for a task without running it
+    fn dry_run_result(task_id: Uuid, strategy: &TaskStrategy, selected_agents: Vec<Uuid>) -> TaskResult {
+        let status = TaskStatus::DryRun {
+            strategy: Self::concrete_strategy(strategy),
+            selected_agents,
+        };
+        Self::bare_result(task_id, status, chrono::Utc::now())
+    }
+    
+    /// A result with no output or contributions, ending now
+    fn bare_result(task_id: Uuid, status: TaskStatus, start_time: chrono::DateTime<chrono::Utc>) -> TaskResult {
+        let end_time = chrono::Utc::now();
         
-        match &self.topology {
-            SwarmTopology::Hierarchical { .. } => {
-                // Order by level
-                let mut by_level: Vec<(usize, Uuid)> = agents
-                    .iter()
-                    .filter_map(|(id, _)| {
-                        placements.get(id).and_then(|p| {
-                            if let AgentPlacement::Hierarchical { level, .. } = p {
-                                Some((*level, *id))
-                            } else {
-                                None
-                            }
-                        })
-                    })
-                    .collect();
-                
-                by_level.sort_by_key(|(level, _)| *level);
-                ordered = by_level.into_iter().map(|(_, id)| id).collect();
-            }
-            SwarmTopology::Ring => {
-                // Follow ring order
-                if let Some((start_id, _)) = agents.first() {
-                    ordered.push(*start_id);
-                    let mut current = *start_id;
-                    
-                    while ordered.len() < agents.len() {
-                        if let Some(placement) = placements.get(&current) {
-                            if let AgentPlacement::Ring { next, .. } = placement {
-                                if let Some(next_id) = next {
-                                    if !ordered.contains(next_id) {
-                                        ordered.push(*next_id);
-                                        current = *next_id;
-                                        continue;
-                                    }
-                                }
-                            }
-                        }
-                        break;
-                    }
-                }
-            }
-            _ => {
-                // Default order
-                ordered = agents.into_iter().map(|(id, _)| id).collect();
-            }
+        TaskResult {
+            task_id,
+            status,
+            output: None,
+            metadata: TaskMetadata {
+                start_time,
+                end_time: Some(end_time),
+                duration_ms: Some((end_time - start_time).num_milliseconds() as u64),
+                iterations: 0,
+                neural_activity: NeuralActivityMetrics::default(),
+                reassignments: Vec::new(),
+                agent_failures: Vec::new(),
+                escalations: Vec::new(),
+                consensus: None,
+                low_confidence: false,
+            },
+            agent_contributions: HashMap::new(),
         }
-        
-        ordered
     }
     
-    /// Execute task in parallel across all assigned agents
-    async fn execute_parallel(
+    /// The strategy that actually runs; adaptive execution currently settles on parallel
+    fn concrete_strategy(strategy: &TaskStrategy) -> TaskStrategy {
+        match strategy {
+            TaskStrategy::Adaptive => TaskStrategy::Parallel,
+            other => other.clone(),
+        }
+    }
+    
+    /// Build a result from the contributions gathered before a task timed out
+    async fn timed_out_result(
         &self,
-        task: Task,
-        agent_ids: Vec<Uuid>,
-        agents: HashMap<Uuid, Arc<dyn CognitiveAgent>>,
-    ) -> Result<TaskResult, String> {
-        debug!("Executing task {} in parallel with {} agents", task.id, agent_ids.len());
+        task_id: Uuid,
+        start_time: chrono::DateTime<chrono::Utc>,
+    ) -> TaskResult {
+        let (agent_contributions, reassignments) = self.active_tasks.read().await
+            .get(&task_id)
+            .map(|execution| (execution.contributions.clone(), execution.reassignments.clone()))
+            .unwrap_or_default();
         
-        let mut handles = Vec::new();
-        let start_time = chrono::Utc::now();
+        let end_time = chrono::Utc::now();
         
-        // Spawn parallel tasks
-        for agent_id in &agent_ids {
-            if let Some(agent) = agents.get(agent_id) {
-                let agent = agent.clone();
-                let task_clone = task.clone();
-                let neural_network = self.neural_network.clone();
-                
-                let handle = tokio::spawn(async move {
-                    // Simulate agent processing
-                    // In production, call actual agent process method
-                    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
-                    
-                    WorkItem {
-                        description: format!("Processed by {}", agent.name()),
-                        result: Some(serde_json::json!({
-                            "agent": agent.name(),
-                            "confidence": 0.85,
-                        })),
-                        timestamp: chrono::Utc::now(),
-                    }
+        TaskResult {
+            task_id,
+            status: TaskStatus::Timeout,
+            output: None,
+            metadata: TaskMetadata {
+                start_time,
+                end_time: Some(end_time),
+                duration_ms: Some((end_time - start_time).num_milliseconds() as u64),
+                iterations: agent_contributions.len(),
+                neural_activity: NeuralActivityMetrics::default(),
+                reassignments,
+                agent_failures: Vec::new(),
+                escalations: Vec::new(),
+                consensus: None,
+                low_confidence: false,
+            },
+            agent_contributions,
+        }
+    }
+    
+    /// Stop handing work to an agent; parallel tasks move its in-flight share to
+    /// another assigned agent and sequential tasks wait at its step until it resumes
+    pub fn suspend_agent(&self, agent_id: Uuid) {
+        self.suspended.send_modify(|suspended| {
+            suspended.insert(agent_id);
+        });
+    }
+    
+    pub fn resume_agent(&self, agent_id: Uuid) {
+        self.suspended.send_if_modified(|suspended| suspended.remove(&agent_id));
+    }
+    
+    pub fn is_suspended(&self, agent_id: Uuid) -> bool {
+        self.suspended.borrow().contains(&agent_id)
+    }
+    
+    /// Circuit breaker state of an agent; agents never invoked are closed
+    pub async fn agent_breaker_state(&self, agent_id: Uuid) -> BreakerState {
+        self.breakers.read().await
+            .get(&agent_id)
+            .map(|breaker| breaker.state(self.breaker_cooldown()))
+            .unwrap_or(BreakerState::Closed)
+    }
+    
+    fn breaker_cooldown(&self) -> Duration {
+        Duration::from_millis(self.config.breaker_cooldown_ms)
+    }
+    
+    async fn record_agent_outcome(&self, agent_id: Uuid, succeeded: bool) {
+        let mut breakers = self.breakers.write().await;
+        let breaker = breakers.entry(agent_id).or_default();
+        
+        if succeeded {
+            breaker.record_success();
+        } else {
+            breaker.record_failure(self.config.breaker_failure_threshold);
+            if breaker.state(self.breaker_cooldown()) == BreakerState::Open {
+                warn!("Circuit breaker open for agent {} after {} failures", agent_id, breaker.consecutive_failures());
+            }
+        }
+    }
+    
+    /// Drop agents whose breaker is open; half-open agents stay in for a trial run
+    async fn without_open_breakers(
+        &self,
+        mut agents: HashMap<Uuid, Arc<dyn CognitiveAgent>>,
+    ) -> HashMap<Uuid, Arc<dyn CognitiveAgent>> {
+        let breakers = self.breakers.read().await;
+        let cooldown = self.breaker_cooldown();
+        agents.retain(|id, _| {
+            breakers.get(id).map(|breaker| breaker.state(cooldown)) != Some(BreakerState::Open)
+        });
+        agents
+    }
+    
+    /// Ask a live agent whether it can take work, counting the answer towards its breaker
+    async fn check_agent_health(&self, source: &OutputSource, agent_id: Uuid) -> Result<(), String> {
+        let Some(agent) = source.live_agent(&agent_id) else {
+            return Ok(());
+        };
+        
+        let health = agent.health_check().await.map_err(|e| e.to_string());
+        self.record_agent_outcome(agent_id, health.is_ok()).await;
+        health
+    }
+    
+    /// Hand a failed agent's work to its hierarchy parent, or failing that a sibling,
+    /// skipping anyone in `exclude` and anyone who is not live and healthy
+    async fn escalate(
+        &self,
+        source: &OutputSource,
+        failed: Uuid,
+        reason: &str,
+        exclude: &HashSet<Uuid>,
+    ) -> Option<Escalation> {
+        let candidates: Vec<Uuid> = {
+            let placements = self.agent_placements.read().await;
+            let Some(AgentPlacement::Hierarchical { parent: Some(parent), .. }) = placements.get(&failed) else {
+                return None;
+            };
+            let siblings = placements.iter()
+                .filter(|(_, placement)| matches!(
+                    placement,
+                    AgentPlacement::Hierarchical { parent: Some(p), .. } if p == parent
+                ))
+                .map(|(id, _)| *id);
+            
+            std::iter::once(*parent)
+                .chain(siblings)
+                .filter(|candidate| *candidate != failed && !exclude.contains(candidate))
+                .collect()
+        };
+        
+        for candidate in candidates {
+            if self.can_take_over(source, candidate).await {
+                return Some(Escalation {
+                    from_agent: failed,
+                    to_agent: candidate,
+                    reason: reason.to_string(),
+                    timestamp: chrono::Utc::now(),
                 });
-                
-                handles.push((agent_id, handle));
             }
         }
         
-        // Collect results
-        let mut agent_contributions = HashMap::new();
-        let mut all_results = Vec::new();
+        None
+    }
+    
+    /// Whether `candidate` can pick up another agent's work: live, not suspended,
+    /// breaker not open and passing its health check
+    async fn can_take_over(&self, source: &OutputSource, candidate: Uuid) -> bool {
+        if source.agent_type(&candidate).is_none() || self.is_suspended(candidate) {
+            return false;
+        }
+        if self.agent_breaker_state(candidate).await == BreakerState::Open {
+            return false;
+        }
+        self.check_agent_health(source, candidate).await.is_ok()
+    }
+    
+    async fn wait_until_resumed(&self, agent_id: Uuid) {
+        // The sender lives as long as self, so this only returns once the agent resumes
+        let _ = self.suspended.subscribe().wait_for(|suspended| !suspended.contains(&agent_id)).await;
+    }
+    
+    async fn record_reassignment(&self, task_id: Uuid, reassignment: Reassignment) {
+        if let Some(execution) = self.active_tasks.write().await.get_mut(&task_id) {
+            execution.reassignments.push(reassignment);
+        }
+    }
+    
+    /// Record a finished contribution so it survives a timeout
+    async fn record_contribution(&self, task_id: Uuid, contribution: AgentContribution) {
+        if let Some(execution) = self.active_tasks.write().await.get_mut(&task_id) {
+            execution.contributions.insert(contribution.agent_id, contribution);
+        }
+    }
+    
+    /// Queue pathway updates derived from each agent's neural impact
+    ///
+    /// Each contribution strengthens the pathway from the agent's node to the
+    /// strategy's node, so the network grows with agents rather than tasks.
+    async fn queue_neural_sync(&self, result: &TaskResult, strategy: &TaskStrategy) {
+        if !self.config.neural_sync_enabled {
+            return;
+        }
         
-        for (agent_id, handle) in handles {
-            match handle.await {
-                Ok(work_item) => {
-                    all_results.push(work_item.clone());
-                    
-                    let contribution = AgentContribution {
-                        agent_id: *agent_id,
-                        agent_type: agents.get(agent_id)
-                            .map(|a| a.name().to_string())
-                            .unwrap_or_default(),
-                        work_items: vec![work_item],
-                        confidence: 0.85,
-                        neural_impact: 0.1,
-                    };
-                    
-                    agent_contributions.insert(*agent_id, contribution);
+        let mut pathway_updates = Vec::new();
+        for contribution in result.agent_contributions.values() {
+            if contribution.neural_impact == 0.0 {
+                continue;
+            }
+            let source = self.sync_node(SyncNode::Agent(contribution.agent_id)).await;
+            let target = self.sync_node(SyncNode::Strategy(strategy.name())).await;
+            pathway_updates.push((source, target, contribution.neural_impact));
+        }
+        
+        if pathway_updates.is_empty() {
+            return;
+        }
+        
+        // Without a loop draining the channel, or once it's backed up, apply the
+        // updates here rather than wait on a channel that may never empty
+        if self.coordination_loops.load(Ordering::SeqCst) == 0 {
+            self.apply_pathway_updates(pathway_updates).await;
+            return;
+        }
+        
+        match self.coordination_tx.try_send(CoordinationMessage::NeuralSync { pathway_updates }) {
+            Ok(()) => {}
+            Err(mpsc::error::TrySendError::Full(CoordinationMessage::NeuralSync { pathway_updates })) => {
+                warn!("Coordination channel full, applying neural sync for task {} inline", result.task_id);
+                self.apply_pathway_updates(pathway_updates).await;
+            }
+            Err(_) => {
+                error!("Coordination channel closed, dropping neural sync for task {}", result.task_id);
+            }
+        }
+    }
+    
+    /// Network node for an agent or strategy, added on first use
+    async fn sync_node(&self, key: SyncNode) -> Uuid {
+        if let Some(node_id) = self.sync_nodes.read().await.get(&key) {
+            return *node_id;
+        }
+        
+        let mut nodes = self.sync_nodes.write().await;
+        if let Some(node_id) = nodes.get(&key) {
+            return *node_id;
+        }
+        let node_type = match key {
+            SyncNode::Agent(_) => NodeType::Agent,
+            SyncNode::Strategy(_) => NodeType::Thinking,
+        };
+        let node_id = self.neural_network.add_node(node_type).await;
+        nodes.insert(key, node_id);
+        node_id
+    }
+    
+    /// Network node that neural sync uses for an agent, if it has contributed
+    pub async fn agent_sync_node(&self, agent_id: Uuid) -> Option<Uuid> {
+        self.sync_nodes.read().await.get(&SyncNode::Agent(agent_id)).copied()
+    }
+    
+    /// Drain pending coordination messages, returning how many were handled
+    pub async fn process_coordination_messages(&self) -> usize {
+        let mut processed = 0;
+        let mut rx = self.coordination_rx.write().await;
+        
+        while let Ok(message) = rx.try_recv() {
+            processed += 1;
+            
+            match message {
+                CoordinationMessage::AgentProgress { agent_id, task_id, progress } => {
+                    debug!("Agent {} reported {:.0}% progress on task {}", agent_id, progress * 100.0, task_id);
+                    if let Some(execution) = self.active_tasks.write().await.get_mut(&task_id) {
+                        execution.progress = progress;
+                    }
                 }
-                Err(e) => {
-                    error!("Agent {} failed: {}", agent_id, e);
+                CoordinationMessage::AgentResult { agent_id, task_id, .. } => {
+                    debug!("Agent {} submitted a result for task {}", agent_id, task_id);
+                }
+                CoordinationMessage::TaskComplete { task_id } => {
+                    debug!("Task {} complete", task_id);
+                }
+                CoordinationMessage::NeuralSync { pathway_updates } => {
+                    if self.config.neural_sync_enabled {
+                        self.apply_pathway_updates(pathway_updates).await;
+                    }
                 }
             }
         }
         
-        let end_time = chrono::Utc::now();
-        let duration_ms = (end_time - start_time).num_milliseconds() as u64;
+        processed
+    }
+    
+    async fn apply_pathway_updates(&self, pathway_updates: Vec<(Uuid, Uuid, f64)>) {
+        for (source, target, delta) in pathway_updates {
+            match self.neural_network.find_pathway(source, target).await {
+                Some(pathway_id) => {
+                    self.neural_network.strengthen_pathway(pathway_id, delta).await;
+                }
+                None => {
+                    self.neural_network.create_pathway(source, target, delta.clamp(0.0, 1.0)).await;
+                }
+            }
+        }
+    }
+    
+    /// Active tasks plus coordination messages waiting to be processed
+    pub async fn coordination_load(&self) -> usize {
+        let queued = self.coordination_tx.max_capacity() - self.coordination_tx.capacity();
+        self.active_task_count().await + queued
+    }
+    
+    /// Spawn a loop that processes coordination messages, starting every
+    /// `coordination_interval_ms` and then adapting to the current load
+    ///
+    /// The loop stops once the orchestrator is dropped.
+    pub fn start_coordination_loop(self: &Arc<Self>) -> tokio::task::JoinHandle<()> {
+        let orchestrator = Arc::downgrade(self);
+        let mut interval = Duration::from_millis(self.config.coordination_interval_ms.max(1));
+        let running = RunningLoop::start(self.coordination_loops.clone());
         
-        Ok(TaskResult {
-            task_id: task.id,
-            status: TaskStatus::Completed,
-            output: Some(TaskOutput::Multiple(
-                all_results.into_iter()
-                    .filter_map(|w| w.result.map(|r| TaskOutput::Text(r.to_string())))
-                    .collect()
-            )),
-            metadata: TaskMetadata {
-                start_time,
-                end_time: Some(end_time),
-                duration_ms: Some(duration_ms),
-                iterations: 1,
-                neural_activity: NeuralActivityMetrics::default(),
-            },
-            agent_contributions,
+        tokio::spawn(async move {
+            let _running = running;
+            loop {
+                tokio::time::sleep(interval).await;
+                
+                let Some(orchestrator) = orchestrator.upgrade() else {
+                    break;
+                };
+                
+                // Measure before draining so queued messages count towards the load
+                let load = orchestrator.coordination_load().await;
+                orchestrator.process_coordination_messages().await;
+                
+                interval = orchestrator.config.coordination_interval(load);
+                if let Some(observer) = &orchestrator.interval_observer {
+                    observer(interval);
+                }
+            }
         })
     }
     
-    /// Execute task sequentially through assigned agents
-    async fn execute_sequential(
+    /// Dispatch a task to the executor for its strategy
+    async fn run_strategy(
         &self,
         task: Task,
-        agent_ids: Vec<Uuid>,
-        agents: HashMap<Uuid, Arc<dyn CognitiveAgent>>,
+        strategy: TaskStrategy,
+        selected_agents: Vec<Uuid>,
+        source: OutputSource,
     ) -> Result<TaskResult, String> {
-        debug!("Executing task {} sequentially through {} agents", task.id, agent_ids.len());
+        match strategy {
+            TaskStrategy::Parallel => {
+                self.execute_parallel(task, selected_agents, source).await
+            }
+            TaskStrategy::Sequential => {
+                self.execute_sequential(task, selected_agents, source).await
+            }
+            TaskStrategy::Consensus { min_agreement } => {
+                self.execute_consensus(task, selected_agents, source, min_agreement).await
+            }
+            TaskStrategy::Distributed { max_subtasks } => {
+                self.execute_distributed(task, selected_agents, source, max_subtasks).await
+            }
+            TaskStrategy::Competitive => {
+                self.execute_competitive(task, selected_agents, source).await
+            }
+            TaskStrategy::Adaptive => {
+                self.execute_adaptive(task, selected_agents, source).await
+            }
+            TaskStrategy::Pipeline { stages } => {
+                self.execute_pipeline(task, selected_agents, source, stages).await
+            }
+        }
+    }
+    
+    /// Select agents for task execution
+    async fn select_agents(
+        &self,
+        task: &Task,
+        strategy: &TaskStrategy,
+        available_agents: &HashMap<Uuid, Arc<dyn CognitiveAgent>>,
+    ) -> Result<Vec<Uuid>, String> {
+        if let TaskStrategy::Pipeline { stages } = strategy {
+            return self.select_pipeline_agents(stages, available_agents).await;
+        }
         
-        let start_time = chrono::Utc::now();
-        let mut agent_contributions = HashMap::new();
-        let mut current_result = None;
+        let required = task.requirements.required_capabilities
+            .iter()
+            .map(|name| name.parse::<AgentCapability>().map_err(|e| e.to_string()))
+            .collect::<Result<Vec<_>, _>>()?;
         
-        for agent_id in &agent_ids {
-            if let Some(agent) = agents.get(agent_id) {
-                // Process with current result as input
-                let work_item = WorkItem {
-                    description: format!("Sequential processing by {}", agent.name()),
-                    result: Some(serde_json::json!({
-                        "agent": agent.name(),
-                        "input": current_result,
-                        "output": format!("Processed by {}", agent.name()),
-                    })),
-                    timestamp: chrono::Utc::now(),
-                };
-                
-                current_result = work_item.result.clone();
+        // Filter by required capabilities
+        let capable_agents: Vec<(Uuid, &Arc<dyn CognitiveAgent>)> = if required.is_empty() {
+            available_agents.iter().map(|(id, agent)| (*id, agent)).collect()
+        } else {
+            self.capability_index.read().await
+                .with_all(&required)
+                .into_iter()
+                .filter_map(|id| available_agents.get(&id).map(|agent| (id, agent)))
+                .collect()
+        };
+        
+        let min_agents = task.requirements.min_agents;
+        if capable_agents.len() < min_agents {
+            return Err(format!(
+                "Insufficient capable agents with {:?}. Required: {}, Available: {}",
+                required,
+                min_agents,
+                capable_agents.len()
+            ));
+        }
+        
+        // Clamp the selection size between min_agents and max_agents
+        let default_max = match strategy {
+            TaskStrategy::Consensus { .. } => 5,
+            _ => capable_agents.len(),
+        };
+        let max_agents = task.requirements.max_agents.unwrap_or(default_max).max(min_agents);
+        let count = max_agents.min(capable_agents.len());
+        
+        // Select based on strategy
+        let selected: Vec<Uuid> = match strategy {
+            TaskStrategy::Sequential => {
+                // Select agents in topology order
+                let placements = self.agent_placements.read().await;
+                let mut ordered = self.order_by_topology(capable_agents, &placements);
+                ordered.truncate(count);
+                ordered
+            }
+            TaskStrategy::Consensus { .. } => {
+                if count == 0 {
+                    return Err("Consensus requires at least one capable agent".to_string());
+                }
                 
-                let contribution = AgentContribution {
-                    agent_id: *agent_id,
-                    agent_type: agent.name().to_string(),
-                    work_items: vec![work_item],
-                    confidence: 0.9,
-                    neural_impact: 0.15,
+                // Prefer an odd number for voting, without dropping below min_agents
+                let count = if count % 2 == 0 && count > min_agents.max(1) {
+                    count - 1
+                } else {
+                    count
                 };
-                
-                agent_contributions.insert(*agent_id, contribution);
+                capable_agents
+                    .into_iter()
+                    .take(count)
+                    .map(|(id, _)| id)
+                    .collect()
+            }
+            TaskStrategy::Parallel if self.config.parallel_selection == AgentSelection::LoadAware => {
+                let candidates = capable_agents.into_iter().map(|(id, _)| id).collect();
+                let utilization = self.utilization.read().await;
+                let mut rng = self.selection_rng.lock().unwrap();
+                Self::sample_by_load(candidates, count, &utilization, &mut *rng)
+            }
+            _ => {
+                capable_agents
+                    .into_iter()
+                    .take(count)
+                    .map(|(id, _)| id)
+                    .collect()
             }
+        };
+        
+        Ok(selected)
+    }
+    
+    /// Pick `count` agents at random, each weighted inversely to its recent utilization
+    fn sample_by_load(mut candidates: Vec<Uuid>, count: usize, utilization: &HashMap<Uuid, f64>, rng: &mut impl Rng) -> Vec<Uuid> {
+        if count >= candidates.len() {
+            return candidates;
         }
         
-        let end_time = chrono::Utc::now();
+        // Candidates arrive in map order; fix it so a seeded rng picks the same agents
+        candidates.sort();
         
-        Ok(TaskResult {
-            task_id: task.id,
-            status: TaskStatus::Completed,
-            output: current_result.map(|r| TaskOutput::Text(r.to_string())),
-            metadata: TaskMetadata {
-                start_time,
-                end_time: Some(end_time),
-                duration_ms: Some((end_time - start_time).num_milliseconds() as u64),
-                iterations: agent_ids.len(),
-                neural_activity: NeuralActivityMetrics::default(),
-            },
-            agent_contributions,
-        })
+        // Weighted sampling without replacement: keep the largest u^(1/weight) keys
+        let mut keyed: Vec<(f64, Uuid)> = candidates
+            .into_iter()
+            .map(|id| {
+                let weight = 1.0 / (1.0 + utilization.get(&id).copied().unwrap_or(0.0));
+                (rng.gen::<f64>().powf(1.0 / weight), id)
+            })
+            .collect();
+        keyed.sort_by(|a, b| b.0.total_cmp(&a.0));
+        
+        keyed.into_iter().take(count).map(|(_, id)| id).collect()
     }
     
-    /// Execute with consensus voting
-    async fn execute_consensus(
-        &self,
-        task: Task,
-        agent_ids: Vec<Uuid>,
-        agents: HashMap<Uuid, Arc<dyn CognitiveAgent>>,
-        min_agreement: f64,
-    ) -> Result<TaskResult, String> {
-        // Similar to parallel but with voting mechanism
-        self.execute_parallel(task, agent_ids, agents).await
+    /// Count work handed to `agents`, letting earlier assignments fade
+    async fn record_assignment(&self, agents: &[Uuid]) {
+        let mut utilization = self.utilization.write().await;
+        for load in utilization.values_mut() {
+            *load *= UTILIZATION_DECAY;
+        }
+        for agent_id in agents {
+            *utilization.entry(*agent_id).or_insert(0.0) += 1.0;
+        }
     }
     
-    /// Execute by distributing subtasks
-    async fn execute_distributed(
-        &self,
-        task: Task,
-        agent_ids: Vec<Uuid>,
-        agents: HashMap<Uuid, Arc<dyn CognitiveAgent>>,
-        max_subtasks: usize,
-    ) -> Result<TaskResult, String> {
-        // Break task into subtasks and distribute
-        self.execute_parallel(task, agent_ids, agents).await
+    /// Work counters from tasks run through this orchestrator, per agent
+    pub async fn agent_work_metrics(&self) -> HashMap<Uuid, AgentMetrics> {
+        self.work_metrics.read().await.clone()
     }
     
-    /// Execute competitively - best result wins
-    async fn execute_competitive(
-        &self,
-        task: Task,
-        agent_ids: Vec<Uuid>,
-        agents: HashMap<Uuid, Arc<dyn CognitiveAgent>>,
-    ) -> Result<TaskResult, String> {
-        // Similar to parallel but select best result
-        self.execute_parallel(task, agent_ids, agents).await
+    /// Recent utilization of an agent, as a decayed count of assignments
+    pub async fn agent_utilization(&self, agent_id: Uuid) -> f64 {
+        self.utilization.read().await.get(&agent_id).copied().unwrap_or(0.0)
     }
     
-    /// Adaptive execution - adjust strategy based on progress
-    async fn execute_adaptive(
+    /// Pick a distinct capable agent for each stage, in stage order
+    async fn select_pipeline_agents(
         &self,
-        task: Task,
-        agent_ids: Vec<Uuid>,
-        agents: HashMap<Uuid, Arc<dyn CognitiveAgent>>,
-    ) -> Result<TaskResult, String> {
-        // Start with parallel, adapt if needed
-        self.execute_parallel(task, agent_ids, agents).await
+        stages: &[StageSpec],
+        available_agents: &HashMap<Uuid, Arc<dyn CognitiveAgent>>,
+    ) -> Result<Vec<Uuid>, String> {
+        let index = self.capability_index.read().await;
+        
+        let mut selected = Vec::with_capacity(stages.len());
+        for stage in stages {
+            let agent_id = index
+                .members(&stage.capability)
+                .find(|id| !selected.contains(id) && available_agents.contains_key(id))
+                .ok_or_else(|| format!(
+                    "No available agent with {:?} capability for pipeline stage '{}'",
+                    stage.capability, stage.name
+                ))?;
+            selected.push(agent_id);
+        }
+        
+        Ok(selected)
     }
     
-    /// Get count of active tasks
-    pub async fn active_task_count(&self) -> usize {
-        self.active_tasks.read().await.len()
+    /// Order agents by topology placement
+    fn order_by_topology(
+        &self,
+        agents: Vec<(Uuid, &Arc<dyn CognitiveAgent>)>,
+        placements: &HashMap<Uuid, AgentPlacement>,
+    ) -> Vec<Uuid> {
+        let mut ordered = Vec::new();
+        
+        match &self.topology {
+            SwarmTopology::Hierarchical { .. } => {
+                // Order by level
+                let mut by_level: Vec<(usize, Uuid)> = agents
+                    .iter()
+                    .filter_map(|(id, _)| {
+                        placements.get(id).and_then(|p| {
+                            if let AgentPlacement::Hierarchical { level, .. } = p {
+                                Some((*level, *id))
+                            } else {
+                                None
+                            }
+                        })
+                    })
+                    .collect();
+                
+                by_level.sort_by_key(|(level, _)| *level);
+                ordered = by_level.into_iter().map(|(_, id)| id).collect();
+            }
+            SwarmTopology::Ring => {
+                // Follow ring order
+                if let Some((start_id, _)) = agents.first() {
+                    ordered.push(*start_id);
+                    let mut current = *start_id;
+                    
+                    while ordered.len() < agents.len() {
+                        if let Some(placement) = placements.get(&current) {
+                            if let AgentPlacement::Ring { next, .. } = placement {
+                                if let Some(next_id) = next {
+                                    if !ordered.contains(next_id) {
+                                        ordered.push(*next_id);
+                                        current = *next_id;
+                                        continue;
+                                    }
+                                }
+                            }
+                        }
+                        break;
+                    }
+                }
+            }
+            _ => {
+                // Default order
+                ordered = agents.into_iter().map(|(id, _)| id).collect();
+            }
+        }
+        
+        ordered
+    }
+    
+    /// Execute task in parallel across all assigned agents
+    async fn execute_parallel(
+        &self,
+        task: Task,
+        agent_ids: Vec<Uuid>,
+        source: OutputSource,
+    ) -> Result<TaskResult, String> {
+        debug!("Executing task {} in parallel with {} agents", task.id, agent_ids.len());
+        
+        // Dropping the set (e.g. on timeout) aborts any agents still running
+        let mut invocations = tokio::task::JoinSet::new();
+        let start_time = chrono::Utc::now();
+        let processing_time = tokio::time::Duration::from_millis(self.config.agent_processing_ms);
+        let agent_timeout = task.requirements.agent_timeout;
+        let task_input = serde_json::to_value(&task.input).unwrap_or_default();
+        
+        let spawn_invocation = |invocations: &mut tokio::task::JoinSet<(Uuid, Invocation)>, agent_id: Uuid| {
+            let Some(agent_type) = source.agent_type(&agent_id) else {
+                return;
+            };
+            let recorded = source.recorded_work(&agent_id);
+            let live_agent = source.live_agent(&agent_id);
+            let suspended = self.suspended.subscribe();
+            let description = task.description.clone();
+            let input = task_input.clone();
+            
+            let correlation_id = current_correlation_id().unwrap_or(task.id);
+            let span = info_span!("agent_invocation", correlation_id = %correlation_id, agent_id = %agent_id);
+            
+            let invocation = async move {
+                debug!("Agent {} processing task", agent_type);
+                
+                if let Some(work_item) = recorded {
+                    return (agent_id, Invocation::Done(work_item));
+                }
+                
+                if let Some(agent) = live_agent {
+                    if let Err(e) = agent.health_check().await {
+                        return (agent_id, Invocation::Failed(e.to_string()));
+                    }
+                    
+                    match agent.process_task(&description, input).await {
+                        Ok(Some(result)) => return (agent_id, Invocation::Done(WorkItem {
+                            description: format!("Processed by {}", agent_type),
+                            result: Some(result),
+                            timestamp: chrono::Utc::now(),
+                        })),
+                        Ok(None) => {}
+                        Err(e) => return (agent_id, Invocation::Failed(e.to_string())),
+                    }
+                }
+                
+                // Simulate agent processing for agents that don't handle tasks themselves
+                if !process_unless_suspended(suspended, agent_id, processing_time).await {
+                    return (agent_id, Invocation::Suspended);
+                }
+                
+                let work_item = WorkItem {
+                    description: format!("Processed by {}", agent_type),
+                    result: Some(serde_json::json!({
+                        "agent": agent_type,
+                        "confidence": 0.85,
+                        "correlation_id": correlation_id,
+                    })),
+                    timestamp: chrono::Utc::now(),
+                };
+                
+                (agent_id, Invocation::Done(work_item))
+            };
+            
+            // A straggler is dropped rather than holding up the whole join
+            let invocation = async move {
+                match agent_timeout {
+                    Some(limit) => tokio::time::timeout(limit, invocation).await
+                        .unwrap_or((agent_id, Invocation::TimedOut)),
+                    None => invocation.await,
+                }
+            };
+            
+            // Spawned tasks don't inherit the task-local correlation id, so re-scope it
+            invocations.spawn(
+                with_correlation_id(correlation_id, invocation).instrument(span)
+            );
+        };
+        
+        // Spawn parallel tasks
+        for agent_id in &agent_ids {
+            spawn_invocation(&mut invocations, *agent_id);
+        }
+        
+        // Collect results
+        let mut agent_contributions: HashMap<Uuid, AgentContribution> = HashMap::new();
+        let mut all_results = Vec::new();
+        let mut reassignments = Vec::new();
+        let mut agent_failures = Vec::new();
+        let mut escalations = Vec::new();
+        let mut failed = HashSet::new();
+        
+        while let Some(joined) = invocations.join_next().await {
+            match joined {
+                Ok((agent_id, Invocation::Done(work_item))) => {
+                    if source.live_agent(&agent_id).is_some() {
+                        self.record_agent_outcome(agent_id, true).await;
+                    }
+                    all_results.push(work_item.clone());
+                    
+                    // An agent that picked up reassigned work contributes more than once
+                    let contribution = agent_contributions.entry(agent_id)
+                        .and_modify(|contribution| contribution.work_items.push(work_item.clone()))
+                        .or_insert_with(|| AgentContribution {
+                            agent_id,
+                            agent_type: source.agent_type(&agent_id).unwrap_or_default(),
+                            work_items: vec![work_item],
+                            confidence: 0.85,
+                            neural_impact: 0.1,
+                        })
+                        .clone();
+                    
+                    self.record_contribution(task.id, contribution).await;
+                }
+                Ok((agent_id, Invocation::Failed(e))) => {
+                    self.record_agent_outcome(agent_id, false).await;
+                    failed.insert(agent_id);
+                    
+                    if let Some(escalation) = self.escalate(&source, agent_id, &e, &failed).await {
+                        warn!("Agent {} failed on task {}, escalating its share to {}: {}", agent_id, task.id, escalation.to_agent, e);
+                        spawn_invocation(&mut invocations, escalation.to_agent);
+                        escalations.push(escalation);
+                        continue;
+                    }
+                    
+                    error!("Agent {} failed on task {}: {}", agent_id, task.id, e);
+                    agent_failures.push(AgentFailure {
+                        agent_id,
+                        reason: AgentFailureReason::Error { message: e },
+                        timestamp: chrono::Utc::now(),
+                    });
+                }
+                Ok((agent_id, Invocation::TimedOut)) => {
+                    warn!("Agent {} timed out on task {}, continuing without it", agent_id, task.id);
+                    self.record_agent_outcome(agent_id, false).await;
+                    agent_failures.push(AgentFailure {
+                        agent_id,
+                        reason: AgentFailureReason::TimedOut,
+                        timestamp: chrono::Utc::now(),
+                    });
+                }
+                Ok((agent_id, Invocation::Suspended)) => {
+                    // Agents that already failed this task would only fail the share again
+                    let mut replacement = None;
+                    for candidate in agent_ids.iter().copied() {
+                        if candidate != agent_id && !failed.contains(&candidate)
+                            && self.can_take_over(&source, candidate).await
+                        {
+                            replacement = Some(candidate);
+                            break;
+                        }
+                    }
+                    let Some(replacement) = replacement else {
+                        warn!("Agent {} suspended on task {} with no one left to take over", agent_id, task.id);
+                        agent_failures.push(AgentFailure {
+                            agent_id,
+                            reason: AgentFailureReason::Error {
+                                message: "Suspended with no active agent to take over its share".to_string(),
+                            },
+                            timestamp: chrono::Utc::now(),
+                        });
+                        continue;
+                    };
+                    
+                    info!("Agent {} suspended, reassigning its share of task {} to {}", agent_id, task.id, replacement);
+                    let reassignment = Reassignment {
+                        from_agent: agent_id,
+                        to_agent: replacement,
+                        timestamp: chrono::Utc::now(),
+                    };
+                    self.record_reassignment(task.id, reassignment.clone()).await;
+                    reassignments.push(reassignment);
+                    spawn_invocation(&mut invocations, replacement);
+                }
+                Err(e) => {
+                    error!("Agent invocation failed: {}", e);
+                }
+            }
+        }
+        
+        // Order outputs by when the work finished, not when the join noticed
+        all_results.sort_by_key(|work_item| work_item.timestamp);
+        
+        let end_time = chrono::Utc::now();
+        let duration_ms = (end_time - start_time).num_milliseconds() as u64;
+        
+        let status = if agent_contributions.is_empty() {
+            TaskStatus::Failed {
+                error: format!("All {} agents failed", agent_failures.len()),
+            }
+        } else if !agent_failures.is_empty() {
+            TaskStatus::PartiallyCompleted {
+                completed: agent_contributions.len(),
+                total: agent_contributions.len() + agent_failures.len(),
+            }
+        } else {
+            TaskStatus::Completed
+        };
+        
+        Ok(TaskResult {
+            task_id: task.id,
+            status,
+            output: Some(TaskOutput::Multiple(
+                all_results.into_iter()
+                    .filter_map(|w| w.result.map(|r| TaskOutput::Text(r.to_string())))
+                    .collect()
+            )),
+            metadata: TaskMetadata {
+                start_time,
+                end_time: Some(end_time),
+                duration_ms: Some(duration_ms),
+                iterations: 1,
+                neural_activity: NeuralActivityMetrics::default(),
+                reassignments,
+                agent_failures,
+                escalations,
+                consensus: None,
+                low_confidence: false,
+            },
+            agent_contributions,
+        })
+    }
+    
+    /// Execute task sequentially through assigned agents
+    async fn execute_sequential(
+        &self,
+        task: Task,
+        agent_ids: Vec<Uuid>,
+        source: OutputSource,
+    ) -> Result<TaskResult, String> {
+        debug!("Executing task {} sequentially through {} agents", task.id, agent_ids.len());
+        
+        let start_time = chrono::Utc::now();
+        let mut agent_contributions = HashMap::new();
+        let mut current_result = None;
+        let processing_time = tokio::time::Duration::from_millis(self.config.agent_processing_ms);
+        
+        let correlation_id = current_correlation_id().unwrap_or(task.id);
+        let mut agent_failures = Vec::new();
+        let mut escalations = Vec::new();
+        let mut failed = HashSet::new();
+        
+        for assigned_id in &agent_ids {
+            if source.agent_type(assigned_id).is_some() {
+                let agent_id = match self.check_agent_health(&source, *assigned_id).await {
+                    Ok(()) => *assigned_id,
+                    Err(e) => {
+                        failed.insert(*assigned_id);
+                        match self.escalate(&source, *assigned_id, &e, &failed).await {
+                            Some(escalation) => {
+                                warn!("Agent {} failed on task {}, escalating its step to {}: {}", assigned_id, task.id, escalation.to_agent, e);
+                                let to_agent = escalation.to_agent;
+                                escalations.push(escalation);
+                                to_agent
+                            }
+                            None => {
+                                error!("Agent {} failed on task {}, skipping its step: {}", assigned_id, task.id, e);
+                                agent_failures.push(AgentFailure {
+                                    agent_id: *assigned_id,
+                                    reason: AgentFailureReason::Error { message: e },
+                                    timestamp: chrono::Utc::now(),
+                                });
+                                continue;
+                            }
+                        }
+                    }
+                };
+                let agent_type = source.agent_type(&agent_id).unwrap_or_default();
+                
+                let span = info_span!("agent_invocation", correlation_id = %correlation_id, agent_id = %agent_id);
+                span.in_scope(|| debug!("Agent {} processing task", agent_type));
+                
+                let handled = match source.live_agent(&agent_id) {
+                    Some(agent) => {
+                        let input = current_result.clone()
+                            .unwrap_or_else(|| serde_json::to_value(&task.input).unwrap_or_default());
+                        agent.process_task(&task.description, input).await
+                    }
+                    None => Ok(None),
+                };
+                let handled = match handled {
+                    Ok(handled) => handled,
+                    Err(e) => {
+                        error!("Agent {} failed on task {}, skipping its step: {}", agent_id, task.id, e);
+                        agent_failures.push(AgentFailure {
+                            agent_id,
+                            reason: AgentFailureReason::Error { message: e.to_string() },
+                            timestamp: chrono::Utc::now(),
+                        });
+                        continue;
+                    }
+                };
+                
+                let work_item = match (source.recorded_work(&agent_id), handled) {
+                    (Some(work_item), _) => work_item,
+                    (None, Some(result)) => WorkItem {
+                        description: format!("Sequential processing by {}", agent_type),
+                        result: Some(result),
+                        timestamp: chrono::Utc::now(),
+                    },
+                    (None, None) => {
+                        // Simulate agent processing; a suspended agent holds the sequence
+                        // at its step and redoes the step once resumed
+                        while !process_unless_suspended(self.suspended.subscribe(), agent_id, processing_time).await {
+                            info!("Agent {} suspended, pausing task {}", agent_id, task.id);
+                            self.wait_until_resumed(agent_id).await;
+                        }
+                        
+                        // Process with current result as input
+                        WorkItem {
+                            description: format!("Sequential processing by {}", agent_type),
+                            result: Some(serde_json::json!({
+                                "agent": agent_type,
+                                "input": current_result,
+                                "output": format!("Processed by {}", agent_type),
+                                "correlation_id": correlation_id,
+                            })),
+                            timestamp: chrono::Utc::now(),
+                        }
+                    }
+                };
+                
+                current_result = work_item.result.clone();
+                
+                // An agent that took over an escalated step contributes more than once
+                let contribution = agent_contributions.entry(agent_id)
+                    .and_modify(|contribution: &mut AgentContribution| contribution.work_items.push(work_item.clone()))
+                    .or_insert_with(|| AgentContribution {
+                        agent_id,
+                        agent_type,
+                        work_items: vec![work_item],
+                        confidence: 0.9,
+                        neural_impact: 0.15,
+                    })
+                    .clone();
+                
+                self.record_contribution(task.id, contribution).await;
+            }
+        }
+        
+        let end_time = chrono::Utc::now();
+        
+        // A skipped step leaves the sequence short even when later steps finish
+        let status = if agent_contributions.is_empty() && !agent_failures.is_empty() {
+            TaskStatus::Failed {
+                error: format!("All {} steps failed", agent_failures.len()),
+            }
+        } else if !agent_failures.is_empty() {
+            let total = agent_ids.len();
+            TaskStatus::PartiallyCompleted {
+                completed: total - agent_failures.len(),
+                total,
+            }
+        } else {
+            TaskStatus::Completed
+        };
+        
+        Ok(TaskResult {
+            task_id: task.id,
+            status,
+            output: current_result.map(|r| TaskOutput::Text(r.to_string())),
+            metadata: TaskMetadata {
+                start_time,
+                end_time: Some(end_time),
+                duration_ms: Some((end_time - start_time).num_milliseconds() as u64),
+                iterations: agent_ids.len(),
+                neural_activity: NeuralActivityMetrics::default(),
+                reassignments: Vec::new(),
+                agent_failures,
+                escalations,
+                consensus: None,
+                low_confidence: false,
+            },
+            agent_contributions,
+        })
+    }
+    
+    /// Execute pipeline stages in order, handing each stage's output to the next
+    async fn execute_pipeline(
+        &self,
+        task: Task,
+        agent_ids: Vec<Uuid>,
+        source: OutputSource,
+        stages: Vec<StageSpec>,
+    ) -> Result<TaskResult, String> {
+        debug!("Executing task {} through a {}-stage pipeline", task.id, stages.len());
+        
+        let start_time = chrono::Utc::now();
+        let mut agent_contributions = HashMap::new();
+        let mut current_result = None;
+        let processing_time = tokio::time::Duration::from_millis(self.config.agent_processing_ms);
+        
+        let correlation_id = current_correlation_id().unwrap_or(task.id);
+        
+        for (stage, agent_id) in stages.iter().zip(&agent_ids) {
+            let agent_type = source.agent_type(agent_id)
+                .ok_or_else(|| format!("Agent {} for pipeline stage '{}' is unavailable", agent_id, stage.name))?;
+            
+            let span = info_span!("agent_invocation", correlation_id = %correlation_id, agent_id = %agent_id);
+            span.in_scope(|| debug!("Agent {} handling stage '{}'", agent_type, stage.name));
+            
+            self.check_agent_health(&source, *agent_id).await
+                .map_err(|e| format!("Agent {} failed pipeline stage '{}': {}", agent_id, stage.name, e))?;
+            
+            // Each stage works on the previous stage's output, the first on the task input
+            let handled = match source.live_agent(agent_id) {
+                Some(agent) => {
+                    let input = current_result.clone()
+                        .unwrap_or_else(|| serde_json::to_value(&task.input).unwrap_or_default());
+                    agent.process_task(&task.description, input).await
+                        .map_err(|e| format!("Agent {} failed pipeline stage '{}': {}", agent_id, stage.name, e))?
+                }
+                None => None,
+            };
+            
+            let work_item = match (source.recorded_work(agent_id), handled) {
+                (Some(work_item), _) => work_item,
+                (None, Some(result)) => WorkItem {
+                    description: format!("Stage '{}' handled by {}", stage.name, agent_type),
+                    result: Some(result),
+                    timestamp: chrono::Utc::now(),
+                },
+                (None, None) => {
+                    // Simulate agent processing for agents that don't handle tasks themselves
+                    tokio::time::sleep(processing_time).await;
+                    
+                    WorkItem {
+                        description: format!("Stage '{}' handled by {}", stage.name, agent_type),
+                        result: Some(serde_json::json!({
+                            "stage": stage.name,
+                            "agent": agent_type,
+                            "input": current_result,
+                            "output": format!("{} output from {}", stage.name, agent_type),
+                            "correlation_id": correlation_id,
+                        })),
+                        timestamp: chrono::Utc::now(),
+                    }
+                }
+            };
+            
+            if let Some(critic) = &self.critic {
+                let critique = critic.critique(&work_item);
+                if !critique.passes(critic.veto_threshold()) {
+                    return Err(format!(
+                        "Critic vetoed pipeline stage '{}' output from {} (score {:.2}): {}",
+                        stage.name, agent_type, critique.score, critique.issues.join("; ")
+                    ));
+                }
+            }
+            
+            current_result = work_item.result.clone();
+            
+            let contribution = AgentContribution {
+                agent_id: *agent_id,
+                agent_type,
+                work_items: vec![work_item],
+                confidence: 0.9,
+                neural_impact: 0.15,
+            };
+            
+            self.record_contribution(task.id, contribution.clone()).await;
+            agent_contributions.insert(*agent_id, contribution);
+        }
+        
+        let end_time = chrono::Utc::now();
+        
+        Ok(TaskResult {
+            task_id: task.id,
+            status: TaskStatus::Completed,
+            output: current_result.map(|r| TaskOutput::Text(r.to_string())),
+            metadata: TaskMetadata {
+                start_time,
+                end_time: Some(end_time),
+                duration_ms: Some((end_time - start_time).num_milliseconds() as u64),
+                iterations: stages.len(),
+                neural_activity: NeuralActivityMetrics::default(),
+                reassignments: Vec::new(),
+                agent_failures: Vec::new(),
+                escalations: Vec::new(),
+                consensus: None,
+                low_confidence: false,
+            },
+            agent_contributions,
+        })
+    }
+    
+    /// Execute with consensus voting
+    async fn execute_consensus(
+        &self,
+        task: Task,
+        agent_ids: Vec<Uuid>,
+        source: OutputSource,
+        min_agreement: f64,
+    ) -> Result<TaskResult, String> {
+        let mut aggregator = ConsensusAggregator::new(min_agreement);
+        if let Some(schedule) = self.config.consensus_schedule {
+            aggregator = aggregator.with_schedule(ConvergenceSchedule { initial: min_agreement, ..schedule });
+        }
+        let max_rounds = self.config.consensus_max_rounds.max(1);
+        let deadline = task.requirements.timeout.map(|limit| tokio::time::Instant::now() + limit);
+        
+        loop {
+            let round_started = tokio::time::Instant::now();
+            let mut result = self.consensus_round(task.clone(), agent_ids.clone(), source.clone()).await?;
+            
+            // Each agent votes for its latest output, with the confidence it reported
+            let mut votes = 0;
+            for (agent_id, contribution) in &result.agent_contributions {
+                if let Some(proposal) = contribution.work_items.last().and_then(proposal_of) {
+                    aggregator.add_vote(*agent_id, proposal, contribution.confidence);
+                    votes += 1;
+                }
+            }
+            
+            // Nothing proposed an output to agree on, so stand by the combined results
+            if votes == 0 {
+                return Ok(result);
+            }
+            
+            let rounds_exhausted = aggregator.round() + 1 >= max_rounds;
+            // Another round that takes as long as this one would overrun the task's timeout
+            let out_of_time = deadline.is_some_and(|deadline| {
+                tokio::time::Instant::now() + round_started.elapsed() >= deadline
+            });
+            
+            let consensus = match aggregator.resolve(false) {
+                Some(consensus) => consensus,
+                None if !rounds_exhausted && !out_of_time => {
+                    let threshold = aggregator.next_round();
+                    debug!("Consensus on task {} unsettled, deliberating again at {:.2}", result.task_id, threshold);
+                    continue;
+                }
+                None => {
+                    let best_effort = self.config.best_effort_consensus
+                        .then(|| aggregator.best_effort(rounds_exhausted))
+                        .flatten();
+                    let Some(consensus) = best_effort else {
+                        return Err(format!(
+                            "Consensus did not reach min_agreement {} in {} round(s)",
+                            min_agreement, aggregator.round() + 1
+                        ));
+                    };
+                    warn!(
+                        "Consensus on task {} fell short of {:.2} at {:.2} after {} round(s), returning best effort",
+                        result.task_id, aggregator.convergence_threshold, consensus.agreement(), aggregator.round() + 1
+                    );
+                    consensus
+                }
+            };
+            
+            result.output = Some(TaskOutput::Text(consensus.proposal().to_string()));
+            result.metadata.low_confidence = consensus.is_low_confidence();
+            result.metadata.consensus = Some(consensus);
+            
+            return Ok(result);
+        }
+    }
+    
+    /// One round of deliberation: every agent works the task in parallel, and
+    /// whatever the critic vetoes is dropped before the vote
+    async fn consensus_round(
+        &self,
+        task: Task,
+        agent_ids: Vec<Uuid>,
+        source: OutputSource,
+    ) -> Result<TaskResult, String> {
+        let mut result = self.execute_parallel(task, agent_ids, source).await?;
+        
+        // Vetoed outputs get no say in the outcome
+        if let Some(critic) = &self.critic {
+            let before = result.agent_contributions.len();
+            result.agent_contributions.retain(|_, contribution| {
+                !contribution.work_items.iter().any(|work_item| critic.vetoes(work_item))
+            });
+            
+            if before > 0 && result.agent_contributions.is_empty() {
+                return Err("Critic vetoed every consensus contribution".to_string());
+            }
+            
+            // The combined output must not carry vetoed work either
+            if result.agent_contributions.len() < before {
+                let mut surviving: Vec<&WorkItem> = result.agent_contributions.values()
+                    .flat_map(|contribution| &contribution.work_items)
+                    .collect();
+                surviving.sort_by_key(|work_item| work_item.timestamp);
+                result.output = Some(TaskOutput::Multiple(
+                    surviving.into_iter()
+                        .filter_map(|w| w.result.as_ref().map(|r| TaskOutput::Text(r.to_string())))
+                        .collect()
+                ));
+            }
+        }
+        
+        Ok(result)
+    }
+    
+    /// Execute by distributing subtasks
+    async fn execute_distributed(
+        &self,
+        task: Task,
+        agent_ids: Vec<Uuid>,
+        source: OutputSource,
+        max_subtasks: usize,
+    ) -> Result<TaskResult, String> {
+        // Break task into subtasks and distribute
+        self.execute_parallel(task, agent_ids, source).await
+    }
+    
+    /// Execute competitively - best result wins
+    async fn execute_competitive(
+        &self,
+        task: Task,
+        agent_ids: Vec<Uuid>,
+        source: OutputSource,
+    ) -> Result<TaskResult, String> {
+        // Similar to parallel but select best result
+        self.execute_parallel(task, agent_ids, source).await
+    }
+    
+    /// Adaptive execution - adjust strategy based on progress
+    async fn execute_adaptive(
+        &self,
+        task: Task,
+        agent_ids: Vec<Uuid>,
+        source: OutputSource,
+    ) -> Result<TaskResult, String> {
+        // Start with parallel, adapt if needed; keep in step with `concrete_strategy`
+        self.execute_parallel(task, agent_ids, source).await
+    }
+    
+    /// Get count of active tasks
+    pub async fn active_task_count(&self) -> usize {
+        self.active_tasks.read().await.len()
+    }
+    
+    /// Tasks waiting for a concurrency slot, next to run first
+    pub async fn queued_tasks(&self) -> Vec<QueuedTaskInfo> {
+        self.admission.queued()
+    }
+    
+    /// Whether a task is queued or running, `None` once it has finished or if it is unknown
+    pub async fn task_status(&self, task_id: Uuid) -> Option<TaskStatus> {
+        if let Some(position) = self.admission.position(task_id) {
+            return Some(TaskStatus::Queued { position });
+        }
+        
+        self.active_tasks.read().await
+            .get(&task_id)
+            .map(|execution| TaskStatus::Running { progress: execution.progress })
+    }
+    
+    /// Snapshot of every running task, oldest first
+    pub async fn active_tasks(&self) -> Vec<ActiveTaskInfo> {
+        let now = chrono::Utc::now();
+        let mut tasks: Vec<ActiveTaskInfo> = self.active_tasks.read().await
+            .values()
+            .map(|execution| ActiveTaskInfo {
+                task_id: execution.task.id,
+                description: execution.task.description.clone(),
+                strategy: execution.strategy.clone(),
+                assigned_agents: execution.assigned_agents.clone(),
+                progress: execution.progress,
+                start_time: execution.start_time,
+                elapsed_ms: (now - execution.start_time).num_milliseconds().max(0) as u64,
+            })
+            .collect();
+        
+        tasks.sort_by_key(|task| task.start_time);
+        tasks
+    }
+}
+
+/// Subscribed to an `EventBus`, keeps capability-based selection in step with
+/// capabilities agents gain or lose at runtime
+#[async_trait::async_trait]
+impl EventHandler for SwarmOrchestrator {
+    async fn handle(&self, event: SystemEvent) {
+        if let SystemEvent::AgentCapabilitiesChanged { agent_id, added, removed } = event {
+            let parse = |names: &[String]| names.iter().filter_map(|name| name.parse().ok()).collect::<Vec<_>>();
+            self.on_agent_capabilities_changed(agent_id, &parse(&added), &parse(&removed)).await;
+        }
+    }
+    
+    fn event_types(&self) -> Vec<std::any::TypeId> {
+        vec![std::any::TypeId::of::<SystemEvent>()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consensus::ConsensusResult;
+    use crate::task::{TaskInput, TaskRequirements};
+    use crate::test_support::ScriptedAgent;
+    use amos_agents::{AgentCapability, ConsciousnessEmergent, TrafficSeer, MemoryWeaver};
+    
+    fn test_agents(count: usize) -> HashMap<Uuid, Arc<dyn CognitiveAgent>> {
+        (0..count)
+            .map(|i| {
+                let agent: Arc<dyn CognitiveAgent> = if i % 2 == 0 {
+                    Arc::new(TrafficSeer::new())
+                } else {
+                    Arc::new(MemoryWeaver::new())
+                };
+                (agent.id(), agent)
+            })
+            .collect()
+    }
+    
+    async fn join_agents(orchestrator: &SwarmOrchestrator, agents: &HashMap<Uuid, Arc<dyn CognitiveAgent>>) {
+        for (id, agent) in agents {
+            orchestrator.on_agent_joined(*id, agent.name(), &agent.capabilities()).await.unwrap();
+        }
+    }
+    
+    fn test_orchestrator() -> SwarmOrchestrator {
+        SwarmOrchestrator::new(
+            SwarmTopology::Mesh { max_connections: 6 },
+            Arc::new(ForgeNeuralNetwork::new()),
+        )
+    }
+    
+    #[derive(Clone, Default)]
+    struct CapturedLogs(Arc<std::sync::Mutex<Vec<u8>>>);
+    
+    impl std::io::Write for CapturedLogs {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_correlation_id_spans_agent_invocations() {
+        let logs = CapturedLogs::default();
+        let writer = logs.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+        
+        let orchestrator = test_orchestrator();
+        let agents = test_agents(2);
+        let task = Task::new("Trace me".to_string(), TaskInput::Text("".to_string()));
+        let task_id = task.id;
+        
+        let result = orchestrator
+            .execute_task(task, TaskStrategy::Parallel, agents, false)
+            .await
+            .unwrap();
+        
+        assert_eq!(result.agent_contributions.len(), 2);
+        for contribution in result.agent_contributions.values() {
+            let work_result = contribution.work_items[0].result.as_ref().unwrap();
+            assert_eq!(work_result["correlation_id"], serde_json::json!(task_id));
+        }
+        
+        let output = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+        let agent_lines: Vec<&str> = output
+            .lines()
+            .filter(|line| line.contains("processing task"))
+            .collect();
+        assert_eq!(agent_lines.len(), 2);
+        assert!(agent_lines
+            .iter()
+            .all(|line| line.contains(&format!("correlation_id={}", task_id))));
+    }
+    
+    #[tokio::test]
+    async fn test_neural_sync_strengthens_contribution_pathways() {
+        let neural_network = Arc::new(ForgeNeuralNetwork::new());
+        let orchestrator = Arc::new(SwarmOrchestrator::new(
+            SwarmTopology::Mesh { max_connections: 6 },
+            neural_network.clone(),
+        ));
+        let agents = test_agents(2);
+        
+        let loop_handle = orchestrator.start_coordination_loop();
+        
+        let task = Task::new("Learn".to_string(), TaskInput::Text("".to_string()));
+        let result = orchestrator
+            .execute_task(task, TaskStrategy::Parallel, agents.clone(), false)
+            .await
+            .unwrap();
+        
+        tokio::time::sleep(tokio::time::Duration::from_millis(300)).await;
+        
+        let strategy_node = orchestrator.sync_node(SyncNode::Strategy("parallel")).await;
+        for contribution in result.agent_contributions.values() {
+            let agent_node = orchestrator.agent_sync_node(contribution.agent_id).await.unwrap();
+            assert!(neural_network.get_node(agent_node).await.is_some());
+            let pathway_id = neural_network
+                .find_pathway(agent_node, strategy_node)
+                .await
+                .expect("pathway created for contribution");
+            let pathway = neural_network.get_pathway(pathway_id).await.unwrap();
+            assert!((pathway.strength - contribution.neural_impact).abs() < 1e-9);
+        }
+        
+        // A new task with the same strategy strengthens the same pathways
+        // instead of adding nodes and pathways per task
+        let nodes_before = neural_network.node_count().await;
+        let task = Task::new("Learn again".to_string(), TaskInput::Text("".to_string()));
+        orchestrator
+            .execute_task(task, TaskStrategy::Parallel, agents, false)
+            .await
+            .unwrap();
+        orchestrator.process_coordination_messages().await;
+        
+        assert_eq!(neural_network.pathway_count().await, 2);
+        assert_eq!(neural_network.node_count().await, nodes_before);
+        for contribution in result.agent_contributions.values() {
+            let agent_node = orchestrator.agent_sync_node(contribution.agent_id).await.unwrap();
+            let pathway_id = neural_network.find_pathway(agent_node, strategy_node).await.unwrap();
+            let pathway = neural_network.get_pathway(pathway_id).await.unwrap();
+            assert!(pathway.strength > contribution.neural_impact);
+        }
+        
+        loop_handle.abort();
+    }
+    
+    #[tokio::test]
+    async fn test_neural_sync_without_coordination_loop_applies_inline() {
+        let neural_network = Arc::new(ForgeNeuralNetwork::new());
+        let orchestrator = SwarmOrchestrator::new(
+            SwarmTopology::Mesh { max_connections: 6 },
+            neural_network.clone(),
+        );
+        
+        let task = Task::new("Learn".to_string(), TaskInput::Text("".to_string()));
+        orchestrator
+            .execute_task(task, TaskStrategy::Parallel, test_agents(2), false)
+            .await
+            .unwrap();
+        
+        assert_eq!(neural_network.pathway_count().await, 2);
+        assert_eq!(orchestrator.process_coordination_messages().await, 0);
+    }
+    
+    #[tokio::test]
+    async fn test_neural_sync_disabled_leaves_network_untouched() {
+        let neural_network = Arc::new(ForgeNeuralNetwork::new());
+        let orchestrator = SwarmOrchestrator::new(
+            SwarmTopology::Mesh { max_connections: 6 },
+            neural_network.clone(),
+        ).with_config(SwarmConfig {
+            neural_sync_enabled: false,
+            ..Default::default()
+        });
+        
+        let task = Task::new("Quiet".to_string(), TaskInput::Text("".to_string()));
+        orchestrator
+            .execute_task(task, TaskStrategy::Parallel, test_agents(2), false)
+            .await
+            .unwrap();
+        orchestrator.process_coordination_messages().await;
+        
+        assert_eq!(neural_network.pathway_count().await, 0);
+    }
+    
+    fn slow_orchestrator(agent_processing_ms: u64) -> SwarmOrchestrator {
+        test_orchestrator().with_config(SwarmConfig {
+            agent_processing_ms,
+            ..Default::default()
+        })
+    }
+    
+    fn task_with_timeout(timeout: std::time::Duration) -> Task {
+        Task::new("Slow".to_string(), TaskInput::Text("".to_string()))
+            .with_requirements(TaskRequirements {
+                timeout: Some(timeout),
+                ..Default::default()
+            })
+    }
+    
+    #[tokio::test]
+    async fn test_parallel_task_times_out() {
+        let orchestrator = slow_orchestrator(5_000);
+        let task = task_with_timeout(std::time::Duration::from_secs(1));
+        
+        let started = std::time::Instant::now();
+        let result = orchestrator
+            .execute_task(task, TaskStrategy::Parallel, test_agents(3), false)
+            .await
+            .unwrap();
+        
+        assert!(matches!(result.status, TaskStatus::Timeout));
+        assert!(result.agent_contributions.is_empty());
+        assert!(started.elapsed() < std::time::Duration::from_secs(3));
+        assert_eq!(orchestrator.active_task_count().await, 0);
+    }
+    
+    #[tokio::test]
+    async fn test_sequential_timeout_keeps_partial_contributions() {
+        let orchestrator = slow_orchestrator(600);
+        let task = task_with_timeout(std::time::Duration::from_secs(1));
+        
+        let result = orchestrator
+            .execute_task(task, TaskStrategy::Sequential, test_agents(3), false)
+            .await
+            .unwrap();
+        
+        assert!(matches!(result.status, TaskStatus::Timeout));
+        assert_eq!(result.agent_contributions.len(), 1);
+    }
+    
+    #[tokio::test]
+    async fn test_active_tasks_lists_running_work() {
+        let orchestrator = Arc::new(slow_orchestrator(300));
+        
+        let mut handles = Vec::new();
+        for strategy in [TaskStrategy::Parallel, TaskStrategy::Sequential] {
+            let orchestrator = orchestrator.clone();
+            let task = Task::new(format!("{:?} job", strategy), TaskInput::Text("".to_string()));
+            handles.push(tokio::spawn(async move {
+                orchestrator.execute_task(task, strategy, test_agents(2), false).await
+            }));
+        }
+        
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        
+        let active = orchestrator.active_tasks().await;
+        assert_eq!(active.len(), 2);
+        assert!(active.iter().any(|t| matches!(t.strategy, TaskStrategy::Parallel)));
+        assert!(active.iter().any(|t| matches!(t.strategy, TaskStrategy::Sequential)));
+        assert!(active.iter().all(|t| !t.assigned_agents.is_empty()));
+        
+        for handle in handles {
+            handle.await.unwrap().unwrap();
+        }
+        assert!(orchestrator.active_tasks().await.is_empty());
+    }
+    
+    #[tokio::test]
+    async fn test_consensus_with_two_agents_selects_one() {
+        let orchestrator = test_orchestrator();
+        let agents = test_agents(2);
+        let task = Task::new("Vote".to_string(), TaskInput::Text("".to_string()));
+        
+        let selected = orchestrator
+            .select_agents(&task, &TaskStrategy::Consensus { min_agreement: 0.5 }, &agents)
+            .await
+            .unwrap();
+        
+        assert_eq!(selected.len(), 1);
+    }
+    
+    #[tokio::test]
+    async fn test_consensus_selects_odd_count() {
+        let orchestrator = test_orchestrator();
+        let agents = test_agents(4);
+        let task = Task::new("Vote".to_string(), TaskInput::Text("".to_string()));
+        
+        let selected = orchestrator
+            .select_agents(&task, &TaskStrategy::Consensus { min_agreement: 0.5 }, &agents)
+            .await
+            .unwrap();
+        
+        assert_eq!(selected.len(), 3);
+    }
+    
+    #[tokio::test]
+    async fn test_selection_respects_min_agents() {
+        let orchestrator = test_orchestrator();
+        let agents = test_agents(2);
+        let task = Task::new("Big job".to_string(), TaskInput::Text("".to_string()))
+            .with_requirements(TaskRequirements {
+                min_agents: 3,
+                max_agents: Some(1),
+                ..Default::default()
+            });
+        
+        assert!(orchestrator
+            .select_agents(&task, &TaskStrategy::Parallel, &agents)
+            .await
+            .is_err());
+        
+        let agents = test_agents(4);
+        let selected = orchestrator
+            .select_agents(&task, &TaskStrategy::Parallel, &agents)
+            .await
+            .unwrap();
+        assert_eq!(selected.len(), 3);
+    }
+    
+    #[tokio::test(start_paused = true)]
+    async fn test_load_aware_selection_spreads_parallel_work() {
+        let agents = test_agents(6);
+        let task = || Task::new("Small job".to_string(), TaskInput::Text("".to_string()))
+            .with_requirements(TaskRequirements {
+                max_agents: Some(2),
+                ..Default::default()
+            });
+        
+        let assignments = |config: SwarmConfig| {
+            let agents = agents.clone();
+            async move {
+                let orchestrator = test_orchestrator().with_config(config).with_selection_seed(7);
+                let mut counts: HashMap<Uuid, usize> = HashMap::new();
+                for _ in 0..30 {
+                    let result = orchestrator
+                        .execute_task(task(), TaskStrategy::Parallel, agents.clone(), false)
+                        .await
+                        .unwrap();
+                    for agent_id in result.agent_contributions.keys() {
+                        *counts.entry(*agent_id).or_default() += 1;
+                    }
+                }
+                counts
+            }
+        };
+        
+        let spread = assignments(SwarmConfig { agent_processing_ms: 1, ..Default::default() }).await;
+        assert_eq!(spread.len(), 6, "{:?}", spread);
+        assert!(spread.values().all(|count| *count >= 4), "{:?}", spread);
+        
+        // Without load awareness the same two agents take every task
+        let concentrated = assignments(SwarmConfig {
+            agent_processing_ms: 1,
+            parallel_selection: AgentSelection::First,
+            ..Default::default()
+        }).await;
+        assert_eq!(concentrated.len(), 2, "{:?}", concentrated);
+    }
+    
+    #[tokio::test]
+    async fn test_tasks_beyond_concurrency_limit_queue_by_priority() {
+        use crate::task::TaskPriority;
+        
+        let orchestrator = Arc::new(test_orchestrator().with_config(SwarmConfig {
+            max_concurrent_tasks: 1,
+            agent_processing_ms: 50,
+            ..Default::default()
+        }));
+        let agents = test_agents(1);
+        
+        let submit = |name: &str, priority: TaskPriority| {
+            let task = Task::new(name.to_string(), TaskInput::Text("".to_string())).with_priority(priority);
+            let task_id = task.id;
+            let orchestrator = orchestrator.clone();
+            let agents = agents.clone();
+            let handle = tokio::spawn(async move {
+                orchestrator.execute_task(task, TaskStrategy::Parallel, agents, false).await.unwrap()
+            });
+            (task_id, handle)
+        };
+        
+        let (running, first) = submit("running", TaskPriority::Low);
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        let mut waiting = Vec::new();
+        for (name, priority) in [("low", TaskPriority::Low), ("high", TaskPriority::High), ("medium", TaskPriority::Medium)] {
+            waiting.push(submit(name, priority));
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        let (low, high, medium) = (waiting[0].0, waiting[1].0, waiting[2].0);
+        
+        let queued = orchestrator.queued_tasks().await;
+        let order: Vec<(Uuid, usize)> = queued.iter().map(|info| (info.task_id, info.position)).collect();
+        assert_eq!(order, vec![(high, 0), (medium, 1), (low, 2)]);
+        assert!(matches!(orchestrator.task_status(low).await, Some(TaskStatus::Queued { position: 2 })));
+        assert!(matches!(orchestrator.task_status(running).await, Some(TaskStatus::Running { .. })));
+        
+        first.await.unwrap();
+        let mut finished = Vec::new();
+        for (task_id, handle) in waiting {
+            finished.push((handle.await.unwrap().metadata.end_time.unwrap(), task_id));
+        }
+        finished.sort();
+        let run_order: Vec<Uuid> = finished.into_iter().map(|(_, task_id)| task_id).collect();
+        assert_eq!(run_order, vec![high, medium, low]);
+        assert!(orchestrator.queued_tasks().await.is_empty());
+        assert!(orchestrator.task_status(low).await.is_none());
+    }
+    
+    #[tokio::test]
+    async fn test_replay_reproduces_recorded_outputs() {
+        let orchestrator = test_orchestrator();
+        let agents = test_agents(3);
+        let mut run = RecordedRun::new();
+        let mut live_results = Vec::new();
+        
+        for strategy in [TaskStrategy::Sequential, TaskStrategy::Parallel] {
+            let task = Task::new(format!("{:?} job", strategy), TaskInput::Text("".to_string()));
+            let result = orchestrator
+                .execute_task(task.clone(), strategy.clone(), agents.clone(), false)
+                .await
+                .unwrap();
+            run.record(task, strategy, &result);
+            live_results.push(result);
+        }
+        
+        // A fresh orchestrator with no agents still reproduces the run
+        let replayed = test_orchestrator().replay(&run).await;
+        
+        assert_eq!(replayed.len(), live_results.len());
+        for (live, replayed) in live_results.iter().zip(&replayed) {
+            assert_eq!(replayed.task_id, live.task_id);
+            assert_eq!(
+                serde_json::to_value(&replayed.output).unwrap(),
+                serde_json::to_value(&live.output).unwrap()
+            );
+            assert_eq!(replayed.agent_contributions.len(), live.agent_contributions.len());
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_replay_reproduces_reassigned_work() {
+        let orchestrator = Arc::new(test_orchestrator().with_config(SwarmConfig {
+            agent_processing_ms: 150,
+            ..Default::default()
+        }));
+        let agents = test_agents(2);
+        let paused = *agents.keys().next().unwrap();
+        
+        let task = Task::new("Split work".to_string(), TaskInput::Text("".to_string()));
+        let running = {
+            let orchestrator = orchestrator.clone();
+            let task = task.clone();
+            tokio::spawn(async move {
+                orchestrator.execute_task(task, TaskStrategy::Parallel, agents, false).await
+            })
+        };
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        orchestrator.suspend_agent(paused);
+        let live = running.await.unwrap().unwrap();
+        assert_eq!(live.metadata.reassignments.len(), 1);
+        
+        let mut run = RecordedRun::new();
+        run.record(task, TaskStrategy::Parallel, &live);
+        let replayed = test_orchestrator().replay(&run).await;
+        
+        // The agent that took over contributes both of its items again
+        assert_eq!(
+            serde_json::to_value(&replayed[0].output).unwrap(),
+            serde_json::to_value(&live.output).unwrap()
+        );
+        for (agent_id, contribution) in &live.agent_contributions {
+            assert_eq!(
+                serde_json::to_value(&replayed[0].agent_contributions[agent_id].work_items).unwrap(),
+                serde_json::to_value(&contribution.work_items).unwrap()
+            );
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_replay_keeps_a_result_for_a_task_that_fails() {
+        let orchestrator = test_orchestrator();
+        let agents = test_agents(2);
+        let mut run = RecordedRun::new();
+        
+        let task = Task::new("Fine".to_string(), TaskInput::Text("".to_string()));
+        let result = orchestrator.execute_task(task.clone(), TaskStrategy::Parallel, agents.clone(), false).await.unwrap();
+        run.record(task, TaskStrategy::Parallel, &result);
+        
+        // Two different proposals can't reach unanimous agreement when replayed
+        let mut outputs = run.tasks[0].outputs.clone();
+        outputs[1].work_item.result = Some(serde_json::json!({ "output": "something else" }));
+        run.tasks.insert(0, crate::replay::RecordedTask {
+            task: Task::new("Split".to_string(), TaskInput::Text("".to_string())),
+            strategy: TaskStrategy::Consensus { min_agreement: 1.0 },
+            outputs,
+        });
+        
+        let replayed = orchestrator.replay(&run).await;
+        
+        assert_eq!(replayed.len(), run.tasks.len());
+        for (recorded, replayed) in run.tasks.iter().zip(&replayed) {
+            assert_eq!(replayed.task_id, recorded.task.id);
+        }
+        assert!(matches!(replayed[0].status, TaskStatus::Failed { .. }));
+        assert!(matches!(replayed[1].status, TaskStatus::Completed));
+    }
+    
+    #[tokio::test]
+    async fn test_pipeline_runs_stages_in_order_by_capability() {
+        let orchestrator = test_orchestrator();
+        let agents = test_agents(2);
+        join_agents(&orchestrator, &agents).await;
+        let task = Task::new("Build then remember".to_string(), TaskInput::Text("".to_string()));
+        let strategy = TaskStrategy::Pipeline {
+            stages: vec![
+                StageSpec::new("observe", AgentCapability::PatternRecognition),
+                StageSpec::new("store", AgentCapability::MemoryManagement),
+            ],
+        };
+        
+        let result = orchestrator.execute_task(task, strategy, agents, false).await.unwrap();
+        
+        let mut work: Vec<&WorkItem> = result.agent_contributions.values()
+            .flat_map(|contribution| &contribution.work_items)
+            .collect();
+        work.sort_by_key(|work_item| work_item.timestamp);
+        let stages: Vec<_> = work.iter().map(|w| w.result.as_ref().unwrap()["stage"].clone()).collect();
+        assert_eq!(stages, vec![serde_json::json!("observe"), serde_json::json!("store")]);
+        
+        // The second stage consumed the first stage's output
+        let last = work[1].result.as_ref().unwrap();
+        assert_eq!(last["agent"], "MemoryWeaver");
+        assert_eq!(last["input"]["stage"], "observe");
+    }
+    
+    #[tokio::test]
+    async fn test_pipeline_hands_each_stage_the_previous_agents_output() {
+        let orchestrator = test_orchestrator();
+        let observer: Arc<dyn CognitiveAgent> = Arc::new(ScriptedAgent::transforming(
+            |input| serde_json::json!({ "observed": input }),
+        ));
+        let store: Arc<dyn CognitiveAgent> = Arc::new(MemoryWeaver::new());
+        let (observer_id, store_id) = (observer.id(), store.id());
+        let agents = HashMap::from([(observer_id, observer), (store_id, store)]);
+        join_agents(&orchestrator, &agents).await;
+        let task = Task::new("Observe then remember".to_string(), TaskInput::Text("raw".to_string()));
+        let strategy = TaskStrategy::Pipeline {
+            stages: vec![
+                StageSpec::new("observe", AgentCapability::PatternRecognition),
+                StageSpec::new("store", AgentCapability::MemoryManagement),
+            ],
+        };
+        
+        let result = orchestrator.execute_task(task.clone(), strategy, agents, false).await.unwrap();
+        
+        // The first stage's agent did the work on the task input
+        let observed = serde_json::json!({ "observed": serde_json::to_value(&task.input).unwrap() });
+        assert_eq!(result.agent_contributions[&observer_id].work_items[0].result, Some(observed.clone()));
+        
+        // and the second stage received exactly what it produced
+        let stored = result.agent_contributions[&store_id].work_items[0].result.as_ref().unwrap();
+        assert_eq!(stored["input"], observed);
+    }
+    
+    #[tokio::test]
+    async fn test_critic_vetoes_consensus_outputs_below_threshold() {
+        let agents = test_agents(3);
+        let task = Task::new("Vote".to_string(), TaskInput::Text("".to_string()));
+        let strategy = TaskStrategy::Consensus { min_agreement: 0.5 };
+        
+        // Parallel work carries no output field, which a lenient critic lets through
+        let lenient = test_orchestrator().with_critic(Arc::new(Critic::new()));
+        join_agents(&lenient, &agents).await;
+        let result = lenient.execute_task(task.clone(), strategy.clone(), agents.clone(), false).await.unwrap();
+        assert!(!result.agent_contributions.is_empty());
+        
+        let strict = test_orchestrator().with_critic(Arc::new(Critic::new().with_veto_threshold(0.9)));
+        join_agents(&strict, &agents).await;
+        let err = strict.execute_task(task, strategy, agents, false).await.unwrap_err();
+        assert!(err.contains("Critic vetoed"), "{}", err);
+    }
+    
+    #[tokio::test]
+    async fn test_vetoed_work_is_left_out_of_consensus_output() {
+        let kept: Arc<dyn CognitiveAgent> = Arc::new(TrafficSeer::new());
+        let also_kept: Arc<dyn CognitiveAgent> = Arc::new(TrafficSeer::new());
+        let vetoed: Arc<dyn CognitiveAgent> = Arc::new(ScriptedAgent::answering(
+            serde_json::json!({ "output": null, "confidence": 0.1 }),
+        ));
+        let agents: HashMap<Uuid, Arc<dyn CognitiveAgent>> = [kept.clone(), also_kept.clone(), vetoed.clone()]
+            .into_iter()
+            .map(|agent| (agent.id(), agent))
+            .collect();
+        
+        // Neither result proposes an output, so no vote is held
+        let orchestrator = test_orchestrator().with_critic(Arc::new(Critic::new().with_veto_threshold(0.6)));
+        join_agents(&orchestrator, &agents).await;
+        let task = Task::new("Vote".to_string(), TaskInput::Text("".to_string()));
+        let result = orchestrator
+            .execute_task(task, TaskStrategy::Consensus { min_agreement: 0.5 }, agents, false)
+            .await
+            .unwrap();
+        
+        assert!(result.agent_contributions.contains_key(&kept.id()));
+        assert!(result.agent_contributions.contains_key(&also_kept.id()));
+        assert!(!result.agent_contributions.contains_key(&vetoed.id()));
+        let Some(TaskOutput::Multiple(outputs)) = &result.output else {
+            panic!("expected combined outputs, got {:?}", result.output);
+        };
+        assert_eq!(outputs.len(), 2);
+        assert!(outputs.iter().all(|output| matches!(output, TaskOutput::Text(text) if text.contains("TrafficSeer"))));
+    }
+    
+    #[tokio::test]
+    async fn test_consensus_across_agent_types_without_outputs_keeps_parallel_result() {
+        use amos_agents::{LearningOracle, PathwaySculptor};
+        
+        let agents: HashMap<Uuid, Arc<dyn CognitiveAgent>> = [
+            Arc::new(TrafficSeer::new()) as Arc<dyn CognitiveAgent>,
+            Arc::new(MemoryWeaver::new()),
+            Arc::new(PathwaySculptor::new()),
+            Arc::new(LearningOracle::new()),
+            Arc::new(ConsciousnessEmergent::new()),
+        ]
+            .into_iter()
+            .map(|agent| (agent.id(), agent))
+            .collect();
+        let orchestrator = test_orchestrator()
+            .with_config(SwarmConfig { agent_processing_ms: 1, ..Default::default() });
+        join_agents(&orchestrator, &agents).await;
+        
+        let task = Task::new("Vote".to_string(), TaskInput::Text("".to_string()));
+        let result = orchestrator
+            .execute_task(task, TaskStrategy::Consensus { min_agreement: 0.5 }, agents, false)
+            .await
+            .unwrap();
+        
+        // No agent proposed an output, so there is nothing to vote on
+        assert!(matches!(result.status, TaskStatus::Completed));
+        assert!(result.metadata.consensus.is_none());
+        assert!(matches!(result.output, Some(TaskOutput::Multiple(ref outputs)) if outputs.len() == 5));
+    }
+    
+    #[tokio::test]
+    async fn test_unconverged_consensus_returns_best_effort_when_configured() {
+        let agents = split_vote(Duration::ZERO);
+        let task = Task::new("Vote".to_string(), TaskInput::Text("".to_string()));
+        let strategy = TaskStrategy::Consensus { min_agreement: 0.9 };
+        
+        let strict = test_orchestrator();
+        join_agents(&strict, &agents).await;
+        let err = strict.execute_task(task.clone(), strategy.clone(), agents.clone(), false).await.unwrap_err();
+        assert!(err.contains("min_agreement"), "{}", err);
+        
+        let lenient = test_orchestrator()
+            .with_config(SwarmConfig { best_effort_consensus: true, ..Default::default() });
+        join_agents(&lenient, &agents).await;
+        let result = lenient.execute_task(task, strategy, agents, false).await.unwrap();
+        
+        assert!(matches!(result.status, TaskStatus::Completed));
+        assert!(result.metadata.low_confidence);
+        assert!(matches!(result.output, Some(TaskOutput::Text(ref proposal)) if proposal == "scale_up"));
+        match result.metadata.consensus {
+            Some(ConsensusResult::BestEffort { proposal, agreement, rounds_exhausted }) => {
+                assert_eq!(proposal, "scale_up");
+                assert!(agreement < 0.9);
+                assert!(rounds_exhausted);
+            }
+            other => panic!("expected a best-effort result, got {:?}", other),
+        }
+    }
+    
+    /// Two agents for scaling up and one for holding, each taking `delay` to propose
+    fn split_vote(delay: Duration) -> HashMap<Uuid, Arc<dyn CognitiveAgent>> {
+        ["scale_up", "scale_up", "hold"]
+            .into_iter()
+            .map(|proposal| {
+                let agent: Arc<dyn CognitiveAgent> = Arc::new(ScriptedAgent::proposing(proposal).taking(delay));
+                (agent.id(), agent)
+            })
+            .collect()
+    }
+    
+    #[tokio::test]
+    async fn test_consensus_anneals_over_rounds_until_it_converges() {
+        let agents = split_vote(Duration::ZERO);
+        let orchestrator = test_orchestrator().with_config(SwarmConfig {
+            agent_processing_ms: 1,
+            consensus_max_rounds: 10,
+            consensus_schedule: Some(ConvergenceSchedule { initial: 0.9, floor: 0.1, decay_per_round: 0.2 }),
+            ..Default::default()
+        });
+        join_agents(&orchestrator, &agents).await;
+        
+        let task = Task::new("Vote".to_string(), TaskInput::Text("".to_string()));
+        let result = orchestrator
+            .execute_task(task, TaskStrategy::Consensus { min_agreement: 0.9 }, agents, false)
+            .await
+            .unwrap();
+        
+        assert!(!result.metadata.low_confidence);
+        assert!(matches!(result.metadata.consensus, Some(ConsensusResult::Converged { ref proposal, .. }) if proposal == "scale_up"));
+    }
+    
+    #[tokio::test(start_paused = true)]
+    async fn test_consensus_settles_for_best_effort_when_time_runs_out() {
+        let agents = split_vote(Duration::from_millis(100));
+        let orchestrator = test_orchestrator().with_config(SwarmConfig {
+            best_effort_consensus: true,
+            consensus_max_rounds: 10,
+            ..Default::default()
+        });
+        join_agents(&orchestrator, &agents).await;
+        
+        // Rounds take 100ms each, so a third would overrun the budget
+        let task = task_with_timeout(Duration::from_millis(250));
+        let result = orchestrator
+            .execute_task(task, TaskStrategy::Consensus { min_agreement: 0.9 }, agents, false)
+            .await
+            .unwrap();
+        
+        assert!(matches!(result.status, TaskStatus::Completed));
+        assert!(result.metadata.low_confidence);
+        match result.metadata.consensus {
+            Some(ConsensusResult::BestEffort { proposal, rounds_exhausted, .. }) => {
+                assert_eq!(proposal, "scale_up");
+                assert!(!rounds_exhausted);
+            }
+            other => panic!("expected a best-effort result, got {:?}", other),
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_task_history_keeps_newest_within_retention() {
+        let orchestrator = test_orchestrator()
+            .with_config(SwarmConfig { agent_processing_ms: 1, task_history_retention: 3, ..Default::default() });
+        let agents = test_agents(2);
+        join_agents(&orchestrator, &agents).await;
+        
+        let mut task_ids = Vec::new();
+        for i in 0..4 {
+            let task = Task::new(format!("Task {}", i), TaskInput::Text("".to_string()));
+            task_ids.push(task.id);
+            orchestrator.execute_task(task, TaskStrategy::Parallel, agents.clone(), false).await.unwrap();
+        }
+        
+        // The first task has aged out
+        let history = orchestrator.task_history(10).await;
+        let ids: Vec<Uuid> = history.iter().map(|record| record.task_id).collect();
+        assert_eq!(ids, vec![task_ids[3], task_ids[2], task_ids[1]]);
+        assert!(history.iter().all(|record| matches!(record.result.status, TaskStatus::Completed)));
+        assert!(history.iter().all(|record| record.selected_agents.len() == 2));
+        
+        assert_eq!(orchestrator.task_history(1).await[0].description, "Task 3");
+    }
+    
+    #[tokio::test]
+    async fn test_task_history_records_rejected_failed_and_timed_out_tasks() {
+        let agents: HashMap<Uuid, Arc<dyn CognitiveAgent>> = ["scale_up", "hold"]
+            .into_iter()
+            .map(|proposal| {
+                let agent: Arc<dyn CognitiveAgent> = Arc::new(ScriptedAgent::proposing(proposal));
+                (agent.id(), agent)
+            })
+            .collect();
+        let orchestrator = test_orchestrator()
+            .with_config(SwarmConfig { agent_processing_ms: 1, ..Default::default() });
+        join_agents(&orchestrator, &agents).await;
+        
+        let rejected = Task::new("Too big".to_string(), TaskInput::Text("".to_string()))
+            .with_requirements(TaskRequirements { min_agents: 5, ..Default::default() });
+        orchestrator.execute_task(rejected, TaskStrategy::Parallel, agents.clone(), false).await.unwrap_err();
+        
+        let split = Task::new("Split vote".to_string(), TaskInput::Text("".to_string()));
+        orchestrator
+            .execute_task(split, TaskStrategy::Consensus { min_agreement: 0.9 }, agents.clone(), false)
+            .await
+            .unwrap_err();
+        
+        let slow = ScriptedAgent::slow(Duration::from_secs(5));
+        let slow_agents = HashMap::from([(slow.id(), Arc::new(slow) as Arc<dyn CognitiveAgent>)]);
+        orchestrator
+            .execute_task(task_with_timeout(Duration::from_millis(100)), TaskStrategy::Parallel, slow_agents, false)
+            .await
+            .unwrap();
+        
+        let history = orchestrator.task_history(10).await;
+        assert_eq!(history.len(), 3);
+        assert!(matches!(history[0].result.status, TaskStatus::Timeout));
+        
+        assert_eq!(history[1].description, "Split vote");
+        assert!(!history[1].selected_agents.is_empty());
+        assert!(matches!(&history[1].result.status, TaskStatus::Failed { error } if error.contains("min_agreement")));
+        
+        assert_eq!(history[2].description, "Too big");
+        assert!(history[2].selected_agents.is_empty());
+        assert!(matches!(&history[2].result.status, TaskStatus::Failed { error } if error.contains("Required: 5")));
+    }
+    
+    #[tokio::test]
+    async fn test_pipeline_fails_fast_on_missing_capability() {
+        let orchestrator = test_orchestrator();
+        let task = Task::new("Generate".to_string(), TaskInput::Text("".to_string()));
+        let strategy = TaskStrategy::Pipeline {
+            stages: vec![
+                StageSpec::new("observe", AgentCapability::PatternRecognition),
+                StageSpec::new("draft", AgentCapability::Generation),
+            ],
+        };
+        
+        let agents = test_agents(2);
+        join_agents(&orchestrator, &agents).await;
+        
+        let err = orchestrator.execute_task(task, strategy, agents, false).await.unwrap_err();
+        
+        assert!(err.contains("'draft'"), "{}", err);
+        assert_eq!(orchestrator.active_task_count().await, 0);
+    }
+    
+    #[tokio::test]
+    async fn test_latency_percentiles_expose_slow_tail() {
+        let latency = Arc::new(LatencyTracker::new());
+        let fast = test_orchestrator()
+            .with_config(SwarmConfig { agent_processing_ms: 1, ..Default::default() })
+            .with_latency_tracker(latency.clone());
+        let slow = test_orchestrator()
+            .with_config(SwarmConfig { agent_processing_ms: 80, ..Default::default() })
+            .with_latency_tracker(latency.clone());
+        let agents = test_agents(2);
+        
+        for i in 0..40 {
+            let task = Task::new(format!("Fast {}", i), TaskInput::Text("".to_string()));
+            fast.execute_task(task, TaskStrategy::Parallel, agents.clone(), false).await.unwrap();
+        }
+        for i in 0..2 {
+            let task = Task::new(format!("Slow {}", i), TaskInput::Text("".to_string()));
+            slow.execute_task(task, TaskStrategy::Parallel, agents.clone(), false).await.unwrap();
+        }
+        
+        let percentiles = fast.latency_percentiles(&TaskStrategy::Parallel).await;
+        assert_eq!(percentiles.samples, 42);
+        assert!(percentiles.p99 >= 80.0, "{:?}", percentiles);
+        assert!(percentiles.p99 > percentiles.p50 * 5.0, "{:?}", percentiles);
+        assert_eq!(fast.latency_percentiles(&TaskStrategy::Sequential).await.samples, 0);
+    }
+    
+    #[tokio::test]
+    async fn test_dry_run_returns_plan_without_running_agents() {
+        let orchestrator = test_orchestrator();
+        let agents = test_agents(3);
+        let task = Task::new("Plan only".to_string(), TaskInput::Text("".to_string()));
+        
+        let result = orchestrator
+            .execute_task(task, TaskStrategy::Adaptive, agents.clone(), true)
+            .await
+            .unwrap();
+        
+        match &result.status {
+            TaskStatus::DryRun { strategy, selected_agents } => {
+                assert!(matches!(strategy, TaskStrategy::Parallel));
+                assert_eq!(selected_agents.len(), 3);
+                assert!(selected_agents.iter().all(|id| agents.contains_key(id)));
+            }
+            other => panic!("expected a dry run, got {:?}", other),
+        }
+        assert_eq!(result.metadata.duration_ms, Some(0));
+        assert!(result.agent_contributions.is_empty());
+        assert!(result.output.is_none());
+        assert_eq!(orchestrator.latency_percentiles(&TaskStrategy::Adaptive).await.samples, 0);
+    }
+    
+    #[tokio::test]
+    async fn test_capability_index_follows_joins_and_leaves() {
+        let orchestrator = test_orchestrator();
+        let seer: Arc<dyn CognitiveAgent> = Arc::new(TrafficSeer::new());
+        let emergent: Arc<dyn CognitiveAgent> = Arc::new(ConsciousnessEmergent::new());
+        let weaver: Arc<dyn CognitiveAgent> = Arc::new(MemoryWeaver::new());
+        let agents: HashMap<Uuid, Arc<dyn CognitiveAgent>> = [&seer, &emergent, &weaver]
+            .into_iter()
+            .map(|agent| (agent.id(), agent.clone()))
+            .collect();
+        join_agents(&orchestrator, &agents).await;
+        
+        // ConsciousnessEmergent shares Monitoring with the seer and Learning with the weaver
+        let mut monitors = vec![seer.id(), emergent.id()];
+        monitors.sort();
+        let mut learners = vec![emergent.id(), weaver.id()];
+        learners.sort();
+        assert_eq!(orchestrator.agents_with_capability(&AgentCapability::Monitoring).await, monitors);
+        assert_eq!(orchestrator.agents_with_capability(&AgentCapability::Learning).await, learners);
+        
+        orchestrator.on_agent_left(emergent.id()).await;
+        
+        assert_eq!(orchestrator.agents_with_capability(&AgentCapability::Monitoring).await, vec![seer.id()]);
+        assert_eq!(orchestrator.agents_with_capability(&AgentCapability::Learning).await, vec![weaver.id()]);
+        assert_eq!(orchestrator.agents_with_capability(&AgentCapability::PatternRecognition).await, vec![seer.id()]);
+    }
+    
+    #[tokio::test]
+    async fn test_selection_filters_by_required_capabilities() {
+        let orchestrator = test_orchestrator();
+        let agents = test_agents(4);
+        join_agents(&orchestrator, &agents).await;
+        
+        let mut task = Task::new("Remember".to_string(), TaskInput::Text("".to_string()));
+        task.requirements.required_capabilities = vec!["memory_management".to_string()];
+        let weavers = orchestrator.agents_with_capability(&AgentCapability::MemoryManagement).await;
+        
+        let selected = orchestrator
+            .select_agents(&task, &TaskStrategy::Parallel, &agents)
+            .await
+            .unwrap();
+        assert_eq!(selected, weavers);
+        
+        task.requirements.required_capabilities = vec!["Telepathy".to_string()];
+        let err = orchestrator
+            .select_agents(&task, &TaskStrategy::Parallel, &agents)
+            .await
+            .unwrap_err();
+        assert!(err.contains("Telepathy"), "{}", err);
+    }
+    
+    #[tokio::test]
+    async fn test_granted_capability_makes_agent_selectable() {
+        struct Forward(mpsc::UnboundedSender<SystemEvent>);
+        
+        #[async_trait::async_trait]
+        impl EventHandler for Forward {
+            async fn handle(&self, event: SystemEvent) {
+                let _ = self.0.send(event);
+            }
+            
+            fn event_types(&self) -> Vec<std::any::TypeId> {
+                vec![std::any::TypeId::of::<SystemEvent>()]
+            }
+        }
+        
+        let event_bus = Arc::new(EventBus::new());
+        event_bus.clone().start_processing().await;
+        let orchestrator = Arc::new(test_orchestrator());
+        event_bus.subscribe(orchestrator.clone()).await;
+        let (events_tx, mut events) = mpsc::unbounded_channel();
+        event_bus.subscribe(Arc::new(Forward(events_tx))).await;
+        
+        let mut seer = TrafficSeer::new();
+        seer.initialize(Arc::new(ForgeNeuralNetwork::new()), event_bus.clone()).await.unwrap();
+        let seer_id = seer.id();
+        orchestrator.on_agent_joined(seer_id, seer.name(), &seer.capabilities()).await.unwrap();
+        assert!(orchestrator.agents_with_capability(&AgentCapability::NeuralOptimization).await.is_empty());
+        
+        seer.grant_capability(AgentCapability::NeuralOptimization).await.unwrap();
+        
+        let changed = tokio::time::timeout(Duration::from_secs(1), async {
+            loop {
+                if let Some(SystemEvent::AgentCapabilitiesChanged { agent_id, added, removed }) = events.recv().await {
+                    return (agent_id, added, removed);
+                }
+            }
+        }).await.unwrap();
+        assert_eq!(changed, (seer_id, vec!["neural_optimization".to_string()], Vec::new()));
+        
+        // The orchestrator handles the same event on its own task
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let agents: HashMap<Uuid, Arc<dyn CognitiveAgent>> = HashMap::from([(seer_id, Arc::new(seer) as Arc<dyn CognitiveAgent>)]);
+        let mut task = Task::new("Optimize".to_string(), TaskInput::Text("".to_string()));
+        task.requirements.required_capabilities = vec!["neural_optimization".to_string()];
+        let selected = orchestrator
+            .select_agents(&task, &TaskStrategy::Parallel, &agents)
+            .await
+            .unwrap();
+        assert_eq!(selected, vec![seer_id]);
+    }
+    
+    #[test]
+    fn test_coordination_interval_scales_with_load() {
+        let config = SwarmConfig {
+            max_concurrent_tasks: 4,
+            min_coordination_interval_ms: 10,
+            max_coordination_interval_ms: 90,
+            ..Default::default()
+        };
+        
+        assert_eq!(config.coordination_interval(0), Duration::from_millis(90));
+        assert_eq!(config.coordination_interval(2), Duration::from_millis(50));
+        assert_eq!(config.coordination_interval(4), Duration::from_millis(10));
+        assert_eq!(config.coordination_interval(40), Duration::from_millis(10));
+        
+        let zero = SwarmConfig {
+            min_coordination_interval_ms: 0,
+            max_coordination_interval_ms: 0,
+            ..config
+        };
+        assert_eq!(zero.coordination_interval(0), Duration::from_millis(1));
+        assert_eq!(zero.coordination_interval(40), Duration::from_millis(1));
+    }
+    
+    #[tokio::test(start_paused = true)]
+    async fn test_coordination_interval_shrinks_under_burst_and_relaxes() {
+        let observed = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let recorder = observed.clone();
+        let orchestrator = Arc::new(
+            test_orchestrator()
+                .with_config(SwarmConfig {
+                    max_concurrent_tasks: 4,
+                    coordination_interval_ms: 20,
+                    min_coordination_interval_ms: 5,
+                    max_coordination_interval_ms: 40,
+                    agent_processing_ms: 200,
+                    ..Default::default()
+                })
+                .with_interval_observer(Arc::new(move |interval| recorder.lock().unwrap().push(interval))),
+        );
+        let _coordination = orchestrator.start_coordination_loop();
+        let latest = || *observed.lock().unwrap().last().unwrap();
+        
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(latest(), Duration::from_millis(40));
+        
+        let burst: Vec<_> = (0..4)
+            .map(|i| {
+                let orchestrator = orchestrator.clone();
+                tokio::spawn(async move {
+                    let task = Task::new(format!("Burst {}", i), TaskInput::Text("".to_string()));
+                    orchestrator.execute_task(task, TaskStrategy::Parallel, test_agents(2), false).await
+                })
+            })
+            .collect();
+        
+        tokio::time::sleep(Duration::from_millis(120)).await;
+        assert_eq!(latest(), Duration::from_millis(5));
+        
+        for task in burst {
+            task.await.unwrap().unwrap();
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(latest(), Duration::from_millis(40));
+    }
+    
+    #[tokio::test]
+    async fn test_suspended_parallel_agent_work_is_reassigned() {
+        let orchestrator = Arc::new(test_orchestrator().with_config(SwarmConfig {
+            agent_processing_ms: 150,
+            ..Default::default()
+        }));
+        let agents = test_agents(2);
+        let mut ids: Vec<Uuid> = agents.keys().copied().collect();
+        ids.sort();
+        let (paused, remaining) = (ids[0], ids[1]);
+        
+        let running = {
+            let orchestrator = orchestrator.clone();
+            tokio::spawn(async move {
+                let task = Task::new("Split work".to_string(), TaskInput::Text("".to_string()));
+                orchestrator.execute_task(task, TaskStrategy::Parallel, agents, false).await
+            })
+        };
+        
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        orchestrator.suspend_agent(paused);
+        
+        let result = running.await.unwrap().unwrap();
+        
+        assert!(matches!(result.status, TaskStatus::Completed));
+        assert!(!result.agent_contributions.contains_key(&paused));
+        assert_eq!(result.agent_contributions[&remaining].work_items.len(), 2);
+        assert_eq!(result.metadata.reassignments.len(), 1);
+        assert_eq!(result.metadata.reassignments[0].from_agent, paused);
+        assert_eq!(result.metadata.reassignments[0].to_agent, remaining);
+    }
+    
+    #[tokio::test]
+    async fn test_suspending_every_parallel_agent_records_dropped_work() {
+        let orchestrator = Arc::new(test_orchestrator().with_config(SwarmConfig {
+            agent_processing_ms: 150,
+            ..Default::default()
+        }));
+        let agents = test_agents(2);
+        let ids: Vec<Uuid> = agents.keys().copied().collect();
+        
+        let running = {
+            let orchestrator = orchestrator.clone();
+            tokio::spawn(async move {
+                let task = Task::new("Nobody left".to_string(), TaskInput::Text("".to_string()));
+                orchestrator.execute_task(task, TaskStrategy::Parallel, agents, false).await
+            })
+        };
+        
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        for id in &ids {
+            orchestrator.suspend_agent(*id);
+        }
+        
+        let result = running.await.unwrap().unwrap();
+        
+        assert!(matches!(result.status, TaskStatus::Failed { .. }));
+        assert!(result.agent_contributions.is_empty());
+        assert!(result.metadata.reassignments.is_empty());
+        let mut failed: Vec<Uuid> = result.metadata.agent_failures.iter().map(|f| f.agent_id).collect();
+        failed.sort();
+        let mut expected = ids.clone();
+        expected.sort();
+        assert_eq!(failed, expected);
+    }
+    
+    #[tokio::test]
+    async fn test_suspended_work_is_not_reassigned_to_an_agent_that_failed() {
+        let orchestrator = Arc::new(test_orchestrator().with_config(SwarmConfig {
+            agent_processing_ms: 150,
+            ..Default::default()
+        }));
+        let worker: Arc<dyn CognitiveAgent> = Arc::new(TrafficSeer::new());
+        let broken: Arc<dyn CognitiveAgent> = Arc::new(ScriptedAgent::unreachable());
+        let (worker_id, broken_id) = (worker.id(), broken.id());
+        let agents = HashMap::from([(worker_id, worker), (broken_id, broken)]);
+        
+        let running = {
+            let orchestrator = orchestrator.clone();
+            tokio::spawn(async move {
+                let task = Task::new("Nobody healthy".to_string(), TaskInput::Text("".to_string()));
+                orchestrator.execute_task(task, TaskStrategy::Parallel, agents, false).await
+            })
+        };
+        
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        orchestrator.suspend_agent(worker_id);
+        
+        let result = running.await.unwrap().unwrap();
+        
+        assert!(result.metadata.reassignments.is_empty());
+        assert!(result.agent_contributions.is_empty());
+        let mut failed: Vec<Uuid> = result.metadata.agent_failures.iter().map(|f| f.agent_id).collect();
+        failed.sort();
+        let mut expected = vec![worker_id, broken_id];
+        expected.sort();
+        assert_eq!(failed, expected);
+    }
+    
+    #[tokio::test]
+    async fn test_suspended_sequential_agent_pauses_until_resumed() {
+        let orchestrator = Arc::new(test_orchestrator().with_config(SwarmConfig {
+            agent_processing_ms: 50,
+            ..Default::default()
+        }));
+        let agents = test_agents(2);
+        let ids: Vec<Uuid> = agents.keys().copied().collect();
+        for id in &ids {
+            orchestrator.suspend_agent(*id);
+        }
+        
+        let running = {
+            let orchestrator = orchestrator.clone();
+            tokio::spawn(async move {
+                let task = Task::new("Wait for me".to_string(), TaskInput::Text("".to_string()));
+                orchestrator.execute_task(task, TaskStrategy::Sequential, agents, false).await
+            })
+        };
+        
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        assert!(!running.is_finished());
+        assert_eq!(orchestrator.active_task_count().await, 1);
+        
+        for id in &ids {
+            orchestrator.resume_agent(*id);
+        }
+        let result = running.await.unwrap().unwrap();
+        
+        assert!(matches!(result.status, TaskStatus::Completed));
+        assert_eq!(result.agent_contributions.len(), 2);
+        assert!(result.metadata.reassignments.is_empty());
+    }
+    
+    #[tokio::test]
+    async fn test_failed_child_escalates_its_step_to_parent() {
+        let orchestrator = SwarmOrchestrator::new(
+            SwarmTopology::Hierarchical { levels: 2, agents_per_level: 2 },
+            Arc::new(ForgeNeuralNetwork::new()),
+        ).with_config(SwarmConfig {
+            agent_processing_ms: 1,
+            ..Default::default()
+        });
+        let parent: Arc<dyn CognitiveAgent> = Arc::new(TrafficSeer::new());
+        let child: Arc<dyn CognitiveAgent> = Arc::new(ScriptedAgent::unreachable());
+        let (parent_id, child_id) = (parent.id(), child.id());
+        
+        // The first agent to join becomes the root, the next its child
+        orchestrator.on_agent_joined(parent_id, parent.name(), &parent.capabilities()).await.unwrap();
+        orchestrator.on_agent_joined(child_id, child.name(), &child.capabilities()).await.unwrap();
+        assert!(matches!(
+            orchestrator.agent_placements().await[&child_id],
+            AgentPlacement::Hierarchical { parent: Some(p), .. } if p == parent_id
+        ));
+        
+        let agents = HashMap::from([(parent_id, parent), (child_id, child)]);
+        let task = Task::new("Escalate".to_string(), TaskInput::Text("".to_string()));
+        let result = orchestrator.execute_task(task, TaskStrategy::Sequential, agents, false).await.unwrap();
+        
+        assert!(matches!(result.status, TaskStatus::Completed));
+        assert_eq!(result.metadata.escalations.len(), 1);
+        assert_eq!(result.metadata.escalations[0].from_agent, child_id);
+        assert_eq!(result.metadata.escalations[0].to_agent, parent_id);
+        assert!(result.metadata.agent_failures.is_empty());
+        
+        // The parent did its own step and then the child's
+        assert!(!result.agent_contributions.contains_key(&child_id));
+        assert_eq!(result.agent_contributions[&parent_id].work_items.len(), 2);
+    }
+    
+    #[tokio::test]
+    async fn test_failing_agent_breaker_opens_and_cools_down() {
+        let orchestrator = test_orchestrator().with_config(SwarmConfig {
+            agent_processing_ms: 1,
+            breaker_failure_threshold: 2,
+            breaker_cooldown_ms: 150,
+            ..Default::default()
+        });
+        let mut agents = test_agents(1);
+        let failing: Arc<dyn CognitiveAgent> = Arc::new(ScriptedAgent::unreachable());
+        let failing_id = failing.id();
+        agents.insert(failing_id, failing);
+        
+        for attempt in 1..=2 {
+            assert_eq!(orchestrator.agent_breaker_state(failing_id).await, BreakerState::Closed, "attempt {}", attempt);
+            let task = Task::new("Flaky".to_string(), TaskInput::Text("".to_string()));
+            let result = orchestrator.execute_task(task, TaskStrategy::Parallel, agents.clone(), false).await.unwrap();
+            assert!(!result.agent_contributions.contains_key(&failing_id));
+            assert!(matches!(result.status, TaskStatus::PartiallyCompleted { completed: 1, total: 2 }));
+        }
+        
+        assert_eq!(orchestrator.agent_breaker_state(failing_id).await, BreakerState::Open);
+        let task = Task::new("Flaky".to_string(), TaskInput::Text("".to_string()));
+        let plan = orchestrator.execute_task(task.clone(), TaskStrategy::Parallel, agents.clone(), true).await.unwrap();
+        let TaskStatus::DryRun { selected_agents, .. } = plan.status else { panic!("expected a dry run") };
+        assert_eq!(selected_agents.len(), 1);
+        assert!(!selected_agents.contains(&failing_id));
+        
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert_eq!(orchestrator.agent_breaker_state(failing_id).await, BreakerState::HalfOpen);
+        
+        // Half-open agents get a trial run; it fails too, so the breaker reopens straight away
+        let plan = orchestrator.execute_task(task.clone(), TaskStrategy::Parallel, agents.clone(), true).await.unwrap();
+        let TaskStatus::DryRun { selected_agents, .. } = plan.status else { panic!("expected a dry run") };
+        assert!(selected_agents.contains(&failing_id));
+        orchestrator.execute_task(task, TaskStrategy::Parallel, agents, false).await.unwrap();
+        assert_eq!(orchestrator.agent_breaker_state(failing_id).await, BreakerState::Open);
+    }
+    
+    #[tokio::test]
+    async fn test_parallel_fails_when_every_agent_fails() {
+        let orchestrator = test_orchestrator().with_config(SwarmConfig {
+            agent_processing_ms: 1,
+            ..Default::default()
+        });
+        let agents: HashMap<Uuid, Arc<dyn CognitiveAgent>> = (0..2)
+            .map(|_| {
+                let agent: Arc<dyn CognitiveAgent> = Arc::new(ScriptedAgent::unreachable());
+                (agent.id(), agent)
+            })
+            .collect();
+        
+        let task = Task::new("Nobody home".to_string(), TaskInput::Text("".to_string()));
+        let result = orchestrator.execute_task(task, TaskStrategy::Parallel, agents, false).await.unwrap();
+        
+        assert!(result.agent_contributions.is_empty());
+        assert_eq!(result.metadata.agent_failures.len(), 2);
+        assert!(matches!(result.status, TaskStatus::Failed { .. }));
+    }
+    
+    #[tokio::test]
+    async fn test_sequential_with_a_failed_step_is_partially_completed() {
+        let orchestrator = test_orchestrator().with_config(SwarmConfig {
+            agent_processing_ms: 1,
+            ..Default::default()
+        });
+        let mut agents = test_agents(1);
+        let failing: Arc<dyn CognitiveAgent> = Arc::new(ScriptedAgent::failing_tasks());
+        let failing_id = failing.id();
+        agents.insert(failing_id, failing);
+        
+        let task = Task::new("One step short".to_string(), TaskInput::Text("".to_string()));
+        let result = orchestrator.execute_task(task, TaskStrategy::Sequential, agents, false).await.unwrap();
+        
+        assert_eq!(result.metadata.agent_failures.len(), 1);
+        assert_eq!(result.metadata.agent_failures[0].agent_id, failing_id);
+        assert_eq!(result.agent_contributions.len(), 1);
+        assert!(matches!(result.status, TaskStatus::PartiallyCompleted { completed: 1, total: 2 }));
+    }
+    
+    #[tokio::test]
+    async fn test_parallel_straggler_is_dropped_after_agent_timeout() {
+        let orchestrator = test_orchestrator().with_config(SwarmConfig {
+            agent_processing_ms: 10,
+            ..Default::default()
+        });
+        let mut agents = test_agents(2);
+        let straggler: Arc<dyn CognitiveAgent> = Arc::new(ScriptedAgent::slow(Duration::from_secs(5)));
+        let straggler_id = straggler.id();
+        agents.insert(straggler_id, straggler);
+        
+        let task = Task::new("Don't wait up".to_string(), TaskInput::Text("".to_string()))
+            .with_requirements(TaskRequirements {
+                agent_timeout: Some(Duration::from_millis(100)),
+                ..Default::default()
+            });
+        let started = std::time::Instant::now();
+        let result = orchestrator.execute_task(task, TaskStrategy::Parallel, agents, false).await.unwrap();
+        
+        assert!(started.elapsed() < Duration::from_secs(1), "took {:?}", started.elapsed());
+        assert!(matches!(result.status, TaskStatus::PartiallyCompleted { completed: 2, total: 3 }));
+        assert_eq!(result.agent_contributions.len(), 2);
+        assert!(!result.agent_contributions.contains_key(&straggler_id));
+        assert_eq!(result.metadata.agent_failures.len(), 1);
+        assert_eq!(result.metadata.agent_failures[0].agent_id, straggler_id);
+        assert_eq!(result.metadata.agent_failures[0].reason, AgentFailureReason::TimedOut);
     }
-}
\ No newline at end of file
+}
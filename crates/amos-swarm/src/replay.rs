@@ -0,0 +1,65 @@
+use crate::task::{Task, TaskResult, TaskStrategy, WorkItem};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Work one agent produced for a recorded task
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedOutput {
+    pub agent_id: Uuid,
+    pub agent_type: String,
+    pub work_item: WorkItem,
+}
+
+/// A task as it was executed, with each agent's output in the order it was produced
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedTask {
+    pub task: Task,
+    pub strategy: TaskStrategy,
+    pub outputs: Vec<RecordedOutput>,
+}
+
+impl RecordedTask {
+    /// Agents in the order they produced their work, once per work item, so an
+    /// agent that took over reassigned work is invoked as often as it was live
+    pub fn agent_ids(&self) -> Vec<Uuid> {
+        self.outputs.iter().map(|output| output.agent_id).collect()
+    }
+}
+
+/// A captured sequence of tasks that can be replayed deterministically
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RecordedRun {
+    pub tasks: Vec<RecordedTask>,
+}
+
+impl RecordedRun {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Capture a finished task along with every work item each agent contributed
+    pub fn record(&mut self, task: Task, strategy: TaskStrategy, result: &TaskResult) {
+        let mut outputs: Vec<RecordedOutput> = result.agent_contributions.values()
+            .flat_map(|contribution| {
+                contribution.work_items.iter().map(|work_item| RecordedOutput {
+                    agent_id: contribution.agent_id,
+                    agent_type: contribution.agent_type.clone(),
+                    work_item: work_item.clone(),
+                })
+            })
+            .collect();
+        
+        // Contributions are unordered; work timestamps restore the original sequence
+        outputs.sort_by_key(|output| output.work_item.timestamp);
+        
+        self.tasks.push(RecordedTask { task, strategy, outputs });
+    }
+    
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+}
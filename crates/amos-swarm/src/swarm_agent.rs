@@ -0,0 +1,256 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use std::sync::{Arc, Mutex};
+use amos_core::{ForgeNeuralNetwork, EventBus, SystemEvent};
+use amos_agents::{CognitiveAgent, BaseAgent, AgentState, AgentCapability};
+use anyhow::{anyhow, Result};
+use crate::task::{Task, TaskInput, TaskStrategy};
+use crate::AmosSwarm;
+
+/// Presents a whole swarm as a single agent, so it can join another swarm
+/// as one member of a "team of teams"
+pub struct SwarmAgent {
+    base: BaseAgent,
+    swarm: AmosSwarm,
+    strategy: TaskStrategy,
+    /// What the members could do when last asked, for when the roster is being changed
+    last_capabilities: Mutex<Vec<AgentCapability>>,
+}
+
+impl SwarmAgent {
+    /// Wrap `swarm`, advertising whatever capabilities its agents have at the time
+    pub fn new(swarm: AmosSwarm) -> Self {
+        Self {
+            base: BaseAgent::new(swarm.name.clone(), Vec::new()),
+            swarm,
+            strategy: TaskStrategy::Parallel,
+            last_capabilities: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// How the inner swarm splits up the tasks handed to it
+    pub fn with_strategy(mut self, strategy: TaskStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    pub fn swarm(&self) -> &AmosSwarm {
+        &self.swarm
+    }
+}
+
+#[async_trait]
+impl CognitiveAgent for SwarmAgent {
+    fn id(&self) -> Uuid {
+        self.base.id
+    }
+
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn capabilities(&self) -> Vec<AgentCapability> {
+        let mut last = self.last_capabilities.lock().unwrap_or_else(|e| e.into_inner());
+        // Sync callers can't wait out a roster change, so they see the roster from before it
+        if let Ok(agents) = self.swarm.agents.try_read() {
+            *last = member_capabilities(agents.values());
+        }
+        last.clone()
+    }
+
+    async fn initialize(&mut self, neural_network: Arc<ForgeNeuralNetwork>, event_bus: Arc<EventBus>) -> Result<()> {
+        self.base.transition_state(AgentState::Initializing).await?;
+
+        self.base.neural_network = Some(neural_network);
+        self.base.event_bus = Some(event_bus);
+
+        self.base.logger.info(&format!("Sub-swarm {} initialized", self.swarm.name));
+
+        self.base.transition_state(AgentState::Active).await?;
+        Ok(())
+    }
+
+    async fn activate(&mut self) -> Result<()> {
+        self.base.transition_state(AgentState::Active).await?;
+        Ok(())
+    }
+
+    async fn process(&mut self) -> Result<()> {
+        // Work arrives through `process_task`, so a cycle only marks activity
+        self.base.transition_state(AgentState::Processing).await?;
+        self.base.update_activity();
+        self.base.transition_state(AgentState::Active).await?;
+        Ok(())
+    }
+
+    async fn suspend(&mut self) -> Result<()> {
+        self.base.transition_state(AgentState::Suspended).await?;
+        Ok(())
+    }
+
+    async fn terminate(&mut self) -> Result<()> {
+        self.base.transition_state(AgentState::Terminating).await?;
+        self.base.transition_state(AgentState::Terminated).await?;
+        Ok(())
+    }
+
+    fn state(&self) -> AgentState {
+        self.base.state.clone()
+    }
+
+    fn base(&self) -> Option<&BaseAgent> {
+        Some(&self.base)
+    }
+
+    async fn receive_event(&mut self, _event: SystemEvent) -> Result<()> {
+        self.base.update_activity();
+        Ok(())
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        if self.swarm.agents.read().await.is_empty() {
+            return Err(anyhow!("Sub-swarm {} has no agents", self.swarm.name));
+        }
+        Ok(())
+    }
+
+    async fn process_task(&self, description: &str, input: serde_json::Value) -> Result<Option<serde_json::Value>> {
+        let task = Task::new(description.to_string(), TaskInput::Custom(input));
+        let result = self.swarm.orchestrate(task, self.strategy.clone()).await
+            .map_err(|e| anyhow!("Sub-swarm {} failed: {}", self.swarm.name, e))?;
+
+        Ok(Some(serde_json::to_value(result)?))
+    }
+}
+
+/// Every capability at least one of `agents` has, in first-seen order
+pub(crate) fn member_capabilities<'a>(agents: impl Iterator<Item = &'a Arc<dyn CognitiveAgent>>) -> Vec<AgentCapability> {
+    let mut capabilities: Vec<AgentCapability> = Vec::new();
+    for agent in agents {
+        for capability in agent.capabilities() {
+            if !capabilities.contains(&capability) {
+                capabilities.push(capability);
+            }
+        }
+    }
+    capabilities
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SwarmTopology, TaskResult, StageSpec};
+    use amos_agents::{MemoryWeaver, TrafficSeer};
+
+    #[tokio::test]
+    async fn test_outer_swarm_task_flows_into_nested_swarm() {
+        let inner = AmosSwarm::new(
+            "Inner Team".to_string(),
+            SwarmTopology::Mesh { max_connections: 6 },
+            Arc::new(ForgeNeuralNetwork::new()),
+        );
+        let inner_ids = vec![
+            inner.spawn_agent(Arc::new(TrafficSeer::new())).await.unwrap(),
+            inner.spawn_agent(Arc::new(MemoryWeaver::new())).await.unwrap(),
+        ];
+
+        let outer = AmosSwarm::new(
+            "Outer Team".to_string(),
+            SwarmTopology::Mesh { max_connections: 6 },
+            Arc::new(ForgeNeuralNetwork::new()),
+        );
+        let team_id = outer.spawn_swarm(inner).await.unwrap();
+
+        let result = outer.orchestrate(
+            Task::new("Review traffic".to_string(), TaskInput::Text("logs".to_string())),
+            TaskStrategy::Parallel,
+        ).await.unwrap();
+
+        // The sub-swarm is one contributor to the outer task, and ran the task on its own agents
+        let team = &result.agent_contributions[&team_id];
+        assert_eq!(team.agent_type, "Inner Team");
+        let inner_result: TaskResult = serde_json::from_value(
+            team.work_items[0].result.clone().unwrap()
+        ).unwrap();
+        assert_eq!(inner_result.agent_contributions.len(), 2);
+        for id in &inner_ids {
+            assert!(inner_result.agent_contributions.contains_key(id));
+        }
+
+        let status = outer.status().await;
+        assert_eq!(status.agent_count, 1);
+        assert_eq!(status.sub_swarms.len(), 1);
+        assert_eq!(status.sub_swarms[0].name, "Inner Team");
+        assert_eq!(status.sub_swarms[0].agent_count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_nested_swarm_runs_as_a_pipeline_stage() {
+        let inner = team("Inner Team");
+        let seer_id = inner.spawn_agent(Arc::new(TrafficSeer::new())).await.unwrap();
+        let outer = team("Outer Team");
+        let team_id = outer.spawn_swarm(inner).await.unwrap();
+        let weaver_id = outer.spawn_agent(Arc::new(MemoryWeaver::new())).await.unwrap();
+
+        let result = outer.orchestrate(
+            Task::new("Observe then remember".to_string(), TaskInput::Text("logs".to_string())),
+            TaskStrategy::Pipeline {
+                stages: vec![
+                    StageSpec::new("observe", AgentCapability::PatternRecognition),
+                    StageSpec::new("store", AgentCapability::MemoryManagement),
+                ],
+            },
+        ).await.unwrap();
+
+        // The team's stage ran on its own agents
+        let observed = result.agent_contributions[&team_id].work_items[0].result.clone().unwrap();
+        let inner_result: TaskResult = serde_json::from_value(observed.clone()).unwrap();
+        assert!(inner_result.agent_contributions.contains_key(&seer_id));
+
+        // and its output was handed to the next stage
+        let stored = result.agent_contributions[&weaver_id].work_items[0].result.as_ref().unwrap();
+        assert_eq!(stored["input"], observed);
+    }
+
+    fn team(name: &str) -> AmosSwarm {
+        AmosSwarm::new(
+            name.to_string(),
+            SwarmTopology::Mesh { max_connections: 6 },
+            Arc::new(ForgeNeuralNetwork::new()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_swarm_cannot_join_itself_or_a_descendant() {
+        let outer = team("Outer Team");
+        let inner = team("Inner Team");
+        inner.spawn_agent(Arc::new(TrafficSeer::new())).await.unwrap();
+        outer.spawn_swarm(inner.clone()).await.unwrap();
+
+        assert!(outer.spawn_swarm(outer.clone()).await.is_err());
+        assert!(inner.spawn_swarm(outer.clone()).await.is_err());
+        assert_eq!(inner.agents.read().await.len(), 1);
+
+        // Status still terminates, since no loop was formed
+        assert_eq!(outer.status().await.sub_swarms.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_nested_swarm_capabilities_follow_its_roster() {
+        let inner = team("Inner Team");
+        inner.spawn_agent(Arc::new(TrafficSeer::new())).await.unwrap();
+        let outer = team("Outer Team");
+        let team_id = outer.spawn_swarm(inner.clone()).await.unwrap();
+
+        let weaver = MemoryWeaver::new();
+        assert!(!outer.agents.read().await[&team_id].capabilities().contains(&AgentCapability::MemoryManagement));
+        inner.spawn_agent(Arc::new(weaver)).await.unwrap();
+        assert!(outer.agents.read().await[&team_id].capabilities().contains(&AgentCapability::MemoryManagement));
+
+        // The outer swarm finds the team for work only its new member can do
+        let mut task = Task::new("Recall traffic".to_string(), TaskInput::Text("logs".to_string()));
+        task.requirements.required_capabilities = vec!["memory_management".to_string()];
+        let result = outer.orchestrate(task, TaskStrategy::Parallel).await.unwrap();
+        assert!(result.agent_contributions.contains_key(&team_id));
+    }
+}
@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use std::time::{Duration, Instant};
 use std::collections::HashMap;
+use amos_agents::AgentCapability;
+use crate::consensus::ConsensusResult;
 
 /// A task to be executed by the swarm
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,7 +19,7 @@ pub struct Task {
 impl Task {
     pub fn new(description: String, input: TaskInput) -> Self {
         Self {
-            id: Uuid::new_v4(),
+            id: amos_core::ids::next_id(),
             description,
             input,
             requirements: TaskRequirements::default(),
@@ -55,6 +57,10 @@ pub struct TaskRequirements {
     pub required_capabilities: Vec<String>,
     pub timeout: Option<Duration>,
     pub max_iterations: Option<usize>,
+    /// How long a single agent may take in parallel execution before the task
+    /// carries on without it
+    #[serde(default)]
+    pub agent_timeout: Option<Duration>,
 }
 
 impl Default for TaskRequirements {
@@ -65,6 +71,7 @@ impl Default for TaskRequirements {
             required_capabilities: Vec::new(),
             timeout: Some(Duration::from_secs(300)), // 5 minutes
             max_iterations: Some(100),
+            agent_timeout: None,
         }
     }
 }
@@ -107,6 +114,40 @@ pub enum TaskStrategy {
     
     /// Adapt strategy based on task progress
     Adaptive,
+    
+    /// Stages run in order, each handled by an agent with the stage's capability
+    Pipeline { stages: Vec<StageSpec> },
+}
+
+impl TaskStrategy {
+    /// Strategy name without its parameters
+    pub fn name(&self) -> &'static str {
+        match self {
+            TaskStrategy::Parallel => "parallel",
+            TaskStrategy::Sequential => "sequential",
+            TaskStrategy::Consensus { .. } => "consensus",
+            TaskStrategy::Distributed { .. } => "distributed",
+            TaskStrategy::Competitive => "competitive",
+            TaskStrategy::Adaptive => "adaptive",
+            TaskStrategy::Pipeline { .. } => "pipeline",
+        }
+    }
+}
+
+/// One stage of a pipeline and the capability its agent must have
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StageSpec {
+    pub name: String,
+    pub capability: AgentCapability,
+}
+
+impl StageSpec {
+    pub fn new(name: impl Into<String>, capability: AgentCapability) -> Self {
+        Self {
+            name: name.into(),
+            capability,
+        }
+    }
 }
 
 /// Result of task execution
@@ -119,15 +160,119 @@ pub struct TaskResult {
     pub agent_contributions: HashMap<Uuid, AgentContribution>,
 }
 
+impl TaskResult {
+    /// Fold distributed subtask results into one. Contributions from the same
+    /// agent are combined, outputs are gathered in input order, and the time
+    /// bounds span every sub-result.
+    pub fn merge(results: Vec<TaskResult>) -> TaskResult {
+        let total = results.len();
+        let completed = results.iter()
+            .filter(|result| matches!(result.status, TaskStatus::Completed))
+            .count();
+        
+        let start_time = results.iter()
+            .map(|result| result.metadata.start_time)
+            .min()
+            .unwrap_or_else(chrono::Utc::now);
+        let end_time = results.iter()
+            .filter_map(|result| result.metadata.end_time)
+            .max();
+        
+        let mut outputs = Vec::new();
+        let mut agent_contributions: HashMap<Uuid, AgentContribution> = HashMap::new();
+        // Summed confidence and count per agent, averaged once every result is in
+        let mut confidences: HashMap<Uuid, (f64, usize)> = HashMap::new();
+        let mut iterations = 0;
+        let mut neural_activity = NeuralActivityMetrics::default();
+        let mut strength_total = 0.0;
+        let mut reassignments = Vec::new();
+        let mut agent_failures = Vec::new();
+        let mut escalations = Vec::new();
+        let mut low_confidence = false;
+        
+        for result in results {
+            outputs.extend(result.output);
+            iterations += result.metadata.iterations;
+            reassignments.extend(result.metadata.reassignments);
+            agent_failures.extend(result.metadata.agent_failures);
+            escalations.extend(result.metadata.escalations);
+            low_confidence |= result.metadata.low_confidence;
+            
+            let activity = result.metadata.neural_activity;
+            neural_activity.pathways_activated += activity.pathways_activated;
+            neural_activity.hormonal_bursts += activity.hormonal_bursts;
+            neural_activity.memory_consolidations += activity.memory_consolidations;
+            strength_total += activity.avg_pathway_strength * activity.pathways_activated as f64;
+            
+            for (agent_id, contribution) in result.agent_contributions {
+                let (sum, count) = confidences.entry(agent_id).or_insert((0.0, 0));
+                *sum += contribution.confidence;
+                *count += 1;
+                
+                match agent_contributions.get_mut(&agent_id) {
+                    Some(existing) => {
+                        existing.work_items.extend(contribution.work_items);
+                        existing.neural_impact += contribution.neural_impact;
+                    }
+                    None => {
+                        agent_contributions.insert(agent_id, contribution);
+                    }
+                }
+            }
+        }
+        
+        for (agent_id, (sum, count)) in confidences {
+            if let Some(contribution) = agent_contributions.get_mut(&agent_id) {
+                contribution.confidence = sum / count as f64;
+            }
+        }
+        
+        if neural_activity.pathways_activated > 0 {
+            neural_activity.avg_pathway_strength = strength_total / neural_activity.pathways_activated as f64;
+        }
+        
+        TaskResult {
+            task_id: amos_core::ids::next_id(),
+            status: if total == 0 {
+                TaskStatus::Failed { error: "No subtask results to merge".to_string() }
+            } else if completed == total {
+                TaskStatus::Completed
+            } else {
+                TaskStatus::PartiallyCompleted { completed, total }
+            },
+            output: (!outputs.is_empty()).then_some(TaskOutput::Multiple(outputs)),
+            metadata: TaskMetadata {
+                start_time,
+                end_time,
+                duration_ms: end_time.map(|end| (end - start_time).num_milliseconds().max(0) as u64),
+                iterations,
+                neural_activity,
+                reassignments,
+                agent_failures,
+                escalations,
+                consensus: None,
+                low_confidence,
+            },
+            agent_contributions,
+        }
+    }
+}
+
 /// Task execution status
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TaskStatus {
     Pending,
+    /// Waiting for a concurrency slot; `position` 0 runs next
+    Queued { position: usize },
     Running { progress: f64 },
     Completed,
     Failed { error: String },
     Cancelled,
     Timeout,
+    /// Nothing ran; the agents and concrete strategy that would have been used
+    DryRun { strategy: TaskStrategy, selected_agents: Vec<Uuid> },
+    /// Merged from sub-results of which only `completed` of `total` completed
+    PartiallyCompleted { completed: usize, total: usize },
 }
 
 /// Task output
@@ -147,6 +292,53 @@ pub struct TaskMetadata {
     pub duration_ms: Option<u64>,
     pub iterations: usize,
     pub neural_activity: NeuralActivityMetrics,
+    /// Work moved off agents that were suspended mid-task
+    #[serde(default)]
+    pub reassignments: Vec<Reassignment>,
+    /// Agents the task completed without
+    #[serde(default)]
+    pub agent_failures: Vec<AgentFailure>,
+    /// Failed work handed up a hierarchy instead of being dropped
+    #[serde(default)]
+    pub escalations: Vec<Escalation>,
+    /// How a consensus task's vote came out
+    #[serde(default)]
+    pub consensus: Option<ConsensusResult>,
+    /// Set when the output is a best effort rather than an agreed result
+    #[serde(default)]
+    pub low_confidence: bool,
+}
+
+/// An agent dropped from a task that went on without it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AgentFailure {
+    pub agent_id: Uuid,
+    pub reason: AgentFailureReason,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AgentFailureReason {
+    /// Ran past the task's `agent_timeout`
+    TimedOut,
+    Error { message: String },
+}
+
+/// An agent's share of a task handed to another agent after it was suspended
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Reassignment {
+    pub from_agent: Uuid,
+    pub to_agent: Uuid,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+/// A failed agent's work handed to its hierarchy parent, or a sibling, to finish
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Escalation {
+    pub from_agent: Uuid,
+    pub to_agent: Uuid,
+    pub reason: String,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
 }
 
 /// Neural activity during task execution
@@ -214,7 +406,12 @@ impl TaskQueue {
     }
     
     pub fn dequeue(&mut self) -> Option<Task> {
-        self.pending.pop()
+        // Pending tasks are kept in descending priority order
+        if self.pending.is_empty() {
+            None
+        } else {
+            Some(self.pending.remove(0))
+        }
     }
     
     pub fn start_task(&mut self, task: Task) {
@@ -283,4 +480,86 @@ mod tests {
         let third = queue.dequeue().unwrap();
         assert_eq!(third.description, "Low");
     }
-}
\ No newline at end of file
+    
+    fn sub_result(status: TaskStatus, start_offset_ms: i64, end_offset_ms: i64, agent_id: Uuid) -> TaskResult {
+        let base = chrono::DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z").unwrap().with_timezone(&chrono::Utc);
+        let work_item = WorkItem {
+            description: "slice".to_string(),
+            result: None,
+            timestamp: base,
+        };
+        
+        TaskResult {
+            task_id: Uuid::new_v4(),
+            status,
+            output: Some(TaskOutput::Text(format!("from {}", agent_id))),
+            metadata: TaskMetadata {
+                start_time: base + chrono::Duration::milliseconds(start_offset_ms),
+                end_time: Some(base + chrono::Duration::milliseconds(end_offset_ms)),
+                duration_ms: Some((end_offset_ms - start_offset_ms) as u64),
+                iterations: 1,
+                neural_activity: NeuralActivityMetrics::default(),
+                reassignments: Vec::new(),
+                agent_failures: Vec::new(),
+                escalations: Vec::new(),
+                consensus: None,
+                low_confidence: false,
+            },
+            agent_contributions: HashMap::from([(agent_id, AgentContribution {
+                agent_id,
+                agent_type: "TrafficSeer".to_string(),
+                work_items: vec![work_item],
+                confidence: 0.8,
+                neural_impact: 0.1,
+            })]),
+        }
+    }
+    
+    #[test]
+    fn test_merge_with_failed_subtask_is_partially_completed() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let results = vec![
+            sub_result(TaskStatus::Completed, 100, 400, a),
+            sub_result(TaskStatus::Completed, 50, 300, b),
+            sub_result(TaskStatus::Failed { error: "boom".to_string() }, 200, 900, a),
+        ];
+        let base = results[0].metadata.start_time - chrono::Duration::milliseconds(100);
+        
+        let merged = TaskResult::merge(results);
+        
+        assert!(matches!(merged.status, TaskStatus::PartiallyCompleted { completed: 2, total: 3 }));
+        assert_eq!(merged.agent_contributions.len(), 2);
+        assert_eq!(merged.agent_contributions[&a].work_items.len(), 2);
+        assert_eq!(merged.agent_contributions[&b].work_items.len(), 1);
+        assert!(matches!(&merged.output, Some(TaskOutput::Multiple(outputs)) if outputs.len() == 3));
+        assert_eq!(merged.metadata.iterations, 3);
+        assert_eq!(merged.metadata.start_time, base + chrono::Duration::milliseconds(50));
+        assert_eq!(merged.metadata.end_time, Some(base + chrono::Duration::milliseconds(900)));
+        assert_eq!(merged.metadata.duration_ms, Some(850));
+    }
+    
+    #[test]
+    fn test_merge_of_nothing_fails() {
+        let merged = TaskResult::merge(Vec::new());
+        
+        assert!(matches!(merged.status, TaskStatus::Failed { .. }));
+        assert!(merged.output.is_none());
+    }
+    
+    #[test]
+    fn test_merge_weighs_every_contribution_equally() {
+        let agent_id = Uuid::new_v4();
+        let results: Vec<TaskResult> = [0.9, 0.6, 0.3]
+            .into_iter()
+            .map(|confidence| {
+                let mut result = sub_result(TaskStatus::Completed, 0, 100, agent_id);
+                result.agent_contributions.get_mut(&agent_id).unwrap().confidence = confidence;
+                result
+            })
+            .collect();
+        
+        let merged = TaskResult::merge(results);
+        
+        assert!((merged.agent_contributions[&agent_id].confidence - 0.6).abs() < 1e-9);
+    }
+}
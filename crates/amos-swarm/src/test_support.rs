@@ -0,0 +1,148 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use std::sync::Arc;
+use std::time::Duration;
+use amos_core::{ForgeNeuralNetwork, EventBus, SystemEvent};
+use amos_agents::{CognitiveAgent, AgentState, AgentCapability, TrafficSeer};
+
+/// How a `ScriptedAgent` answers the tasks it is handed
+enum Reply {
+    /// Leave the work to the orchestrator's own processing
+    Nothing,
+    /// The same value for every task
+    Fixed(serde_json::Value),
+    /// The task's own input
+    Echo,
+    /// The task's input run through a function
+    Transform(fn(serde_json::Value) -> serde_json::Value),
+    /// An error for every task
+    Fail,
+}
+
+/// A pattern recognizer whose startup, health checks and answers are scripted by each test
+pub(crate) struct ScriptedAgent {
+    inner: TrafficSeer,
+    health_delay: Duration,
+    healthy: bool,
+    starts: bool,
+    task_delay: Duration,
+    reply: Reply,
+}
+
+impl ScriptedAgent {
+    fn new() -> Self {
+        Self {
+            inner: TrafficSeer::new(),
+            health_delay: Duration::ZERO,
+            healthy: true,
+            starts: true,
+            task_delay: Duration::ZERO,
+            reply: Reply::Nothing,
+        }
+    }
+
+    /// Its dependencies are never reachable
+    pub(crate) fn unreachable() -> Self {
+        Self { healthy: false, ..Self::new() }
+    }
+
+    pub(crate) fn slow(health_delay: Duration) -> Self {
+        Self { health_delay, ..Self::new() }
+    }
+
+    /// Refuses to initialize
+    pub(crate) fn failing_to_start() -> Self {
+        Self { starts: false, ..Self::new() }
+    }
+
+    /// Answers every task with `proposal`
+    pub(crate) fn proposing(proposal: &'static str) -> Self {
+        Self::answering(serde_json::json!({ "output": proposal }))
+    }
+
+    /// Answers every task with `answer` as its whole result
+    pub(crate) fn answering(answer: serde_json::Value) -> Self {
+        Self { reply: Reply::Fixed(answer), ..Self::new() }
+    }
+
+    /// Takes `delay` over each task and answers with its input
+    pub(crate) fn echoing(delay: Duration) -> Self {
+        Self { reply: Reply::Echo, ..Self::new() }.taking(delay)
+    }
+
+    /// Answers each task with `transform` applied to its input
+    pub(crate) fn transforming(transform: fn(serde_json::Value) -> serde_json::Value) -> Self {
+        Self { reply: Reply::Transform(transform), ..Self::new() }
+    }
+
+    /// Passes its health checks but errors on every task
+    pub(crate) fn failing_tasks() -> Self {
+        Self { reply: Reply::Fail, ..Self::new() }
+    }
+
+    /// Takes `delay` over each task before answering
+    pub(crate) fn taking(self, delay: Duration) -> Self {
+        Self { task_delay: delay, ..self }
+    }
+}
+
+#[async_trait]
+impl CognitiveAgent for ScriptedAgent {
+    fn id(&self) -> Uuid {
+        self.inner.id()
+    }
+
+    fn name(&self) -> &str {
+        "ScriptedAgent"
+    }
+
+    fn capabilities(&self) -> Vec<AgentCapability> {
+        self.inner.capabilities()
+    }
+
+    async fn initialize(&mut self, neural_network: Arc<ForgeNeuralNetwork>, event_bus: Arc<EventBus>) -> anyhow::Result<()> {
+        anyhow::ensure!(self.starts, "no neural pathways available");
+        self.inner.initialize(neural_network, event_bus).await
+    }
+
+    async fn activate(&mut self) -> anyhow::Result<()> {
+        self.inner.activate().await
+    }
+
+    async fn process(&mut self) -> anyhow::Result<()> {
+        self.inner.process().await
+    }
+
+    async fn suspend(&mut self) -> anyhow::Result<()> {
+        self.inner.suspend().await
+    }
+
+    async fn terminate(&mut self) -> anyhow::Result<()> {
+        self.inner.terminate().await
+    }
+
+    fn state(&self) -> AgentState {
+        self.inner.state()
+    }
+
+    async fn receive_event(&mut self, event: SystemEvent) -> anyhow::Result<()> {
+        self.inner.receive_event(event).await
+    }
+
+    async fn health_check(&self) -> anyhow::Result<()> {
+        tokio::time::sleep(self.health_delay).await;
+        anyhow::ensure!(self.healthy, "upstream unreachable");
+        Ok(())
+    }
+
+    async fn process_task(&self, _description: &str, input: serde_json::Value) -> anyhow::Result<Option<serde_json::Value>> {
+        tokio::time::sleep(self.task_delay).await;
+        match &self.reply {
+            Reply::Nothing => Ok(None),
+            Reply::Fixed(answer) => Ok(Some(answer.clone())),
+            Reply::Echo => Ok(Some(input)),
+            Reply::Transform(transform) => Ok(Some(transform(input))),
+            Reply::Fail => anyhow::bail!("task rejected"),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt::Write;
 
 /// Swarm topology defines how agents are connected and communicate
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -52,11 +53,12 @@ impl SwarmTopology {
                     }
                 }
                 
-                // Find level with room
+                // Find the least populated level with room, preferring shallower levels
                 let target_level = level_counts
                     .iter()
                     .enumerate()
-                    .find(|(_, &count)| count < *agents_per_level)
+                    .filter(|(_, &count)| count < *agents_per_level)
+                    .min_by_key(|(_, &count)| count)
                     .map(|(level, _)| level)
                     .unwrap_or(0);
                 
@@ -151,10 +153,79 @@ impl SwarmTopology {
             }
         }
     }
+    
+    /// Render placements as a Graphviz graph, labelling agents from `labels`.
+    /// Hierarchical swarms become a digraph with one cluster per level.
+    pub fn render_dot(
+        &self,
+        placements: &HashMap<Uuid, AgentPlacement>,
+        labels: &HashMap<Uuid, String>,
+    ) -> String {
+        let hierarchical = matches!(self, SwarmTopology::Hierarchical { .. });
+        let (kind, edge_op) = if hierarchical { ("digraph", "->") } else { ("graph", "--") };
+        
+        let mut ids: Vec<Uuid> = placements.keys().copied().collect();
+        ids.sort();
+        
+        let node = |id: &Uuid| {
+            let label = labels.get(id).map(String::as_str).unwrap_or("agent");
+            format!("\"{}\" [label=\"{}\\n{}\"];", id, label, &id.to_string()[..8])
+        };
+        
+        let mut dot = String::new();
+        let _ = writeln!(dot, "{} swarm {{", kind);
+        
+        if hierarchical {
+            let mut levels: BTreeMap<usize, Vec<Uuid>> = BTreeMap::new();
+            for id in &ids {
+                if let AgentPlacement::Hierarchical { level, .. } = &placements[id] {
+                    levels.entry(*level).or_default().push(*id);
+                }
+            }
+            for (level, members) in levels {
+                let _ = writeln!(dot, "    subgraph cluster_level_{} {{", level);
+                let _ = writeln!(dot, "        label=\"Level {}\";", level);
+                for id in &members {
+                    let _ = writeln!(dot, "        {}", node(id));
+                }
+                let _ = writeln!(dot, "    }}");
+            }
+        } else {
+            for id in &ids {
+                let _ = writeln!(dot, "    {}", node(id));
+            }
+        }
+        
+        // Each link once; hierarchical links always point from parent to child
+        let mut edges = BTreeSet::new();
+        for (id, placement) in placements {
+            match placement {
+                AgentPlacement::Hierarchical { parent, children, .. } => {
+                    if let Some(parent) = parent {
+                        edges.insert((*parent, *id));
+                    }
+                    edges.extend(children.iter().map(|child| (*id, *child)));
+                }
+                _ => {
+                    edges.extend(placement.connections().into_iter()
+                        .map(|other| if *id < other { (*id, other) } else { (other, *id) }));
+                }
+            }
+        }
+        for (from, to) in edges {
+            if placements.contains_key(&from) && placements.contains_key(&to) {
+                let _ = writeln!(dot, "    \"{}\" {} \"{}\";", from, edge_op, to);
+            }
+        }
+        
+        dot.push_str("}\n");
+        dot
+    }
 }
 
 /// Agent placement within the swarm topology
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type")]
 pub enum AgentPlacement {
     Mesh {
         connections: HashSet<Uuid>,
@@ -74,6 +74,7 @@ pub struct ProcessResult {
     pub output: String,
     pub pathways_activated: u32,
     pub agents_involved: Vec<String>,
+    pub activation_scores: HashMap<String, f64>,
     pub processing_time_ms: u32,
 }
 
@@ -87,14 +88,41 @@ pub struct MeshStatus {
     pub uptime_seconds: u32,
 }
 
+// Neural change reported to JavaScript listeners
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NeuralEvent {
+    PathwayCreated {
+        pathway_id: String,
+        source_node: String,
+        target_node: String,
+        strength: f64,
+    },
+    PathwayStrengthened {
+        pathway_id: String,
+        source_node: String,
+        target_node: String,
+        strength: f64,
+        delta: f64,
+    },
+}
+
+// Score an agent must exceed to be activated by user input
+const ACTIVATION_THRESHOLD: f64 = 1.0;
+
+// Weight contributed by each matched keyword
+const KEYWORD_MATCH_WEIGHT: f64 = 0.5;
+
 // Main AMOS client for WASM
 #[wasm_bindgen]
 pub struct AMOSClient {
     agents: HashMap<String, AgentInfo>,
+    agent_nodes: HashMap<String, Vec<String>>,
     pathways: HashMap<String, NeuralPathway>,
     nodes: HashMap<String, CognitiveNode>,
     hormone_levels: HashMap<String, f64>,
     start_time: f64,
+    neural_listeners: Vec<js_sys::Function>,
 }
 
 #[wasm_bindgen]
@@ -116,10 +144,12 @@ impl AMOSClient {
         
         Ok(AMOSClient {
             agents: HashMap::new(),
+            agent_nodes: HashMap::new(),
             pathways: HashMap::new(),
             nodes: HashMap::new(),
             hormone_levels,
             start_time: js_sys::Date::now(),
+            neural_listeners: Vec::new(),
         })
     }
     
@@ -189,9 +219,10 @@ impl AMOSClient {
         let mut activated_pathways = 0;
         let mut involved_agents = Vec::new();
         
-        // Activate relevant agents based on input
-        let agents_to_activate: Vec<String> = self.agents.iter()
-            .filter(|(_, agent)| self.should_activate_agent(&agent.agent_type, input))
+        // Activate agents whose pathway-weighted score clears the threshold
+        let activation_scores = self.score_agents(input);
+        let agents_to_activate: Vec<String> = activation_scores.iter()
+            .filter(|(_, score)| **score > ACTIVATION_THRESHOLD)
             .map(|(id, _)| id.clone())
             .collect();
         
@@ -209,6 +240,7 @@ impl AMOSClient {
             output,
             pathways_activated: activated_pathways,
             agents_involved: involved_agents,
+            activation_scores,
             processing_time_ms: processing_time,
         };
         
@@ -220,27 +252,51 @@ impl AMOSClient {
     pub fn strengthen_pathway(&mut self, source: &str, target: &str, delta: f64) -> Result<(), JsError> {
         let pathway_key = format!("{}->{}", source, target);
         
-        if let Some(pathway) = self.pathways.get_mut(&pathway_key) {
-            pathway.strength = (pathway.strength + delta).min(1.0);
+        let event = if let Some(pathway) = self.pathways.get_mut(&pathway_key) {
+            let previous = pathway.strength;
+            pathway.strength = (pathway.strength + delta).clamp(0.0, 1.0);
             pathway.usage_count += 1;
             log!("Strengthened pathway {} to {:.2}", pathway_key, pathway.strength);
+            
+            NeuralEvent::PathwayStrengthened {
+                pathway_id: pathway.id.clone(),
+                source_node: source.to_string(),
+                target_node: target.to_string(),
+                strength: pathway.strength,
+                delta: pathway.strength - previous,
+            }
         } else {
             // Create new pathway if it doesn't exist
             let pathway = NeuralPathway {
                 id: Uuid::new_v4().to_string(),
                 source_node: source.to_string(),
                 target_node: target.to_string(),
-                strength: delta.min(1.0),
+                strength: delta.clamp(0.0, 1.0),
                 usage_count: 1,
             };
             
+            let event = NeuralEvent::PathwayCreated {
+                pathway_id: pathway.id.clone(),
+                source_node: source.to_string(),
+                target_node: target.to_string(),
+                strength: pathway.strength,
+            };
+            
             self.pathways.insert(pathway_key.clone(), pathway);
             log!("Created new pathway {}", pathway_key);
-        }
+            event
+        };
         
+        self.emit_neural_event(&event);
         Ok(())
     }
     
+    // Call `callback` with each pathway creation or strengthening
+    #[wasm_bindgen(js_name = onNeuralEvent)]
+    pub fn on_neural_event(&mut self, callback: js_sys::Function) {
+        self.neural_listeners.push(callback);
+    }
+    
     // Trigger a hormonal burst
     #[wasm_bindgen(js_name = triggerHormonalBurst)]
     pub fn trigger_hormonal_burst(&mut self, hormone: HormoneType, intensity: f64) -> Result<(), JsError> {
@@ -345,7 +401,28 @@ impl AMOSClient {
 
 // Private implementation methods
 impl AMOSClient {
-    fn create_agent_nodes(&mut self, _agent_id: &str) -> Result<(), JsError> {
+    fn emit_neural_event(&self, event: &NeuralEvent) {
+        if self.neural_listeners.is_empty() {
+            return;
+        }
+        
+        let value = match to_value(event) {
+            Ok(value) => value,
+            Err(e) => {
+                log!("Failed to serialize neural event: {}", e);
+                return;
+            }
+        };
+        
+        // A throwing listener shouldn't stop the others or the mutation that triggered it
+        for listener in &self.neural_listeners {
+            if let Err(e) = listener.call1(&JsValue::NULL, &value) {
+                log!("Neural event listener failed: {:?}", e);
+            }
+        }
+    }
+    
+    fn create_agent_nodes(&mut self, agent_id: &str) -> Result<(), JsError> {
         // Create base nodes for the agent
         let memory_node = self.add_node("memory", JsValue::NULL)?;
         let thinking_node = self.add_node("thinking", JsValue::NULL)?;
@@ -355,34 +432,80 @@ impl AMOSClient {
         self.connect_nodes(&memory_node, &thinking_node, 0.5)?;
         self.connect_nodes(&thinking_node, &agent_node, 0.5)?;
         
+        self.agent_nodes.insert(
+            agent_id.to_string(),
+            vec![memory_node, thinking_node, agent_node],
+        );
+        
         Ok(())
     }
     
-    fn should_activate_agent(&self, agent_type: &AgentType, input: &str) -> bool {
+    fn agent_keywords(agent_type: &AgentType) -> &'static [&'static str] {
+        match agent_type {
+            AgentType::TrafficSeer => &["traffic", "flow"],
+            AgentType::PathwaySculptor => &["pathway", "connection"],
+            AgentType::MemoryWeaver => &["memory", "remember"],
+            AgentType::Architect => &["design", "architecture"],
+            AgentType::Builder => &["build", "create"],
+            AgentType::Critic => &["review", "quality"],
+            AgentType::Guardian => &["security", "protect"],
+            AgentType::Tester => &["test", "verify"],
+            AgentType::Optimizer => &["optimize", "performance"],
+            AgentType::Explorer => &["explore", "discover"],
+            AgentType::Coordinator => &["coordinate", "manage"],
+            AgentType::__Invalid => &[],
+        }
+    }
+    
+    fn keyword_match_weight(agent_type: &AgentType, input: &str) -> f64 {
         let input_lower = input.to_lowercase();
         
-        match agent_type {
-            AgentType::TrafficSeer => input_lower.contains("traffic") || input_lower.contains("flow"),
-            AgentType::PathwaySculptor => input_lower.contains("pathway") || input_lower.contains("connection"),
-            AgentType::MemoryWeaver => input_lower.contains("memory") || input_lower.contains("remember"),
-            AgentType::Architect => input_lower.contains("design") || input_lower.contains("architecture"),
-            AgentType::Builder => input_lower.contains("build") || input_lower.contains("create"),
-            AgentType::Critic => input_lower.contains("review") || input_lower.contains("quality"),
-            AgentType::Guardian => input_lower.contains("security") || input_lower.contains("protect"),
-            AgentType::Tester => input_lower.contains("test") || input_lower.contains("verify"),
-            AgentType::Optimizer => input_lower.contains("optimize") || input_lower.contains("performance"),
-            AgentType::Explorer => input_lower.contains("explore") || input_lower.contains("discover"),
-            AgentType::Coordinator => input_lower.contains("coordinate") || input_lower.contains("manage"),
-            AgentType::__Invalid => false,
+        let matches = Self::agent_keywords(agent_type)
+            .iter()
+            .filter(|keyword| input_lower.contains(*keyword))
+            .count();
+        
+        matches as f64 * KEYWORD_MATCH_WEIGHT
+    }
+    
+    // Mean strength of the pathways leaving the agent's own nodes
+    fn mean_outgoing_strength(&self, agent_id: &str) -> f64 {
+        let Some(node_ids) = self.agent_nodes.get(agent_id) else {
+            return 0.0;
+        };
+        
+        let strengths: Vec<f64> = self.pathways.values()
+            .filter(|pathway| node_ids.contains(&pathway.source_node))
+            .map(|pathway| pathway.strength)
+            .collect();
+        
+        if strengths.is_empty() {
+            0.0
+        } else {
+            strengths.iter().sum::<f64>() / strengths.len() as f64
         }
     }
     
-    fn activate_agent_pathways(&mut self, _agent_id: &str) -> Result<u32, JsError> {
+    fn score_agents(&self, input: &str) -> HashMap<String, f64> {
+        self.agents.iter()
+            .map(|(id, agent)| {
+                let keyword_weight = Self::keyword_match_weight(&agent.agent_type, input);
+                if keyword_weight == 0.0 {
+                    // Training alone never activates an agent on unrelated input
+                    return (id.clone(), 0.0);
+                }
+                (id.clone(), keyword_weight + self.mean_outgoing_strength(id))
+            })
+            .collect()
+    }
+    
+    fn activate_agent_pathways(&mut self, agent_id: &str) -> Result<u32, JsError> {
         let mut activated = 0;
+        let node_ids = self.agent_nodes.get(agent_id).cloned().unwrap_or_default();
         
-        // Simulate pathway activation
-        for (_, pathway) in self.pathways.iter_mut() {
-            if pathway.strength > 0.3 {
+        // Simulate activation of the agent's own pathways
+        for pathway in self.pathways.values_mut() {
+            if node_ids.contains(&pathway.source_node) && pathway.strength > 0.3 {
                 pathway.usage_count += 1;
                 activated += 1;
             }
@@ -391,21 +514,62 @@ impl AMOSClient {
         Ok(activated)
     }
     
+    // Position of an agent type's contribution in a composed response
+    fn response_order(agent_type: &AgentType) -> u8 {
+        match agent_type {
+            AgentType::Architect => 0,
+            AgentType::Explorer => 1,
+            AgentType::Coordinator => 2,
+            AgentType::TrafficSeer => 3,
+            AgentType::PathwaySculptor => 4,
+            AgentType::MemoryWeaver => 5,
+            AgentType::Builder => 6,
+            AgentType::Optimizer => 7,
+            AgentType::Tester => 8,
+            AgentType::Guardian => 9,
+            AgentType::Critic => 10,
+            AgentType::__Invalid => u8::MAX,
+        }
+    }
+    
+    fn response_fragment(agent: &AgentInfo, input: &str) -> String {
+        let name = &agent.name;
+        match agent.agent_type {
+            AgentType::Architect => format!("{} outlined a structure for '{}'.", name, input),
+            AgentType::Explorer => format!("{} surveyed alternative approaches.", name),
+            AgentType::Coordinator => format!("{} split the work between the agents involved.", name),
+            AgentType::TrafficSeer => format!("{} traced how signals flow through it.", name),
+            AgentType::PathwaySculptor => format!("{} reshaped the pathways it relies on.", name),
+            AgentType::MemoryWeaver => format!("{} recalled related context.", name),
+            AgentType::Builder => format!("{} drafted the artifacts it needs.", name),
+            AgentType::Optimizer => format!("{} pointed out the hot paths worth tuning.", name),
+            AgentType::Tester => format!("{} proposed checks to verify it.", name),
+            AgentType::Guardian => format!("{} screened it for security risks.", name),
+            AgentType::Critic => format!("Caveat from {}: this is a first pass and its assumptions still need review.", name),
+            AgentType::__Invalid => String::new(),
+        }
+    }
+    
     fn generate_response(&self, input: &str, involved_agents: &[String]) -> String {
         if involved_agents.is_empty() {
             return format!("Processed: '{}' (no specific agents activated)", input);
         }
         
-        let agent_names: Vec<String> = involved_agents.iter()
+        let mut agents: Vec<&AgentInfo> = involved_agents.iter()
             .filter_map(|id| self.agents.get(id))
-            .map(|agent| agent.name.clone())
+            .collect();
+        agents.sort_by(|a, b| {
+            Self::response_order(&a.agent_type).cmp(&Self::response_order(&b.agent_type))
+                .then_with(|| a.name.cmp(&b.name))
+                .then_with(|| a.id.cmp(&b.id))
+        });
+        
+        let fragments: Vec<String> = agents.iter()
+            .map(|agent| Self::response_fragment(agent, input))
+            .filter(|fragment| !fragment.is_empty())
             .collect();
         
-        format!(
-            "Processed: '{}' with assistance from: {}",
-            input,
-            agent_names.join(", ")
-        )
+        format!("Processed: '{}'. {}", input, fragments.join(" "))
     }
     
     fn apply_hormone_effects(&mut self, hormone: &str, intensity: f64) -> Result<(), JsError> {
@@ -485,4 +649,61 @@ mod tests {
         client.trigger_hormonal_burst(HormoneType::Dopamine, 0.3).unwrap();
         assert_eq!(*client.hormone_levels.get("Dopamine").unwrap(), 0.8);
     }
-}
\ No newline at end of file
+
+    #[wasm_bindgen_test]
+    fn test_trained_agent_activates_on_borderline_input() {
+        let mut client = AMOSClient::new().unwrap();
+        let trained = client.spawn_agent(AgentType::TrafficSeer).unwrap();
+        let untrained = client.spawn_agent(AgentType::TrafficSeer).unwrap();
+        
+        let nodes = client.agent_nodes.get(&trained).unwrap().clone();
+        client.strengthen_pathway(&nodes[0], &nodes[1], 0.3).unwrap();
+        client.strengthen_pathway(&nodes[1], &nodes[2], 0.3).unwrap();
+        
+        // A single keyword match is borderline on its own
+        let scores = client.score_agents("watch the traffic");
+        assert!(scores[&trained] > ACTIVATION_THRESHOLD);
+        assert!(scores[&untrained] <= ACTIVATION_THRESHOLD);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_neural_event_callback_sees_strengthening() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+        
+        let mut client = AMOSClient::new().unwrap();
+        let events: Rc<RefCell<Vec<JsValue>>> = Rc::new(RefCell::new(Vec::new()));
+        let sink = events.clone();
+        let callback = Closure::wrap(Box::new(move |event: JsValue| {
+            sink.borrow_mut().push(event);
+        }) as Box<dyn FnMut(JsValue)>);
+        client.on_neural_event(callback.as_ref().unchecked_ref::<js_sys::Function>().clone());
+        
+        let source = client.add_node("Thinking", JsValue::NULL).unwrap();
+        let target = client.add_node("Memory", JsValue::NULL).unwrap();
+        client.connect_nodes(&source, &target, 0.2).unwrap();
+        client.strengthen_pathway(&source, &target, 0.3).unwrap();
+        
+        let events = events.borrow();
+        assert_eq!(events.len(), 2);
+        let field = |event: &JsValue, name: &str| js_sys::Reflect::get(event, &name.into()).unwrap();
+        assert_eq!(field(&events[0], "type").as_string().unwrap(), "pathway_created");
+        assert_eq!(field(&events[1], "type").as_string().unwrap(), "pathway_strengthened");
+        assert_eq!(field(&events[1], "source_node").as_string().unwrap(), source);
+        assert!((field(&events[1], "delta").as_f64().unwrap() - 0.3).abs() < 1e-9);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_response_puts_architect_before_critic() {
+        let mut client = AMOSClient::new().unwrap();
+        let architect = client.spawn_agent(AgentType::Architect).unwrap();
+        let critic = client.spawn_agent(AgentType::Critic).unwrap();
+        
+        // One keyword each sits on the activation threshold, so involve both directly
+        let response = client.generate_response("design and review", &[critic, architect]);
+        
+        let architect_at = response.find("System Architect outlined").unwrap();
+        let critic_at = response.find("Caveat from Quality Critic").unwrap();
+        assert!(architect_at < critic_at, "{}", response);
+    }
+}
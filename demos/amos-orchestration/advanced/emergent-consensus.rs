@@ -8,7 +8,7 @@ use amos_agents::{
     consciousness_emergent::ConsciousnessEmergent,
 };
 use amos_core::{ForgeNeuralNetwork, EventBus, SystemEvent, HormonalState};
-use amos_swarm::{AmosSwarm, SwarmTopology, Task, TaskStrategy};
+use amos_swarm::{AmosSwarm, ConsensusAggregator, ConvergenceSchedule, SwarmTopology, Task, TaskStrategy};
 use std::sync::Arc;
 use std::collections::HashMap;
 use tracing::{info, warn, Level};
@@ -18,54 +18,6 @@ use tokio::time::{sleep, Duration};
 use rand::Rng;
 use dashmap::DashMap;
 
-// Consensus tracking structure
-#[derive(Debug, Clone)]
-struct ConsensusState {
-    proposals: HashMap<String, f64>,
-    votes: HashMap<uuid::Uuid, HashMap<String, f64>>,
-    convergence_threshold: f64,
-    rounds: usize,
-}
-
-impl ConsensusState {
-    fn new() -> Self {
-        Self {
-            proposals: HashMap::new(),
-            votes: HashMap::new(),
-            convergence_threshold: 0.8,
-            rounds: 0,
-        }
-    }
-
-    fn add_vote(&mut self, agent_id: uuid::Uuid, proposal: String, confidence: f64) {
-        self.votes.entry(agent_id)
-            .or_insert_with(HashMap::new)
-            .insert(proposal, confidence);
-    }
-
-    fn calculate_consensus(&self) -> Option<(String, f64)> {
-        let mut proposal_scores: HashMap<String, f64> = HashMap::new();
-        
-        // Aggregate votes
-        for agent_votes in self.votes.values() {
-            for (proposal, confidence) in agent_votes {
-                *proposal_scores.entry(proposal.clone()).or_insert(0.0) += confidence;
-            }
-        }
-        
-        // Normalize by number of agents
-        let agent_count = self.votes.len() as f64;
-        for score in proposal_scores.values_mut() {
-            *score /= agent_count;
-        }
-        
-        // Find highest scoring proposal
-        proposal_scores.into_iter()
-            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
-            .filter(|(_, score)| *score >= self.convergence_threshold)
-    }
-}
-
 #[tokio::main]
 async fn main() -> Result<()> {
     // Initialize logging
@@ -93,8 +45,11 @@ async fn main() -> Result<()> {
     
     info!("🔗 Created mesh swarm for distributed consensus");
 
-    // Shared consensus state
-    let consensus_state = Arc::new(tokio::sync::RwLock::new(ConsensusState::new()));
+    // Shared consensus state; the bar for agreement lowers a little each round
+    let schedule = ConvergenceSchedule { initial: 0.8, floor: 0.5, decay_per_round: 0.05 };
+    let consensus_state = Arc::new(tokio::sync::RwLock::new(
+        ConsensusAggregator::default().with_schedule(schedule)
+    ));
     
     // Spawn diverse agents for different perspectives
     info!("🚀 Spawning diverse cognitive agents...");
@@ -181,13 +136,11 @@ async fn main() -> Result<()> {
         info!("🎯 Scenario: {}", scenario_name);
         info!("📋 Proposals: {:?}", proposals);
         
-        // Reset consensus state
+        // Reset votes and the annealed threshold, keeping agent weights learned in earlier scenarios
         {
             let mut state = consensus_state.write().await;
-            *state = ConsensusState::new();
-            for proposal in &proposals {
-                state.proposals.insert(proposal.to_string(), 0.0);
-            }
+            state.clear_votes();
+            *state = state.clone().with_schedule(schedule);
         }
         
         // Create consensus task
@@ -211,7 +164,7 @@ async fn main() -> Result<()> {
         
         // Simulate multiple rounds of voting
         let mut consensus_reached = false;
-        let max_rounds = 5;
+        let max_rounds = schedule.rounds_to_floor() + 1;
         
         for round in 1..=max_rounds {
             info!("🔄 Consensus Round {}/{}", round, max_rounds);
@@ -282,12 +235,15 @@ async fn main() -> Result<()> {
             }
             
             // Check for consensus
-            let state = consensus_state.read().await;
+            let mut state = consensus_state.write().await;
             if let Some((winning_proposal, confidence)) = state.calculate_consensus() {
                 info!("✅ Consensus reached! Proposal: '{}' with {:.2}% agreement", 
                       winning_proposal, confidence * 100.0);
                 consensus_reached = true;
                 
+                // Agents that backed the winner count for more next time
+                state.update_weights_from_outcome(&winning_proposal, 0.1);
+                
                 // Trigger neural synchronization event
                 event_bus.publish(SystemEvent::NeuralSynchronization {
                     sync_level: confidence,
@@ -299,17 +255,7 @@ async fn main() -> Result<()> {
                 info!("❌ No consensus yet. Continuing deliberation...");
                 
                 // Show current standings
-                let mut proposal_scores: HashMap<String, f64> = HashMap::new();
-                for agent_votes in state.votes.values() {
-                    for (proposal, confidence) in agent_votes {
-                        *proposal_scores.entry(proposal.clone()).or_insert(0.0) += confidence;
-                    }
-                }
-                
-                let agent_count = state.votes.len() as f64;
-                let mut sorted_proposals: Vec<_> = proposal_scores.into_iter()
-                    .map(|(p, s)| (p, s / agent_count))
-                    .collect();
+                let mut sorted_proposals: Vec<_> = state.proposal_scores().into_iter().collect();
                 sorted_proposals.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
                 
                 for (proposal, score) in sorted_proposals.iter().take(3) {
@@ -317,11 +263,15 @@ async fn main() -> Result<()> {
                 }
             }
             
+            let threshold = state.next_round();
+            drop(state);
+            info!("   Agreement threshold lowered to {:.2}%", threshold * 100.0);
+            
             // Neural adaptation between rounds
             sleep(Duration::from_millis(500)).await;
             
             // Stimulate neural network for better synchronization
-            neural_network.stimulate_region("consensus_cortex", round as f64 * 0.2);
+            neural_network.stimulate_region("consensus_cortex", round as f64 * 0.2).await;
         }
         
         if !consensus_reached {
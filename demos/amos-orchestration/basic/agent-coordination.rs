@@ -38,7 +38,7 @@ async fn main() -> Result<()> {
             agents_per_level: 4 
         },
         neural_network.clone(),
-    );
+    ).with_event_bus(event_bus.clone()).await;
     info!("🏗️ Created hierarchical swarm structure");
 
     // Spawn coordinator (Mesh Harmonizer at top level)
@@ -180,17 +180,12 @@ async fn main() -> Result<()> {
     // Demonstrate agent failure and recovery
     info!("⚠️ Simulating agent failure and coordination recovery...");
     
-    // Remove an agent to simulate failure
+    // Remove an agent to simulate failure; the swarm announces the departure
     swarm.remove_agent(memory_id).await?;
     warn!("Memory Weaver went offline!");
     
     sleep(Duration::from_millis(500)).await;
     
-    // Other agents should adapt
-    event_bus.publish(SystemEvent::AgentDeactivated {
-        agent_id: memory_id,
-    }).await;
-    
     // Test coordination with reduced capacity
     let recovery_task = Task {
         id: uuid::Uuid::new_v4(),